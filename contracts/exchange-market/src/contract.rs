@@ -0,0 +1,154 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cw2::set_contract_version;
+
+use crate::{
+    error::ContractError,
+    execute::{
+        execute_cancel, execute_cancel_order, execute_claim_winnings, execute_expire_market,
+        execute_place_order, execute_score, execute_update,
+    },
+    msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, UpdateParams},
+    queries::{
+        query_config, query_market, query_order, query_order_book, query_orders_by_address,
+        query_positions_by_address,
+    },
+    state::{Config, Market, Status, CONFIG, MARKET, ORDER_COUNT},
+    validation::{
+        validate_commission_bps, validate_resolution_grace_period, DEFAULT_RESOLUTION_GRACE_PERIOD,
+    },
+};
+
+pub const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    validate_commission_bps(msg.commission_bps)?;
+
+    let resolution_grace_period = msg
+        .resolution_grace_period
+        .unwrap_or(DEFAULT_RESOLUTION_GRACE_PERIOD);
+    validate_resolution_grace_period(resolution_grace_period)?;
+
+    set_contract_version(
+        deps.storage,
+        format!("crates.io:{CONTRACT_NAME}"),
+        CONTRACT_VERSION,
+    )?;
+
+    let state = Config {
+        admin_addr: msg.admin_addr.clone(),
+        treasury_addr: msg.treasury_addr.clone(),
+        commission_bps: msg.commission_bps,
+        denom: msg.denom.clone(),
+        denom_precision: msg.denom_precision,
+        resolution_grace_period,
+    };
+    CONFIG.save(deps.storage, &state)?;
+
+    let market = Market {
+        id: msg.id,
+        label: msg.label,
+        home_team: msg.home_team,
+        away_team: msg.away_team,
+        start_timestamp: msg.start_timestamp,
+        resolution_deadline: msg
+            .start_timestamp
+            .checked_add(resolution_grace_period)
+            .ok_or(ContractError::InvalidTimestamp(msg.start_timestamp))?,
+        status: Status::ACTIVE,
+        result: None,
+        is_drawable: msg.is_drawable,
+    };
+    MARKET.save(deps.storage, &market)?;
+
+    ORDER_COUNT.save(deps.storage, &0)?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "exchange")
+        .add_attribute("action", "create_market")
+        .add_attribute("sender", info.sender)
+        .add_attribute("admin_addr", msg.admin_addr)
+        .add_attribute("treasury_addr", msg.treasury_addr)
+        .add_attribute("denom", msg.denom)
+        .add_attribute("denom_precision", msg.denom_precision.to_string())
+        .add_attribute("commission_bps", msg.commission_bps.to_string())
+        .add_attribute("id", market.id)
+        .add_attribute("label", market.label)
+        .add_attribute("home_team", market.home_team)
+        .add_attribute("away_team", market.away_team)
+        .add_attribute("start_timestamp", market.start_timestamp.to_string())
+        .add_attribute(
+            "resolution_deadline",
+            market.resolution_deadline.to_string(),
+        )
+        .add_attribute("is_drawable", msg.is_drawable.to_string())
+        .add_attribute("status", Status::ACTIVE.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::Market {} => to_json_binary(&query_market(deps)?),
+        QueryMsg::Order { id } => to_json_binary(&query_order(deps, id)?),
+        QueryMsg::OrdersByAddress {
+            address,
+            start_after,
+            limit,
+        } => to_json_binary(&query_orders_by_address(deps, address, start_after, limit)?),
+        QueryMsg::OrderBook { result, limit } => {
+            to_json_binary(&query_order_book(deps, result, limit)?)
+        }
+        QueryMsg::PositionsByAddress { address } => {
+            to_json_binary(&query_positions_by_address(deps, address)?)
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::PlaceOrder { result, side, odds } => {
+            execute_place_order(deps, env, info, result, side, odds)
+        }
+        ExecuteMsg::CancelOrder { id } => execute_cancel_order(deps, info, id),
+        ExecuteMsg::ClaimWinnings { receiver } => execute_claim_winnings(deps, info, receiver),
+        ExecuteMsg::ExpireMarket {} => execute_expire_market(deps, env, info),
+        ExecuteMsg::Update {
+            admin_addr,
+            treasury_addr,
+            commission_bps,
+            start_timestamp,
+        } => execute_update(
+            deps,
+            info,
+            UpdateParams {
+                admin_addr,
+                treasury_addr,
+                commission_bps,
+                start_timestamp,
+            },
+        ),
+        ExecuteMsg::Score { result } => execute_score(deps, env, info, result),
+        ExecuteMsg::Cancel {} => execute_cancel(deps, info),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    Ok(Response::default())
+}
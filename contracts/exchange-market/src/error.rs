@@ -0,0 +1,56 @@
+use cosmwasm_std::{Decimal, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Invalid commission bps: {0}")]
+    InvalidCommissionBps(u64),
+
+    #[error("Invalid resolution grace period: {0}")]
+    InvalidResolutionGracePeriod(u64),
+
+    #[error("Invalid timestamp: {0}")]
+    InvalidTimestamp(u64),
+
+    #[error("Invalid odds: {0}")]
+    InvalidOdds(Decimal),
+
+    #[error("Invalid stake")]
+    InvalidStake {},
+
+    #[error("Market not active")]
+    MarketNotActive {},
+
+    #[error("Market not closed")]
+    MarketNotClosed {},
+
+    #[error("Market not drawable")]
+    MarketNotDrawable {},
+
+    #[error("Market not scoreable")]
+    MarketNotScoreable {},
+
+    #[error("Market not expired")]
+    MarketNotExpired {},
+
+    #[error("Bets no longer accepted")]
+    BetsNotAccepted {},
+
+    #[error("Payment error")]
+    PaymentError {},
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Order not open")]
+    OrderNotOpen {},
+
+    #[error("Claim already made")]
+    ClaimAlreadyMade {},
+
+    #[error("No winnings")]
+    NoWinnings {},
+}
@@ -0,0 +1,582 @@
+use cosmwasm_std::{
+    coin, Addr, BankMsg, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Order as StorageOrder,
+    Response, StdResult, Storage,
+};
+use cw_storage_plus::{Bound, Map};
+
+use crate::{
+    error::ContractError,
+    logic::{
+        calculate_bets_cutoff, calculate_commission, calculate_lay_stake, calculate_liability,
+        calculate_position_payout,
+    },
+    msg::UpdateParams,
+    state::{
+        book_for, book_price, MarketResult, Order, OrderStatus, Position, Side, Status, CLAIMS,
+        CONFIG, MARKET, OPEN_ORDERS_BY_OWNER, ORDERS, ORDERS_BY_OWNER, ORDER_COUNT, POSITIONS_AWAY,
+        POSITIONS_DRAW, POSITIONS_HOME,
+    },
+    validation::{
+        validate_commission_bps, validate_market_result, validate_odds, validate_order_stake,
+    },
+};
+
+/// Max number of resting orders matched by a single order, the rest of its stake stays on the book
+pub const MAX_ORDER_MATCHES: u32 = 30;
+
+/// Places a back or lay limit order on a result of the market and matches it
+/// against the opposite orders of the book
+///
+/// A back order stakes the funds sent, a lay order covers with the funds sent the liability
+/// of the backer stake, see `calculate_lay_stake`.
+///
+/// The order is matched against the best opposite orders first, the highest lay odds for a back
+/// order and the lowest back odds for a lay order, then the oldest orders at the same odds.
+/// Each match is made at the odds of the resting order and the unmatched stake stays on the book,
+/// as well as the stake left once `MAX_ORDER_MATCHES` resting orders were matched.
+/// The funds left once an order is filled are returned to its owner.
+///
+/// It will make the following checks:
+/// - If the order is on draw, the market needs to be drawable
+/// - The odds need to be greater than 1 and up to 1000, with up to 2 decimal places
+/// - The market needs to be active
+/// - The current block timestamp needs to be at least 5 minutes before the start timestamp
+/// - The funds need to be sent in the denom of the market
+/// - The stake of the order needs to be at least 0.01 of the denom
+pub fn execute_place_order(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    result: MarketResult,
+    side: Side,
+    odds: Decimal,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
+
+    validate_market_result(&result, market.is_drawable)?;
+    validate_odds(odds)?;
+
+    if market.status != Status::ACTIVE {
+        return Err(ContractError::MarketNotActive {});
+    }
+
+    // Orders are accepted up until 5 minutes before the start of the match
+    if calculate_bets_cutoff(market.start_timestamp)? < env.block.time.seconds() {
+        return Err(ContractError::BetsNotAccepted {});
+    }
+
+    let funds = cw_utils::must_pay(&info, &config.denom);
+    if funds.is_err() {
+        return Err(ContractError::PaymentError {});
+    }
+    let funds = funds.unwrap().u128();
+
+    let stake = match side {
+        Side::BACK => funds,
+        Side::LAY => calculate_lay_stake(funds, odds),
+    };
+    validate_order_stake(stake, config.denom_precision)?;
+
+    let id = ORDER_COUNT.load(deps.storage)? + 1;
+    ORDER_COUNT.save(deps.storage, &id)?;
+
+    let mut order = Order {
+        id,
+        owner: info.sender.clone(),
+        result: result.clone(),
+        side: side.clone(),
+        odds,
+        stake,
+        matched_stake: 0,
+        remaining_funds: funds,
+        status: OrderStatus::OPEN,
+        placed_at: env.block.time.seconds(),
+    };
+
+    // The opposite orders are matched from the best price of the book up to the odds of the order,
+    // each match fills either the resting order, which leaves the book, or the order
+    let book = book_for(&result);
+    let opposite_side = side.opposite();
+    let max_price = Bound::inclusive((book_price(&opposite_side, odds), u64::MAX));
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let mut matched_stake = 0;
+    let mut matches = 0;
+    while order.unmatched_stake() > 0 && matches < MAX_ORDER_MATCHES {
+        let best = book
+            .sub_prefix(opposite_side.as_str())
+            .keys(
+                deps.storage,
+                None,
+                Some(max_price.clone()),
+                StorageOrder::Ascending,
+            )
+            .next()
+            .transpose()?;
+        let mut resting_order = match best {
+            Some((_, id)) => ORDERS.load(deps.storage, id)?,
+            None => break,
+        };
+
+        let stake = order.unmatched_stake().min(resting_order.unmatched_stake());
+        let liability = calculate_liability(stake, resting_order.odds);
+
+        let (backer, layer) = match side {
+            Side::BACK => (&mut order, &mut resting_order),
+            Side::LAY => (&mut resting_order, &mut order),
+        };
+        backer.matched_stake += stake;
+        backer.remaining_funds -= stake;
+        layer.matched_stake += stake;
+        layer.remaining_funds -= liability;
+
+        let positions = positions_for(&result);
+        positions.update(
+            deps.storage,
+            backer.owner.clone(),
+            |position| -> StdResult<_> {
+                let mut position = position.unwrap_or_default();
+                position.back_stake += stake;
+                position.back_profit += liability;
+                Ok(position)
+            },
+        )?;
+        positions.update(
+            deps.storage,
+            layer.owner.clone(),
+            |position| -> StdResult<_> {
+                let mut position = position.unwrap_or_default();
+                position.lay_stake += stake;
+                position.lay_liability += liability;
+                Ok(position)
+            },
+        )?;
+        matched_stake += stake;
+        matches += 1;
+
+        if let Some(message) = fill_order(&mut resting_order, &config.denom) {
+            messages.push(message);
+        }
+        if resting_order.status == OrderStatus::FILLED {
+            remove_open_order(deps.storage, &resting_order);
+        }
+        ORDERS.save(deps.storage, resting_order.id, &resting_order)?;
+    }
+
+    if let Some(message) = fill_order(&mut order, &config.denom) {
+        messages.push(message);
+    }
+    ORDERS.save(deps.storage, order.id, &order)?;
+    ORDERS_BY_OWNER.save(deps.storage, (order.owner.clone(), order.id), &())?;
+    if order.status == OrderStatus::OPEN {
+        book.save(
+            deps.storage,
+            (side.as_str(), book_price(&side, odds), order.id),
+            &(),
+        )?;
+        OPEN_ORDERS_BY_OWNER.save(deps.storage, (order.owner.clone(), order.id), &())?;
+    }
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "exchange")
+        .add_attribute("action", "place_order")
+        .add_attribute("sender", info.sender)
+        .add_attribute("id", order.id.to_string())
+        .add_attribute("result", result.to_string())
+        .add_attribute("side", side.to_string())
+        .add_attribute("odds", odds.to_string())
+        .add_attribute("stake", stake.to_string())
+        .add_attribute("matched_stake", matched_stake.to_string())
+        .add_attribute("status", order.status.to_string()))
+}
+
+/// Cancels the unmatched stake of an order and returns its remaining funds,
+/// the matched stake of the order stays as bets on the market
+///
+/// The unmatched orders can no longer be matched after the cutoff,
+/// they can be cancelled at any time or are refunded when claiming the winnings.
+///
+/// It will make the following checks:
+/// - The sender needs to be the owner of the order
+/// - The order needs to be open
+pub fn execute_cancel_order(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut order = ORDERS.load(deps.storage, id)?;
+
+    if order.owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if order.status != OrderStatus::OPEN {
+        return Err(ContractError::OrderNotOpen {});
+    }
+
+    let refund = order.remaining_funds;
+    order.remaining_funds = 0;
+    order.status = OrderStatus::CANCELLED;
+    ORDERS.save(deps.storage, id, &order)?;
+    remove_open_order(deps.storage, &order);
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    if refund > 0 {
+        messages.push(
+            BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: vec![coin(refund, config.denom)],
+            }
+            .into(),
+        );
+    }
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "exchange")
+        .add_attribute("action", "cancel_order")
+        .add_attribute("sender", info.sender)
+        .add_attribute("id", id.to_string())
+        .add_attribute("matched_stake", order.matched_stake.to_string())
+        .add_attribute("refund", refund.to_string()))
+}
+
+/// Claims winnings for the sender or the receiver if defined or returns all bets
+/// made if the market was cancelled, the unmatched orders of the address are
+/// cancelled and their remaining funds are returned as well
+///
+/// The commission is charged on the net winnings of the address over all its bets
+/// and sent to the treasury, see `calculate_commission`.
+///
+/// It will make the following checks:
+/// - The market needs to be closed
+/// - The address can't have claimed already
+/// - The address needs to have some amount to claim
+pub fn execute_claim_winnings(
+    deps: DepsMut,
+    info: MessageInfo,
+    receiver: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
+
+    let addr = match receiver {
+        Some(receiver) => deps.api.addr_validate(receiver.as_str())?,
+        None => info.sender.clone(),
+    };
+
+    if market.status == Status::ACTIVE {
+        return Err(ContractError::MarketNotClosed {});
+    }
+
+    if CLAIMS.has(deps.storage, addr.clone()) {
+        return Err(ContractError::ClaimAlreadyMade {});
+    }
+
+    let unmatched_funds = cancel_open_orders(deps.storage, &addr)?;
+
+    let mut payout = 0;
+    let mut risked = 0;
+    for result in [MarketResult::HOME, MarketResult::AWAY, MarketResult::DRAW] {
+        let position = positions_for(&result)
+            .may_load(deps.storage, addr.clone())?
+            .unwrap_or_default();
+        risked += position.back_stake + position.lay_liability;
+        if let Some(market_result) = &market.result {
+            payout += calculate_position_payout(&position, *market_result == result);
+        }
+    }
+
+    let mut commission = 0;
+    if market.status == Status::CANCELLED {
+        payout = risked;
+    } else {
+        commission = calculate_commission(payout, risked, config.commission_bps);
+        payout -= commission;
+    }
+
+    if payout + unmatched_funds == 0 {
+        return Err(ContractError::NoWinnings {});
+    }
+
+    let mut messages: Vec<CosmosMsg> = vec![BankMsg::Send {
+        to_address: addr.to_string(),
+        amount: vec![coin(payout + unmatched_funds, &config.denom)],
+    }
+    .into()];
+    if commission > 0 {
+        messages.push(
+            BankMsg::Send {
+                to_address: config.treasury_addr.to_string(),
+                amount: vec![coin(commission, &config.denom)],
+            }
+            .into(),
+        );
+    }
+
+    CLAIMS.save(deps.storage, addr.clone(), &true)?;
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "exchange")
+        .add_attribute("action", "claim_winnings")
+        .add_attribute("sender", info.sender)
+        .add_attribute("receiver", addr)
+        .add_attribute("payout", payout.to_string())
+        .add_attribute("commission", commission.to_string())
+        .add_attribute("unmatched_funds", unmatched_funds.to_string()))
+}
+
+/// Updates the market config and start timestamp
+///
+/// It will make the following checks:
+/// - The sender needs to be the admin
+/// - The market needs to be active
+/// - The commission bps needs to be valid
+pub fn execute_update(
+    deps: DepsMut,
+    info: MessageInfo,
+    params: UpdateParams,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    let mut market = MARKET.load(deps.storage)?;
+
+    if info.sender != config.admin_addr {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if market.status != Status::ACTIVE {
+        return Err(ContractError::MarketNotActive {});
+    }
+
+    let mut admin_addr_update = String::default();
+    if let Some(admin_addr) = params.admin_addr {
+        config.admin_addr = admin_addr.clone();
+        admin_addr_update = admin_addr.to_string();
+    }
+
+    let mut treasury_addr_update = String::default();
+    if let Some(treasury_addr) = params.treasury_addr {
+        config.treasury_addr = treasury_addr.clone();
+        treasury_addr_update = treasury_addr.to_string();
+    }
+
+    let mut commission_bps_update = String::default();
+    if let Some(commission_bps) = params.commission_bps {
+        validate_commission_bps(commission_bps)?;
+        config.commission_bps = commission_bps;
+        commission_bps_update = commission_bps.to_string();
+    }
+
+    let mut start_timestamp_update = String::default();
+    if let Some(start_timestamp) = params.start_timestamp {
+        market.start_timestamp = start_timestamp;
+        market.resolution_deadline = start_timestamp
+            .checked_add(config.resolution_grace_period)
+            .ok_or(ContractError::InvalidTimestamp(start_timestamp))?;
+        start_timestamp_update = start_timestamp.to_string();
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+    MARKET.save(deps.storage, &market)?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "exchange")
+        .add_attribute("action", "update_market")
+        .add_attribute("sender", info.sender)
+        .add_attribute("admin_addr", admin_addr_update)
+        .add_attribute("treasury_addr", treasury_addr_update)
+        .add_attribute("commission_bps", commission_bps_update)
+        .add_attribute("start_timestamp", start_timestamp_update))
+}
+
+/// Scores the market with the result of the match
+///
+/// The winnings are paid out when each address claims them, see `execute_claim_winnings`.
+///
+/// It will make the following checks:
+/// - The sender needs to be the admin
+/// - If the result is draw, the market needs to be drawable
+/// - The market needs to be active
+/// - The current block timestamp needs to be at least 30 minutes after the start timestamp
+pub fn execute_score(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    result: MarketResult,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut market = MARKET.load(deps.storage)?;
+
+    if info.sender != config.admin_addr {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    validate_market_result(&result, market.is_drawable)?;
+
+    if market.status != Status::ACTIVE {
+        return Err(ContractError::MarketNotActive {});
+    }
+
+    // Market can only be scored after 30 minutes of its start timestamp
+    let scoring_timestamp = market
+        .start_timestamp
+        .checked_add(30 * 60)
+        .ok_or(ContractError::InvalidTimestamp(market.start_timestamp))?;
+    if env.block.time.seconds() < scoring_timestamp {
+        return Err(ContractError::MarketNotScoreable {});
+    }
+
+    market.status = Status::CLOSED;
+    market.result = Some(result.clone());
+    MARKET.save(deps.storage, &market)?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "exchange")
+        .add_attribute("action", "score_market")
+        .add_attribute("sender", info.sender)
+        .add_attribute("status", Status::CLOSED.to_string())
+        .add_attribute("result", result.to_string()))
+}
+
+/// Cancels the market, all the bets and unmatched orders are returned when claiming
+///
+/// It will make the following checks:
+/// - The sender needs to be the admin
+/// - The market needs to be active
+pub fn execute_cancel(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
+
+    if info.sender != config.admin_addr {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if market.status != Status::ACTIVE {
+        return Err(ContractError::MarketNotActive {});
+    }
+
+    MARKET.update(deps.storage, |mut market| -> Result<_, ContractError> {
+        market.status = Status::CANCELLED;
+        Ok(market)
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "exchange")
+        .add_attribute("action", "cancel_market")
+        .add_attribute("sender", info.sender)
+        .add_attribute("status", Status::CANCELLED.to_string()))
+}
+
+/// Cancels the market once the resolution deadline has passed without it being scored,
+/// all the bets and unmatched orders are returned when claiming
+///
+/// Anyone can expire the market so funds are never stuck if the admin is gone.
+///
+/// It will make the following checks:
+/// - The market needs to be active
+/// - The current block timestamp needs to be after the resolution deadline
+pub fn execute_expire_market(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let market = MARKET.load(deps.storage)?;
+
+    if market.status != Status::ACTIVE {
+        return Err(ContractError::MarketNotActive {});
+    }
+
+    if env.block.time.seconds() < market.resolution_deadline {
+        return Err(ContractError::MarketNotExpired {});
+    }
+
+    MARKET.update(deps.storage, |mut market| -> Result<_, ContractError> {
+        market.status = Status::CANCELLED;
+        Ok(market)
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "exchange")
+        .add_attribute("action", "expire_market")
+        .add_attribute("sender", info.sender)
+        .add_attribute("status", Status::CANCELLED.to_string())
+        .add_attribute(
+            "resolution_deadline",
+            market.resolution_deadline.to_string(),
+        ))
+}
+
+fn positions_for(result: &MarketResult) -> Map<Addr, Position> {
+    match result {
+        MarketResult::HOME => POSITIONS_HOME,
+        MarketResult::AWAY => POSITIONS_AWAY,
+        MarketResult::DRAW => POSITIONS_DRAW,
+    }
+}
+
+/// Marks the order as filled once its whole stake is matched and returns
+/// the message sending the funds left to its owner
+fn fill_order(order: &mut Order, denom: &str) -> Option<CosmosMsg> {
+    if order.unmatched_stake() > 0 {
+        return None;
+    }
+
+    order.status = OrderStatus::FILLED;
+    if order.remaining_funds == 0 {
+        return None;
+    }
+
+    let funds = order.remaining_funds;
+    order.remaining_funds = 0;
+    Some(
+        BankMsg::Send {
+            to_address: order.owner.to_string(),
+            amount: vec![coin(funds, denom)],
+        }
+        .into(),
+    )
+}
+
+/// Cancels the open orders of the address and returns their remaining funds
+fn cancel_open_orders(storage: &mut dyn Storage, addr: &Addr) -> StdResult<u128> {
+    let open_order_ids: Vec<u64> = OPEN_ORDERS_BY_OWNER
+        .prefix(addr.clone())
+        .keys(storage, None, None, StorageOrder::Ascending)
+        .collect::<StdResult<Vec<u64>>>()?;
+
+    let mut unmatched_funds = 0;
+    for id in open_order_ids {
+        let mut order = ORDERS.load(storage, id)?;
+        unmatched_funds += order.remaining_funds;
+        order.remaining_funds = 0;
+        order.status = OrderStatus::CANCELLED;
+        ORDERS.save(storage, order.id, &order)?;
+        remove_open_order(storage, &order);
+    }
+
+    Ok(unmatched_funds)
+}
+
+/// Removes the order from the book and from the open orders of its owner
+fn remove_open_order(storage: &mut dyn Storage, order: &Order) {
+    book_for(&order.result).remove(
+        storage,
+        (
+            order.side.as_str(),
+            book_price(&order.side, order.odds),
+            order.id,
+        ),
+    );
+    OPEN_ORDERS_BY_OWNER.remove(storage, (order.owner.clone(), order.id));
+}
@@ -0,0 +1,8 @@
+pub mod contract;
+pub mod error;
+pub mod execute;
+mod logic;
+pub mod msg;
+pub mod queries;
+pub mod state;
+mod validation;
@@ -0,0 +1,159 @@
+use cosmwasm_std::{Decimal, Fraction, Uint128};
+
+use crate::error::ContractError;
+use crate::state::Position;
+
+/// Calculates the timestamp after which the orders are no longer accepted,
+/// 5 minutes before the start of the match
+///
+/// Fails when the start timestamp is too small to leave room for the cutoff
+pub fn calculate_bets_cutoff(start_timestamp: u64) -> Result<u64, ContractError> {
+    start_timestamp
+        .checked_sub(5 * 60)
+        .ok_or(ContractError::InvalidTimestamp(start_timestamp))
+}
+
+/// Calculates the liability of the layer for a matched backer stake
+///
+/// `liability = stake * (odds - 1)`
+pub fn calculate_liability(stake: u128, odds: Decimal) -> u128 {
+    Uint128::from(stake).mul_floor(odds - Decimal::one()).u128()
+}
+
+/// Calculates the backer stake covered by the funds of a lay order
+///
+/// `stake = funds / (odds - 1)`
+pub fn calculate_lay_stake(funds: u128, odds: Decimal) -> u128 {
+    let profit_odds = odds - Decimal::one();
+    Uint128::from(funds)
+        .multiply_ratio(profit_odds.denominator(), profit_odds.numerator())
+        .u128()
+}
+
+/// Calculates the payout of a position once the market is scored
+///
+/// The backer gets its stake and profit back when the result wins,
+/// the layer gets its liability and the backer stake when it doesn't.
+pub fn calculate_position_payout(position: &Position, is_winning_result: bool) -> u128 {
+    if is_winning_result {
+        position.back_stake + position.back_profit
+    } else {
+        position.lay_stake + position.lay_liability
+    }
+}
+
+/// Calculates the commission on the net winnings of an address over all its positions
+///
+/// `commission = max(payout - risked, 0) * commission_bps / 10000`
+pub fn calculate_commission(payout: u128, risked: u128, commission_bps: u64) -> u128 {
+    if payout <= risked {
+        return 0;
+    }
+
+    Uint128::from(payout - risked)
+        .multiply_ratio(commission_bps, 10000_u128)
+        .u128()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ContractError;
+    use crate::logic::{
+        calculate_bets_cutoff, calculate_commission, calculate_lay_stake, calculate_liability,
+        calculate_position_payout,
+    };
+    use crate::state::Position;
+    use cosmwasm_std::Decimal;
+
+    mod calculate_bets_cutoff {
+        use super::*;
+
+        #[test]
+        fn it_calculates_the_bets_cutoff() {
+            assert_eq!(
+                1_000_000 - 5 * 60,
+                calculate_bets_cutoff(1_000_000).unwrap()
+            );
+            assert_eq!(0, calculate_bets_cutoff(5 * 60).unwrap());
+        }
+
+        #[test]
+        fn it_fails_when_the_start_timestamp_is_before_the_cutoff() {
+            assert_eq!(
+                ContractError::InvalidTimestamp(60),
+                calculate_bets_cutoff(60).unwrap_err()
+            );
+        }
+    }
+
+    mod calculate_liability {
+        use super::*;
+
+        #[test]
+        fn it_calculates_the_liability_of_the_backer_stake() {
+            assert_eq!(
+                15_000_000,
+                calculate_liability(10_000_000, Decimal::from_atomics(25_u128, 1).unwrap())
+            );
+            assert_eq!(
+                910_000,
+                calculate_liability(1_000_000, Decimal::from_atomics(191_u128, 2).unwrap())
+            );
+            assert_eq!(
+                0,
+                calculate_liability(1, Decimal::from_atomics(15_u128, 1).unwrap())
+            );
+        }
+    }
+
+    mod calculate_lay_stake {
+        use super::*;
+
+        #[test]
+        fn it_calculates_the_backer_stake_covered_by_the_funds() {
+            assert_eq!(
+                10_000_000,
+                calculate_lay_stake(15_000_000, Decimal::from_atomics(25_u128, 1).unwrap())
+            );
+            assert_eq!(
+                1_098_901,
+                calculate_lay_stake(1_000_000, Decimal::from_atomics(191_u128, 2).unwrap())
+            );
+        }
+
+        #[test]
+        fn the_liability_of_the_stake_is_covered_by_the_funds() {
+            let odds = Decimal::from_atomics(191_u128, 2).unwrap();
+            let stake = calculate_lay_stake(1_000_000, odds);
+            assert!(calculate_liability(stake, odds) <= 1_000_000);
+        }
+    }
+
+    mod calculate_position_payout {
+        use super::*;
+
+        #[test]
+        fn it_pays_the_backer_when_the_result_wins_and_the_layer_otherwise() {
+            let position = Position {
+                back_stake: 10_000_000,
+                back_profit: 15_000_000,
+                lay_stake: 4_000_000,
+                lay_liability: 2_000_000,
+            };
+            assert_eq!(25_000_000, calculate_position_payout(&position, true));
+            assert_eq!(6_000_000, calculate_position_payout(&position, false));
+        }
+    }
+
+    mod calculate_commission {
+        use super::*;
+
+        #[test]
+        fn it_charges_the_commission_on_the_net_winnings() {
+            assert_eq!(750_000, calculate_commission(25_000_000, 10_000_000, 500));
+            assert_eq!(0, calculate_commission(10_000_000, 10_000_000, 500));
+            assert_eq!(0, calculate_commission(5_000_000, 10_000_000, 500));
+            assert_eq!(0, calculate_commission(25_000_000, 10_000_000, 0));
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal};
+
+use crate::state::{Config, Market, MarketResult, Order, Position, Side};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub admin_addr: Addr,
+    pub treasury_addr: Addr,
+    pub denom: String,
+    pub denom_precision: u32,
+    pub commission_bps: u64, // Commission in basis points of the net winnings
+    pub id: String,
+    pub label: String,
+    pub home_team: String,
+    pub away_team: String,
+    pub start_timestamp: u64,
+    pub is_drawable: bool,
+    pub resolution_grace_period: Option<u64>, // Defaults to 7 days
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    PlaceOrder {
+        result: MarketResult,
+        side: Side,
+        odds: Decimal,
+    },
+    CancelOrder {
+        id: u64,
+    },
+    ClaimWinnings {
+        receiver: Option<Addr>,
+    },
+    ExpireMarket {},
+    // Admin
+    Update {
+        admin_addr: Option<Addr>,
+        treasury_addr: Option<Addr>,
+        commission_bps: Option<u64>,
+        start_timestamp: Option<u64>,
+    },
+    Score {
+        result: MarketResult,
+    },
+    Cancel {},
+}
+
+#[cw_serde]
+pub struct UpdateParams {
+    pub admin_addr: Option<Addr>,
+    pub treasury_addr: Option<Addr>,
+    pub commission_bps: Option<u64>, // Commission in basis points
+    pub start_timestamp: Option<u64>,
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(ConfigResponse)]
+    Config {},
+    #[returns(MarketResponse)]
+    Market {},
+    #[returns(OrderResponse)]
+    Order { id: u64 },
+    #[returns(OrdersByAddressResponse)]
+    OrdersByAddress {
+        address: Addr,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(OrderBookResponse)]
+    OrderBook {
+        result: MarketResult,
+        limit: Option<u32>, // Price levels on each side
+    },
+    #[returns(PositionsByAddressResponse)]
+    PositionsByAddress { address: Addr },
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+pub struct ConfigResponse {
+    pub config: Config,
+}
+
+#[cw_serde]
+pub struct MarketResponse {
+    pub market: Market,
+}
+
+#[cw_serde]
+pub struct OrderResponse {
+    pub order: Order,
+}
+
+#[cw_serde]
+pub struct OrdersByAddressResponse {
+    pub address: Addr,
+    pub orders: Vec<Order>,
+}
+
+#[cw_serde]
+pub struct PriceLevel {
+    pub odds: Decimal,
+    pub stake: u128, // Unmatched backer stake available at the odds
+}
+
+#[cw_serde]
+pub struct OrderBookResponse {
+    pub result: MarketResult,
+    pub backs: Vec<PriceLevel>, // Best odds to lay against first, lowest odds first
+    pub lays: Vec<PriceLevel>,  // Best odds to back against first, highest odds first
+}
+
+#[cw_serde]
+pub struct PositionsByAddressResponse {
+    pub address: Addr,
+    pub home: Position,
+    pub away: Position,
+    pub draw: Position,
+}
@@ -0,0 +1,132 @@
+use cosmwasm_std::{Addr, Deps, Order as StorageOrder, StdResult};
+use cw_storage_plus::Bound;
+
+use crate::{
+    msg::{
+        ConfigResponse, MarketResponse, OrderBookResponse, OrderResponse, OrdersByAddressResponse,
+        PositionsByAddressResponse, PriceLevel,
+    },
+    state::{
+        book_for, MarketResult, Side, CONFIG, MARKET, ORDERS, ORDERS_BY_OWNER, POSITIONS_AWAY,
+        POSITIONS_DRAW, POSITIONS_HOME,
+    },
+};
+
+const DEFAULT_ORDERS_LIMIT: u32 = 10;
+const MAX_ORDERS_LIMIT: u32 = 30;
+const DEFAULT_PRICE_LEVELS_LIMIT: u32 = 10;
+const MAX_PRICE_LEVELS_LIMIT: u32 = 30;
+
+/// Returns the current config of the market
+pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(ConfigResponse { config })
+}
+
+/// Returns the current state and data of the market
+pub fn query_market(deps: Deps) -> StdResult<MarketResponse> {
+    let market = MARKET.load(deps.storage)?;
+    Ok(MarketResponse { market })
+}
+
+/// Returns an order by its id
+pub fn query_order(deps: Deps, id: u64) -> StdResult<OrderResponse> {
+    let order = ORDERS.load(deps.storage, id)?;
+    Ok(OrderResponse { order })
+}
+
+/// Returns the orders placed by a specific address, paginated by id
+pub fn query_orders_by_address(
+    deps: Deps,
+    address: Addr,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<OrdersByAddressResponse> {
+    let limit = limit.unwrap_or(DEFAULT_ORDERS_LIMIT).min(MAX_ORDERS_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let orders = ORDERS_BY_OWNER
+        .prefix(address.clone())
+        .keys(deps.storage, start, None, StorageOrder::Ascending)
+        .take(limit)
+        .map(|item| item.and_then(|id| ORDERS.load(deps.storage, id)))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(OrdersByAddressResponse { address, orders })
+}
+
+/// Returns the unmatched stake of the open orders on a result aggregated by odds,
+/// up to the limit of price levels on each side
+///
+/// The back orders are sorted from the lowest odds and the lay orders
+/// from the highest odds, so the best odds to match come first on each side.
+pub fn query_order_book(
+    deps: Deps,
+    result: MarketResult,
+    limit: Option<u32>,
+) -> StdResult<OrderBookResponse> {
+    let limit = limit
+        .unwrap_or(DEFAULT_PRICE_LEVELS_LIMIT)
+        .min(MAX_PRICE_LEVELS_LIMIT) as usize;
+
+    Ok(OrderBookResponse {
+        backs: query_price_levels(deps, &result, &Side::BACK, limit)?,
+        lays: query_price_levels(deps, &result, &Side::LAY, limit)?,
+        result,
+    })
+}
+
+/// Returns the matched bets of a specific address on each result
+pub fn query_positions_by_address(
+    deps: Deps,
+    address: Addr,
+) -> StdResult<PositionsByAddressResponse> {
+    let home = POSITIONS_HOME
+        .may_load(deps.storage, address.clone())?
+        .unwrap_or_default();
+    let away = POSITIONS_AWAY
+        .may_load(deps.storage, address.clone())?
+        .unwrap_or_default();
+    let draw = POSITIONS_DRAW
+        .may_load(deps.storage, address.clone())?
+        .unwrap_or_default();
+    Ok(PositionsByAddressResponse {
+        address,
+        home,
+        away,
+        draw,
+    })
+}
+
+/// Aggregates the open orders of a side of the book by odds, from the best odds to match
+fn query_price_levels(
+    deps: Deps,
+    result: &MarketResult,
+    side: &Side,
+    limit: usize,
+) -> StdResult<Vec<PriceLevel>> {
+    let mut levels: Vec<PriceLevel> = vec![];
+
+    for item in book_for(result).sub_prefix(side.as_str()).keys(
+        deps.storage,
+        None,
+        None,
+        StorageOrder::Ascending,
+    ) {
+        let (_, id) = item?;
+        let order = ORDERS.load(deps.storage, id)?;
+        if let Some(level) = levels.last_mut().filter(|level| level.odds == order.odds) {
+            level.stake += order.unmatched_stake();
+            continue;
+        }
+        if levels.len() == limit {
+            break;
+        }
+        levels.push(PriceLevel {
+            odds: order.odds,
+            stake: order.unmatched_stake(),
+        });
+    }
+
+    Ok(levels)
+}
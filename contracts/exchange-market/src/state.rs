@@ -0,0 +1,183 @@
+use std::fmt;
+
+use cosmwasm_std::{Addr, Decimal};
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const MARKET: Item<Market> = Item::new("market");
+pub const CLAIMS: Map<Addr, bool> = Map::new("claims");
+
+// Back and lay limit orders posted on the book, matched into bets between two addresses
+pub const ORDER_COUNT: Item<u64> = Item::new("order_count");
+pub const ORDERS: Map<u64, Order> = Map::new("orders");
+pub const ORDERS_BY_OWNER: Map<(Addr, u64), ()> = Map::new("orders_by_owner");
+pub const OPEN_ORDERS_BY_OWNER: Map<(Addr, u64), ()> = Map::new("open_orders_by_owner");
+
+// Open orders of each result keyed by side, price and id, see `book_price`
+pub const BOOK_HOME: Map<(&str, u128, u64), ()> = Map::new("book_home");
+pub const BOOK_AWAY: Map<(&str, u128, u64), ()> = Map::new("book_away");
+pub const BOOK_DRAW: Map<(&str, u128, u64), ()> = Map::new("book_draw");
+
+// Matched bets of each address on each result
+pub const POSITIONS_HOME: Map<Addr, Position> = Map::new("positions_home");
+pub const POSITIONS_AWAY: Map<Addr, Position> = Map::new("positions_away");
+pub const POSITIONS_DRAW: Map<Addr, Position> = Map::new("positions_draw");
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct Config {
+    pub admin_addr: Addr,
+    pub treasury_addr: Addr,
+    pub commission_bps: u64, // Commission in basis points of the net winnings of each address
+    pub denom: String,
+    pub denom_precision: u32,
+    pub resolution_grace_period: u64, // Seconds after the start timestamp to score the market
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub enum Status {
+    ACTIVE,
+    CLOSED,
+    CANCELLED,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::ACTIVE => write!(f, "ACTIVE"),
+            Status::CLOSED => write!(f, "CLOSED"),
+            Status::CANCELLED => write!(f, "CANCELLED"),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub enum MarketResult {
+    HOME,
+    AWAY,
+    DRAW,
+}
+
+impl fmt::Display for MarketResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MarketResult::HOME => write!(f, "HOME"),
+            MarketResult::AWAY => write!(f, "AWAY"),
+            MarketResult::DRAW => write!(f, "DRAW"),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub enum Side {
+    BACK, // Bets on the result to win, risking the stake
+    LAY,  // Bets against the result, risking the liability of the backer stake
+}
+
+impl fmt::Display for Side {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Side::BACK => write!(f, "BACK"),
+            Side::LAY => write!(f, "LAY"),
+        }
+    }
+}
+
+impl Side {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Side::BACK => "BACK",
+            Side::LAY => "LAY",
+        }
+    }
+
+    pub fn opposite(&self) -> Side {
+        match self {
+            Side::BACK => Side::LAY,
+            Side::LAY => Side::BACK,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub enum OrderStatus {
+    OPEN,
+    FILLED,
+    CANCELLED,
+}
+
+impl fmt::Display for OrderStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OrderStatus::OPEN => write!(f, "OPEN"),
+            OrderStatus::FILLED => write!(f, "FILLED"),
+            OrderStatus::CANCELLED => write!(f, "CANCELLED"),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct Market {
+    pub id: String,
+    pub label: String,
+    pub home_team: String,
+    pub away_team: String,
+    pub start_timestamp: u64,
+    pub resolution_deadline: u64, // Anyone can expire the market once passed without being scored
+    pub status: Status,
+    pub result: Option<MarketResult>,
+    pub is_drawable: bool,
+}
+
+/// Limit order to back or lay a result at the given odds
+///
+/// The stake is always the backer stake, a lay order covers the liability
+/// of the backer stake with its funds.
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct Order {
+    pub id: u64,
+    pub owner: Addr,
+    pub result: MarketResult,
+    pub side: Side,
+    pub odds: Decimal,
+    pub stake: u128,
+    pub matched_stake: u128,
+    pub remaining_funds: u128, // Funds locked for the stake not matched yet
+    pub status: OrderStatus,
+    pub placed_at: u64,
+}
+
+impl Order {
+    pub fn unmatched_stake(&self) -> u128 {
+        self.stake - self.matched_stake
+    }
+}
+
+/// Returns the book of the open orders on a result
+pub fn book_for(result: &MarketResult) -> Map<(&'static str, u128, u64), ()> {
+    match result {
+        MarketResult::HOME => BOOK_HOME,
+        MarketResult::AWAY => BOOK_AWAY,
+        MarketResult::DRAW => BOOK_DRAW,
+    }
+}
+
+/// Returns the price of an order on the book, so the orders of a side are sorted
+/// from the best odds to match against, the lowest back odds and the highest lay odds
+/// first, then from the oldest order at the same odds
+pub fn book_price(side: &Side, odds: Decimal) -> u128 {
+    match side {
+        Side::BACK => odds.atomics().u128(),
+        Side::LAY => u128::MAX - odds.atomics().u128(),
+    }
+}
+
+/// Matched bets of an address on a result
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema, Default)]
+pub struct Position {
+    pub back_stake: u128,
+    pub back_profit: u128, // Won on top of the back stake if the result wins
+    pub lay_stake: u128,   // Backer stakes won if the result doesn't win
+    pub lay_liability: u128,
+}
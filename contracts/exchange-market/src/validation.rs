@@ -0,0 +1,59 @@
+use cosmwasm_std::Decimal;
+
+use crate::{error::ContractError, state::MarketResult};
+
+pub const MIN_RESOLUTION_GRACE_PERIOD: u64 = 24 * 60 * 60; // 1 day
+pub const DEFAULT_RESOLUTION_GRACE_PERIOD: u64 = 7 * 24 * 60 * 60; // 7 days
+pub const ODDS_DECIMAL_PLACES: u32 = 2; // Tick size of 0.01
+pub const MIN_ORDER_STAKE_DECIMAL_PLACES: u32 = 2; // Min stake of 0.01 of the denom
+
+pub fn validate_commission_bps(commission_bps: u64) -> Result<(), ContractError> {
+    if commission_bps > 1_000 {
+        return Err(ContractError::InvalidCommissionBps(commission_bps));
+    }
+
+    Ok(())
+}
+
+pub fn validate_resolution_grace_period(resolution_grace_period: u64) -> Result<(), ContractError> {
+    if resolution_grace_period < MIN_RESOLUTION_GRACE_PERIOD {
+        return Err(ContractError::InvalidResolutionGracePeriod(
+            resolution_grace_period,
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_odds(odds: Decimal) -> Result<(), ContractError> {
+    if odds <= Decimal::one() || odds > Decimal::from_atomics(1_000_u128, 0).unwrap() {
+        return Err(ContractError::InvalidOdds(odds));
+    }
+
+    let tick_size = 10_u128.pow(Decimal::DECIMAL_PLACES - ODDS_DECIMAL_PLACES);
+    if !odds.atomics().u128().is_multiple_of(tick_size) {
+        return Err(ContractError::InvalidOdds(odds));
+    }
+
+    Ok(())
+}
+
+pub fn validate_order_stake(stake: u128, denom_precision: u32) -> Result<(), ContractError> {
+    let min_stake = 10_u128.pow(denom_precision.saturating_sub(MIN_ORDER_STAKE_DECIMAL_PLACES));
+    if stake < min_stake {
+        return Err(ContractError::InvalidStake {});
+    }
+
+    Ok(())
+}
+
+pub fn validate_market_result(
+    result: &MarketResult,
+    is_drawable: bool,
+) -> Result<(), ContractError> {
+    if !is_drawable && *result == MarketResult::DRAW {
+        return Err(ContractError::MarketNotDrawable {});
+    }
+
+    Ok(())
+}
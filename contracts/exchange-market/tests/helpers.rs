@@ -0,0 +1,198 @@
+use cosmwasm_std::{Addr, Coin, Decimal, StdResult};
+use cw_multi_test::{
+    error::{AnyError, AnyResult},
+    App, AppBuilder, AppResponse, BankKeeper, ContractWrapper, Executor, MockApiBech32,
+};
+use derivative::Derivative;
+use exchange_market::{
+    contract::{execute, instantiate, query},
+    msg::{
+        ConfigResponse, ExecuteMsg, InstantiateMsg, MarketResponse, OrderBookResponse,
+        OrderResponse, OrdersByAddressResponse, PositionsByAddressResponse, QueryMsg, UpdateParams,
+    },
+    state::{MarketResult, Side},
+};
+
+/// BlockchainContract is a wrapper around blockchain App and contract Addr
+/// that provides a lot of helpers for working with this contract.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct BlockchainContract {
+    #[derivative(Debug = "ignore")]
+    pub blockchain: App<BankKeeper, MockApiBech32>,
+    pub contract_addr: Addr,
+}
+
+impl BlockchainContract {
+    pub fn addr(&self) -> Addr {
+        self.contract_addr.clone()
+    }
+
+    pub fn query_config(&self) -> StdResult<ConfigResponse> {
+        self.blockchain
+            .wrap()
+            .query_wasm_smart(self.addr(), &QueryMsg::Config {})
+    }
+
+    pub fn query_market(&self) -> StdResult<MarketResponse> {
+        self.blockchain
+            .wrap()
+            .query_wasm_smart(self.addr(), &QueryMsg::Market {})
+    }
+
+    pub fn query_order(&self, id: u64) -> StdResult<OrderResponse> {
+        self.blockchain
+            .wrap()
+            .query_wasm_smart(self.addr(), &QueryMsg::Order { id })
+    }
+
+    pub fn query_orders_by_address(
+        &self,
+        address: &Addr,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<OrdersByAddressResponse> {
+        self.blockchain.wrap().query_wasm_smart(
+            self.addr(),
+            &QueryMsg::OrdersByAddress {
+                address: address.clone(),
+                start_after,
+                limit,
+            },
+        )
+    }
+
+    pub fn query_order_book(
+        &self,
+        result: MarketResult,
+        limit: Option<u32>,
+    ) -> StdResult<OrderBookResponse> {
+        self.blockchain
+            .wrap()
+            .query_wasm_smart(self.addr(), &QueryMsg::OrderBook { result, limit })
+    }
+
+    pub fn query_positions_by_address(
+        &self,
+        address: &Addr,
+    ) -> StdResult<PositionsByAddressResponse> {
+        self.blockchain.wrap().query_wasm_smart(
+            self.addr(),
+            &QueryMsg::PositionsByAddress {
+                address: address.clone(),
+            },
+        )
+    }
+
+    pub fn place_order(
+        &mut self,
+        sender: &Addr,
+        result: MarketResult,
+        side: Side,
+        odds: Decimal,
+        funds: &[Coin],
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::PlaceOrder { result, side, odds },
+            funds,
+        )
+    }
+
+    pub fn cancel_order(&mut self, sender: &Addr, id: u64) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::CancelOrder { id },
+            &[],
+        )
+    }
+
+    pub fn claim_winnings(
+        &mut self,
+        sender: &Addr,
+        receiver: Option<Addr>,
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::ClaimWinnings { receiver },
+            &[],
+        )
+    }
+
+    pub fn expire_market(&mut self, sender: &Addr) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::ExpireMarket {},
+            &[],
+        )
+    }
+
+    pub fn cancel_market(&mut self, sender: &Addr) -> AnyResult<AppResponse> {
+        self.blockchain
+            .execute_contract(sender.clone(), self.addr(), &ExecuteMsg::Cancel {}, &[])
+    }
+
+    pub fn update_market(&mut self, sender: &Addr, params: UpdateParams) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::Update {
+                admin_addr: params.admin_addr,
+                treasury_addr: params.treasury_addr,
+                commission_bps: params.commission_bps,
+                start_timestamp: params.start_timestamp,
+            },
+            &[],
+        )
+    }
+
+    pub fn score_market(&mut self, sender: &Addr, result: MarketResult) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::Score { result },
+            &[],
+        )
+    }
+}
+
+pub fn setup_blockchain_and_contract(
+    admin: Addr,
+    initial_balances: Vec<(Addr, Vec<Coin>)>,
+    instantiate_msg: InstantiateMsg,
+    instantiate_funds: Vec<Coin>,
+) -> Result<BlockchainContract, AnyError> {
+    let mut blockchain = AppBuilder::new()
+        .with_api(MockApiBech32::new("neutron"))
+        .build(|router, _, storage| {
+            initial_balances.into_iter().for_each(|(addr, coins)| {
+                router.bank.init_balance(storage, &addr, coins).unwrap();
+            });
+        });
+
+    let code = Box::new(ContractWrapper::new(execute, instantiate, query));
+
+    let code_id = blockchain.store_code(code);
+
+    let contract_addr = blockchain.instantiate_contract(
+        code_id,
+        admin.clone(),
+        &instantiate_msg,
+        &instantiate_funds,
+        "Market",
+        None,
+    );
+
+    if contract_addr.is_err() {
+        return Err(contract_addr.err().unwrap());
+    }
+
+    Ok(BlockchainContract {
+        blockchain,
+        contract_addr: contract_addr.unwrap(),
+    })
+}
@@ -0,0 +1,2872 @@
+use cosmwasm_std::{coin, coins, Decimal, Timestamp, Uint128};
+use cw_multi_test::MockApiBech32;
+use exchange_market::{
+    error::ContractError,
+    msg::{InstantiateMsg, PriceLevel},
+    state::{MarketResult, OrderStatus, Side, Status},
+};
+use helpers::setup_blockchain_and_contract;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+mod helpers;
+
+const NATIVE_DENOM: &str = "denom";
+const NATIVE_DENOM_PRECISION: u32 = 6;
+const FAKE_DENOM: &str = "fakedenom";
+const ADMIN: &str = "ADMIN";
+const TREASURY: &str = "TREASURY";
+const ANYONE: &str = "USER_ANYONE";
+const USER_A: &str = "USER_A";
+const USER_B: &str = "USER_B";
+const USER_C: &str = "USER_C";
+const DEFAULT_COMMISSION_BPS: u64 = 250;
+const INITIAL_BALANCE: u128 = 1_000_000_000;
+
+mod create_market {
+    use super::*;
+
+    #[test]
+    fn it_properly_creates_a_market() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let query_config = blockchain_contract.query_config().unwrap();
+        assert_eq!(250, query_config.config.commission_bps);
+        assert_eq!(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            query_config.config.admin_addr
+        );
+        assert_eq!(
+            MockApiBech32::new("neutron").addr_make(TREASURY),
+            query_config.config.treasury_addr
+        );
+        assert_eq!(NATIVE_DENOM, query_config.config.denom.as_str());
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!("game-cs2-test-league", query_market.market.id);
+        assert_eq!(
+            "CS2 - Test League - Team A vs Team B",
+            query_market.market.label
+        );
+        assert_eq!("Team A", query_market.market.home_team);
+        assert_eq!("Team B", query_market.market.away_team);
+        assert_eq!(start_timestamp, query_market.market.start_timestamp);
+        assert_eq!(
+            start_timestamp + 7 * 24 * 60 * 60,
+            query_market.market.resolution_deadline
+        );
+        assert_eq!(Status::ACTIVE, query_market.market.status);
+        assert_eq!(None, query_market.market.result);
+        assert!(query_market.market.is_drawable);
+    }
+
+    #[test]
+    fn it_cant_create_a_market_with_invalid_commission_bps() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let err = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: 1_001,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidCommissionBps(1_001),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod place_order {
+    use super::*;
+    use exchange_market::execute::MAX_ORDER_MATCHES;
+
+    #[test]
+    fn it_properly_matches_back_and_lay_orders() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+
+        blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("2.5").unwrap(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_order(
+                &user_b,
+                MarketResult::HOME,
+                Side::LAY,
+                Decimal::from_str("2.5").unwrap(),
+                &coins(15_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_order = blockchain_contract.query_order(1).unwrap();
+        assert_eq!(OrderStatus::FILLED, query_order.order.status);
+        assert_eq!(10_000_000, query_order.order.stake);
+        assert_eq!(10_000_000, query_order.order.matched_stake);
+        assert_eq!(0, query_order.order.remaining_funds);
+
+        let query_order = blockchain_contract.query_order(2).unwrap();
+        assert_eq!(OrderStatus::FILLED, query_order.order.status);
+        assert_eq!(10_000_000, query_order.order.stake);
+        assert_eq!(10_000_000, query_order.order.matched_stake);
+        assert_eq!(0, query_order.order.remaining_funds);
+
+        let query_user_a_positions = blockchain_contract
+            .query_positions_by_address(&user_a)
+            .unwrap();
+        assert_eq!(10_000_000, query_user_a_positions.home.back_stake);
+        assert_eq!(15_000_000, query_user_a_positions.home.back_profit);
+        assert_eq!(0, query_user_a_positions.home.lay_stake);
+        assert_eq!(0, query_user_a_positions.home.lay_liability);
+
+        let query_user_b_positions = blockchain_contract
+            .query_positions_by_address(&user_b)
+            .unwrap();
+        assert_eq!(0, query_user_b_positions.home.back_stake);
+        assert_eq!(0, query_user_b_positions.home.back_profit);
+        assert_eq!(10_000_000, query_user_b_positions.home.lay_stake);
+        assert_eq!(15_000_000, query_user_b_positions.home.lay_liability);
+
+        let query_order_book = blockchain_contract
+            .query_order_book(MarketResult::HOME, None)
+            .unwrap();
+        assert!(query_order_book.backs.is_empty());
+        assert!(query_order_book.lays.is_empty());
+
+        let contract_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(blockchain_contract.addr(), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(Uint128::new(25_000_000), contract_balance.amount);
+    }
+
+    #[test]
+    fn it_keeps_the_unmatched_stake_on_the_book() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+
+        blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("2").unwrap(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_order(
+                &user_b,
+                MarketResult::HOME,
+                Side::LAY,
+                Decimal::from_str("2").unwrap(),
+                &coins(4_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_order = blockchain_contract.query_order(1).unwrap();
+        assert_eq!(OrderStatus::OPEN, query_order.order.status);
+        assert_eq!(4_000_000, query_order.order.matched_stake);
+        assert_eq!(6_000_000, query_order.order.remaining_funds);
+
+        let query_order = blockchain_contract.query_order(2).unwrap();
+        assert_eq!(OrderStatus::FILLED, query_order.order.status);
+        assert_eq!(4_000_000, query_order.order.matched_stake);
+
+        let query_order_book = blockchain_contract
+            .query_order_book(MarketResult::HOME, None)
+            .unwrap();
+        assert_eq!(
+            vec![PriceLevel {
+                odds: Decimal::from_str("2").unwrap(),
+                stake: 6_000_000,
+            }],
+            query_order_book.backs
+        );
+        assert!(query_order_book.lays.is_empty());
+
+        let query_user_a_orders = blockchain_contract
+            .query_orders_by_address(&user_a, None, None)
+            .unwrap();
+        assert_eq!(1, query_user_a_orders.orders.len());
+        assert_eq!(1, query_user_a_orders.orders[0].id);
+    }
+
+    #[test]
+    fn it_matches_at_the_odds_of_the_resting_order_and_returns_the_funds_left() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+
+        blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("2").unwrap(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_order(
+                &user_b,
+                MarketResult::HOME,
+                Side::LAY,
+                Decimal::from_str("3").unwrap(),
+                &coins(20_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_order = blockchain_contract.query_order(2).unwrap();
+        assert_eq!(OrderStatus::FILLED, query_order.order.status);
+        assert_eq!(10_000_000, query_order.order.matched_stake);
+        assert_eq!(0, query_order.order.remaining_funds);
+
+        let query_user_b_positions = blockchain_contract
+            .query_positions_by_address(&user_b)
+            .unwrap();
+        assert_eq!(10_000_000, query_user_b_positions.home.lay_stake);
+        assert_eq!(10_000_000, query_user_b_positions.home.lay_liability);
+
+        let user_b_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_B),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(
+            Uint128::new(INITIAL_BALANCE - 10_000_000),
+            user_b_balance.amount
+        );
+    }
+
+    #[test]
+    fn it_matches_the_best_odds_first() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_C),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+        let user_c = MockApiBech32::new("neutron").addr_make(USER_C);
+
+        blockchain_contract
+            .place_order(
+                &user_b,
+                MarketResult::HOME,
+                Side::LAY,
+                Decimal::from_str("2").unwrap(),
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_order(
+                &user_c,
+                MarketResult::HOME,
+                Side::LAY,
+                Decimal::from_str("3").unwrap(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("2").unwrap(),
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_order = blockchain_contract.query_order(1).unwrap();
+        assert_eq!(OrderStatus::OPEN, query_order.order.status);
+        assert_eq!(0, query_order.order.matched_stake);
+
+        let query_order = blockchain_contract.query_order(2).unwrap();
+        assert_eq!(OrderStatus::FILLED, query_order.order.status);
+        assert_eq!(5_000_000, query_order.order.matched_stake);
+
+        let query_user_a_positions = blockchain_contract
+            .query_positions_by_address(&user_a)
+            .unwrap();
+        assert_eq!(5_000_000, query_user_a_positions.home.back_stake);
+        assert_eq!(10_000_000, query_user_a_positions.home.back_profit);
+    }
+
+    #[test]
+    fn it_matches_up_to_the_max_order_matches_and_keeps_the_rest_on_the_book() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+
+        for _ in 0..=MAX_ORDER_MATCHES {
+            blockchain_contract
+                .place_order(
+                    &user_a,
+                    MarketResult::HOME,
+                    Side::BACK,
+                    Decimal::from_str("2").unwrap(),
+                    &coins(1_000_000, NATIVE_DENOM),
+                )
+                .unwrap();
+        }
+        blockchain_contract
+            .place_order(
+                &user_b,
+                MarketResult::HOME,
+                Side::LAY,
+                Decimal::from_str("2").unwrap(),
+                &coins(31_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_order = blockchain_contract.query_order(32).unwrap();
+        assert_eq!(OrderStatus::OPEN, query_order.order.status);
+        assert_eq!(31_000_000, query_order.order.stake);
+        assert_eq!(30_000_000, query_order.order.matched_stake);
+        assert_eq!(1_000_000, query_order.order.remaining_funds);
+
+        let query_order = blockchain_contract.query_order(30).unwrap();
+        assert_eq!(OrderStatus::FILLED, query_order.order.status);
+        let query_order = blockchain_contract.query_order(31).unwrap();
+        assert_eq!(OrderStatus::OPEN, query_order.order.status);
+        assert_eq!(0, query_order.order.matched_stake);
+
+        // The rest of the order is left on the book until the next order matches it
+        let query_order_book = blockchain_contract
+            .query_order_book(MarketResult::HOME, None)
+            .unwrap();
+        assert_eq!(
+            vec![PriceLevel {
+                odds: Decimal::from_str("2").unwrap(),
+                stake: 1_000_000,
+            }],
+            query_order_book.backs
+        );
+        assert_eq!(
+            vec![PriceLevel {
+                odds: Decimal::from_str("2").unwrap(),
+                stake: 1_000_000,
+            }],
+            query_order_book.lays
+        );
+    }
+
+    #[test]
+    fn it_cant_place_order_with_invalid_odds() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let err = blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("1").unwrap(),
+                &coins(1_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidOdds(Decimal::one()),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_place_order_with_odds_off_the_tick_size() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let err = blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("2.505").unwrap(),
+                &coins(1_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidOdds(Decimal::from_str("2.505").unwrap()),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_place_order_below_the_min_stake() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let err = blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("2").unwrap(),
+                &coins(9_999, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidStake {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        // The funds of a lay order cover a backer stake below the min stake
+        let err = blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::LAY,
+                Decimal::from_str("3").unwrap(),
+                &coins(19_998, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidStake {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_place_order_on_draw_when_market_isnt_drawable() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: false,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let err = blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::DRAW,
+                Side::BACK,
+                Decimal::from_str("2").unwrap(),
+                &coins(1_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotDrawable {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_can_only_place_orders_up_until_5_minutes_before_market_start_timestamp() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5
+            - 1; // 4 minutes and 59 seconds from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(start_timestamp);
+        });
+
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let err = blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("2").unwrap(),
+                &coins(1_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::BetsNotAccepted {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_place_order_without_sending_funds_in_the_market_denom() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    vec![
+                        coin(INITIAL_BALANCE, NATIVE_DENOM),
+                        coin(INITIAL_BALANCE, FAKE_DENOM),
+                    ],
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    vec![
+                        coin(INITIAL_BALANCE, NATIVE_DENOM),
+                        coin(INITIAL_BALANCE, FAKE_DENOM),
+                    ],
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let err = blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("2").unwrap(),
+                &coins(1_000, FAKE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::PaymentError {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod cancel_order {
+    use super::*;
+
+    #[test]
+    fn it_properly_cancels_the_unmatched_stake_of_an_order() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+
+        blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("2").unwrap(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_order(
+                &user_b,
+                MarketResult::HOME,
+                Side::LAY,
+                Decimal::from_str("2").unwrap(),
+                &coins(4_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.cancel_order(&user_a, 1).unwrap();
+
+        let query_order = blockchain_contract.query_order(1).unwrap();
+        assert_eq!(OrderStatus::CANCELLED, query_order.order.status);
+        assert_eq!(4_000_000, query_order.order.matched_stake);
+        assert_eq!(0, query_order.order.remaining_funds);
+
+        let query_user_a_positions = blockchain_contract
+            .query_positions_by_address(&user_a)
+            .unwrap();
+        assert_eq!(4_000_000, query_user_a_positions.home.back_stake);
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(
+            Uint128::new(INITIAL_BALANCE - 4_000_000),
+            user_a_balance.amount
+        );
+    }
+
+    #[test]
+    fn it_cant_cancel_an_order_of_another_address() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+
+        blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("2").unwrap(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let err = blockchain_contract.cancel_order(&user_b, 1).unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_cancel_an_order_that_isnt_open() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+
+        blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("2").unwrap(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_order(
+                &user_b,
+                MarketResult::HOME,
+                Side::LAY,
+                Decimal::from_str("2").unwrap(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let err = blockchain_contract.cancel_order(&user_a, 1).unwrap_err();
+        assert_eq!(
+            ContractError::OrderNotOpen {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod claim_winnings {
+    use super::*;
+
+    #[test]
+    fn it_properly_claims_winnings_and_collects_commission() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+
+        blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("2.5").unwrap(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_order(
+                &user_b,
+                MarketResult::HOME,
+                Side::LAY,
+                Decimal::from_str("2.5").unwrap(),
+                &coins(15_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(&admin, MarketResult::HOME)
+            .unwrap();
+
+        blockchain_contract.claim_winnings(&user_a, None).unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(
+            Uint128::new(INITIAL_BALANCE - 10_000_000 + 24_625_000),
+            user_a_balance.amount
+        );
+
+        let treasury_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(TREASURY),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(Uint128::new(375_000), treasury_balance.amount);
+
+        let err = blockchain_contract
+            .claim_winnings(&user_b, None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NoWinnings {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_pays_the_layer_when_the_result_doesnt_win() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+
+        blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("2.5").unwrap(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_order(
+                &user_b,
+                MarketResult::HOME,
+                Side::LAY,
+                Decimal::from_str("2.5").unwrap(),
+                &coins(15_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(&admin, MarketResult::AWAY)
+            .unwrap();
+
+        blockchain_contract.claim_winnings(&user_b, None).unwrap();
+
+        let user_b_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_B),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(
+            Uint128::new(INITIAL_BALANCE - 15_000_000 + 24_750_000),
+            user_b_balance.amount
+        );
+
+        let treasury_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(TREASURY),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(Uint128::new(250_000), treasury_balance.amount);
+
+        let err = blockchain_contract
+            .claim_winnings(&user_a, None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NoWinnings {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_returns_the_unmatched_funds_when_claiming() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+
+        blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("2").unwrap(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_order(
+                &user_b,
+                MarketResult::HOME,
+                Side::LAY,
+                Decimal::from_str("2").unwrap(),
+                &coins(4_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(&admin, MarketResult::AWAY)
+            .unwrap();
+
+        blockchain_contract.claim_winnings(&user_a, None).unwrap();
+
+        let query_order = blockchain_contract.query_order(1).unwrap();
+        assert_eq!(OrderStatus::CANCELLED, query_order.order.status);
+        assert_eq!(0, query_order.order.remaining_funds);
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(
+            Uint128::new(INITIAL_BALANCE - 4_000_000),
+            user_a_balance.amount
+        );
+
+        blockchain_contract.claim_winnings(&user_b, None).unwrap();
+
+        let user_b_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_B),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(
+            Uint128::new(INITIAL_BALANCE - 4_000_000 + 7_900_000),
+            user_b_balance.amount
+        );
+    }
+
+    #[test]
+    fn it_will_return_all_bets_made_if_market_was_cancelled() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+
+        blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("2.5").unwrap(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_order(
+                &user_b,
+                MarketResult::HOME,
+                Side::LAY,
+                Decimal::from_str("2.5").unwrap(),
+                &coins(15_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::AWAY,
+                Side::BACK,
+                Decimal::from_str("3").unwrap(),
+                &coins(1_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.cancel_market(&admin).unwrap();
+
+        blockchain_contract.claim_winnings(&user_a, None).unwrap();
+        blockchain_contract.claim_winnings(&user_b, None).unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(Uint128::new(INITIAL_BALANCE), user_a_balance.amount);
+
+        let user_b_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_B),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(Uint128::new(INITIAL_BALANCE), user_b_balance.amount);
+
+        let treasury_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(TREASURY),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(Uint128::new(0), treasury_balance.amount);
+    }
+
+    #[test]
+    fn it_can_claim_on_behalf_of_the_receiver_when_defined() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(ANYONE),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+        let anyone = MockApiBech32::new("neutron").addr_make(ANYONE);
+
+        blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("2.5").unwrap(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_order(
+                &user_b,
+                MarketResult::HOME,
+                Side::LAY,
+                Decimal::from_str("2.5").unwrap(),
+                &coins(15_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(&admin, MarketResult::HOME)
+            .unwrap();
+
+        blockchain_contract
+            .claim_winnings(&anyone, Some(user_a.clone()))
+            .unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(
+            Uint128::new(INITIAL_BALANCE - 10_000_000 + 24_625_000),
+            user_a_balance.amount
+        );
+
+        let anyone_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(ANYONE),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(Uint128::new(INITIAL_BALANCE), anyone_balance.amount);
+    }
+
+    #[test]
+    fn it_cant_claim_winnings_while_market_is_active() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+
+        blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("2.5").unwrap(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_order(
+                &user_b,
+                MarketResult::HOME,
+                Side::LAY,
+                Decimal::from_str("2.5").unwrap(),
+                &coins(15_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .claim_winnings(&user_a, None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotClosed {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_claim_winnings_twice() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+
+        blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("2.5").unwrap(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_order(
+                &user_b,
+                MarketResult::HOME,
+                Side::LAY,
+                Decimal::from_str("2.5").unwrap(),
+                &coins(15_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(&admin, MarketResult::HOME)
+            .unwrap();
+
+        blockchain_contract.claim_winnings(&user_a, None).unwrap();
+
+        let err = blockchain_contract
+            .claim_winnings(&user_a, None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ClaimAlreadyMade {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod update_market {
+    use super::*;
+    use exchange_market::msg::UpdateParams;
+
+    #[test]
+    fn it_properly_updates_the_market() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        blockchain_contract
+            .update_market(
+                &admin,
+                UpdateParams {
+                    admin_addr: None,
+                    treasury_addr: Some(user_a.clone()),
+                    commission_bps: Some(500),
+                    start_timestamp: Some(start_timestamp + 60 * 60),
+                },
+            )
+            .unwrap();
+
+        let query_config = blockchain_contract.query_config().unwrap();
+        assert_eq!(user_a, query_config.config.treasury_addr);
+        assert_eq!(500, query_config.config.commission_bps);
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(
+            start_timestamp + 60 * 60,
+            query_market.market.start_timestamp
+        );
+        assert_eq!(
+            start_timestamp + 60 * 60 + 7 * 24 * 60 * 60,
+            query_market.market.resolution_deadline
+        );
+    }
+
+    #[test]
+    fn it_cant_update_market_if_sender_isnt_the_admin() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let err = blockchain_contract
+            .update_market(
+                &user_a,
+                UpdateParams {
+                    admin_addr: Some(user_a.clone()),
+                    treasury_addr: None,
+                    commission_bps: None,
+                    start_timestamp: None,
+                },
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_update_market_with_invalid_commission_bps() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+
+        let err = blockchain_contract
+            .update_market(
+                &admin,
+                UpdateParams {
+                    admin_addr: None,
+                    treasury_addr: None,
+                    commission_bps: Some(1_001),
+                    start_timestamp: None,
+                },
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidCommissionBps(1_001),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod score_market {
+    use super::*;
+
+    #[test]
+    fn it_cant_score_the_market_if_sender_isnt_the_admin() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        let err = blockchain_contract
+            .score_market(&user_a, MarketResult::HOME)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_score_the_market_with_draw_if_the_market_isnt_drawable() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: false,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        let err = blockchain_contract
+            .score_market(&admin, MarketResult::DRAW)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotDrawable {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_can_only_score_the_market_after_30_minutes_of_its_start_timestamp() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30 - 1, // 29 minutes and 59 seconds after the start timestamp
+            );
+        });
+
+        let err = blockchain_contract
+            .score_market(&admin, MarketResult::HOME)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotScoreable {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(&admin, MarketResult::HOME)
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::CLOSED, query_market.market.status);
+        assert_eq!(Some(MarketResult::HOME), query_market.market.result);
+    }
+}
+
+mod cancel_market {
+    use super::*;
+
+    #[test]
+    fn it_cant_cancel_the_market_if_sender_isnt_the_admin() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let err = blockchain_contract.cancel_market(&user_a).unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_cancel_the_market_if_it_is_no_longer_active() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+
+        blockchain_contract.cancel_market(&admin).unwrap();
+
+        let err = blockchain_contract.cancel_market(&admin).unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotActive {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod expire_market {
+    use super::*;
+
+    #[test]
+    fn it_lets_anyone_expire_the_market_after_the_resolution_deadline() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let anyone = MockApiBech32::new("neutron").addr_make(ANYONE);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+
+        blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("2.5").unwrap(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_order(
+                &user_b,
+                MarketResult::HOME,
+                Side::LAY,
+                Decimal::from_str("2.5").unwrap(),
+                &coins(15_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 7 * 24 * 60 * 60, // At the resolution deadline
+            );
+        });
+
+        blockchain_contract.expire_market(&anyone).unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::CANCELLED, query_market.market.status);
+
+        blockchain_contract.claim_winnings(&user_a, None).unwrap();
+        blockchain_contract.claim_winnings(&user_b, None).unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(Uint128::new(INITIAL_BALANCE), user_a_balance.amount);
+
+        let user_b_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_B),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(Uint128::new(INITIAL_BALANCE), user_b_balance.amount);
+    }
+
+    #[test]
+    fn it_cant_expire_the_market_before_the_resolution_deadline() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let anyone = MockApiBech32::new("neutron").addr_make(ANYONE);
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 7 * 24 * 60 * 60 - 1, // 1 second before the resolution deadline
+            );
+        });
+
+        let err = blockchain_contract.expire_market(&anyone).unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotExpired {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod order_book {
+    use super::*;
+
+    #[test]
+    fn it_aggregates_the_open_orders_by_odds() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_C),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+        let user_c = MockApiBech32::new("neutron").addr_make(USER_C);
+
+        blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("2").unwrap(),
+                &coins(1_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_order(
+                &user_b,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("2").unwrap(),
+                &coins(2_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_order(
+                &user_c,
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("3").unwrap(),
+                &coins(1_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_order(
+                &user_a,
+                MarketResult::HOME,
+                Side::LAY,
+                Decimal::from_str("1.5").unwrap(),
+                &coins(500_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_order(
+                &user_b,
+                MarketResult::HOME,
+                Side::LAY,
+                Decimal::from_str("1.8").unwrap(),
+                &coins(800_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_order(
+                &user_c,
+                MarketResult::AWAY,
+                Side::BACK,
+                Decimal::from_str("2").unwrap(),
+                &coins(1_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_order_book = blockchain_contract
+            .query_order_book(MarketResult::HOME, None)
+            .unwrap();
+        assert_eq!(
+            vec![
+                PriceLevel {
+                    odds: Decimal::from_str("2").unwrap(),
+                    stake: 3_000_000,
+                },
+                PriceLevel {
+                    odds: Decimal::from_str("3").unwrap(),
+                    stake: 1_000_000,
+                },
+            ],
+            query_order_book.backs
+        );
+        assert_eq!(
+            vec![
+                PriceLevel {
+                    odds: Decimal::from_str("1.8").unwrap(),
+                    stake: 1_000_000,
+                },
+                PriceLevel {
+                    odds: Decimal::from_str("1.5").unwrap(),
+                    stake: 1_000_000,
+                },
+            ],
+            query_order_book.lays
+        );
+    }
+
+    #[test]
+    fn it_limits_the_price_levels_on_each_side() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+
+        for odds in ["2", "2.5", "2", "3"] {
+            blockchain_contract
+                .place_order(
+                    &user_a,
+                    MarketResult::HOME,
+                    Side::BACK,
+                    Decimal::from_str(odds).unwrap(),
+                    &coins(1_000_000, NATIVE_DENOM),
+                )
+                .unwrap();
+        }
+        for odds in ["1.5", "1.8", "1.2"] {
+            blockchain_contract
+                .place_order(
+                    &user_b,
+                    MarketResult::HOME,
+                    Side::LAY,
+                    Decimal::from_str(odds).unwrap(),
+                    &coins(1_000_000, NATIVE_DENOM),
+                )
+                .unwrap();
+        }
+
+        let query_order_book = blockchain_contract
+            .query_order_book(MarketResult::HOME, Some(2))
+            .unwrap();
+        assert_eq!(
+            vec![
+                PriceLevel {
+                    odds: Decimal::from_str("2").unwrap(),
+                    stake: 2_000_000,
+                },
+                PriceLevel {
+                    odds: Decimal::from_str("2.5").unwrap(),
+                    stake: 1_000_000,
+                },
+            ],
+            query_order_book.backs
+        );
+        assert_eq!(
+            vec![
+                PriceLevel {
+                    odds: Decimal::from_str("1.8").unwrap(),
+                    stake: 1_250_000,
+                },
+                PriceLevel {
+                    odds: Decimal::from_str("1.5").unwrap(),
+                    stake: 2_000_000,
+                },
+            ],
+            query_order_book.lays
+        );
+    }
+
+    #[test]
+    fn it_paginates_the_orders_of_an_address() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+
+        for _ in 0..3 {
+            blockchain_contract
+                .place_order(
+                    &user_a,
+                    MarketResult::HOME,
+                    Side::BACK,
+                    Decimal::from_str("2").unwrap(),
+                    &coins(1_000_000, NATIVE_DENOM),
+                )
+                .unwrap();
+            blockchain_contract
+                .place_order(
+                    &user_b,
+                    MarketResult::AWAY,
+                    Side::BACK,
+                    Decimal::from_str("2").unwrap(),
+                    &coins(1_000_000, NATIVE_DENOM),
+                )
+                .unwrap();
+        }
+
+        let query_user_a_orders = blockchain_contract
+            .query_orders_by_address(&user_a, None, Some(2))
+            .unwrap();
+        assert_eq!(
+            vec![1, 3],
+            query_user_a_orders
+                .orders
+                .iter()
+                .map(|order| order.id)
+                .collect::<Vec<u64>>()
+        );
+
+        let query_user_a_orders = blockchain_contract
+            .query_orders_by_address(&user_a, Some(3), Some(2))
+            .unwrap();
+        assert_eq!(
+            vec![5],
+            query_user_a_orders
+                .orders
+                .iter()
+                .map(|order| order.id)
+                .collect::<Vec<u64>>()
+        );
+    }
+}
+
+mod checked_math {
+    use super::*;
+
+    #[test]
+    fn it_cant_place_an_order_when_the_start_timestamp_leaves_no_room_for_the_cutoff() {
+        let start_timestamp = 60; // 1 minute after the epoch
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                coins(INITIAL_BALANCE, NATIVE_DENOM),
+            )],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let err = blockchain_contract
+            .place_order(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Side::BACK,
+                Decimal::from_str("2").unwrap(),
+                &coins(1_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidTimestamp(start_timestamp),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_create_a_market_with_a_resolution_deadline_out_of_range() {
+        let err = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                commission_bps: DEFAULT_COMMISSION_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp: u64::MAX,
+                is_drawable: true,
+                resolution_grace_period: None,
+            },
+            vec![],
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidTimestamp(u64::MAX),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
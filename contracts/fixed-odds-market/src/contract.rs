@@ -8,18 +8,36 @@ use cw2::set_contract_version;
 use crate::{
     error::ContractError,
     execute::{
-        execute_cancel, execute_claim_winnings, execute_place_bet, execute_score, execute_update,
+        execute_approve, execute_cancel, execute_cancel_limit_bet, execute_claim_by_token,
+        execute_claim_winnings, execute_confirm_bet, execute_expire_market, execute_limit_bets,
+        execute_mint_bet_slip, execute_place_bet, execute_place_limit_bet,
+        execute_place_quoted_bet, execute_postpone, execute_reconcile_surplus, execute_reschedule,
+        execute_reserve_parlay_liability, execute_resume, execute_revoke, execute_score,
+        execute_score_final, execute_score_total, execute_send_nft, execute_suspend,
+        execute_transfer_nft, execute_transfer_position, execute_update, execute_update_odds,
+        execute_void, execute_withdraw_stake,
     },
-    logic::{calculate_max_bet, calculate_odds},
+    logic::PricingModel,
     msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, UpdateParams},
-    queries::{query_bets, query_bets_by_address, query_config, query_market, query_max_bets},
+    queries::{
+        query_bets, query_bets_by_address, query_config, query_contract_info, query_exposure,
+        query_ledger, query_limit_bet, query_limit_bets, query_market, query_max_bets,
+        query_nft_info, query_num_tokens, query_odds, query_owner_of, query_pending_bet,
+        query_quote, query_solvency, query_tokens,
+    },
     state::{
-        Config, Market, Status, CONFIG, MARKET, POTENTIAL_PAYOUT_AWAY, POTENTIAL_PAYOUT_HOME,
-        TOTAL_BETS_AWAY, TOTAL_BETS_HOME,
+        load_side_book, Config, HalfPayouts, Ledger, Market, MarketKind, PricingModelKind, Status,
+        BET_SLIP_COUNT, CONFIG, HALF_PAYOUTS_AWAY, HALF_PAYOUTS_HOME, LEDGER, LIMIT_BET_COUNT,
+        MARKET, PARLAY_LIABILITY_AWAY, PARLAY_LIABILITY_HOME, PENDING_BET_COUNT,
+        POTENTIAL_PAYOUT_AWAY, POTENTIAL_PAYOUT_HOME, TOTAL_BETS_AWAY, TOTAL_BETS_HOME,
+        TOTAL_LIMIT_BETS, TOTAL_PENDING_BETS,
     },
     validation::{
-        validate_fee_spread_odds, validate_max_bet_risk_factor, validate_odd,
-        validate_seed_liquidity_amplifier,
+        validate_bet_delay, validate_denom_precision, validate_fee_spread_odds,
+        validate_market_kind, validate_max_bet_risk_factor, validate_metadata, validate_odd,
+        validate_postponement_period, validate_pricing_model, validate_resolution_grace_period,
+        validate_seed_liquidity_amplifier, validate_text, validate_trader_pubkey,
+        DEFAULT_POSTPONEMENT_PERIOD, DEFAULT_RESOLUTION_GRACE_PERIOD, MAX_TEXT_LENGTH,
     },
 };
 
@@ -29,20 +47,56 @@ pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
-    env: Env,
+    _env: Env,
     info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
+    validate_denom_precision(msg.denom_precision)?;
     validate_fee_spread_odds(msg.fee_spread_odds)?;
     validate_max_bet_risk_factor(msg.max_bet_risk_factor)?;
     validate_seed_liquidity_amplifier(msg.seed_liquidity_amplifier)?;
     validate_odd(msg.initial_odds_home)?;
     validate_odd(msg.initial_odds_away)?;
 
-    let market_balance = deps
-        .querier
-        .query_balance(&env.contract.address, &msg.denom)?
-        .amount;
+    let kind = msg.kind.unwrap_or(MarketKind::MONEYLINE);
+    validate_market_kind(&kind)?;
+
+    let pricing_model = msg.pricing_model.unwrap_or(PricingModelKind::SEED_BLEND);
+    validate_pricing_model(&pricing_model)?;
+
+    let resolution_grace_period = msg
+        .resolution_grace_period
+        .unwrap_or(DEFAULT_RESOLUTION_GRACE_PERIOD);
+    validate_resolution_grace_period(resolution_grace_period)?;
+
+    let postponement_period = msg
+        .postponement_period
+        .unwrap_or(DEFAULT_POSTPONEMENT_PERIOD);
+    validate_postponement_period(postponement_period)?;
+
+    if let Some(live) = &msg.live {
+        validate_bet_delay(live.bet_delay)?;
+    }
+
+    if let Some(trader_pubkey) = &msg.trader_pubkey {
+        validate_trader_pubkey(trader_pubkey)?;
+    }
+
+    validate_text("label", &msg.label, MAX_TEXT_LENGTH)?;
+    validate_text("home_team", &msg.home_team, MAX_TEXT_LENGTH)?;
+    validate_text("away_team", &msg.away_team, MAX_TEXT_LENGTH)?;
+    if let Some(metadata) = &msg.metadata {
+        validate_metadata(metadata)?;
+    }
+
+    // Only the funds sent along are the seed liquidity, not the coins sent to the contract before
+    let market_balance = info
+        .funds
+        .iter()
+        .filter(|fund| fund.denom == msg.denom)
+        .try_fold(Uint128::zero(), |total, fund| {
+            total.checked_add(fund.amount)
+        })?;
 
     if market_balance.is_zero() {
         return Err(ContractError::MarketNotInitiallyFunded {});
@@ -65,6 +119,12 @@ pub fn instantiate(
         seed_liquidity_amplifier: msg.seed_liquidity_amplifier,
         initial_odds_home: msg.initial_odds_home,
         initial_odds_away: msg.initial_odds_away,
+        parlay_addr: msg.parlay_addr.clone(),
+        live: msg.live.clone(),
+        resolution_grace_period,
+        postponement_period,
+        pricing_model: pricing_model.clone(),
+        trader_pubkey: msg.trader_pubkey.clone(),
     };
     CONFIG.save(deps.storage, &config)?;
 
@@ -72,27 +132,87 @@ pub fn instantiate(
     POTENTIAL_PAYOUT_HOME.save(deps.storage, &0)?;
     TOTAL_BETS_AWAY.save(deps.storage, &0)?;
     POTENTIAL_PAYOUT_AWAY.save(deps.storage, &0)?;
+    PARLAY_LIABILITY_HOME.save(deps.storage, &0)?;
+    HALF_PAYOUTS_HOME.save(
+        deps.storage,
+        &HalfPayouts {
+            half_win: 0,
+            half_lose: 0,
+        },
+    )?;
+    HALF_PAYOUTS_AWAY.save(
+        deps.storage,
+        &HalfPayouts {
+            half_win: 0,
+            half_lose: 0,
+        },
+    )?;
+    PARLAY_LIABILITY_AWAY.save(deps.storage, &0)?;
+    PENDING_BET_COUNT.save(deps.storage, &0)?;
+    BET_SLIP_COUNT.save(deps.storage, &0)?;
+    TOTAL_PENDING_BETS.save(deps.storage, &0)?;
+    LIMIT_BET_COUNT.save(deps.storage, &0)?;
+    TOTAL_LIMIT_BETS.save(deps.storage, &0)?;
+    LEDGER.save(
+        deps.storage,
+        &Ledger {
+            seed: market_balance,
+            stakes: Uint128::zero(),
+            fees: Uint128::zero(),
+            claimed: Uint128::zero(),
+            unclaimed: Uint128::zero(),
+        },
+    )?;
 
-    let (home_odds, away_odds) =
-        calculate_odds(&config, market_balance, Uint128::zero(), Uint128::zero());
+    let (home_odds, away_odds) = config.pricing_model.odds(
+        &config,
+        market_balance,
+        &load_side_book(deps.storage, true)?,
+        &load_side_book(deps.storage, false)?,
+    )?;
 
     let market = Market {
         id: msg.id,
         label: msg.label,
+        kind: kind.clone(),
         home_team: msg.home_team,
         home_odds,
         away_team: msg.away_team,
         away_odds,
+        metadata: msg.metadata,
         start_timestamp: msg.start_timestamp,
+        resolution_deadline: msg
+            .start_timestamp
+            .checked_add(resolution_grace_period)
+            .ok_or(ContractError::InvalidTimestamp(msg.start_timestamp))?,
+        postponement_deadline: None,
         status: Status::ACTIVE,
         result: None,
+        settlement: None,
+        suspended: false,
+        odds_revision: 0,
+        void_reason: None,
     };
     MARKET.save(deps.storage, &market)?;
 
     let home_max_bet =
-        calculate_max_bet(&config, market_balance, Uint128::zero(), market.home_odds);
+        config
+            .pricing_model
+            .max_bet(&config, market_balance, Uint128::zero(), market.home_odds)?;
     let away_max_bet =
-        calculate_max_bet(&config, market_balance, Uint128::zero(), market.away_odds);
+        config
+            .pricing_model
+            .max_bet(&config, market_balance, Uint128::zero(), market.away_odds)?;
+
+    let mut line = String::default();
+    let mut handicap = String::default();
+    match kind {
+        MarketKind::TOTALS { line: totals_line } => line = totals_line.to_string(),
+        MarketKind::HANDICAP {
+            handicap: home_handicap,
+        } => handicap = home_handicap.to_string(),
+        MarketKind::MONEYLINE => (),
+    }
 
     Ok(Response::new()
         .add_attribute("protocol", "vendetta-markets")
@@ -112,8 +232,37 @@ pub fn instantiate(
         .add_attribute("seed_liquidity", market_balance.to_string())
         .add_attribute("initial_odds_home", msg.initial_odds_home.to_string())
         .add_attribute("initial_odds_away", msg.initial_odds_away.to_string())
+        .add_attribute("pricing_model", pricing_model.to_string())
+        .add_attribute(
+            "parlay_addr",
+            msg.parlay_addr
+                .map(|addr| addr.to_string())
+                .unwrap_or_default(),
+        )
+        .add_attribute(
+            "trader_addr",
+            msg.live
+                .as_ref()
+                .map(|live| live.trader_addr.to_string())
+                .unwrap_or_default(),
+        )
+        .add_attribute(
+            "bet_delay",
+            msg.live
+                .map(|live| live.bet_delay.to_string())
+                .unwrap_or_default(),
+        )
+        .add_attribute(
+            "trader_pubkey",
+            msg.trader_pubkey
+                .map(|trader_pubkey| trader_pubkey.to_base64())
+                .unwrap_or_default(),
+        )
         .add_attribute("id", market.id)
         .add_attribute("label", market.label)
+        .add_attribute("kind", kind.to_string())
+        .add_attribute("line", line)
+        .add_attribute("handicap", handicap)
         .add_attribute("home_team", market.home_team)
         .add_attribute("home_odds", market.home_odds.to_string())
         .add_attribute("home_max_bet", home_max_bet.to_string())
@@ -121,7 +270,12 @@ pub fn instantiate(
         .add_attribute("away_odds", market.away_odds.to_string())
         .add_attribute("away_max_bet", away_max_bet.to_string())
         .add_attribute("start_timestamp", market.start_timestamp.to_string())
-        .add_attribute("status", Status::ACTIVE.to_string()))
+        .add_attribute(
+            "resolution_deadline",
+            market.resolution_deadline.to_string(),
+        )
+        .add_attribute("status", Status::ACTIVE.to_string())
+        .add_attributes(market.metadata.unwrap_or_default().attributes()))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -129,11 +283,35 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
         QueryMsg::Market {} => to_json_binary(&query_market(deps)?),
-        QueryMsg::MaxBets {} => to_json_binary(&query_max_bets(deps, env)?),
+        QueryMsg::Ledger {} => to_json_binary(&query_ledger(deps)?),
+        QueryMsg::MaxBets {} => to_json_binary(&query_max_bets(deps)?),
         QueryMsg::Bets {} => to_json_binary(&query_bets(deps)?),
         QueryMsg::BetsByAddress { address } => {
             to_json_binary(&query_bets_by_address(deps, address)?)
         }
+        QueryMsg::PendingBet { id } => to_json_binary(&query_pending_bet(deps, id)?),
+        QueryMsg::LimitBet { id } => to_json_binary(&query_limit_bet(deps, id)?),
+        QueryMsg::LimitBets { start_after, limit } => {
+            to_json_binary(&query_limit_bets(deps, start_after, limit)?)
+        }
+        QueryMsg::Odds { format } => to_json_binary(&query_odds(deps, format)?),
+        QueryMsg::Exposure {} => to_json_binary(&query_exposure(deps)?),
+        QueryMsg::Solvency {} => to_json_binary(&query_solvency(deps, env)?),
+        QueryMsg::Quote { result, bet_amount } => {
+            to_json_binary(&query_quote(deps, result, bet_amount)?)
+        }
+        QueryMsg::OwnerOf {
+            token_id,
+            include_expired,
+        } => to_json_binary(&query_owner_of(deps, env, token_id, include_expired)?),
+        QueryMsg::NftInfo { token_id } => to_json_binary(&query_nft_info(deps, token_id)?),
+        QueryMsg::NumTokens {} => to_json_binary(&query_num_tokens(deps)?),
+        QueryMsg::Tokens {
+            owner,
+            start_after,
+            limit,
+        } => to_json_binary(&query_tokens(deps, owner, start_after, limit)?),
+        QueryMsg::ContractInfo {} => to_json_binary(&query_contract_info(deps)?),
     }
 }
 
@@ -150,7 +328,55 @@ pub fn execute(
             min_odds,
             receiver,
         } => execute_place_bet(deps, env, info, result, min_odds, receiver),
-        ExecuteMsg::ClaimWinnings { receiver } => execute_claim_winnings(deps, info, receiver),
+        ExecuteMsg::PlaceQuotedBet { quote, signature } => {
+            execute_place_quoted_bet(deps, env, info, quote, signature)
+        }
+        ExecuteMsg::PlaceLimitBet {
+            result,
+            target_odds,
+            expires_at,
+        } => execute_place_limit_bet(deps, env, info, result, target_odds, expires_at),
+        ExecuteMsg::CancelLimitBet { id } => execute_cancel_limit_bet(deps, info, id),
+        ExecuteMsg::ExecuteLimitBets { start_after, limit } => {
+            execute_limit_bets(deps, env, info, start_after, limit)
+        }
+        ExecuteMsg::ClaimWinnings { receiver } => execute_claim_winnings(deps, env, info, receiver),
+        ExecuteMsg::ConfirmBet { id } => execute_confirm_bet(deps, env, info, id),
+        ExecuteMsg::ExpireMarket {} => execute_expire_market(deps, env, info),
+        ExecuteMsg::WithdrawStake { token_id } => execute_withdraw_stake(deps, env, info, token_id),
+        ExecuteMsg::TransferPosition {
+            result,
+            amount,
+            recipient,
+        } => execute_transfer_position(deps, info, result, amount, recipient),
+        ExecuteMsg::MintBetSlip { result, amount } => {
+            execute_mint_bet_slip(deps, info, result, amount)
+        }
+        ExecuteMsg::ClaimByToken { token_id } => execute_claim_by_token(deps, env, info, token_id),
+        ExecuteMsg::TransferNft {
+            recipient,
+            token_id,
+        } => execute_transfer_nft(deps, env, info, recipient, token_id),
+        ExecuteMsg::SendNft {
+            contract,
+            token_id,
+            msg,
+        } => execute_send_nft(deps, env, info, contract, token_id, msg),
+        ExecuteMsg::Approve {
+            spender,
+            token_id,
+            expires,
+        } => execute_approve(deps, env, info, spender, token_id, expires),
+        ExecuteMsg::Revoke { spender, token_id } => execute_revoke(deps, info, spender, token_id),
+        ExecuteMsg::ReserveParlayLiability { result, amount } => {
+            execute_reserve_parlay_liability(deps, env, info, result, amount)
+        }
+        ExecuteMsg::Suspend {} => execute_suspend(deps, info),
+        ExecuteMsg::Resume {} => execute_resume(deps, info),
+        ExecuteMsg::UpdateOdds {
+            initial_odds_home,
+            initial_odds_away,
+        } => execute_update_odds(deps, info, initial_odds_home, initial_odds_away),
         ExecuteMsg::Update {
             admin_addr,
             treasury_addr,
@@ -160,9 +386,13 @@ pub fn execute(
             initial_odds_home,
             initial_odds_away,
             start_timestamp,
+            parlay_addr,
+            label,
+            home_team,
+            away_team,
+            metadata,
         } => execute_update(
             deps,
-            env,
             info,
             UpdateParams {
                 admin_addr,
@@ -173,10 +403,26 @@ pub fn execute(
                 initial_odds_home,
                 initial_odds_away,
                 start_timestamp,
+                parlay_addr,
+                label,
+                home_team,
+                away_team,
+                metadata,
             },
         ),
         ExecuteMsg::Score { result } => execute_score(deps, env, info, result),
-        ExecuteMsg::Cancel {} => execute_cancel(deps, env, info),
+        ExecuteMsg::ScoreTotal { total } => execute_score_total(deps, env, info, total),
+        ExecuteMsg::ScoreFinal {
+            home_score,
+            away_score,
+        } => execute_score_final(deps, env, info, home_score, away_score),
+        ExecuteMsg::Cancel {} => execute_cancel(deps, info),
+        ExecuteMsg::Void { reason } => execute_void(deps, info, reason),
+        ExecuteMsg::Postpone {} => execute_postpone(deps, env, info),
+        ExecuteMsg::Reschedule { start_timestamp } => {
+            execute_reschedule(deps, env, info, start_timestamp)
+        }
+        ExecuteMsg::ReconcileSurplus {} => execute_reconcile_surplus(deps, env, info),
     }
 }
 
@@ -1,11 +1,22 @@
-use cosmwasm_std::{Decimal, StdError};
+use cosmwasm_std::{Decimal, OverflowError, SignedDecimal, StdError};
 use thiserror::Error;
 
+use crate::state::MarketResult;
+
 #[derive(Error, Debug, PartialEq)]
 pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("Invalid timestamp: {0}")]
+    InvalidTimestamp(u64),
+
+    #[error("Invalid precision: {0}")]
+    InvalidPrecision(u32),
+
     #[error("Invalid address: {0}")]
     InvalidAddress(String),
 
@@ -18,6 +29,18 @@ pub enum ContractError {
     #[error("Invalid odds combination")]
     InvalidOddsCombination,
 
+    #[error("Invalid totals line: {0}")]
+    InvalidLine(Decimal),
+
+    #[error("Invalid handicap: {0}")]
+    InvalidHandicap(SignedDecimal),
+
+    #[error("Invalid market result: {0}")]
+    InvalidMarketResult(MarketResult),
+
+    #[error("Invalid market kind")]
+    InvalidMarketKind {},
+
     #[error("Invalid fee spread odds: {0}")]
     InvalidFeeSpreadOdds(Decimal),
 
@@ -27,9 +50,24 @@ pub enum ContractError {
     #[error("Invalid seed liquidity amplifier: {0}")]
     InvalidSeedLiquidityAmplifier(Decimal),
 
+    #[error("Invalid LMSR liquidity: {0}")]
+    InvalidLmsrLiquidity(Decimal),
+
     #[error("Market not initially funded")]
     MarketNotInitiallyFunded {},
 
+    #[error("Invalid resolution grace period: {0}")]
+    InvalidResolutionGracePeriod(u64),
+
+    #[error("Invalid postponement period: {0}")]
+    InvalidPostponementPeriod(u64),
+
+    #[error("Invalid bet delay: {0}")]
+    InvalidBetDelay(u64),
+
+    #[error("Invalid metadata: {0}")]
+    InvalidMetadata(String),
+
     #[error("Market not active")]
     MarketNotActive {},
 
@@ -39,6 +77,18 @@ pub enum ContractError {
     #[error("Market not scoreable")]
     MarketNotScoreable {},
 
+    #[error("Market not expired")]
+    MarketNotExpired {},
+
+    #[error("Market not postponed")]
+    MarketNotPostponed {},
+
+    #[error("Postponement expired")]
+    PostponementExpired {},
+
+    #[error("No stake to withdraw")]
+    NoStakeToWithdraw {},
+
     #[error("Bets no longer accepted")]
     BetsNotAccepted {},
 
@@ -59,4 +109,78 @@ pub enum ContractError {
 
     #[error("Max bet exceeded")]
     MaxBetExceeded {},
+
+    #[error("Max liability exceeded")]
+    MaxLiabilityExceeded {},
+
+    #[error("Parlay liability reserved")]
+    ParlayLiabilityReserved {},
+
+    #[error("Live mode not enabled")]
+    LiveModeNotEnabled {},
+
+    #[error("Market suspended")]
+    MarketSuspended {},
+
+    #[error("Market not suspended")]
+    MarketNotSuspended {},
+
+    #[error("Bet delay not elapsed")]
+    BetDelayNotElapsed {},
+
+    #[error("Invalid transfer amount")]
+    InvalidTransferAmount {},
+
+    #[error("Invalid transfer recipient")]
+    InvalidTransferRecipient {},
+
+    #[error("Invalid bet slip amount")]
+    InvalidBetSlipAmount {},
+
+    #[error("Bet slip not found")]
+    BetSlipNotFound {},
+
+    #[error("Approval expired")]
+    ApprovalExpired {},
+
+    #[error("Invalid limit bet expiration: {0}")]
+    InvalidLimitBetExpiration(u64),
+
+    #[error("Quoted bets not enabled")]
+    QuotedBetsNotEnabled {},
+
+    #[error("Invalid trader public key")]
+    InvalidTraderPubkey {},
+
+    #[error("Invalid quote signature")]
+    InvalidQuoteSignature {},
+
+    #[error("Invalid quote")]
+    InvalidQuote {},
+
+    #[error("Quote expired")]
+    QuoteExpired {},
+
+    #[error("Quote already used")]
+    QuoteAlreadyUsed {},
+
+    #[error("Quote max stake exceeded")]
+    QuoteMaxStakeExceeded {},
+
+    #[error("No surplus to reconcile")]
+    NoSurplus {},
+
+    #[error("Market can't be priced")]
+    MarketNotPriceable {},
+}
+
+/// The queries share the market math with the execute messages,
+/// its errors are surfaced as generic errors on them
+impl From<ContractError> for StdError {
+    fn from(err: ContractError) -> Self {
+        match err {
+            ContractError::Std(err) => err,
+            err => StdError::generic_err(err.to_string()),
+        }
+    }
 }
@@ -1,35 +1,60 @@
 use cosmwasm_std::{
-    coin, Addr, BankMsg, CosmosMsg, Decimal, DepsMut, Env, Fraction, MessageInfo, Response,
-    StdResult, Uint128,
+    coin, Addr, Attribute, BankMsg, Binary, CosmosMsg, Decimal, DepsMut, Env, Fraction,
+    MessageInfo, Order, Response, StdResult, Storage, Uint128,
 };
+use cw_storage_plus::Bound;
+use cw_utils::Expiration;
 
 use crate::{
     error::ContractError,
-    logic::{calculate_max_bet, calculate_odds},
-    msg::UpdateParams,
+    logic::{
+        calculate_bets_cutoff, calculate_confirmable_at, calculate_handicap_result,
+        calculate_handicap_settlement, calculate_positions_payout, calculate_result_settlement,
+        calculate_settled_payout, calculate_settlement_amounts, calculate_totals_result,
+        PricingModel, SettlementAmounts,
+    },
+    msg::{BetQuote, Cw721ReceiveMsg, UpdateParams},
+    queries::{
+        query_market_balance, query_unclaimed, DEFAULT_LIMIT_BETS_LIMIT, MAX_LIMIT_BETS_LIMIT,
+    },
     state::{
-        MarketResult, Status, ADDR_BETS_AWAY, ADDR_BETS_HOME, CLAIMS, CONFIG, MARKET,
-        POTENTIAL_PAYOUT_AWAY, POTENTIAL_PAYOUT_HOME, TOTAL_BETS_AWAY, TOTAL_BETS_HOME,
+        load_half_payouts, load_side_book, Approval, BetPayout, BetRecord, BetSlip, Config, Ledger,
+        LimitBet, Market, MarketKind, MarketMetadata, MarketResult, PendingBet, Settlement,
+        SettlementOutcome, Status, VoidReason, ADDR_BETS_AWAY, ADDR_BETS_HOME, BET_SLIPS,
+        BET_SLIP_COUNT, CLAIMS, CONFIG, HALF_PAYOUTS_AWAY, HALF_PAYOUTS_HOME, LEDGER, LIMIT_BETS,
+        LIMIT_BET_COUNT, MARKET, PARLAY_LIABILITY_AWAY, PARLAY_LIABILITY_HOME, PENDING_BETS,
+        PENDING_BET_COUNT, POTENTIAL_PAYOUT_AWAY, POTENTIAL_PAYOUT_HOME, TOTAL_BETS_AWAY,
+        TOTAL_BETS_HOME, TOTAL_LIMIT_BETS, TOTAL_PENDING_BETS, USED_QUOTE_NONCES,
     },
     validation::{
-        validate_fee_spread_odds, validate_max_bet_risk_factor, validate_odd,
-        validate_seed_liquidity_amplifier,
+        validate_fee_spread_odds, validate_market_result, validate_max_bet_risk_factor,
+        validate_metadata, validate_odd, validate_quote_signature,
+        validate_seed_liquidity_amplifier, validate_text, validate_trader, MAX_TEXT_LENGTH,
     },
 };
 
 /// Places a bet on the market
 ///
+/// The bet is priced along the curve of the pricing model, see `PricingModel::quote`,
+/// so the odds locked for the bet amount are the average odds of its own price impact.
+///
 /// The total bets result, potential payout result are updated and the address bets result
 /// that records the average odd and total bet amount per address is updated.
 ///
 /// Then it will recalculate the new odds based on the new bet.
 ///
+/// On a live market the bets placed in play are not accepted right away, they are recorded
+/// as pending bets that can be confirmed once the bet delay has elapsed, see `execute_confirm_bet`.
+///
 /// It will make the following checks:
+/// - The result needs to be valid for the market kind
 /// - The market needs to be active
-/// - The current block timestamp needs to be at least 5 minutes before the start timestamp
-/// - The minimum odds need to be less than the current odds
+/// - The market can't be suspended
+/// - The current block timestamp needs to be at least 5 minutes before the start timestamp,
+///   unless the market is live
 /// - The bet amount needs to be greater than zero
-/// - The bet amount needs to be less than the max allowed bet
+/// - The minimum odds need to be less than the average odds of the bet amount
+/// - The bet amount needs to be less than the max allowed bet at the average odds
 pub fn execute_place_bet(
     deps: DepsMut,
     env: Env,
@@ -39,29 +64,27 @@ pub fn execute_place_bet(
     receiver: Option<Addr>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    let mut market = MARKET.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
 
     let addr = match receiver {
         Some(receiver) => deps.api.addr_validate(receiver.as_str())?,
         None => info.sender.clone(),
     };
 
+    validate_market_result(&market.kind, &result)?;
+
     if market.status != Status::ACTIVE {
         return Err(ContractError::MarketNotActive {});
     }
 
-    // Bets are accepted up until 5 minutes before the start of the match
-    if market.start_timestamp - 5 * 60 < env.block.time.seconds() {
-        return Err(ContractError::BetsNotAccepted {});
+    if market.suspended {
+        return Err(ContractError::MarketSuspended {});
     }
 
-    let odds: Decimal = match result {
-        MarketResult::HOME => market.home_odds,
-        MarketResult::AWAY => market.away_odds,
-    };
-
-    if odds < min_odds {
-        return Err(ContractError::MinimumOddsNotKept {});
+    // Bets are accepted up until 5 minutes before the start of the match, unless the market is live
+    let in_play = calculate_bets_cutoff(market.start_timestamp)? < env.block.time.seconds();
+    if in_play && config.live.is_none() {
+        return Err(ContractError::BetsNotAccepted {});
     }
 
     let bet_amount = cw_utils::must_pay(&info, &config.denom);
@@ -70,206 +93,2110 @@ pub fn execute_place_bet(
     }
     let bet_amount = bet_amount.unwrap();
 
-    let market_balance = deps
-        .querier
-        .query_balance(env.contract.address, &config.denom)?
-        .amount;
+    let market_balance = query_market_balance(deps.as_ref())?;
 
-    let potential_market_payout = match result {
-        MarketResult::HOME => POTENTIAL_PAYOUT_HOME.load(deps.storage)?,
-        MarketResult::AWAY => POTENTIAL_PAYOUT_AWAY.load(deps.storage)?,
+    // The bet is priced along the curve of the pricing model from the market balance
+    // before the bet, so it is locked at the average odds of its own price impact
+    let payout = config.pricing_model.quote(
+        &config,
+        market_balance,
+        &load_side_book(deps.storage, true)?,
+        &load_side_book(deps.storage, false)?,
+        result.is_home_side(),
+        bet_amount,
+    )?;
+    let odds = Decimal::from_ratio(payout, bet_amount);
+
+    if odds < min_odds {
+        return Err(ContractError::MinimumOddsNotKept {});
+    }
+
+    let potential_market_payout = if result.is_home_side() {
+        POTENTIAL_PAYOUT_HOME.load(deps.storage)?
+    } else {
+        POTENTIAL_PAYOUT_AWAY.load(deps.storage)?
     };
 
-    let max_bet = calculate_max_bet(
+    let max_bet = config.pricing_model.max_bet(
         &config,
-        market_balance - bet_amount,
+        market_balance,
         Uint128::from(potential_market_payout),
         odds,
-    );
+    )?;
     if bet_amount > max_bet {
         return Err(ContractError::MaxBetExceeded {});
     }
 
-    let bet_record = match result {
-        MarketResult::HOME => ADDR_BETS_HOME.may_load(deps.storage, addr.clone())?,
-        MarketResult::AWAY => ADDR_BETS_AWAY.may_load(deps.storage, addr.clone())?,
-    };
+    if in_play {
+        let bet_delay = config.live.as_ref().map_or(0, |live| live.bet_delay);
+        let confirmable_at = calculate_confirmable_at(env.block.time.seconds(), bet_delay)?;
+
+        let id = PENDING_BET_COUNT.load(deps.storage)? + 1;
+        PENDING_BET_COUNT.save(deps.storage, &id)?;
+        PENDING_BETS.save(
+            deps.storage,
+            id,
+            &PendingBet {
+                id,
+                addr: addr.clone(),
+                result: result.clone(),
+                bet_amount: bet_amount.into(),
+                odds,
+                placed_at: env.block.time.seconds(),
+                odds_revision: market.odds_revision,
+            },
+        )?;
+        TOTAL_PENDING_BETS.update(deps.storage, |total| -> StdResult<_> {
+            Ok(Uint128::from(total).checked_add(bet_amount)?.into())
+        })?;
+
+        return Ok(Response::new()
+            .add_attribute("protocol", "vendetta-markets")
+            .add_attribute("market_type", "fixed-odds")
+            .add_attribute("action", "place_pending_bet")
+            .add_attribute("sender", info.sender)
+            .add_attribute("receiver", addr)
+            .add_attribute("id", id.to_string())
+            .add_attribute("result", result.to_string())
+            .add_attribute("bet_amount", bet_amount.to_string())
+            .add_attribute("odds", odds.to_string())
+            .add_attribute("confirmable_at", confirmable_at.to_string()));
+    }
 
-    let mut total_bet_amount = bet_amount;
-    let payout = bet_amount.multiply_ratio(odds.numerator(), odds.denominator());
-    let mut total_payout = payout;
-    if bet_record.is_some() {
-        let (previous_total_bet_amount, previous_total_payout) = bet_record.unwrap();
-        total_bet_amount += Uint128::from(previous_total_bet_amount);
-        total_payout += Uint128::from(previous_total_payout);
+    let attributes = accept_bet(deps, &config, market, &addr, &result, bet_amount, payout)?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "fixed-odds")
+        .add_attribute("action", "place_bet")
+        .add_attribute("sender", info.sender)
+        .add_attribute("receiver", addr)
+        .add_attribute("result", result.to_string())
+        .add_attribute("bet_amount", bet_amount.to_string())
+        .add_attribute("odds", odds.to_string())
+        .add_attributes(attributes))
+}
+
+/// Places a bet at the odds quoted off-chain by the trader
+///
+/// The quote is signed with the trader public key and can only be used once by the
+/// bettor it was issued to. The bet is accepted right away at the quoted odds, even in
+/// play, as the trader already priced it, and it moves the odds of the market as any other bet.
+///
+/// It will make the following checks:
+/// - The quoted bets need to be enabled with a trader public key
+/// - The quote signature needs to be valid for the trader public key
+/// - The quote needs to be for this market and this contract, and issued to the sender
+/// - The quote can't be expired nor already used
+/// - The result needs to be valid for the kind of market
+/// - The market needs to be active
+/// - The market can't be suspended
+/// - The current block timestamp needs to be at least 5 minutes before the start timestamp,
+///   unless the market is live
+/// - The quoted odds need to be valid
+/// - The bet amount needs to be greater than zero
+/// - The bet amount needs to be less than the max stake of the quote
+/// - The bet amount needs to be less than the max allowed bet at the quoted odds
+pub fn execute_place_quoted_bet(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    quote: BetQuote,
+    signature: Binary,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
+
+    validate_quote_signature(deps.api, &config, &quote, &signature)?;
+
+    if quote.market_id != market.id
+        || quote.contract_addr != env.contract.address
+        || quote.bettor != info.sender
+    {
+        return Err(ContractError::InvalidQuote {});
     }
 
-    match result {
-        MarketResult::HOME => {
-            TOTAL_BETS_HOME.update(deps.storage, |total| -> StdResult<_> {
-                Ok((Uint128::from(total) + bet_amount).into())
-            })?;
-            ADDR_BETS_HOME.save(
-                deps.storage,
-                addr.clone(),
-                &(total_bet_amount.into(), total_payout.into()),
-            )?;
-            POTENTIAL_PAYOUT_HOME.update(deps.storage, |total| -> StdResult<_> {
-                Ok((Uint128::from(total) + payout).into())
-            })?;
-        }
-        MarketResult::AWAY => {
-            TOTAL_BETS_AWAY.update(deps.storage, |total| -> StdResult<_> {
-                Ok((Uint128::from(total) + bet_amount).into())
-            })?;
-            ADDR_BETS_AWAY.save(
-                deps.storage,
-                addr.clone(),
-                &(total_bet_amount.into(), total_payout.into()),
-            )?;
-            POTENTIAL_PAYOUT_AWAY.update(deps.storage, |total| -> StdResult<_> {
-                Ok((Uint128::from(total) + payout).into())
-            })?;
-        }
-    };
+    if env.block.time.seconds() > quote.expiry {
+        return Err(ContractError::QuoteExpired {});
+    }
 
-    let home_total_bets = TOTAL_BETS_HOME.load(deps.storage)?;
-    let away_total_bets = TOTAL_BETS_AWAY.load(deps.storage)?;
+    if USED_QUOTE_NONCES.has(deps.storage, quote.nonce) {
+        return Err(ContractError::QuoteAlreadyUsed {});
+    }
 
-    let (new_home_odds, new_away_odds) = calculate_odds(
-        &config,
-        market_balance,
-        Uint128::from(home_total_bets),
-        Uint128::from(away_total_bets),
-    );
-    market.home_odds = new_home_odds;
-    market.away_odds = new_away_odds;
-    MARKET.save(deps.storage, &market)?;
+    validate_market_result(&market.kind, &quote.result)?;
 
-    let potential_payout_home = POTENTIAL_PAYOUT_HOME.load(deps.storage)?;
-    let potential_payout_away = POTENTIAL_PAYOUT_AWAY.load(deps.storage)?;
-    let new_home_max_bet = calculate_max_bet(
+    if market.status != Status::ACTIVE {
+        return Err(ContractError::MarketNotActive {});
+    }
+
+    if market.suspended {
+        return Err(ContractError::MarketSuspended {});
+    }
+
+    // Bets are accepted up until 5 minutes before the start of the match, unless the market is live
+    let in_play = calculate_bets_cutoff(market.start_timestamp)? < env.block.time.seconds();
+    if in_play && config.live.is_none() {
+        return Err(ContractError::BetsNotAccepted {});
+    }
+
+    validate_odd(quote.odds)?;
+
+    let bet_amount = cw_utils::must_pay(&info, &config.denom);
+    if bet_amount.is_err() {
+        return Err(ContractError::PaymentError {});
+    }
+    let bet_amount = bet_amount.unwrap();
+
+    if bet_amount > Uint128::from(quote.max_stake) {
+        return Err(ContractError::QuoteMaxStakeExceeded {});
+    }
+
+    let market_balance = query_market_balance(deps.as_ref())?;
+
+    let potential_market_payout = if quote.result.is_home_side() {
+        POTENTIAL_PAYOUT_HOME.load(deps.storage)?
+    } else {
+        POTENTIAL_PAYOUT_AWAY.load(deps.storage)?
+    };
+
+    let max_bet = config.pricing_model.max_bet(
         &config,
         market_balance,
-        Uint128::from(potential_payout_home),
-        new_home_odds,
-    );
-    let new_away_max_bet = calculate_max_bet(
+        Uint128::from(potential_market_payout),
+        quote.odds,
+    )?;
+    if bet_amount > max_bet {
+        return Err(ContractError::MaxBetExceeded {});
+    }
+
+    USED_QUOTE_NONCES.save(deps.storage, quote.nonce, &true)?;
+
+    let attributes = accept_bet(
+        deps,
         &config,
-        market_balance,
-        Uint128::from(potential_payout_away),
-        new_away_odds,
-    );
+        market,
+        &info.sender,
+        &quote.result,
+        bet_amount,
+        bet_amount.multiply_ratio(quote.odds.numerator(), quote.odds.denominator()),
+    )?;
 
     Ok(Response::new()
         .add_attribute("protocol", "vendetta-markets")
         .add_attribute("market_type", "fixed-odds")
-        .add_attribute("action", "place_bet")
+        .add_attribute("action", "place_quoted_bet")
         .add_attribute("sender", info.sender)
-        .add_attribute("receiver", addr)
-        .add_attribute("result", result.to_string())
+        .add_attribute("nonce", quote.nonce.to_string())
+        .add_attribute("result", quote.result.to_string())
         .add_attribute("bet_amount", bet_amount.to_string())
-        .add_attribute("odds", odds.to_string())
-        .add_attribute("potential_payout", payout.to_string())
-        .add_attribute("new_home_odds", market.home_odds.to_string())
-        .add_attribute("new_away_odds", market.away_odds.to_string())
-        .add_attribute("new_home_max_bet", new_home_max_bet.to_string())
-        .add_attribute("new_away_max_bet", new_away_max_bet.to_string())
-        .add_attribute("total_bets_home", home_total_bets.to_string())
-        .add_attribute("total_bets_away", away_total_bets.to_string())
-        .add_attribute("potential_payout_home", potential_payout_home.to_string())
-        .add_attribute("potential_payout_away", potential_payout_away.to_string()))
+        .add_attribute("odds", quote.odds.to_string())
+        .add_attributes(attributes))
 }
 
-/// Claims winnings for the sender or the receiver if defined or returns all bets
-/// made if the market was cancelled, it will calculate the winnings based on the
-/// average odds and the total bet amount for the address.
+/// Places a limit bet on the market, filled once the odds reach the target odds
+///
+/// The stake is escrowed in the order book apart from the market balance until the limit
+/// bet is filled by `execute_limit_bets`, cancelled or refunded once expired.
 ///
 /// It will make the following checks:
-/// - The market needs to be closed
-/// - The address can't have claimed already
-/// - The address needs to have some amount to claim
-pub fn execute_claim_winnings(
+/// - The result needs to be valid for the kind of market
+/// - The market needs to be active
+/// - The current block timestamp needs to be at least 5 minutes before the start timestamp
+/// - The target odds need to be valid
+/// - The expiration needs to be in the future
+/// - The bet amount needs to be greater than zero
+pub fn execute_place_limit_bet(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
-    receiver: Option<Addr>,
+    result: MarketResult,
+    target_odds: Decimal,
+    expires_at: u64,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     let market = MARKET.load(deps.storage)?;
 
-    let addr = match receiver {
-        Some(receiver) => deps.api.addr_validate(receiver.as_str())?,
-        None => info.sender.clone(),
-    };
+    validate_market_result(&market.kind, &result)?;
 
-    if market.status == Status::ACTIVE {
-        return Err(ContractError::MarketNotClosed {});
+    if market.status != Status::ACTIVE {
+        return Err(ContractError::MarketNotActive {});
     }
 
-    if CLAIMS.has(deps.storage, addr.clone()) {
-        return Err(ContractError::ClaimAlreadyMade {});
+    // Limit bets are only filled up until 5 minutes before the start of the match
+    if calculate_bets_cutoff(market.start_timestamp)? < env.block.time.seconds() {
+        return Err(ContractError::BetsNotAccepted {});
     }
 
-    let home_bet = ADDR_BETS_HOME.may_load(deps.storage, addr.clone())?;
-    let away_bet = ADDR_BETS_AWAY.may_load(deps.storage, addr.clone())?;
+    validate_odd(target_odds)?;
 
-    let mut payout = 0;
-    if market.status == Status::CANCELLED {
-        payout = home_bet.unwrap_or((0, 0)).0 + away_bet.unwrap_or((0, 0)).0;
-    } else {
-        match market.result {
-            Some(MarketResult::HOME) => {
-                if let Some((_, total_payout)) = home_bet {
-                    payout = total_payout;
-                }
-            }
-            Some(MarketResult::AWAY) => {
-                if let Some((_, total_payout)) = away_bet {
-                    payout = total_payout;
-                }
-            }
-            None => (),
-        }
+    if expires_at <= env.block.time.seconds() {
+        return Err(ContractError::InvalidLimitBetExpiration(expires_at));
     }
 
-    let mut messages: Vec<CosmosMsg> = vec![];
-    if payout > 0 {
-        messages.push(
-            BankMsg::Send {
-                to_address: addr.to_string(),
-                amount: vec![coin(payout, config.denom)],
-            }
-            .into(),
-        );
-    } else {
-        return Err(ContractError::NoWinnings {});
+    let bet_amount = cw_utils::must_pay(&info, &config.denom);
+    if bet_amount.is_err() {
+        return Err(ContractError::PaymentError {});
     }
+    let bet_amount = bet_amount.unwrap();
 
-    CLAIMS.save(deps.storage, addr.clone(), &true)?;
+    let id = LIMIT_BET_COUNT.load(deps.storage)? + 1;
+    LIMIT_BET_COUNT.save(deps.storage, &id)?;
+    LIMIT_BETS.save(
+        deps.storage,
+        id,
+        &LimitBet {
+            id,
+            addr: info.sender.clone(),
+            result: result.clone(),
+            bet_amount: bet_amount.into(),
+            target_odds,
+            expires_at,
+            placed_at: env.block.time.seconds(),
+        },
+    )?;
+    TOTAL_LIMIT_BETS.update(deps.storage, |total| -> StdResult<_> {
+        Ok(Uint128::from(total).checked_add(bet_amount)?.into())
+    })?;
 
     Ok(Response::new()
-        .add_messages(messages)
         .add_attribute("protocol", "vendetta-markets")
         .add_attribute("market_type", "fixed-odds")
-        .add_attribute("action", "claim_winnings")
+        .add_attribute("action", "place_limit_bet")
         .add_attribute("sender", info.sender)
-        .add_attribute("receiver", addr)
-        .add_attribute("payout", payout.to_string()))
+        .add_attribute("id", id.to_string())
+        .add_attribute("result", result.to_string())
+        .add_attribute("bet_amount", bet_amount.to_string())
+        .add_attribute("target_odds", target_odds.to_string())
+        .add_attribute("expires_at", expires_at.to_string()))
+}
+
+/// Cancels a limit bet that wasn't filled yet and refunds its stake
+///
+/// It will make the following checks:
+/// - The limit bet needs to exist
+/// - The sender needs to be the address that placed the limit bet
+pub fn execute_cancel_limit_bet(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let limit_bet = LIMIT_BETS.load(deps.storage, id)?;
+
+    if limit_bet.addr != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let refund = remove_limit_bet(deps.storage, &config, &limit_bet)?;
+
+    Ok(Response::new()
+        .add_message(refund)
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "fixed-odds")
+        .add_attribute("action", "cancel_limit_bet")
+        .add_attribute("sender", info.sender)
+        .add_attribute("id", id.to_string())
+        .add_attribute("bet_amount", limit_bet.bet_amount.to_string()))
+}
+
+/// Goes through the limit bets of the order book, oldest first, filling the ones
+/// whose target odds are reached and refunding the expired ones
+///
+/// A limit bet is filled through the same path as any other bet, at the average odds
+/// of its bet amount along the pricing model curve, as long as they are at least its
+/// target odds and the bet amount is less than the max allowed bet. Each filled limit
+/// bet moves the odds for the next ones.
+///
+/// The limit bets are expired once their expiration has passed, the bets are no longer
+/// accepted 5 minutes before the start timestamp or the market is no longer active.
+/// While the market is suspended the limit bets are not filled.
+///
+/// Anyone can execute the limit bets.
+pub fn execute_limit_bets(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
+
+    let limit = limit
+        .unwrap_or(DEFAULT_LIMIT_BETS_LIMIT)
+        .min(MAX_LIMIT_BETS_LIMIT) as usize;
+    let limit_bets = LIMIT_BETS
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| item.map(|(_, limit_bet)| limit_bet))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let bets_closed = market.status != Status::ACTIVE
+        || calculate_bets_cutoff(market.start_timestamp)? < env.block.time.seconds();
+
+    let mut refunds = vec![];
+    let mut filled_ids = vec![];
+    let mut refunded_ids = vec![];
+    for limit_bet in limit_bets {
+        if bets_closed || limit_bet.expires_at < env.block.time.seconds() {
+            refunds.push(remove_limit_bet(deps.storage, &config, &limit_bet)?);
+            refunded_ids.push(limit_bet.id.to_string());
+            continue;
+        }
+
+        if market.suspended {
+            continue;
+        }
+
+        // The stake is not part of the market balance until the limit bet is filled
+        let market_balance = query_market_balance(deps.as_ref())?;
+        let bet_amount = Uint128::from(limit_bet.bet_amount);
+        let payout = config.pricing_model.quote(
+            &config,
+            market_balance,
+            &load_side_book(deps.storage, true)?,
+            &load_side_book(deps.storage, false)?,
+            limit_bet.result.is_home_side(),
+            bet_amount,
+        )?;
+        let odds = Decimal::from_ratio(payout, bet_amount);
+        if odds < limit_bet.target_odds {
+            continue;
+        }
+
+        let potential_market_payout = if limit_bet.result.is_home_side() {
+            POTENTIAL_PAYOUT_HOME.load(deps.storage)?
+        } else {
+            POTENTIAL_PAYOUT_AWAY.load(deps.storage)?
+        };
+        let max_bet = config.pricing_model.max_bet(
+            &config,
+            market_balance,
+            Uint128::from(potential_market_payout),
+            odds,
+        )?;
+        if bet_amount > max_bet {
+            continue;
+        }
+
+        LIMIT_BETS.remove(deps.storage, limit_bet.id);
+        TOTAL_LIMIT_BETS.update(deps.storage, |total| -> StdResult<_> {
+            Ok(Uint128::from(total)
+                .checked_sub(limit_bet.bet_amount.into())?
+                .u128())
+        })?;
+
+        let market = MARKET.load(deps.storage)?;
+        accept_bet(
+            deps.branch(),
+            &config,
+            market,
+            &limit_bet.addr,
+            &limit_bet.result,
+            bet_amount,
+            payout,
+        )?;
+        filled_ids.push(limit_bet.id.to_string());
+    }
+
+    let market = MARKET.load(deps.storage)?;
+
+    Ok(Response::new()
+        .add_messages(refunds)
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "fixed-odds")
+        .add_attribute("action", "execute_limit_bets")
+        .add_attribute("sender", info.sender)
+        .add_attribute("filled_limit_bets", filled_ids.join(","))
+        .add_attribute("refunded_limit_bets", refunded_ids.join(","))
+        .add_attribute("new_home_odds", market.home_odds.to_string())
+        .add_attribute("new_away_odds", market.away_odds.to_string()))
+}
+
+/// Removes a limit bet from the order book and returns the refund of its stake
+fn remove_limit_bet(
+    storage: &mut dyn Storage,
+    config: &Config,
+    limit_bet: &LimitBet,
+) -> StdResult<BankMsg> {
+    LIMIT_BETS.remove(storage, limit_bet.id);
+    TOTAL_LIMIT_BETS.update(storage, |total| -> StdResult<_> {
+        Ok(Uint128::from(total)
+            .checked_sub(limit_bet.bet_amount.into())?
+            .u128())
+    })?;
+
+    Ok(BankMsg::Send {
+        to_address: limit_bet.addr.to_string(),
+        amount: vec![coin(limit_bet.bet_amount, &config.denom)],
+    })
+}
+
+/// Records an amount paid out of the market balance in the ledger
+fn record_claimed(storage: &mut dyn Storage, amount: Uint128) -> StdResult<Ledger> {
+    LEDGER.update(storage, |mut ledger| -> StdResult<_> {
+        ledger.claimed = ledger.claimed.checked_add(amount)?;
+        Ok(ledger)
+    })
+}
+
+/// Records the winnings or the refund claimed by a position in the ledger,
+/// paid out of the market balance and no longer owed to the position
+fn record_position_claimed(storage: &mut dyn Storage, amount: Uint128) -> StdResult<Ledger> {
+    LEDGER.update(storage, |mut ledger| -> StdResult<_> {
+        ledger.claimed = ledger.claimed.checked_add(amount)?;
+        ledger.unclaimed = ledger.unclaimed.checked_sub(amount)?;
+        Ok(ledger)
+    })
+}
+
+/// Replaces a position of one side in the half payouts of that side,
+/// a position that doesn't exist is `(0, 0)`
+fn record_position(
+    storage: &mut dyn Storage,
+    home_side: bool,
+    previous: BetRecord,
+    position: BetRecord,
+) -> StdResult<()> {
+    let half_payouts = if home_side {
+        HALF_PAYOUTS_HOME
+    } else {
+        HALF_PAYOUTS_AWAY
+    };
+
+    half_payouts.update(storage, |mut half_payouts| -> StdResult<_> {
+        for (total, outcome) in [
+            (&mut half_payouts.half_win, SettlementOutcome::HALF_WIN),
+            (&mut half_payouts.half_lose, SettlementOutcome::HALF_LOSE),
+        ] {
+            *total = Uint128::from(*total)
+                .checked_sub(calculate_settled_payout(&outcome, previous.0, previous.1).into())?
+                .checked_add(calculate_settled_payout(&outcome, position.0, position.1).into())?
+                .u128();
+        }
+        Ok(half_payouts)
+    })?;
+
+    Ok(())
+}
+
+/// Records the outstanding balance collected from the market in the ledger,
+/// the profit goes to the treasury and the rest back to the admin
+fn record_collected(
+    storage: &mut dyn Storage,
+    outstanding_balance: Uint128,
+    profit: Uint128,
+) -> StdResult<Ledger> {
+    LEDGER.update(storage, |mut ledger| -> StdResult<_> {
+        ledger.fees = ledger.fees.checked_add(profit)?;
+        ledger.claimed = ledger
+            .claimed
+            .checked_add(outstanding_balance.checked_sub(profit)?)?;
+        Ok(ledger)
+    })
+}
+
+/// Confirms a bet placed in play on a live market once the bet delay has elapsed
+///
+/// The bet is accepted at the odds it was placed with if the market is still active, it
+/// hasn't been suspended nor had its odds moved by the trader in the meantime and the bet
+/// amount is still less than the max allowed bet, otherwise the stake is refunded.
+///
+/// Anyone can confirm a pending bet.
+///
+/// It will make the following checks:
+/// - The pending bet needs to exist
+/// - The bet delay needs to have elapsed since the bet was placed
+pub fn execute_confirm_bet(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
+    let pending_bet = PENDING_BETS.load(deps.storage, id)?;
+
+    let bet_delay = config.live.as_ref().map_or(0, |live| live.bet_delay);
+    if env.block.time.seconds() < calculate_confirmable_at(pending_bet.placed_at, bet_delay)? {
+        return Err(ContractError::BetDelayNotElapsed {});
+    }
+
+    PENDING_BETS.remove(deps.storage, id);
+    TOTAL_PENDING_BETS.update(deps.storage, |total| -> StdResult<_> {
+        Ok(Uint128::from(total)
+            .checked_sub(pending_bet.bet_amount.into())?
+            .u128())
+    })?;
+
+    let bet_amount = Uint128::from(pending_bet.bet_amount);
+    let market_balance = query_market_balance(deps.as_ref())?;
+
+    let potential_market_payout = if pending_bet.result.is_home_side() {
+        POTENTIAL_PAYOUT_HOME.load(deps.storage)?
+    } else {
+        POTENTIAL_PAYOUT_AWAY.load(deps.storage)?
+    };
+    let max_bet = config.pricing_model.max_bet(
+        &config,
+        market_balance,
+        Uint128::from(potential_market_payout),
+        pending_bet.odds,
+    )?;
+
+    let response = Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "fixed-odds")
+        .add_attribute("action", "confirm_bet")
+        .add_attribute("sender", info.sender)
+        .add_attribute("id", id.to_string())
+        .add_attribute("receiver", pending_bet.addr.to_string())
+        .add_attribute("result", pending_bet.result.to_string())
+        .add_attribute("bet_amount", bet_amount.to_string())
+        .add_attribute("odds", pending_bet.odds.to_string());
+
+    if market.status != Status::ACTIVE
+        || market.suspended
+        || market.odds_revision != pending_bet.odds_revision
+        || bet_amount > max_bet
+    {
+        return Ok(response
+            .add_message(BankMsg::Send {
+                to_address: pending_bet.addr.to_string(),
+                amount: vec![coin(pending_bet.bet_amount, &config.denom)],
+            })
+            .add_attribute("status", "REFUNDED"));
+    }
+
+    let attributes = accept_bet(
+        deps,
+        &config,
+        market,
+        &pending_bet.addr,
+        &pending_bet.result,
+        bet_amount,
+        bet_amount.multiply_ratio(pending_bet.odds.numerator(), pending_bet.odds.denominator()),
+    )?;
+
+    Ok(response
+        .add_attribute("status", "ACCEPTED")
+        .add_attributes(attributes))
+}
+
+/// Records an accepted bet for the address, adds its stake to the ledger
+/// and recalculates the odds of the market
+///
+/// Returns the attributes with the new state of the market
+fn accept_bet(
+    deps: DepsMut,
+    config: &Config,
+    mut market: Market,
+    addr: &Addr,
+    result: &MarketResult,
+    bet_amount: Uint128,
+    payout: Uint128,
+) -> StdResult<Vec<Attribute>> {
+    let bet_record = if result.is_home_side() {
+        ADDR_BETS_HOME.may_load(deps.storage, addr.clone())?
+    } else {
+        ADDR_BETS_AWAY.may_load(deps.storage, addr.clone())?
+    };
+    let mut total_bet_amount = bet_amount;
+    let mut total_payout = payout;
+    if let Some((previous_total_bet_amount, previous_total_payout)) = bet_record {
+        total_bet_amount = total_bet_amount.checked_add(previous_total_bet_amount.into())?;
+        total_payout = total_payout.checked_add(previous_total_payout.into())?;
+    }
+    record_position(
+        deps.storage,
+        result.is_home_side(),
+        bet_record.unwrap_or((0, 0)),
+        (total_bet_amount.u128(), total_payout.u128()),
+    )?;
+
+    if result.is_home_side() {
+        TOTAL_BETS_HOME.update(deps.storage, |total| -> StdResult<_> {
+            Ok(Uint128::from(total).checked_add(bet_amount)?.into())
+        })?;
+        ADDR_BETS_HOME.save(
+            deps.storage,
+            addr.clone(),
+            &(total_bet_amount.into(), total_payout.into()),
+        )?;
+        POTENTIAL_PAYOUT_HOME.update(deps.storage, |total| -> StdResult<_> {
+            Ok(Uint128::from(total).checked_add(payout)?.into())
+        })?;
+    } else {
+        TOTAL_BETS_AWAY.update(deps.storage, |total| -> StdResult<_> {
+            Ok(Uint128::from(total).checked_add(bet_amount)?.into())
+        })?;
+        ADDR_BETS_AWAY.save(
+            deps.storage,
+            addr.clone(),
+            &(total_bet_amount.into(), total_payout.into()),
+        )?;
+        POTENTIAL_PAYOUT_AWAY.update(deps.storage, |total| -> StdResult<_> {
+            Ok(Uint128::from(total).checked_add(payout)?.into())
+        })?;
+    }
+
+    LEDGER.update(deps.storage, |mut ledger| -> StdResult<_> {
+        ledger.stakes = ledger.stakes.checked_add(bet_amount)?;
+        ledger.unclaimed = ledger.unclaimed.checked_add(bet_amount)?;
+        Ok(ledger)
+    })?;
+
+    let home_total_bets = TOTAL_BETS_HOME.load(deps.storage)?;
+    let away_total_bets = TOTAL_BETS_AWAY.load(deps.storage)?;
+    let market_balance = query_market_balance(deps.as_ref())?;
+
+    let (new_home_odds, new_away_odds) = config.pricing_model.odds(
+        config,
+        market_balance,
+        &load_side_book(deps.storage, true)?,
+        &load_side_book(deps.storage, false)?,
+    )?;
+    market.home_odds = new_home_odds;
+    market.away_odds = new_away_odds;
+    MARKET.save(deps.storage, &market)?;
+
+    let potential_payout_home = POTENTIAL_PAYOUT_HOME.load(deps.storage)?;
+    let potential_payout_away = POTENTIAL_PAYOUT_AWAY.load(deps.storage)?;
+    let new_home_max_bet = config.pricing_model.max_bet(
+        config,
+        market_balance,
+        Uint128::from(potential_payout_home),
+        new_home_odds,
+    )?;
+    let new_away_max_bet = config.pricing_model.max_bet(
+        config,
+        market_balance,
+        Uint128::from(potential_payout_away),
+        new_away_odds,
+    )?;
+
+    Ok(vec![
+        Attribute::new("potential_payout", payout.to_string()),
+        Attribute::new("new_home_odds", market.home_odds.to_string()),
+        Attribute::new("new_away_odds", market.away_odds.to_string()),
+        Attribute::new("new_home_max_bet", new_home_max_bet.to_string()),
+        Attribute::new("new_away_max_bet", new_away_max_bet.to_string()),
+        Attribute::new("total_bets_home", home_total_bets.to_string()),
+        Attribute::new("total_bets_away", away_total_bets.to_string()),
+        Attribute::new("potential_payout_home", potential_payout_home.to_string()),
+        Attribute::new("potential_payout_away", potential_payout_away.to_string()),
+    ])
+}
+
+/// Claims winnings for the sender or the receiver if defined or returns all bets
+/// made if the market was cancelled, it will calculate the winnings based on the
+/// average odds, the total bet amount for the address and how each side of the
+/// market was settled.
+///
+/// See `calculate_settled_payout` for the payout of each settlement outcome.
+///
+/// It will make the following checks:
+/// - The market needs to be closed
+/// - The address can't have claimed already
+/// - The address needs to have some amount to claim
+pub fn execute_claim_winnings(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    receiver: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let (market, mut messages) = expire_postponement(deps.storage, &env)?;
+
+    let addr = match receiver {
+        Some(receiver) => deps.api.addr_validate(receiver.as_str())?,
+        None => info.sender.clone(),
+    };
+
+    if market.status.is_open() {
+        return Err(ContractError::MarketNotClosed {});
+    }
+
+    if CLAIMS.has(deps.storage, addr.clone()) {
+        return Err(ContractError::ClaimAlreadyMade {});
+    }
+
+    let home_bet = ADDR_BETS_HOME.may_load(deps.storage, addr.clone())?;
+    let away_bet = ADDR_BETS_AWAY.may_load(deps.storage, addr.clone())?;
+
+    let (home_bet_amount, home_payout) = home_bet.unwrap_or((0, 0));
+    let (away_bet_amount, away_payout) = away_bet.unwrap_or((0, 0));
+
+    let mut payout = 0;
+    if market.status == Status::CANCELLED || market.status == Status::VOIDED {
+        payout = home_bet_amount + away_bet_amount;
+    } else if let Some(settlement) = market.settlement {
+        payout = calculate_settled_payout(&settlement.home, home_bet_amount, home_payout)
+            + calculate_settled_payout(&settlement.away, away_bet_amount, away_payout);
+    }
+
+    if payout > 0 {
+        messages.push(
+            BankMsg::Send {
+                to_address: addr.to_string(),
+                amount: vec![coin(payout, config.denom)],
+            }
+            .into(),
+        );
+    } else {
+        return Err(ContractError::NoWinnings {});
+    }
+
+    CLAIMS.save(deps.storage, addr.clone(), &true)?;
+    record_position_claimed(deps.storage, payout.into())?;
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "fixed-odds")
+        .add_attribute("action", "claim_winnings")
+        .add_attribute("sender", info.sender)
+        .add_attribute("receiver", addr)
+        .add_attribute("payout", payout.to_string()))
+}
+
+/// Withdraws the whole stake of the sender while the market is postponed,
+/// or the stake of one of its bet slips when a token id is given
+///
+/// The stake is refunded in full, the total bets and the potential payout of each
+/// side are decreased by the withdrawn bets and the odds follow once rescheduled.
+///
+/// It will make the following checks:
+/// - The market needs to be postponed and its postponement deadline can't have passed
+/// - The bet slip needs to exist and the sender needs to be its owner
+/// - The sender needs to have some stake to withdraw
+pub fn execute_withdraw_stake(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
+
+    if market.status != Status::POSTPONED {
+        return Err(ContractError::MarketNotPostponed {});
+    }
+
+    if is_postponement_expired(&market, &env) {
+        return Err(ContractError::PostponementExpired {});
+    }
+
+    let mut refund = 0;
+    if let Some(token_id) = &token_id {
+        let bet_slip = match BET_SLIPS.may_load(deps.storage, token_id.clone())? {
+            Some(bet_slip) => bet_slip,
+            None => return Err(ContractError::BetSlipNotFound {}),
+        };
+
+        if bet_slip.owner != info.sender {
+            return Err(ContractError::Unauthorized {});
+        }
+
+        let (total_bets, potential_payout) = if bet_slip.result.is_home_side() {
+            (TOTAL_BETS_HOME, POTENTIAL_PAYOUT_HOME)
+        } else {
+            (TOTAL_BETS_AWAY, POTENTIAL_PAYOUT_AWAY)
+        };
+        total_bets.update(deps.storage, |total| -> StdResult<_> {
+            Ok(Uint128::from(total)
+                .checked_sub(bet_slip.bet_amount.into())?
+                .u128())
+        })?;
+        potential_payout.update(deps.storage, |total| -> StdResult<_> {
+            Ok(Uint128::from(total)
+                .checked_sub(bet_slip.payout.into())?
+                .u128())
+        })?;
+        BET_SLIPS.remove(deps.storage, token_id.clone());
+        record_position(
+            deps.storage,
+            bet_slip.result.is_home_side(),
+            (bet_slip.bet_amount, bet_slip.payout),
+            (0, 0),
+        )?;
+
+        refund = bet_slip.bet_amount;
+    } else {
+        for (home_side, bets, total_bets, potential_payout) in [
+            (true, ADDR_BETS_HOME, TOTAL_BETS_HOME, POTENTIAL_PAYOUT_HOME),
+            (
+                false,
+                ADDR_BETS_AWAY,
+                TOTAL_BETS_AWAY,
+                POTENTIAL_PAYOUT_AWAY,
+            ),
+        ] {
+            let (bet_amount, payout) = bets
+                .may_load(deps.storage, info.sender.clone())?
+                .unwrap_or((0, 0));
+
+            bets.remove(deps.storage, info.sender.clone());
+            record_position(deps.storage, home_side, (bet_amount, payout), (0, 0))?;
+            total_bets.update(deps.storage, |total| -> StdResult<_> {
+                Ok(Uint128::from(total).checked_sub(bet_amount.into())?.u128())
+            })?;
+            potential_payout.update(deps.storage, |total| -> StdResult<_> {
+                Ok(Uint128::from(total).checked_sub(payout.into())?.u128())
+            })?;
+
+            refund += bet_amount;
+        }
+    }
+
+    if refund == 0 {
+        return Err(ContractError::NoStakeToWithdraw {});
+    }
+
+    record_position_claimed(deps.storage, refund.into())?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![coin(refund, &config.denom)],
+        })
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "fixed-odds")
+        .add_attribute("action", "withdraw_stake")
+        .add_attribute("sender", info.sender)
+        .add_attribute("token_id", token_id.unwrap_or_default())
+        .add_attribute("refund", refund.to_string())
+        .add_attribute(
+            "total_bets_home",
+            TOTAL_BETS_HOME.load(deps.storage)?.to_string(),
+        )
+        .add_attribute(
+            "total_bets_away",
+            TOTAL_BETS_AWAY.load(deps.storage)?.to_string(),
+        )
+        .add_attribute(
+            "potential_payout_home",
+            POTENTIAL_PAYOUT_HOME.load(deps.storage)?.to_string(),
+        )
+        .add_attribute(
+            "potential_payout_away",
+            POTENTIAL_PAYOUT_AWAY.load(deps.storage)?.to_string(),
+        ))
+}
+
+/// Transfers part or all of the position of the sender on one side of the market
+/// to the recipient, so the recipient can claim it once the market is settled
+///
+/// The locked payout is moved proportionally to the transferred bet amount, the
+/// totals and the potential payout of the market are left untouched.
+///
+/// It will make the following checks:
+/// - The result needs to be valid for the market kind
+/// - The market needs to be active or postponed
+/// - The recipient can't be the sender
+/// - The amount needs to be greater than zero and up to the bet amount of the sender
+pub fn execute_transfer_position(
+    deps: DepsMut,
+    info: MessageInfo,
+    result: MarketResult,
+    amount: u128,
+    recipient: Addr,
+) -> Result<Response, ContractError> {
+    let market = MARKET.load(deps.storage)?;
+
+    validate_market_result(&market.kind, &result)?;
+
+    if !market.status.is_open() {
+        return Err(ContractError::MarketNotActive {});
+    }
+
+    let recipient = deps.api.addr_validate(recipient.as_str())?;
+    if recipient == info.sender {
+        return Err(ContractError::InvalidTransferRecipient {});
+    }
+
+    let transferred_payout =
+        match take_position(deps.storage, &info.sender, result.is_home_side(), amount)? {
+            Some(payout) => payout,
+            None => return Err(ContractError::InvalidTransferAmount {}),
+        };
+    add_position(
+        deps.storage,
+        &recipient,
+        result.is_home_side(),
+        amount,
+        transferred_payout,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "fixed-odds")
+        .add_attribute("action", "transfer_position")
+        .add_attribute("sender", info.sender)
+        .add_attribute("recipient", recipient)
+        .add_attribute("result", result.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("payout", transferred_payout.to_string()))
+}
+
+/// Mints part or all of the position of the sender on one side of the market
+/// as a bet slip token owned by the sender
+///
+/// The bet amount and its share of the locked payout are moved out of the bets of the
+/// sender into the bet slip, which can be transferred or sold following the cw721 interface
+/// and is claimed by its owner with `execute_claim_by_token`.
+///
+/// It will make the following checks:
+/// - The result needs to be valid for the market kind
+/// - The market needs to be active
+/// - The amount needs to be greater than zero and up to the bet amount of the sender
+pub fn execute_mint_bet_slip(
+    deps: DepsMut,
+    info: MessageInfo,
+    result: MarketResult,
+    amount: u128,
+) -> Result<Response, ContractError> {
+    let market = MARKET.load(deps.storage)?;
+
+    validate_market_result(&market.kind, &result)?;
+
+    if market.status != Status::ACTIVE {
+        return Err(ContractError::MarketNotActive {});
+    }
+
+    let payout = match take_position(deps.storage, &info.sender, result.is_home_side(), amount)? {
+        Some(payout) => payout,
+        None => return Err(ContractError::InvalidBetSlipAmount {}),
+    };
+
+    record_position(
+        deps.storage,
+        result.is_home_side(),
+        (0, 0),
+        (amount, payout),
+    )?;
+
+    let token_id = (BET_SLIP_COUNT.load(deps.storage)? + 1).to_string();
+    BET_SLIP_COUNT.update(deps.storage, |count| -> StdResult<_> { Ok(count + 1) })?;
+    BET_SLIPS.save(
+        deps.storage,
+        token_id.clone(),
+        &BetSlip {
+            token_id: token_id.clone(),
+            owner: info.sender.clone(),
+            approvals: vec![],
+            result: result.clone(),
+            bet_amount: amount,
+            payout,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "fixed-odds")
+        .add_attribute("action", "mint_bet_slip")
+        .add_attribute("sender", info.sender)
+        .add_attribute("token_id", token_id)
+        .add_attribute("result", result.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("payout", payout.to_string()))
+}
+
+/// Claims the payout of a bet slip for its owner and burns the bet slip, or returns
+/// its bet amount if the market was cancelled
+///
+/// See `calculate_settled_payout` for the payout of each settlement outcome.
+///
+/// It will make the following checks:
+/// - The bet slip needs to exist
+/// - The sender needs to be the owner of the bet slip
+/// - The market needs to be closed
+/// - The bet slip needs to have some amount to claim
+pub fn execute_claim_by_token(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let (market, messages) = expire_postponement(deps.storage, &env)?;
+
+    let bet_slip = match BET_SLIPS.may_load(deps.storage, token_id.clone())? {
+        Some(bet_slip) => bet_slip,
+        None => return Err(ContractError::BetSlipNotFound {}),
+    };
+
+    if bet_slip.owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if market.status.is_open() {
+        return Err(ContractError::MarketNotClosed {});
+    }
+
+    let mut payout = 0;
+    if market.status == Status::CANCELLED || market.status == Status::VOIDED {
+        payout = bet_slip.bet_amount;
+    } else if let Some(settlement) = market.settlement {
+        let outcome = if bet_slip.result.is_home_side() {
+            settlement.home
+        } else {
+            settlement.away
+        };
+        payout = calculate_settled_payout(&outcome, bet_slip.bet_amount, bet_slip.payout);
+    }
+
+    if payout == 0 {
+        return Err(ContractError::NoWinnings {});
+    }
+
+    BET_SLIPS.remove(deps.storage, token_id.clone());
+    record_position_claimed(deps.storage, payout.into())?;
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![coin(payout, config.denom)],
+        })
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "fixed-odds")
+        .add_attribute("action", "claim_by_token")
+        .add_attribute("sender", info.sender)
+        .add_attribute("token_id", token_id)
+        .add_attribute("payout", payout.to_string()))
+}
+
+/// Transfers a bet slip to the recipient, clearing its approvals
+///
+/// It will make the following checks:
+/// - The bet slip needs to exist
+/// - The sender needs to be the owner of the bet slip or have an approval on it that hasn't expired
+pub fn execute_transfer_nft(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+    transfer_bet_slip(deps, &env, &info.sender, &recipient, &token_id)?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "fixed-odds")
+        .add_attribute("action", "transfer_nft")
+        .add_attribute("sender", info.sender)
+        .add_attribute("recipient", recipient)
+        .add_attribute("token_id", token_id))
+}
+
+/// Transfers a bet slip to a contract and notifies it with a `ReceiveNft` message
+///
+/// It will make the following checks:
+/// - The bet slip needs to exist
+/// - The sender needs to be the owner of the bet slip or have an approval on it that hasn't expired
+pub fn execute_send_nft(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    contract: String,
+    token_id: String,
+    msg: Binary,
+) -> Result<Response, ContractError> {
+    let contract = deps.api.addr_validate(&contract)?;
+    transfer_bet_slip(deps, &env, &info.sender, &contract, &token_id)?;
+
+    let receive_msg = Cw721ReceiveMsg {
+        sender: info.sender.to_string(),
+        token_id: token_id.clone(),
+        msg,
+    };
+
+    Ok(Response::new()
+        .add_message(receive_msg.into_cosmos_msg(contract.to_string())?)
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "fixed-odds")
+        .add_attribute("action", "send_nft")
+        .add_attribute("sender", info.sender)
+        .add_attribute("recipient", contract)
+        .add_attribute("token_id", token_id))
+}
+
+/// Approves the spender to transfer a bet slip until the expiration, replacing
+/// any previous approval of the spender on it
+///
+/// It will make the following checks:
+/// - The bet slip needs to exist
+/// - The sender needs to be the owner of the bet slip
+/// - The expiration can't be already expired
+pub fn execute_approve(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    spender: String,
+    token_id: String,
+    expires: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    let spender = deps.api.addr_validate(&spender)?;
+
+    let mut bet_slip = match BET_SLIPS.may_load(deps.storage, token_id.clone())? {
+        Some(bet_slip) => bet_slip,
+        None => return Err(ContractError::BetSlipNotFound {}),
+    };
+
+    if bet_slip.owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let expires = expires.unwrap_or_default();
+    if expires.is_expired(&env.block) {
+        return Err(ContractError::ApprovalExpired {});
+    }
+
+    bet_slip
+        .approvals
+        .retain(|approval| approval.spender != spender);
+    bet_slip.approvals.push(Approval {
+        spender: spender.clone(),
+        expires,
+    });
+    BET_SLIPS.save(deps.storage, token_id.clone(), &bet_slip)?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "fixed-odds")
+        .add_attribute("action", "approve")
+        .add_attribute("sender", info.sender)
+        .add_attribute("spender", spender)
+        .add_attribute("token_id", token_id))
+}
+
+/// Revokes the approval of the spender on a bet slip
+///
+/// It will make the following checks:
+/// - The bet slip needs to exist
+/// - The sender needs to be the owner of the bet slip
+pub fn execute_revoke(
+    deps: DepsMut,
+    info: MessageInfo,
+    spender: String,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    let spender = deps.api.addr_validate(&spender)?;
+
+    let mut bet_slip = match BET_SLIPS.may_load(deps.storage, token_id.clone())? {
+        Some(bet_slip) => bet_slip,
+        None => return Err(ContractError::BetSlipNotFound {}),
+    };
+
+    if bet_slip.owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    bet_slip
+        .approvals
+        .retain(|approval| approval.spender != spender);
+    BET_SLIPS.save(deps.storage, token_id.clone(), &bet_slip)?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "fixed-odds")
+        .add_attribute("action", "revoke")
+        .add_attribute("sender", info.sender)
+        .add_attribute("spender", spender)
+        .add_attribute("token_id", token_id))
+}
+
+/// Moves a bet slip to the recipient if the sender is its owner
+/// or has an approval on it that hasn't expired
+fn transfer_bet_slip(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    recipient: &Addr,
+    token_id: &str,
+) -> Result<(), ContractError> {
+    let mut bet_slip = match BET_SLIPS.may_load(deps.storage, token_id.to_string())? {
+        Some(bet_slip) => bet_slip,
+        None => return Err(ContractError::BetSlipNotFound {}),
+    };
+
+    let is_approved = bet_slip
+        .approvals
+        .iter()
+        .any(|approval| approval.spender == *sender && !approval.expires.is_expired(&env.block));
+    if bet_slip.owner != *sender && !is_approved {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    bet_slip.owner = recipient.clone();
+    bet_slip.approvals = vec![];
+    BET_SLIPS.save(deps.storage, token_id.to_string(), &bet_slip)?;
+
+    Ok(())
+}
+
+/// Removes part or all of the position of the address on one side of the market
+///
+/// Returns the locked payout moved in proportion to the amount, or `None` if the amount
+/// isn't greater than zero and up to the bet amount of the address
+fn take_position(
+    storage: &mut dyn Storage,
+    addr: &Addr,
+    home_side: bool,
+    amount: u128,
+) -> StdResult<Option<BetPayout>> {
+    let addr_bets = if home_side {
+        ADDR_BETS_HOME
+    } else {
+        ADDR_BETS_AWAY
+    };
+
+    let (bet_amount, payout) = addr_bets.may_load(storage, addr.clone())?.unwrap_or((0, 0));
+    if amount == 0 || amount > bet_amount {
+        return Ok(None);
+    }
+
+    if amount == bet_amount {
+        addr_bets.remove(storage, addr.clone());
+        record_position(storage, home_side, (bet_amount, payout), (0, 0))?;
+        return Ok(Some(payout));
+    }
+
+    let moved_payout = Uint128::from(payout)
+        .multiply_ratio(amount, bet_amount)
+        .u128();
+    let position = (bet_amount - amount, payout - moved_payout);
+    addr_bets.save(storage, addr.clone(), &position)?;
+    record_position(storage, home_side, (bet_amount, payout), position)?;
+
+    Ok(Some(moved_payout))
+}
+
+/// Adds the bet amount and its locked payout to the position of the address on one side of the market
+fn add_position(
+    storage: &mut dyn Storage,
+    addr: &Addr,
+    home_side: bool,
+    amount: u128,
+    payout: BetPayout,
+) -> StdResult<()> {
+    let addr_bets = if home_side {
+        ADDR_BETS_HOME
+    } else {
+        ADDR_BETS_AWAY
+    };
+
+    let previous = addr_bets.may_load(storage, addr.clone())?.unwrap_or((0, 0));
+    let position = (previous.0 + amount, previous.1 + payout);
+    addr_bets.save(storage, addr.clone(), &position)?;
+    record_position(storage, home_side, previous, position)?;
+
+    Ok(())
+}
+
+/// Reserves liability on one side of the market for a parlay leg
+///
+/// The amount is added to the potential payout of the side as a bet without stake,
+/// so it is accounted for in the max bets and the solvency of the market, and it is
+/// paid to the parlay contract when the market is scored and the side wins.
+///
+/// It will make the following checks:
+/// - The sender needs to be the parlay contract
+/// - The market can't be a handicap market
+/// - The result needs to be valid for the market kind
+/// - The market needs to be active
+/// - The market can't be suspended
+/// - The current block timestamp needs to be at least 5 minutes before the start timestamp
+/// - The amount needs to be less than the max liability available for the side,
+///   which is the max bet at odds of 1
+pub fn execute_reserve_parlay_liability(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    result: MarketResult,
+    amount: u128,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
+
+    if config.parlay_addr != Some(info.sender.clone()) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let MarketKind::HANDICAP { .. } = market.kind {
+        return Err(ContractError::InvalidMarketKind {});
+    }
+
+    validate_market_result(&market.kind, &result)?;
+
+    if market.status != Status::ACTIVE {
+        return Err(ContractError::MarketNotActive {});
+    }
+
+    if market.suspended {
+        return Err(ContractError::MarketSuspended {});
+    }
+
+    // Liability is reserved up until 5 minutes before the start of the match, like bets
+    if calculate_bets_cutoff(market.start_timestamp)? < env.block.time.seconds() {
+        return Err(ContractError::BetsNotAccepted {});
+    }
+
+    let market_balance = query_market_balance(deps.as_ref())?;
+
+    let potential_market_payout = if result.is_home_side() {
+        POTENTIAL_PAYOUT_HOME.load(deps.storage)?
+    } else {
+        POTENTIAL_PAYOUT_AWAY.load(deps.storage)?
+    };
+
+    let max_liability = config.pricing_model.max_bet(
+        &config,
+        market_balance,
+        Uint128::from(potential_market_payout),
+        Decimal::one(),
+    )?;
+    if Uint128::from(amount) > max_liability {
+        return Err(ContractError::MaxLiabilityExceeded {});
+    }
+
+    if result.is_home_side() {
+        POTENTIAL_PAYOUT_HOME.update(deps.storage, |total| -> StdResult<_> {
+            Ok(Uint128::from(total).checked_add(amount.into())?.u128())
+        })?;
+        PARLAY_LIABILITY_HOME.update(deps.storage, |total| -> StdResult<_> {
+            Ok(Uint128::from(total).checked_add(amount.into())?.u128())
+        })?;
+    } else {
+        POTENTIAL_PAYOUT_AWAY.update(deps.storage, |total| -> StdResult<_> {
+            Ok(Uint128::from(total).checked_add(amount.into())?.u128())
+        })?;
+        PARLAY_LIABILITY_AWAY.update(deps.storage, |total| -> StdResult<_> {
+            Ok(Uint128::from(total).checked_add(amount.into())?.u128())
+        })?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "fixed-odds")
+        .add_attribute("action", "reserve_parlay_liability")
+        .add_attribute("sender", info.sender)
+        .add_attribute("result", result.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute(
+            "potential_payout_home",
+            POTENTIAL_PAYOUT_HOME.load(deps.storage)?.to_string(),
+        )
+        .add_attribute(
+            "potential_payout_away",
+            POTENTIAL_PAYOUT_AWAY.load(deps.storage)?.to_string(),
+        )
+        .add_attribute(
+            "parlay_liability_home",
+            PARLAY_LIABILITY_HOME.load(deps.storage)?.to_string(),
+        )
+        .add_attribute(
+            "parlay_liability_away",
+            PARLAY_LIABILITY_AWAY.load(deps.storage)?.to_string(),
+        ))
+}
+
+/// Suspends the betting on a live market, the pending bets placed
+/// before the suspension will be refunded when confirmed
+///
+/// It will make the following checks:
+/// - The market needs to be live
+/// - The sender needs to be the trader
+/// - The market needs to be active
+/// - The market can't be suspended already
+pub fn execute_suspend(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut market = MARKET.load(deps.storage)?;
+
+    validate_trader(&config, &info.sender)?;
+
+    if market.status != Status::ACTIVE {
+        return Err(ContractError::MarketNotActive {});
+    }
+
+    if market.suspended {
+        return Err(ContractError::MarketSuspended {});
+    }
+
+    market.suspended = true;
+    market.odds_revision += 1;
+    MARKET.save(deps.storage, &market)?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "fixed-odds")
+        .add_attribute("action", "suspend_market")
+        .add_attribute("sender", info.sender)
+        .add_attribute("odds_revision", market.odds_revision.to_string()))
+}
+
+/// Resumes the betting on a suspended live market
+///
+/// It will make the following checks:
+/// - The market needs to be live
+/// - The sender needs to be the trader
+/// - The market needs to be active
+/// - The market needs to be suspended
+pub fn execute_resume(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut market = MARKET.load(deps.storage)?;
+
+    validate_trader(&config, &info.sender)?;
+
+    if market.status != Status::ACTIVE {
+        return Err(ContractError::MarketNotActive {});
+    }
+
+    if !market.suspended {
+        return Err(ContractError::MarketNotSuspended {});
+    }
+
+    market.suspended = false;
+    MARKET.save(deps.storage, &market)?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "fixed-odds")
+        .add_attribute("action", "resume_market")
+        .add_attribute("sender", info.sender)
+        .add_attribute("odds_revision", market.odds_revision.to_string()))
+}
+
+/// Moves the initial odds of a live market during the match, it will recalculate
+/// the new odds and the pending bets placed before will be refunded when confirmed
+///
+/// It will make the following checks:
+/// - The market needs to be live
+/// - The sender needs to be the trader
+/// - The market needs to be active
+/// - The initial odds need to be valid
+pub fn execute_update_odds(
+    deps: DepsMut,
+    info: MessageInfo,
+    initial_odds_home: Decimal,
+    initial_odds_away: Decimal,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    let mut market = MARKET.load(deps.storage)?;
+
+    validate_trader(&config, &info.sender)?;
+
+    if market.status != Status::ACTIVE {
+        return Err(ContractError::MarketNotActive {});
+    }
+
+    validate_odd(initial_odds_home)?;
+    validate_odd(initial_odds_away)?;
+
+    config.initial_odds_home = initial_odds_home;
+    config.initial_odds_away = initial_odds_away;
+    CONFIG.save(deps.storage, &config)?;
+
+    let market_balance = query_market_balance(deps.as_ref())?;
+    let (new_home_odds, new_away_odds) = config.pricing_model.odds(
+        &config,
+        market_balance,
+        &load_side_book(deps.storage, true)?,
+        &load_side_book(deps.storage, false)?,
+    )?;
+    market.home_odds = new_home_odds;
+    market.away_odds = new_away_odds;
+    market.odds_revision += 1;
+    MARKET.save(deps.storage, &market)?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "fixed-odds")
+        .add_attribute("action", "update_odds")
+        .add_attribute("sender", info.sender)
+        .add_attribute("initial_odds_home", initial_odds_home.to_string())
+        .add_attribute("initial_odds_away", initial_odds_away.to_string())
+        .add_attribute("home_odds", market.home_odds.to_string())
+        .add_attribute("away_odds", market.away_odds.to_string())
+        .add_attribute("odds_revision", market.odds_revision.to_string()))
+}
+
+/// Updates the market with the new params, it will recalculate
+/// the new odds based on the new params and the pending bets placed
+/// before will be refunded when confirmed if the odds moved.
+///
+/// The label, the team names and the metadata can be edited as well,
+/// the given metadata replaces the whole metadata of the market.
+///
+/// It will make the following checks:
+/// - The sender needs to be the admin
+/// - The market needs to be active
+/// - The parlay contract can't be changed while parlay liability is reserved
+pub fn execute_update(
+    deps: DepsMut,
+    info: MessageInfo,
+    params: UpdateParams,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    let mut market = MARKET.load(deps.storage)?;
+
+    if info.sender != config.admin_addr {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if market.status != Status::ACTIVE {
+        return Err(ContractError::MarketNotActive {});
+    }
+
+    let mut admin_addr_update = String::default();
+    if let Some(admin_addr) = params.admin_addr {
+        config.admin_addr = admin_addr.clone();
+        admin_addr_update = admin_addr.to_string();
+    }
+
+    let mut treasury_addr_update = String::default();
+    if let Some(treasury_addr) = params.treasury_addr {
+        config.treasury_addr = treasury_addr.clone();
+        treasury_addr_update = treasury_addr.to_string();
+    }
+
+    let mut fee_spread_odds_update = String::default();
+    if let Some(fee_spread_odds) = params.fee_spread_odds {
+        validate_fee_spread_odds(fee_spread_odds)?;
+
+        config.fee_spread_odds = fee_spread_odds;
+        fee_spread_odds_update = fee_spread_odds.to_string();
+    }
+
+    let mut max_bet_risk_factor_update = String::default();
+    if let Some(max_bet_risk_factor) = params.max_bet_risk_factor {
+        validate_max_bet_risk_factor(max_bet_risk_factor)?;
+
+        config.max_bet_risk_factor = max_bet_risk_factor;
+        max_bet_risk_factor_update = max_bet_risk_factor.to_string();
+    }
+
+    let mut seed_liquidity_amplifier_update = String::default();
+    if let Some(seed_liquidity_amplifier) = params.seed_liquidity_amplifier {
+        validate_seed_liquidity_amplifier(seed_liquidity_amplifier)?;
+
+        config.seed_liquidity_amplifier = seed_liquidity_amplifier;
+        seed_liquidity_amplifier_update = seed_liquidity_amplifier.to_string();
+    }
+
+    let mut initial_odds_home_update = String::default();
+    let mut initial_odds_away_update = String::default();
+    let initial_odds_home = match params.initial_odds_home {
+        Some(initial_odds_home) => initial_odds_home,
+        None => Decimal::zero(),
+    };
+    let initial_odds_away = match params.initial_odds_away {
+        Some(initial_odds_away) => initial_odds_away,
+        None => Decimal::zero(),
+    };
+    if initial_odds_home != Decimal::zero() || initial_odds_away != Decimal::zero() {
+        if initial_odds_home == Decimal::zero() || initial_odds_away == Decimal::zero() {
+            return Err(ContractError::InvalidOddsCombination);
+        }
+        validate_odd(initial_odds_home)?;
+        validate_odd(initial_odds_away)?;
+
+        config.initial_odds_home = initial_odds_home;
+        config.initial_odds_away = initial_odds_away;
+        initial_odds_home_update = initial_odds_home.to_string();
+        initial_odds_away_update = initial_odds_away.to_string();
+    }
+
+    let mut start_timestamp_update = String::default();
+    if let Some(start_timestamp) = params.start_timestamp {
+        market.start_timestamp = start_timestamp;
+        market.resolution_deadline = start_timestamp
+            .checked_add(config.resolution_grace_period)
+            .ok_or(ContractError::InvalidTimestamp(start_timestamp))?;
+        start_timestamp_update = start_timestamp.to_string();
+    }
+
+    let mut parlay_addr_update = String::default();
+    if let Some(parlay_addr) = params.parlay_addr {
+        // The reserved liability is paid to the parlay contract that reserved it
+        if config.parlay_addr.as_ref() != Some(&parlay_addr)
+            && (PARLAY_LIABILITY_HOME.load(deps.storage)? > 0
+                || PARLAY_LIABILITY_AWAY.load(deps.storage)? > 0)
+        {
+            return Err(ContractError::ParlayLiabilityReserved {});
+        }
+
+        config.parlay_addr = Some(parlay_addr.clone());
+        parlay_addr_update = parlay_addr.to_string();
+    }
+
+    let mut label_update = String::default();
+    if let Some(label) = params.label {
+        validate_text("label", &label, MAX_TEXT_LENGTH)?;
+        market.label = label.clone();
+        label_update = label;
+    }
+
+    let mut home_team_update = String::default();
+    if let Some(home_team) = params.home_team {
+        validate_text("home_team", &home_team, MAX_TEXT_LENGTH)?;
+        market.home_team = home_team.clone();
+        home_team_update = home_team;
+    }
+
+    let mut away_team_update = String::default();
+    if let Some(away_team) = params.away_team {
+        validate_text("away_team", &away_team, MAX_TEXT_LENGTH)?;
+        market.away_team = away_team.clone();
+        away_team_update = away_team;
+    }
+
+    let mut metadata_update = MarketMetadata::default();
+    if let Some(metadata) = params.metadata {
+        validate_metadata(&metadata)?;
+        market.metadata = Some(metadata.clone());
+        metadata_update = metadata;
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    let market_balance = query_market_balance(deps.as_ref())?;
+    let home_total_bets = TOTAL_BETS_HOME.load(deps.storage)?;
+    let away_total_bets = TOTAL_BETS_AWAY.load(deps.storage)?;
+
+    let (new_home_odds, new_away_odds) = config.pricing_model.odds(
+        &config,
+        market_balance,
+        &load_side_book(deps.storage, true)?,
+        &load_side_book(deps.storage, false)?,
+    )?;
+    if new_home_odds != market.home_odds || new_away_odds != market.away_odds {
+        market.odds_revision += 1;
+    }
+    market.home_odds = new_home_odds;
+    market.away_odds = new_away_odds;
+    MARKET.save(deps.storage, &market)?;
+
+    let potential_payout_home = POTENTIAL_PAYOUT_HOME.load(deps.storage)?;
+    let potential_payout_away = POTENTIAL_PAYOUT_AWAY.load(deps.storage)?;
+    let new_home_max_bet = config.pricing_model.max_bet(
+        &config,
+        market_balance,
+        Uint128::from(potential_payout_home),
+        new_home_odds,
+    )?;
+    let new_away_max_bet = config.pricing_model.max_bet(
+        &config,
+        market_balance,
+        Uint128::from(potential_payout_away),
+        new_away_odds,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "fixed-odds")
+        .add_attribute("action", "update_market")
+        .add_attribute("sender", info.sender)
+        .add_attribute("admin_addr", admin_addr_update)
+        .add_attribute("treasury_addr", treasury_addr_update)
+        .add_attribute("fee_spread_odds", fee_spread_odds_update)
+        .add_attribute("max_bet_risk_factor", max_bet_risk_factor_update)
+        .add_attribute("seed_liquidity_amplifier", seed_liquidity_amplifier_update)
+        .add_attribute("initial_odds_home", initial_odds_home_update)
+        .add_attribute("initial_odds_away", initial_odds_away_update)
+        .add_attribute("start_timestamp", start_timestamp_update)
+        .add_attribute("parlay_addr", parlay_addr_update)
+        .add_attribute("label", label_update)
+        .add_attribute("home_team", home_team_update)
+        .add_attribute("away_team", away_team_update)
+        .add_attributes(metadata_update.attributes())
+        .add_attribute("home_odds", market.home_odds.to_string())
+        .add_attribute("away_odds", market.away_odds.to_string())
+        .add_attribute("odds_revision", market.odds_revision.to_string())
+        .add_attribute("home_max_bet", new_home_max_bet.to_string())
+        .add_attribute("away_max_bet", new_away_max_bet.to_string())
+        .add_attribute("total_bets_home", home_total_bets.to_string())
+        .add_attribute("total_bets_away", away_total_bets.to_string())
+        .add_attribute("potential_payout_home", potential_payout_home.to_string())
+        .add_attribute("potential_payout_away", potential_payout_away.to_string()))
+}
+
+/// Scores the market with the given result, see `score_market`
+///
+/// It will make the following checks:
+/// - The market can't be a handicap market
+/// - The result needs to be valid for the market kind
+pub fn execute_score(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    result: MarketResult,
+) -> Result<Response, ContractError> {
+    let market = MARKET.load(deps.storage)?;
+
+    if let MarketKind::HANDICAP { .. } = market.kind {
+        return Err(ContractError::InvalidMarketKind {});
+    }
+
+    validate_market_result(&market.kind, &result)?;
+
+    let settlement = calculate_result_settlement(&result);
+
+    score_market(deps, env, info, result, settlement)
+}
+
+/// Scores a totals market from the final total, the result is OVER or UNDER
+/// depending on which side of the line the total falls, or PUSH when it
+/// lands exactly on the line, see `score_market`
+///
+/// It will make the following checks:
+/// - The market needs to be a totals market
+pub fn execute_score_total(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    total: u64,
+) -> Result<Response, ContractError> {
+    let market = MARKET.load(deps.storage)?;
+
+    let line = match market.kind {
+        MarketKind::TOTALS { line } => line,
+        _ => return Err(ContractError::InvalidMarketKind {}),
+    };
+
+    let result = calculate_totals_result(line, total);
+    let settlement = calculate_result_settlement(&result);
+
+    Ok(
+        score_market(deps, env, info, result, settlement)?
+            .add_attribute("total", total.to_string()),
+    )
+}
+
+/// Scores a handicap market from the final score, each side is settled with
+/// the outcome of applying the handicap to the home score, which can include
+/// half wins and half losses on quarter lines, see `calculate_handicap_settlement`
+///
+/// The market result is the side that covered the handicap, or PUSH when no side did.
+///
+/// It will make the following checks:
+/// - The market needs to be a handicap market
+pub fn execute_score_final(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    home_score: u64,
+    away_score: u64,
+) -> Result<Response, ContractError> {
+    let market = MARKET.load(deps.storage)?;
+
+    let handicap = match market.kind {
+        MarketKind::HANDICAP { handicap } => handicap,
+        _ => return Err(ContractError::InvalidMarketKind {}),
+    };
+
+    let settlement = calculate_handicap_settlement(handicap, home_score, away_score);
+    let result = calculate_handicap_result(&settlement);
+
+    Ok(score_market(deps, env, info, result, settlement)?
+        .add_attribute("home_score", home_score.to_string())
+        .add_attribute("away_score", away_score.to_string()))
+}
+
+/// Scores the market and collects the outstanding balance to the treasury, the
+/// outstanding balance is calculated by deducing the total payout of the bets
+/// on each side given how that side was settled.
+///
+/// The parlay liability of a winning side is paid to the parlay contract.
+///
+/// It will make the following checks:
+/// - The sender needs to be the admin
+/// - The market needs to be active
+/// - The current block timestamp needs to be at least 30 minutes after the start timestamp
+fn score_market(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    result: MarketResult,
+    settlement: Settlement,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
+
+    if info.sender != config.admin_addr {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if market.status != Status::ACTIVE {
+        return Err(ContractError::MarketNotActive {});
+    }
+
+    // Market can only be scored after 30 minutes of its start timestamp
+    let scoring_timestamp = market
+        .start_timestamp
+        .checked_add(30 * 60)
+        .ok_or(ContractError::InvalidTimestamp(market.start_timestamp))?;
+    if env.block.time.seconds() < scoring_timestamp {
+        return Err(ContractError::MarketNotScoreable {});
+    }
+
+    let mut market = market;
+    market.status = Status::CLOSED;
+    market.result = Some(result.clone());
+    market.settlement = Some(settlement.clone());
+    MARKET.save(deps.storage, &market)?;
+
+    let market_balance = query_market_balance(deps.as_ref())?;
+    let SettlementAmounts {
+        market_payout: _,
+        parlay_payout,
+        outstanding_balance: market_outstanding_balance,
+        profit: market_profit,
+        loss: market_loss,
+    } = calculate_settlement_amounts(
+        config.seed_liquidity,
+        market_balance,
+        &load_side_book(deps.storage, true)?,
+        &load_side_book(deps.storage, false)?,
+        &settlement,
+    )?;
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    if market_outstanding_balance > config.seed_liquidity {
+        messages.push(
+            BankMsg::Send {
+                to_address: config.treasury_addr.to_string(),
+                amount: vec![coin(market_profit.into(), &config.denom)],
+            }
+            .into(),
+        );
+        messages.push(
+            BankMsg::Send {
+                to_address: config.admin_addr.to_string(),
+                amount: vec![coin(config.seed_liquidity.into(), &config.denom)],
+            }
+            .into(),
+        );
+    } else if market_outstanding_balance > Uint128::zero() {
+        messages.push(
+            BankMsg::Send {
+                to_address: config.admin_addr.to_string(),
+                amount: vec![coin(market_outstanding_balance.into(), &config.denom)],
+            }
+            .into(),
+        );
+    }
+
+    record_collected(deps.storage, market_outstanding_balance, market_profit)?;
+
+    // The stakes owed to the positions become their settled payouts
+    let unclaimed = Uint128::from(calculate_positions_payout(
+        &settlement.home,
+        &load_side_book(deps.storage, true)?,
+        &load_half_payouts(deps.storage, true)?,
+    )?)
+    .checked_add(
+        calculate_positions_payout(
+            &settlement.away,
+            &load_side_book(deps.storage, false)?,
+            &load_half_payouts(deps.storage, false)?,
+        )?
+        .into(),
+    )?;
+    LEDGER.update(deps.storage, |mut ledger| -> StdResult<_> {
+        ledger.unclaimed = unclaimed;
+        Ok(ledger)
+    })?;
+
+    if let (true, Some(parlay_addr)) = (!parlay_payout.is_zero(), config.parlay_addr.clone()) {
+        messages.push(
+            BankMsg::Send {
+                to_address: parlay_addr.to_string(),
+                amount: vec![coin(parlay_payout.into(), &config.denom)],
+            }
+            .into(),
+        );
+        record_claimed(deps.storage, parlay_payout)?;
+    }
+
+    let potential_payout_home = POTENTIAL_PAYOUT_HOME.load(deps.storage)?;
+    let potential_payout_away = POTENTIAL_PAYOUT_AWAY.load(deps.storage)?;
+    let home_max_bet = config.pricing_model.max_bet(
+        &config,
+        market_balance,
+        Uint128::from(potential_payout_home),
+        market.home_odds,
+    )?;
+    let away_max_bet = config.pricing_model.max_bet(
+        &config,
+        market_balance,
+        Uint128::from(potential_payout_away),
+        market.away_odds,
+    )?;
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "fixed-odds")
+        .add_attribute("action", "score_market")
+        .add_attribute("sender", info.sender)
+        .add_attribute("status", Status::CLOSED.to_string())
+        .add_attribute("result", result.to_string())
+        .add_attribute("settlement_home", settlement.home.to_string())
+        .add_attribute("settlement_away", settlement.away.to_string())
+        .add_attribute("parlay_payout", parlay_payout.to_string())
+        .add_attribute("market_outstanding_balance", market_outstanding_balance)
+        .add_attribute("market_profit", market_profit)
+        .add_attribute("market_loss", market_loss)
+        .add_attribute("home_odds", market.home_odds.to_string())
+        .add_attribute("away_odds", market.away_odds.to_string())
+        .add_attribute("home_max_bet", home_max_bet.to_string())
+        .add_attribute("away_max_bet", away_max_bet.to_string())
+        .add_attribute(
+            "total_bets_home",
+            TOTAL_BETS_HOME.load(deps.storage)?.to_string(),
+        )
+        .add_attribute(
+            "total_bets_away",
+            TOTAL_BETS_AWAY.load(deps.storage)?.to_string(),
+        )
+        .add_attribute(
+            "potential_payout_home",
+            POTENTIAL_PAYOUT_HOME.load(deps.storage)?.to_string(),
+        )
+        .add_attribute(
+            "potential_payout_away",
+            POTENTIAL_PAYOUT_AWAY.load(deps.storage)?.to_string(),
+        ))
+}
+
+/// Cancels the market
+///
+/// It will make the following checks:
+/// - The sender needs to be the admin
+/// - The market needs to be active or postponed
+pub fn execute_cancel(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
+
+    if info.sender != config.admin_addr {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if !market.status.is_open() {
+        return Err(ContractError::MarketNotActive {});
+    }
+
+    MARKET.update(deps.storage, |mut market| -> Result<_, ContractError> {
+        market.status = Status::CANCELLED;
+        Ok(market)
+    })?;
+
+    refund_market(deps, info, "cancel_market")
+}
+
+/// Expires the market when it wasn't scored before its resolution deadline,
+/// or wasn't rescheduled before its postponement deadline once postponed,
+/// it is cancelled so the bets can be refunded and the seed liquidity
+/// goes back to the admin
+///
+/// Anyone can expire the market so funds are never stuck if the admin is gone.
+///
+/// It will make the following checks:
+/// - The market needs to be active or postponed
+/// - The current block timestamp needs to be after the resolution deadline,
+///   or after the postponement deadline if the market is postponed
+pub fn execute_expire_market(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let market = MARKET.load(deps.storage)?;
+
+    let is_expired = match market.status {
+        Status::ACTIVE => env.block.time.seconds() >= market.resolution_deadline,
+        Status::POSTPONED => is_postponement_expired(&market, &env),
+        _ => return Err(ContractError::MarketNotActive {}),
+    };
+    if !is_expired {
+        return Err(ContractError::MarketNotExpired {});
+    }
+
+    MARKET.update(deps.storage, |mut market| -> Result<_, ContractError> {
+        market.status = Status::CANCELLED;
+        Ok(market)
+    })?;
+
+    Ok(refund_market(deps, info, "expire_market")?
+        .add_attribute(
+            "resolution_deadline",
+            market.resolution_deadline.to_string(),
+        )
+        .add_attribute(
+            "postponement_deadline",
+            market
+                .postponement_deadline
+                .map(|deadline| deadline.to_string())
+                .unwrap_or_default(),
+        ))
+}
+
+/// Voids the market when the match is abandoned after kickoff
+///
+/// The bets are refunded the same way as a cancelled market but the market
+/// keeps a distinct status and the reason it was voided.
+///
+/// It will make the following checks:
+/// - The sender needs to be the admin
+/// - The market needs to be active or postponed
+pub fn execute_void(
+    deps: DepsMut,
+    info: MessageInfo,
+    reason: VoidReason,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
+
+    if info.sender != config.admin_addr {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if !market.status.is_open() {
+        return Err(ContractError::MarketNotActive {});
+    }
+
+    MARKET.update(deps.storage, |mut market| -> Result<_, ContractError> {
+        market.status = Status::VOIDED;
+        market.void_reason = Some(reason.clone());
+        Ok(market)
+    })?;
+
+    Ok(refund_market(deps, info, "void_market")?.add_attribute("reason", reason.to_string()))
 }
 
-/// Updates the market with the new params, it will recalculate
-/// the new odds based on the new params.
+/// Postpones the market when its match is delayed without a new start timestamp yet
+///
+/// The bets are frozen until the market is rescheduled, in the meantime each bettor can
+/// withdraw their stake, see `execute_withdraw_stake`, or stay in. If the market isn't
+/// rescheduled before the postponement deadline its bets are refunded as if cancelled.
 ///
 /// It will make the following checks:
 /// - The sender needs to be the admin
 /// - The market needs to be active
-pub fn execute_update(
+pub fn execute_postpone(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    params: UpdateParams,
 ) -> Result<Response, ContractError> {
-    let mut config = CONFIG.load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
     let mut market = MARKET.load(deps.storage)?;
 
     if info.sender != config.admin_addr {
@@ -280,141 +2207,108 @@ pub fn execute_update(
         return Err(ContractError::MarketNotActive {});
     }
 
-    let mut admin_addr_update = String::default();
-    if let Some(admin_addr) = params.admin_addr {
-        config.admin_addr = admin_addr.clone();
-        admin_addr_update = admin_addr.to_string();
-    }
-
-    let mut treasury_addr_update = String::default();
-    if let Some(treasury_addr) = params.treasury_addr {
-        config.treasury_addr = treasury_addr.clone();
-        treasury_addr_update = treasury_addr.to_string();
-    }
+    let postponement_deadline = env
+        .block
+        .time
+        .seconds()
+        .checked_add(config.postponement_period)
+        .ok_or(ContractError::InvalidTimestamp(env.block.time.seconds()))?;
 
-    let mut fee_spread_odds_update = String::default();
-    if let Some(fee_spread_odds) = params.fee_spread_odds {
-        validate_fee_spread_odds(fee_spread_odds)?;
+    market.status = Status::POSTPONED;
+    market.postponement_deadline = Some(postponement_deadline);
+    market.odds_revision += 1;
+    MARKET.save(deps.storage, &market)?;
 
-        config.fee_spread_odds = fee_spread_odds;
-        fee_spread_odds_update = fee_spread_odds.to_string();
-    }
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "fixed-odds")
+        .add_attribute("action", "postpone_market")
+        .add_attribute("sender", info.sender)
+        .add_attribute("status", Status::POSTPONED.to_string())
+        .add_attribute("postponement_deadline", postponement_deadline.to_string())
+        .add_attribute("odds_revision", market.odds_revision.to_string()))
+}
 
-    let mut max_bet_risk_factor_update = String::default();
-    if let Some(max_bet_risk_factor) = params.max_bet_risk_factor {
-        validate_max_bet_risk_factor(max_bet_risk_factor)?;
+/// Reschedules a postponed market to its new start timestamp and reopens the bets,
+/// it will recalculate the odds as stakes may have been withdrawn meanwhile
+///
+/// It will make the following checks:
+/// - The sender needs to be the admin
+/// - The market needs to be postponed and its postponement deadline can't have passed
+/// - The start timestamp needs to be after the current block timestamp
+pub fn execute_reschedule(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    start_timestamp: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut market = MARKET.load(deps.storage)?;
 
-        config.max_bet_risk_factor = max_bet_risk_factor;
-        max_bet_risk_factor_update = max_bet_risk_factor.to_string();
+    if info.sender != config.admin_addr {
+        return Err(ContractError::Unauthorized {});
     }
 
-    let mut seed_liquidity_amplifier_update = String::default();
-    if let Some(seed_liquidity_amplifier) = params.seed_liquidity_amplifier {
-        validate_seed_liquidity_amplifier(seed_liquidity_amplifier)?;
-
-        config.seed_liquidity_amplifier = seed_liquidity_amplifier;
-        seed_liquidity_amplifier_update = seed_liquidity_amplifier.to_string();
+    if market.status != Status::POSTPONED {
+        return Err(ContractError::MarketNotPostponed {});
     }
 
-    let mut initial_odds_home_update = String::default();
-    let mut initial_odds_away_update = String::default();
-    let initial_odds_home = match params.initial_odds_home {
-        Some(initial_odds_home) => initial_odds_home,
-        None => Decimal::zero(),
-    };
-    let initial_odds_away = match params.initial_odds_away {
-        Some(initial_odds_away) => initial_odds_away,
-        None => Decimal::zero(),
-    };
-    if initial_odds_home != Decimal::zero() || initial_odds_away != Decimal::zero() {
-        if initial_odds_home == Decimal::zero() || initial_odds_away == Decimal::zero() {
-            return Err(ContractError::InvalidOddsCombination);
-        }
-        validate_odd(initial_odds_home)?;
-        validate_odd(initial_odds_away)?;
-
-        config.initial_odds_home = initial_odds_home;
-        config.initial_odds_away = initial_odds_away;
-        initial_odds_home_update = initial_odds_home.to_string();
-        initial_odds_away_update = initial_odds_away.to_string();
+    if is_postponement_expired(&market, &env) {
+        return Err(ContractError::PostponementExpired {});
     }
 
-    let mut start_timestamp_update = String::default();
-    if let Some(start_timestamp) = params.start_timestamp {
-        market.start_timestamp = start_timestamp;
-        start_timestamp_update = start_timestamp.to_string();
+    if start_timestamp <= env.block.time.seconds() {
+        return Err(ContractError::InvalidTimestamp(start_timestamp));
     }
 
-    CONFIG.save(deps.storage, &config)?;
-
-    let market_balance = deps
-        .querier
-        .query_balance(&env.contract.address, &config.denom)?
-        .amount;
-    let home_total_bets = TOTAL_BETS_HOME.load(deps.storage)?;
-    let away_total_bets = TOTAL_BETS_AWAY.load(deps.storage)?;
-
-    let (new_home_odds, new_away_odds) = calculate_odds(
+    let market_balance = query_market_balance(deps.as_ref())?;
+    let (new_home_odds, new_away_odds) = config.pricing_model.odds(
         &config,
         market_balance,
-        Uint128::from(home_total_bets),
-        Uint128::from(away_total_bets),
-    );
+        &load_side_book(deps.storage, true)?,
+        &load_side_book(deps.storage, false)?,
+    )?;
+
+    market.status = Status::ACTIVE;
+    market.start_timestamp = start_timestamp;
+    market.resolution_deadline = start_timestamp
+        .checked_add(config.resolution_grace_period)
+        .ok_or(ContractError::InvalidTimestamp(start_timestamp))?;
+    market.postponement_deadline = None;
     market.home_odds = new_home_odds;
     market.away_odds = new_away_odds;
+    market.odds_revision += 1;
     MARKET.save(deps.storage, &market)?;
 
-    let potential_payout_home = POTENTIAL_PAYOUT_HOME.load(deps.storage)?;
-    let potential_payout_away = POTENTIAL_PAYOUT_AWAY.load(deps.storage)?;
-    let new_home_max_bet = calculate_max_bet(
-        &config,
-        market_balance,
-        Uint128::from(potential_payout_home),
-        new_home_odds,
-    );
-    let new_away_max_bet = calculate_max_bet(
-        &config,
-        market_balance,
-        Uint128::from(potential_payout_away),
-        new_away_odds,
-    );
-
     Ok(Response::new()
         .add_attribute("protocol", "vendetta-markets")
         .add_attribute("market_type", "fixed-odds")
-        .add_attribute("action", "update_market")
+        .add_attribute("action", "reschedule_market")
         .add_attribute("sender", info.sender)
-        .add_attribute("admin_addr", admin_addr_update)
-        .add_attribute("treasury_addr", treasury_addr_update)
-        .add_attribute("fee_spread_odds", fee_spread_odds_update)
-        .add_attribute("max_bet_risk_factor", max_bet_risk_factor_update)
-        .add_attribute("seed_liquidity_amplifier", seed_liquidity_amplifier_update)
-        .add_attribute("initial_odds_home", initial_odds_home_update)
-        .add_attribute("initial_odds_away", initial_odds_away_update)
-        .add_attribute("start_timestamp", start_timestamp_update)
+        .add_attribute("status", Status::ACTIVE.to_string())
+        .add_attribute("start_timestamp", start_timestamp.to_string())
+        .add_attribute(
+            "resolution_deadline",
+            market.resolution_deadline.to_string(),
+        )
         .add_attribute("home_odds", market.home_odds.to_string())
         .add_attribute("away_odds", market.away_odds.to_string())
-        .add_attribute("home_max_bet", new_home_max_bet.to_string())
-        .add_attribute("away_max_bet", new_away_max_bet.to_string())
-        .add_attribute("total_bets_home", home_total_bets.to_string())
-        .add_attribute("total_bets_away", away_total_bets.to_string())
-        .add_attribute("potential_payout_home", potential_payout_home.to_string())
-        .add_attribute("potential_payout_away", potential_payout_away.to_string()))
+        .add_attribute("odds_revision", market.odds_revision.to_string()))
 }
 
-/// Scores the market and collects the outstanding balance to the treasury, the
-/// outstanding balance is calculated by deducing the total payout matching
-/// the market result.
+/// Sweeps the surplus of the contract to the treasury, the funds sent directly to the
+/// contract that are not accounted by the ledger nor held for the pending and limit bets
+///
+/// Once the market is no longer active only the unclaimed positions are owed, so the
+/// rounding dust of the settled payouts left in the ledger is collected as fees too.
 ///
 /// It will make the following checks:
 /// - The sender needs to be the admin
-/// - The market needs to be active
-/// - The current block timestamp needs to be at least 30 minutes after the start timestamp
-pub fn execute_score(
+/// - The contract needs to hold some surplus
+pub fn execute_reconcile_surplus(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    result: MarketResult,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     let market = MARKET.load(deps.storage)?;
@@ -423,88 +2317,85 @@ pub fn execute_score(
         return Err(ContractError::Unauthorized {});
     }
 
-    if market.status != Status::ACTIVE {
-        return Err(ContractError::MarketNotActive {});
+    let balance = deps
+        .querier
+        .query_balance(&env.contract.address, &config.denom)?
+        .amount;
+    let mut market_balance = query_market_balance(deps.as_ref())?;
+
+    let mut dust = Uint128::zero();
+    if !market.status.is_open() {
+        let unclaimed = query_unclaimed(deps.as_ref())?;
+        dust = market_balance.checked_sub(unclaimed)?;
+        market_balance = unclaimed;
     }
 
-    // Market can only be scored after 30 minutes of its start timestamp
-    if env.block.time.seconds() < market.start_timestamp + 30 * 60 {
-        return Err(ContractError::MarketNotScoreable {});
+    let accounted_balance = market_balance
+        .checked_add(TOTAL_PENDING_BETS.load(deps.storage)?.into())?
+        .checked_add(TOTAL_LIMIT_BETS.load(deps.storage)?.into())?;
+
+    let surplus = balance.saturating_sub(accounted_balance);
+    if surplus.is_zero() {
+        return Err(ContractError::NoSurplus {});
     }
 
-    let mut market = market;
-    market.status = Status::CLOSED;
-    market.result = Some(result.clone());
-    MARKET.save(deps.storage, &market)?;
+    record_collected(deps.storage, dust, dust)?;
 
-    let market_balance = deps
-        .querier
-        .query_balance(&env.contract.address, &config.denom)?
-        .amount;
-    let market_payout = match result {
-        MarketResult::HOME => POTENTIAL_PAYOUT_HOME.load(deps.storage)?,
-        MarketResult::AWAY => POTENTIAL_PAYOUT_AWAY.load(deps.storage)?,
-    };
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: config.treasury_addr.to_string(),
+            amount: vec![coin(surplus.into(), &config.denom)],
+        })
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "fixed-odds")
+        .add_attribute("action", "reconcile_surplus")
+        .add_attribute("sender", info.sender)
+        .add_attribute("market_balance", market_balance)
+        .add_attribute("accounted_balance", accounted_balance)
+        .add_attribute("dust", dust)
+        .add_attribute("surplus", surplus))
+}
 
-    let market_outstanding_balance = market_balance - Uint128::from(market_payout);
+/// Returns the seed liquidity to the admin and the profit to the treasury
+/// once all the bets of the market are to be refunded
+fn refund_market(
+    deps: DepsMut,
+    info: MessageInfo,
+    action: &str,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
 
-    let mut messages: Vec<CosmosMsg> = vec![];
-    let mut market_profit: Uint128 = Uint128::zero();
-    let mut market_loss: Uint128 = Uint128::zero();
-    if market_outstanding_balance > Uint128::zero() {
-        if market_outstanding_balance > config.seed_liquidity {
-            market_profit = market_outstanding_balance - config.seed_liquidity;
-            messages.push(
-                BankMsg::Send {
-                    to_address: config.treasury_addr.to_string(),
-                    amount: vec![coin(market_profit.into(), &config.denom)],
-                }
-                .into(),
-            );
-            messages.push(
-                BankMsg::Send {
-                    to_address: config.admin_addr.to_string(),
-                    amount: vec![coin(config.seed_liquidity.into(), &config.denom)],
-                }
-                .into(),
-            );
-        } else {
-            market_loss = config.seed_liquidity - market_outstanding_balance;
-            messages.push(
-                BankMsg::Send {
-                    to_address: config.admin_addr.to_string(),
-                    amount: vec![coin(market_outstanding_balance.into(), &config.denom)],
-                }
-                .into(),
-            );
-        }
-    } else {
-        market_loss = config.seed_liquidity;
-    }
+    let market_balance = query_market_balance(deps.as_ref())?;
+    let SeedLiquidityRelease {
+        messages,
+        market_outstanding_balance,
+        market_profit,
+        market_loss,
+    } = release_seed_liquidity(deps.storage)?;
 
     let potential_payout_home = POTENTIAL_PAYOUT_HOME.load(deps.storage)?;
     let potential_payout_away = POTENTIAL_PAYOUT_AWAY.load(deps.storage)?;
-    let home_max_bet = calculate_max_bet(
+    let home_max_bet = config.pricing_model.max_bet(
         &config,
         market_balance,
         Uint128::from(potential_payout_home),
         market.home_odds,
-    );
-    let away_max_bet = calculate_max_bet(
+    )?;
+    let away_max_bet = config.pricing_model.max_bet(
         &config,
         market_balance,
         Uint128::from(potential_payout_away),
         market.away_odds,
-    );
+    )?;
 
     Ok(Response::new()
         .add_messages(messages)
         .add_attribute("protocol", "vendetta-markets")
         .add_attribute("market_type", "fixed-odds")
-        .add_attribute("action", "score_market")
+        .add_attribute("action", action)
         .add_attribute("sender", info.sender)
-        .add_attribute("status", Status::CLOSED.to_string())
-        .add_attribute("result", result.to_string())
+        .add_attribute("status", market.status.to_string())
         .add_attribute("market_outstanding_balance", market_outstanding_balance)
         .add_attribute("market_profit", market_profit)
         .add_attribute("market_loss", market_loss)
@@ -530,41 +2421,28 @@ pub fn execute_score(
         ))
 }
 
-/// Cancels the market
-///
-/// It will make the following checks:
-/// - The sender needs to be the admin
-/// - The market needs to be active
-pub fn execute_cancel(
-    deps: DepsMut,
-    env: Env,
-    info: MessageInfo,
-) -> Result<Response, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    let market = MARKET.load(deps.storage)?;
-
-    if info.sender != config.admin_addr {
-        return Err(ContractError::Unauthorized {});
-    }
-
-    if market.status != Status::ACTIVE {
-        return Err(ContractError::MarketNotActive {});
-    }
+/// The messages and amounts of the seed liquidity released once a market is refunded
+struct SeedLiquidityRelease {
+    messages: Vec<CosmosMsg>,
+    market_outstanding_balance: Uint128,
+    market_profit: Uint128,
+    market_loss: Uint128,
+}
 
-    MARKET.update(deps.storage, |mut market| -> Result<_, ContractError> {
-        market.status = Status::CANCELLED;
-        Ok(market)
-    })?;
+/// Releases what the market holds beyond the bets to refund, the seed liquidity
+/// goes back to the admin and the profit to the treasury
+fn release_seed_liquidity(
+    storage: &mut dyn Storage,
+) -> Result<SeedLiquidityRelease, ContractError> {
+    let config = CONFIG.load(storage)?;
 
-    let market_balance = deps
-        .querier
-        .query_balance(&env.contract.address, &config.denom)?
-        .amount;
-    let total_bets_home = TOTAL_BETS_HOME.load(deps.storage)?;
-    let total_bets_away = TOTAL_BETS_AWAY.load(deps.storage)?;
+    let market_balance = LEDGER.load(storage)?.balance()?;
+    let total_bets_home = TOTAL_BETS_HOME.load(storage)?;
+    let total_bets_away = TOTAL_BETS_AWAY.load(storage)?;
 
-    let market_outstanding_balance =
-        market_balance - Uint128::from(total_bets_home) - Uint128::from(total_bets_away);
+    let market_outstanding_balance = market_balance
+        .checked_sub(total_bets_home.into())?
+        .checked_sub(total_bets_away.into())?;
 
     let mut messages: Vec<CosmosMsg> = vec![];
     let mut market_profit: Uint128 = Uint128::zero();
@@ -600,49 +2478,39 @@ pub fn execute_cancel(
         market_loss = config.seed_liquidity;
     }
 
-    let potential_payout_home = POTENTIAL_PAYOUT_HOME.load(deps.storage)?;
-    let potential_payout_away = POTENTIAL_PAYOUT_AWAY.load(deps.storage)?;
-    let home_max_bet = calculate_max_bet(
-        &config,
-        market_balance,
-        Uint128::from(potential_payout_home),
-        market.home_odds,
-    );
-    let away_max_bet = calculate_max_bet(
-        &config,
-        market_balance,
-        Uint128::from(potential_payout_away),
-        market.away_odds,
-    );
+    record_collected(storage, market_outstanding_balance, market_profit)?;
 
-    Ok(Response::new()
-        .add_messages(messages)
-        .add_attribute("protocol", "vendetta-markets")
-        .add_attribute("market_type", "fixed-odds")
-        .add_attribute("action", "cancel_market")
-        .add_attribute("sender", info.sender)
-        .add_attribute("status", Status::CANCELLED.to_string())
-        .add_attribute("market_outstanding_balance", market_outstanding_balance)
-        .add_attribute("market_profit", market_profit)
-        .add_attribute("market_loss", market_loss)
-        .add_attribute("home_odds", market.home_odds.to_string())
-        .add_attribute("away_odds", market.away_odds.to_string())
-        .add_attribute("home_max_bet", home_max_bet.to_string())
-        .add_attribute("away_max_bet", away_max_bet.to_string())
-        .add_attribute(
-            "total_bets_home",
-            TOTAL_BETS_HOME.load(deps.storage)?.to_string(),
-        )
-        .add_attribute(
-            "total_bets_away",
-            TOTAL_BETS_AWAY.load(deps.storage)?.to_string(),
-        )
-        .add_attribute(
-            "potential_payout_home",
-            POTENTIAL_PAYOUT_HOME.load(deps.storage)?.to_string(),
-        )
-        .add_attribute(
-            "potential_payout_away",
-            POTENTIAL_PAYOUT_AWAY.load(deps.storage)?.to_string(),
-        ))
+    Ok(SeedLiquidityRelease {
+        messages,
+        market_outstanding_balance,
+        market_profit,
+        market_loss,
+    })
+}
+
+/// Whether the market is postponed and its postponement deadline passed
+fn is_postponement_expired(market: &Market, env: &Env) -> bool {
+    market.status == Status::POSTPONED
+        && market
+            .postponement_deadline
+            .is_some_and(|deadline| env.block.time.seconds() >= deadline)
+}
+
+/// Loads the market, cancelling it first if its postponement expired so its bets are
+/// refunded as if cancelled without waiting for `execute_expire_market`, along with the
+/// messages releasing the seed liquidity of the cancelled market
+fn expire_postponement(
+    storage: &mut dyn Storage,
+    env: &Env,
+) -> Result<(Market, Vec<CosmosMsg>), ContractError> {
+    let mut market = MARKET.load(storage)?;
+
+    if !is_postponement_expired(&market, env) {
+        return Ok((market, vec![]));
+    }
+
+    market.status = Status::CANCELLED;
+    MARKET.save(storage, &market)?;
+
+    Ok((market, release_seed_liquidity(storage)?.messages))
 }
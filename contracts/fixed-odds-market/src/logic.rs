@@ -1,73 +1,594 @@
-use cosmwasm_std::{Decimal, Uint128};
+use cosmwasm_std::{
+    Decimal, Decimal256, Fraction, OverflowError, OverflowOperation, SignedDecimal, Uint128,
+    Uint256,
+};
 use std::cmp::Ordering;
 
-use crate::state::Config;
+use crate::error::ContractError;
+use crate::state::{
+    Config, HalfPayouts, MarketKind, MarketResult, OddsFormat, PricingModelKind, Settlement,
+    SettlementOutcome, SideBook,
+};
 
-/// Calculates the new odds for a market
-///
-/// The new odds are calculated based on the following formula:
+/// Prices the bets of a market, the model is selected by the pricing model of the config
+pub trait PricingModel {
+    /// Returns the odds of each side of the market as a tuple `(home_odds, away_odds)`
+    fn odds(
+        &self,
+        config: &Config,
+        market_balance: Uint128,
+        home: &SideBook,
+        away: &SideBook,
+    ) -> Result<(Decimal, Decimal), ContractError>;
+
+    /// Returns the maximum bet amount on a side of the market at the given odds
+    fn max_bet(
+        &self,
+        config: &Config,
+        market_balance: Uint128,
+        total_payout: Uint128,
+        odds: Decimal,
+    ) -> Result<Uint128, ContractError>;
+
+    /// Returns the payout of a bet amount placed on a side of the market
+    fn quote(
+        &self,
+        config: &Config,
+        market_balance: Uint128,
+        home: &SideBook,
+        away: &SideBook,
+        home_side: bool,
+        bet_amount: Uint128,
+    ) -> Result<Uint128, ContractError>;
+}
+
+impl PricingModel for PricingModelKind {
+    fn odds(
+        &self,
+        config: &Config,
+        market_balance: Uint128,
+        home: &SideBook,
+        away: &SideBook,
+    ) -> Result<(Decimal, Decimal), ContractError> {
+        match self {
+            PricingModelKind::SEED_BLEND => {
+                SeedBlendPricing.odds(config, market_balance, home, away)
+            }
+            PricingModelKind::LMSR { liquidity } => LmsrPricing {
+                liquidity: *liquidity,
+            }
+            .odds(config, market_balance, home, away),
+            PricingModelKind::CONSTANT_PRODUCT => {
+                ConstantProductPricing.odds(config, market_balance, home, away)
+            }
+        }
+    }
+
+    fn max_bet(
+        &self,
+        config: &Config,
+        market_balance: Uint128,
+        total_payout: Uint128,
+        odds: Decimal,
+    ) -> Result<Uint128, ContractError> {
+        match self {
+            PricingModelKind::SEED_BLEND => {
+                SeedBlendPricing.max_bet(config, market_balance, total_payout, odds)
+            }
+            PricingModelKind::LMSR { liquidity } => LmsrPricing {
+                liquidity: *liquidity,
+            }
+            .max_bet(config, market_balance, total_payout, odds),
+            PricingModelKind::CONSTANT_PRODUCT => {
+                ConstantProductPricing.max_bet(config, market_balance, total_payout, odds)
+            }
+        }
+    }
+
+    fn quote(
+        &self,
+        config: &Config,
+        market_balance: Uint128,
+        home: &SideBook,
+        away: &SideBook,
+        home_side: bool,
+        bet_amount: Uint128,
+    ) -> Result<Uint128, ContractError> {
+        match self {
+            PricingModelKind::SEED_BLEND => {
+                SeedBlendPricing.quote(config, market_balance, home, away, home_side, bet_amount)
+            }
+            PricingModelKind::LMSR { liquidity } => LmsrPricing {
+                liquidity: *liquidity,
+            }
+            .quote(config, market_balance, home, away, home_side, bet_amount),
+            PricingModelKind::CONSTANT_PRODUCT => ConstantProductPricing.quote(
+                config,
+                market_balance,
+                home,
+                away,
+                home_side,
+                bet_amount,
+            ),
+        }
+    }
+}
+
+/// Blends the initial probabilities with the probabilities derived from the bets,
+/// the weight of the bets grows as they get bigger than the amplified seed liquidity
+pub struct SeedBlendPricing;
+
+/// Number of increments a bet is split into to be priced along the seed blend curve
+const PRICE_IMPACT_INCREMENTS: u128 = 10;
+
+impl PricingModel for SeedBlendPricing {
+    /// Calculates the new odds for a market
+    ///
+    /// The new odds are calculated based on the following formula:
+    ///
+    /// ```ignore
+    /// market_seed_balance = market_balance - home_total_bets - away_total_bets
+    ///
+    /// initial_home_probability = 1 / initial_home_odds
+    /// initial_away_probability = 1 / initial_away_odds
+    ///
+    /// derived_home_probability = home_total_bets / (home_total_bets + away_total_bets) || 0
+    /// derived_away_probability = away_total_bets / (home_total_bets + away_total_bets) || 0
+    ///
+    /// market_probabilities_weight = (home_total_bets + away_total_bets) / (home_total_bets + away_total_bets + market_seed_balance * seed_amplifier)
+    ///
+    /// new_home_probability = ((derived_home_probability * market_probabilities_weight) + (initial_home_probability * (1 - market_probabilities_weight))) * (1 + fee_spread_odds)
+    /// new_away_probability = ((derived_away_probability * market_probabilities_weight) + (initial_away_probability * (1 - market_probabilities_weight))) * (1 + fee_spread_odds)
+    ///
+    /// new_home_odds = 1 / new_home_probability
+    /// new_away_odds = 1 / new_away_probability
+    /// ```
+    ///
+    /// The probabilities are calculated with 256 bits decimals, so the amounts
+    /// of 18 decimals denoms stay within range.
+    ///
+    /// Returns the new home and away odds as a tuple `(new_home_odds, new_away_odds)`
+    fn odds(
+        &self,
+        config: &Config,
+        market_balance: Uint128,
+        home: &SideBook,
+        away: &SideBook,
+    ) -> Result<(Decimal, Decimal), ContractError> {
+        let market_seed_balance = market_balance
+            .checked_sub(home.total_bets.into())?
+            .checked_sub(away.total_bets.into())?;
+        let initial_home_probability =
+            Decimal256::one() / Decimal256::from(config.initial_odds_home);
+        let initial_away_probability =
+            Decimal256::one() / Decimal256::from(config.initial_odds_away);
+
+        let home_total_bets = to_decimal256(home.total_bets, config.denom_precision)?;
+        let away_total_bets = to_decimal256(away.total_bets, config.denom_precision)?;
+        let total_bets = home_total_bets.checked_add(away_total_bets)?;
+
+        let derived_home_probability = if total_bets != Decimal256::zero() {
+            home_total_bets / total_bets
+        } else {
+            Decimal256::zero()
+        };
+
+        let derived_away_probability = if total_bets != Decimal256::zero() {
+            away_total_bets / total_bets
+        } else {
+            Decimal256::zero()
+        };
+
+        let market_seed_weight = to_decimal256(market_seed_balance.u128(), config.denom_precision)?
+            .checked_mul(Decimal256::from(config.seed_liquidity_amplifier))?;
+        let market_probabilities_weight =
+            total_bets / total_bets.checked_add(market_seed_weight)?;
+
+        let new_home_probability = (derived_home_probability * market_probabilities_weight)
+            + (initial_home_probability * (Decimal256::one() - market_probabilities_weight));
+
+        let new_away_probability = (derived_away_probability * market_probabilities_weight)
+            + (initial_away_probability * (Decimal256::one() - market_probabilities_weight));
+
+        Ok((
+            calculate_probability_odds(config, new_home_probability)?,
+            calculate_probability_odds(config, new_away_probability)?,
+        ))
+    }
+
+    fn max_bet(
+        &self,
+        config: &Config,
+        market_balance: Uint128,
+        total_payout: Uint128,
+        odds: Decimal,
+    ) -> Result<Uint128, ContractError> {
+        calculate_max_bet(config, market_balance, total_payout, odds)
+    }
+
+    /// The bet amount is split into increments, each one paid out at the odds
+    /// left by the increments before it, so the bet is priced along the curve:
+    ///
+    /// ```ignore
+    /// increment = bet_amount / PRICE_IMPACT_INCREMENTS
+    ///
+    /// payout = sum(increment * odds(bets + increment * i)) for i in 0..PRICE_IMPACT_INCREMENTS
+    /// ```
+    fn quote(
+        &self,
+        config: &Config,
+        market_balance: Uint128,
+        home: &SideBook,
+        away: &SideBook,
+        home_side: bool,
+        bet_amount: Uint128,
+    ) -> Result<Uint128, ContractError> {
+        let mut market_balance = market_balance;
+        let mut home = home.clone();
+        let mut away = away.clone();
+
+        let increment = bet_amount / Uint128::from(PRICE_IMPACT_INCREMENTS);
+        let mut remaining_amount = bet_amount;
+        let mut payout = Uint128::zero();
+        for i in 0..PRICE_IMPACT_INCREMENTS {
+            // The last increment takes the remainder of the division
+            let amount = if i == PRICE_IMPACT_INCREMENTS - 1 {
+                remaining_amount
+            } else {
+                increment
+            };
+            if amount.is_zero() {
+                continue;
+            }
+
+            let (home_odds, away_odds) = self.odds(config, market_balance, &home, &away)?;
+            let odds = if home_side { home_odds } else { away_odds };
+            let increment_payout = amount
+                .checked_multiply_ratio(odds.numerator(), odds.denominator())
+                .map_err(|_| OverflowError::new(OverflowOperation::Mul))?;
+
+            let side = if home_side { &mut home } else { &mut away };
+            side.total_bets = Uint128::from(side.total_bets).checked_add(amount)?.u128();
+            side.potential_payout = Uint128::from(side.potential_payout)
+                .checked_add(increment_payout)?
+                .u128();
+            market_balance = market_balance.checked_add(amount)?;
+            remaining_amount -= amount;
+            payout = payout.checked_add(increment_payout)?;
+        }
+
+        Ok(payout)
+    }
+}
+
+/// Logarithmic market scoring rule, the potential payouts of each side are the
+/// outstanding shares priced by the cost function:
 ///
 /// ```ignore
-/// market_seed_balance = market_balance - home_total_bets - away_total_bets
+/// cost = b * ln(e^(home_shares / b) + e^(away_shares / b))
 ///
-/// initial_home_probability = 1 / initial_home_odds
-/// initial_away_probability = 1 / initial_away_odds
-///
-/// derived_home_probability = home_total_bets / (home_total_bets + away_total_bets) || 0
-/// derived_away_probability = away_total_bets / (home_total_bets + away_total_bets) || 0
+/// home_probability = e^(home_shares / b) / (e^(home_shares / b) + e^(away_shares / b))
+/// ```
 ///
-/// market_probabilities_weight = (home_total_bets + away_total_bets) / (home_total_bets + away_total_bets + market_seed_balance * seed_amplifier)
+/// The shares of each side are offset so the market opens at the initial probabilities,
+/// normalized to sum up to 1. The larger the liquidity `b`, the less the bets move the odds.
+pub struct LmsrPricing {
+    pub liquidity: Decimal,
+}
+
+impl LmsrPricing {
+    /// Returns `e^((away_shares - home_shares) / b)`, the ratio between the away and home probabilities
+    fn away_home_ratio(
+        &self,
+        config: &Config,
+        home: &SideBook,
+        away: &SideBook,
+    ) -> Result<Decimal256, ContractError> {
+        let (initial_home_probability, initial_away_probability) = initial_probabilities(config);
+        let liquidity = Decimal256::from(self.liquidity);
+        let home_shares = to_decimal256(home.potential_payout, config.denom_precision)?;
+        let away_shares = to_decimal256(away.potential_payout, config.denom_precision)?;
+
+        let shares_ratio = if away_shares >= home_shares {
+            exp((away_shares - home_shares) / liquidity)
+        } else {
+            Decimal256::one() / exp((home_shares - away_shares) / liquidity)
+        };
+
+        Ok(initial_away_probability / initial_home_probability * shares_ratio)
+    }
+}
+
+impl PricingModel for LmsrPricing {
+    fn odds(
+        &self,
+        config: &Config,
+        _market_balance: Uint128,
+        home: &SideBook,
+        away: &SideBook,
+    ) -> Result<(Decimal, Decimal), ContractError> {
+        let ratio = self.away_home_ratio(config, home, away)?;
+
+        let home_probability = Decimal256::one() / (Decimal256::one() + ratio);
+        let away_probability = Decimal256::one() - home_probability;
+
+        Ok((
+            calculate_probability_odds(config, home_probability)?,
+            calculate_probability_odds(config, away_probability)?,
+        ))
+    }
+
+    fn max_bet(
+        &self,
+        config: &Config,
+        market_balance: Uint128,
+        total_payout: Uint128,
+        odds: Decimal,
+    ) -> Result<Uint128, ContractError> {
+        calculate_max_bet(config, market_balance, total_payout, odds)
+    }
+
+    /// The bet amount buys the shares that increase the cost by the same amount:
+    ///
+    /// ```ignore
+    /// shares = b * ln(e^(bet_amount / b) + ratio * (e^(bet_amount / b) - 1))
+    ///
+    /// payout = shares / (1 + fee_spread_odds)
+    /// ```
+    ///
+    /// Where `ratio` is the ratio between the probabilities of the other side and the side bet on.
+    fn quote(
+        &self,
+        config: &Config,
+        _market_balance: Uint128,
+        home: &SideBook,
+        away: &SideBook,
+        home_side: bool,
+        bet_amount: Uint128,
+    ) -> Result<Uint128, ContractError> {
+        let liquidity = Decimal256::from(self.liquidity);
+        let away_home_ratio = self.away_home_ratio(config, home, away)?;
+        let ratio = if home_side {
+            away_home_ratio
+        } else {
+            Decimal256::one()
+                .checked_div(away_home_ratio)
+                .map_err(|_| ContractError::MarketNotPriceable {})?
+        };
+
+        let bet_amount = to_decimal256(bet_amount.u128(), config.denom_precision)?;
+        let growth = exp(bet_amount / liquidity);
+        let shares = liquidity * ln(growth + ratio * (growth - Decimal256::one()));
+
+        calculate_spread_payout(config, shares, bet_amount)
+    }
+}
+
+/// Constant product curve over two virtual reserves funded with the amplified seed
+/// liquidity, split so the market opens at the initial probabilities normalized to sum up to 1:
 ///
-/// new_home_probability = ((derived_home_probability * market_probabilities_weight) + (initial_home_probability * (1 - market_probabilities_weight))) * (1 + fee_spread_odds)
-/// new_away_probability = ((derived_away_probability * market_probabilities_weight) + (initial_away_probability * (1 - market_probabilities_weight))) * (1 + fee_spread_odds)
+/// ```ignore
+/// home_reserve = seed_liquidity * seed_amplifier * initial_away_probability + total_bets - home_potential_payout
+/// away_reserve = seed_liquidity * seed_amplifier * initial_home_probability + total_bets - away_potential_payout
 ///
-/// new_home_odds = 1 / new_home_probability
-/// new_away_odds = 1 / new_away_probability
+/// home_probability = away_reserve / (home_reserve + away_reserve)
 /// ```
 ///
-/// Returns the new home and away odds as a tuple `(new_home_odds, new_away_odds)`
-pub fn calculate_odds(
+/// Each bet is added to both reserves and its payout is taken out of the reserve of its side,
+/// keeping the product of the reserves constant.
+pub struct ConstantProductPricing;
+
+impl ConstantProductPricing {
+    /// Returns the reserves of each side as a tuple `(home_reserve, away_reserve)`
+    fn reserves(
+        config: &Config,
+        home: &SideBook,
+        away: &SideBook,
+    ) -> Result<(Decimal256, Decimal256), ContractError> {
+        let (initial_home_probability, initial_away_probability) = initial_probabilities(config);
+        let liquidity = to_decimal256(config.seed_liquidity.u128(), config.denom_precision)?
+            * Decimal256::from(config.seed_liquidity_amplifier);
+        let total_bets = to_decimal256(
+            Uint128::from(home.total_bets)
+                .checked_add(away.total_bets.into())?
+                .u128(),
+            config.denom_precision,
+        )?;
+
+        let home_reserve = (liquidity * initial_away_probability + total_bets).saturating_sub(
+            to_decimal256(home.potential_payout, config.denom_precision)?,
+        );
+        let away_reserve = (liquidity * initial_home_probability + total_bets).saturating_sub(
+            to_decimal256(away.potential_payout, config.denom_precision)?,
+        );
+
+        Ok((home_reserve, away_reserve))
+    }
+}
+
+impl PricingModel for ConstantProductPricing {
+    fn odds(
+        &self,
+        config: &Config,
+        _market_balance: Uint128,
+        home: &SideBook,
+        away: &SideBook,
+    ) -> Result<(Decimal, Decimal), ContractError> {
+        let (home_reserve, away_reserve) = Self::reserves(config, home, away)?;
+
+        // Both reserves are drained by the payouts, there are no probabilities left to price
+        let home_probability = away_reserve
+            .checked_div(home_reserve + away_reserve)
+            .map_err(|_| ContractError::MarketNotPriceable {})?;
+        let away_probability = Decimal256::one() - home_probability;
+
+        Ok((
+            calculate_probability_odds(config, home_probability)?,
+            calculate_probability_odds(config, away_probability)?,
+        ))
+    }
+
+    fn max_bet(
+        &self,
+        config: &Config,
+        market_balance: Uint128,
+        total_payout: Uint128,
+        odds: Decimal,
+    ) -> Result<Uint128, ContractError> {
+        calculate_max_bet(config, market_balance, total_payout, odds)
+    }
+
+    /// The bet amount is added to both reserves and the shares taken out of the reserve
+    /// of its side keep the product of the reserves constant:
+    ///
+    /// ```ignore
+    /// shares = reserve + bet_amount - reserve * other_reserve / (other_reserve + bet_amount)
+    ///
+    /// payout = shares / (1 + fee_spread_odds)
+    /// ```
+    fn quote(
+        &self,
+        config: &Config,
+        _market_balance: Uint128,
+        home: &SideBook,
+        away: &SideBook,
+        home_side: bool,
+        bet_amount: Uint128,
+    ) -> Result<Uint128, ContractError> {
+        let (home_reserve, away_reserve) = Self::reserves(config, home, away)?;
+        let (reserve, other_reserve) = if home_side {
+            (home_reserve, away_reserve)
+        } else {
+            (away_reserve, home_reserve)
+        };
+
+        let bet_amount = to_decimal256(bet_amount.u128(), config.denom_precision)?;
+        let shares = reserve + bet_amount - reserve * other_reserve / (other_reserve + bet_amount);
+
+        calculate_spread_payout(config, shares, bet_amount)
+    }
+}
+
+/// Returns the initial probabilities of each side normalized to sum up to 1
+fn initial_probabilities(config: &Config) -> (Decimal256, Decimal256) {
+    let home = Decimal256::one() / Decimal256::from(config.initial_odds_home);
+    let away = Decimal256::one() / Decimal256::from(config.initial_odds_away);
+
+    (home / (home + away), away / (home + away))
+}
+
+/// Odds are capped so a side priced close to a zero probability stays priceable,
+/// its max bet is still bounded by the market balance
+pub const MAX_ODDS: u128 = 1000;
+
+/// Converts a probability into odds with the fee spread applied, truncated to 2 decimal places
+///
+/// The probability is floored at `1 / MAX_ODDS`
+fn calculate_probability_odds(
     config: &Config,
-    market_balance: Uint128,
-    home_total_bets: Uint128,
-    away_total_bets: Uint128,
-) -> (Decimal, Decimal) {
-    let market_seed_balance = market_balance - home_total_bets - away_total_bets;
-    let initial_home_probability = Decimal::one() / config.initial_odds_home;
-    let initial_away_probability = Decimal::one() / config.initial_odds_away;
-
-    let total_bets = Decimal::from_atomics(home_total_bets, config.denom_precision).unwrap()
-        + Decimal::from_atomics(away_total_bets, config.denom_precision).unwrap();
-
-    let derived_home_probability = if total_bets != Decimal::zero() {
-        Decimal::from_atomics(home_total_bets, config.denom_precision).unwrap() / total_bets
-    } else {
-        Decimal::zero()
-    };
+    probability: Decimal256,
+) -> Result<Decimal, ContractError> {
+    let min_probability = Decimal256::from_ratio(1_u128, MAX_ODDS);
+    let probability = (probability
+        * (Decimal256::one() + Decimal256::from(config.fee_spread_odds)))
+    .max(min_probability);
+    let odds = Decimal256::one()
+        .checked_div(probability)
+        .ok()
+        .and_then(|odds| Decimal::try_from(odds).ok())
+        .ok_or(ContractError::MarketNotPriceable {})?;
 
-    let derived_away_probability = if total_bets != Decimal::zero() {
-        Decimal::from_atomics(away_total_bets, config.denom_precision).unwrap() / total_bets
-    } else {
-        Decimal::zero()
-    };
+    Ok(truncate_decimal(odds, 2))
+}
+
+/// Converts the shares bought along a curve into the payout with the fee spread applied,
+/// capped at the bet amount priced at `MAX_ODDS`
+fn calculate_spread_payout(
+    config: &Config,
+    shares: Decimal256,
+    bet_amount: Decimal256,
+) -> Result<Uint128, ContractError> {
+    let payout = (shares / (Decimal256::one() + Decimal256::from(config.fee_spread_odds)))
+        .min(bet_amount.checked_mul(Decimal256::from_ratio(MAX_ODDS, 1_u128))?);
+
+    convert_from_decimal_to_uint128(payout, config.denom_precision)
+}
+
+/// Converts an amount of the denom into a decimal
+///
+/// Fails when the precision of the denom is above the 18 decimal places of a decimal
+pub fn to_decimal256(amount: u128, decimals: u32) -> Result<Decimal256, ContractError> {
+    if decimals > Decimal256::DECIMAL_PLACES {
+        return Err(ContractError::InvalidPrecision(decimals));
+    }
+
+    Decimal256::from_atomics(amount, decimals)
+        .map_err(|_| ContractError::Overflow(OverflowError::new(OverflowOperation::Mul)))
+}
 
-    let market_probabilities_weight = total_bets
-        / (total_bets
-            + Decimal::from_atomics(market_seed_balance, config.denom_precision).unwrap()
-                * config.seed_liquidity_amplifier);
+/// Exponents are capped so the curves stay within the range of a Decimal256,
+/// by then the probability of a side is already below 1e-17
+const MAX_EXPONENT: u128 = 40;
+const LN_2: Decimal256 = Decimal256::raw(693_147_180_559_945_309);
 
-    let new_home_probability = ((derived_home_probability * market_probabilities_weight)
-        + (initial_home_probability * (Decimal::one() - market_probabilities_weight)))
-        * (Decimal::one() + config.fee_spread_odds);
+/// Calculates `e^x` for a positive x
+///
+/// The exponent is halved until it is below 1 for the taylor series to converge quickly,
+/// then the result is squared back: `e^x = (e^(x / 2^k))^(2^k)`
+fn exp(x: Decimal256) -> Decimal256 {
+    let two = Decimal256::from_atomics(2_u128, 0).unwrap();
+    let mut reduced = x.min(Decimal256::from_atomics(MAX_EXPONENT, 0).unwrap());
+    let mut squarings = 0;
+    while reduced > Decimal256::one() {
+        reduced /= two;
+        squarings += 1;
+    }
+
+    let mut term = Decimal256::one();
+    let mut sum = Decimal256::one();
+    for n in 1..=30_u128 {
+        term = term * reduced / Decimal256::from_atomics(n, 0).unwrap();
+        if term.is_zero() {
+            break;
+        }
+        sum += term;
+    }
+
+    for _ in 0..squarings {
+        sum *= sum;
+    }
+
+    sum
+}
+
+/// Calculates `ln(x)` for x greater than or equal to 1
+///
+/// The argument is halved until it is below 2, then the series
+/// `ln(m) = 2 * (z + z^3 / 3 + z^5 / 5 + ...)` with `z = (m - 1) / (m + 1)` is used:
+/// `ln(x) = k * ln(2) + ln(x / 2^k)`
+fn ln(x: Decimal256) -> Decimal256 {
+    let two = Decimal256::from_atomics(2_u128, 0).unwrap();
+    let mut reduced = x.max(Decimal256::one());
+    let mut halvings = 0_u128;
+    while reduced >= two {
+        reduced /= two;
+        halvings += 1;
+    }
 
-    let new_away_probability = ((derived_away_probability * market_probabilities_weight)
-        + (initial_away_probability * (Decimal::one() - market_probabilities_weight)))
-        * (Decimal::one() + config.fee_spread_odds);
+    let z = (reduced - Decimal256::one()) / (reduced + Decimal256::one());
+    let z_squared = z * z;
+    let mut power = z;
+    let mut sum = Decimal256::zero();
+    for n in (1..=61_u128).step_by(2) {
+        let term = power / Decimal256::from_atomics(n, 0).unwrap();
+        if term.is_zero() {
+            break;
+        }
+        sum += term;
+        power *= z_squared;
+    }
 
-    (
-        truncate_decimal(Decimal::one() / new_home_probability, 2),
-        truncate_decimal(Decimal::one() / new_away_probability, 2),
-    )
+    LN_2 * Decimal256::from_atomics(halvings, 0).unwrap() + sum * two
 }
 
 /// Truncates the decimal places
@@ -103,18 +624,20 @@ fn truncate_decimal(decimal: Decimal, decimals: u32) -> Decimal {
 /// max_bet_amount = max_available_payout / odds / max_bet_risk_factor
 /// ```
 ///
-/// The function returns the maximum bet amount as a Uint128.
+/// The amounts are converted to 256 bits decimals, so the amounts of 18 decimals
+/// denoms stay within range. The function returns the maximum bet amount as a Uint128.
 pub fn calculate_max_bet(
     config: &Config,
     market_balance: Uint128,
     total_payout: Uint128,
     odds: Decimal,
-) -> Uint128 {
-    let max_available_payout = Decimal::from_atomics(market_balance, config.denom_precision)
-        .unwrap()
-        - Decimal::from_atomics(total_payout, config.denom_precision).unwrap();
+) -> Result<Uint128, ContractError> {
+    let max_available_payout = to_decimal256(market_balance.u128(), config.denom_precision)?
+        .checked_sub(to_decimal256(total_payout.u128(), config.denom_precision)?)?;
 
-    let max_bet_amount = max_available_payout / odds / config.max_bet_risk_factor;
+    let max_bet_amount = max_available_payout
+        / Decimal256::from(odds)
+        / Decimal256::from(config.max_bet_risk_factor);
 
     convert_from_decimal_to_uint128(max_bet_amount, config.denom_precision)
 }
@@ -125,29 +648,346 @@ pub fn calculate_max_bet(
 /// then converts it to a Uint128.
 ///
 /// For example, if the decimal is 1.2345 and the decimals is 2, the function will return 123.
-fn convert_from_decimal_to_uint128(decimal: Decimal, decimals: u32) -> Uint128 {
-    let mut atomics = decimal.atomics();
+///
+/// Fails when the truncated decimal is out of the range of a Uint128.
+fn convert_from_decimal_to_uint128(
+    decimal: Decimal256,
+    decimals: u32,
+) -> Result<Uint128, ContractError> {
+    if decimals > Decimal256::DECIMAL_PLACES {
+        return Err(ContractError::InvalidPrecision(decimals));
+    }
 
-    let decimal_places = decimal.decimal_places() as i32;
-    let decimal_places_difference: i32 = decimal_places - decimals as i32;
+    let atomics =
+        decimal.atomics() / Uint256::from(10_u128.pow(Decimal256::DECIMAL_PLACES - decimals));
 
-    match decimal_places_difference.cmp(&0) {
-        Ordering::Greater => {
-            atomics /= Uint128::from(10_u128.pow(decimal_places_difference as u32));
+    Uint128::try_from(atomics)
+        .map_err(|_| ContractError::Overflow(OverflowError::new(OverflowOperation::Mul)))
+}
+
+/// Calculates the timestamp after which the bets are no longer accepted,
+/// 5 minutes before the start of the match
+///
+/// Fails when the start timestamp is too small to leave room for the cutoff
+pub fn calculate_bets_cutoff(start_timestamp: u64) -> Result<u64, ContractError> {
+    start_timestamp
+        .checked_sub(5 * 60)
+        .ok_or(ContractError::InvalidTimestamp(start_timestamp))
+}
+
+/// Calculates the timestamp from which an in play bet can be confirmed,
+/// once the bet delay has elapsed since it was placed
+pub fn calculate_confirmable_at(placed_at: u64, bet_delay: u64) -> Result<u64, ContractError> {
+    placed_at
+        .checked_add(bet_delay)
+        .ok_or(ContractError::InvalidTimestamp(placed_at))
+}
+
+/// Calculates the result of a totals market based on the final total
+///
+/// The result is OVER when the total is above the line, UNDER when it is below
+/// and PUSH when an integer line is hit exactly, in which case all bets are refunded.
+pub fn calculate_totals_result(line: Decimal, total: u64) -> MarketResult {
+    match Decimal::from_ratio(total, 1_u128).cmp(&line) {
+        Ordering::Greater => MarketResult::OVER,
+        Ordering::Less => MarketResult::UNDER,
+        Ordering::Equal => MarketResult::PUSH,
+    }
+}
+
+/// Calculates how each side of the market is settled for a single market result
+///
+/// The side matching the result wins and the other side loses, when the result
+/// is PUSH both sides are refunded.
+pub fn calculate_result_settlement(result: &MarketResult) -> Settlement {
+    match result {
+        MarketResult::PUSH => Settlement {
+            home: SettlementOutcome::PUSH,
+            away: SettlementOutcome::PUSH,
+        },
+        result if result.is_home_side() => Settlement {
+            home: SettlementOutcome::WIN,
+            away: SettlementOutcome::LOSE,
+        },
+        _ => Settlement {
+            home: SettlementOutcome::LOSE,
+            away: SettlementOutcome::WIN,
+        },
+    }
+}
+
+/// Calculates how each side of a handicap market is settled from the final score
+///
+/// The handicap is added to the home score and the home side wins if it ends up
+/// ahead, loses if it ends up behind and is refunded when level. Quarter lines
+/// (ex: -0.25, +1.75) split the stake evenly between the two closest half lines,
+/// which results in half win or half lose outcomes:
+///
+/// ```ignore
+/// handicap = -0.25, home 1 - 1 away => half the stake on 0 (PUSH), half on -0.5 (LOSE) => HALF_LOSE
+/// handicap = -0.75, home 2 - 1 away => half the stake on -0.5 (WIN), half on -1 (PUSH) => HALF_WIN
+/// ```
+///
+/// The away side is always settled with the opposite outcome of the home side.
+pub fn calculate_handicap_settlement(
+    handicap: SignedDecimal,
+    home_score: u64,
+    away_score: u64,
+) -> Settlement {
+    let margin =
+        SignedDecimal::from_ratio(home_score, 1_u64) - SignedDecimal::from_ratio(away_score, 1_u64);
+
+    let half = SignedDecimal::from_atomics(5_i128, 1).unwrap();
+    let quarter = SignedDecimal::from_atomics(25_i128, 2).unwrap();
+
+    // Each half of the stake scores 2 for a win, 1 for a push and 0 for a loss
+    let half_stake_score = |line: SignedDecimal| -> u8 {
+        match (margin + line).cmp(&SignedDecimal::zero()) {
+            Ordering::Greater => 2,
+            Ordering::Equal => 1,
+            Ordering::Less => 0,
         }
-        Ordering::Less => {
-            atomics *= Uint128::from(10_u128.pow((-decimal_places_difference) as u32));
+    };
+
+    let score = if (handicap % half).is_zero() {
+        half_stake_score(handicap) * 2
+    } else {
+        half_stake_score(handicap - quarter) + half_stake_score(handicap + quarter)
+    };
+
+    let home = match score {
+        4 => SettlementOutcome::WIN,
+        3 => SettlementOutcome::HALF_WIN,
+        2 => SettlementOutcome::PUSH,
+        1 => SettlementOutcome::HALF_LOSE,
+        _ => SettlementOutcome::LOSE,
+    };
+    let away = match home {
+        SettlementOutcome::WIN => SettlementOutcome::LOSE,
+        SettlementOutcome::HALF_WIN => SettlementOutcome::HALF_LOSE,
+        SettlementOutcome::PUSH => SettlementOutcome::PUSH,
+        SettlementOutcome::HALF_LOSE => SettlementOutcome::HALF_WIN,
+        SettlementOutcome::LOSE => SettlementOutcome::WIN,
+    };
+
+    Settlement { home, away }
+}
+
+/// Calculates the amount paid out for a bet record given its settlement outcome
+///
+/// ```ignore
+/// WIN       => payout
+/// HALF_WIN  => (bet_amount + payout) / 2
+/// PUSH      => bet_amount
+/// HALF_LOSE => bet_amount / 2
+/// LOSE      => 0
+/// ```
+pub fn calculate_settled_payout(
+    outcome: &SettlementOutcome,
+    bet_amount: u128,
+    payout: u128,
+) -> u128 {
+    match outcome {
+        SettlementOutcome::WIN => payout,
+        SettlementOutcome::HALF_WIN => (bet_amount + payout) / 2,
+        SettlementOutcome::PUSH => bet_amount,
+        SettlementOutcome::HALF_LOSE => bet_amount / 2,
+        SettlementOutcome::LOSE => 0,
+    }
+}
+
+/// Returns the payout owed to all the positions of one side given how it was settled,
+/// the parlay liability is left out as it is paid to the parlay contract
+pub fn calculate_positions_payout(
+    outcome: &SettlementOutcome,
+    side: &SideBook,
+    half_payouts: &HalfPayouts,
+) -> Result<u128, ContractError> {
+    Ok(match outcome {
+        SettlementOutcome::WIN => Uint128::from(side.potential_payout)
+            .checked_sub(side.parlay_liability.into())?
+            .u128(),
+        SettlementOutcome::HALF_WIN => half_payouts.half_win,
+        SettlementOutcome::PUSH => side.total_bets,
+        SettlementOutcome::HALF_LOSE => half_payouts.half_lose,
+        SettlementOutcome::LOSE => 0,
+    })
+}
+
+/// Returns the market result recorded for a handicap settlement, the side that
+/// wins or half wins is the result and PUSH when both sides are refunded
+pub fn calculate_handicap_result(settlement: &Settlement) -> MarketResult {
+    match settlement.home {
+        SettlementOutcome::WIN | SettlementOutcome::HALF_WIN => MarketResult::HOME,
+        SettlementOutcome::PUSH => MarketResult::PUSH,
+        SettlementOutcome::HALF_LOSE | SettlementOutcome::LOSE => MarketResult::AWAY,
+    }
+}
+
+/// Returns every way a market of the given kind can be settled with its market result
+///
+/// Totals markets with an integer line can be pushed and handicap markets are settled
+/// with the outcomes reachable by their handicap, from the home side winning to losing.
+pub fn calculate_possible_settlements(kind: &MarketKind) -> Vec<(MarketResult, Settlement)> {
+    match kind {
+        MarketKind::MONEYLINE => vec![MarketResult::HOME, MarketResult::AWAY]
+            .into_iter()
+            .map(|result| (result.clone(), calculate_result_settlement(&result)))
+            .collect(),
+        MarketKind::TOTALS { line } => {
+            let mut results = vec![MarketResult::OVER, MarketResult::UNDER];
+            if line.floor() == *line {
+                results.push(MarketResult::PUSH);
+            }
+            results
+                .into_iter()
+                .map(|result| (result.clone(), calculate_result_settlement(&result)))
+                .collect()
+        }
+        MarketKind::HANDICAP { handicap } => {
+            // Every outcome is reached within 2 goals of the handicap on each side
+            let max_margin = handicap
+                .abs_diff(SignedDecimal::zero())
+                .to_uint_ceil()
+                .u128() as u64
+                + 2;
+
+            let mut settlements: Vec<(MarketResult, Settlement)> = vec![];
+            let margins = (0..=max_margin)
+                .rev()
+                .map(|margin| (margin, 0))
+                .chain((1..=max_margin).map(|margin| (0, margin)));
+            for (home_score, away_score) in margins {
+                let settlement = calculate_handicap_settlement(*handicap, home_score, away_score);
+                if !settlements.iter().any(|(_, known)| *known == settlement) {
+                    settlements.push((calculate_handicap_result(&settlement), settlement));
+                }
+            }
+            settlements
         }
-        Ordering::Equal => {}
     }
+}
 
-    atomics
+/// Amounts moved when a market is settled
+#[derive(Debug, PartialEq)]
+pub struct SettlementAmounts {
+    pub market_payout: Uint128, // Paid out to the bettors and the parlay contract
+    pub parlay_payout: Uint128,
+    pub outstanding_balance: Uint128,
+    pub profit: Uint128,
+    pub loss: Uint128,
+}
+
+/// Calculates the amounts moved when a market is settled
+///
+/// ```ignore
+/// market_payout = settled_payout(home) + settled_payout(away)
+/// parlay_payout = settled_payout(home parlay liability) + settled_payout(away parlay liability)
+///
+/// outstanding_balance = market_balance - market_payout
+///
+/// profit = outstanding_balance - seed_liquidity || 0
+/// loss = seed_liquidity - outstanding_balance || 0
+/// ```
+///
+/// See `calculate_settled_payout` for the payout of each settlement outcome.
+pub fn calculate_settlement_amounts(
+    seed_liquidity: Uint128,
+    market_balance: Uint128,
+    home: &SideBook,
+    away: &SideBook,
+    settlement: &Settlement,
+) -> Result<SettlementAmounts, ContractError> {
+    let market_payout = Uint128::from(
+        calculate_settled_payout(&settlement.home, home.total_bets, home.potential_payout)
+            + calculate_settled_payout(&settlement.away, away.total_bets, away.potential_payout),
+    );
+    let parlay_payout = Uint128::from(
+        calculate_settled_payout(&settlement.home, 0, home.parlay_liability)
+            + calculate_settled_payout(&settlement.away, 0, away.parlay_liability),
+    );
+
+    let outstanding_balance = market_balance.checked_sub(market_payout)?;
+
+    Ok(SettlementAmounts {
+        market_payout,
+        parlay_payout,
+        outstanding_balance,
+        profit: outstanding_balance.saturating_sub(seed_liquidity),
+        loss: seed_liquidity.saturating_sub(outstanding_balance),
+    })
+}
+
+/// Formats decimal odds in the given format, the odds are truncated to 2 decimals first
+///
+/// ```ignore
+/// DECIMAL    => 1.91 => "1.91"
+/// AMERICAN   => 1.91 => "-110", 2.50 => "+150"
+/// FRACTIONAL => 1.91 => "91/100", 2.50 => "3/2"
+/// ```
+///
+/// Returns `None` when the odds can't be represented in the format,
+/// which is the case of American odds of 1.00
+pub fn format_odds(odds: Decimal, format: &OddsFormat) -> Option<String> {
+    let cents = Uint128::new(100).mul_floor(odds).u128();
+    let profit_cents = cents.saturating_sub(100);
+
+    match format {
+        OddsFormat::DECIMAL => Some(format!("{}.{:02}", cents / 100, cents % 100)),
+        OddsFormat::AMERICAN => match profit_cents {
+            0 => None,
+            profit_cents if profit_cents >= 100 => Some(format!("+{profit_cents}")),
+            profit_cents => Some(format!(
+                "-{}",
+                (10_000 + profit_cents / 2) / profit_cents // Rounded to the nearest integer
+            )),
+        },
+        OddsFormat::FRACTIONAL => {
+            let divisor = greatest_common_divisor(profit_cents, 100);
+            Some(format!("{}/{}", profit_cents / divisor, 100 / divisor))
+        }
+    }
+}
+
+fn greatest_common_divisor(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        a
+    } else {
+        greatest_common_divisor(b, a % b)
+    }
+}
+
+/// Calculates the overround of a market, the bookmaker margin, from the implied
+/// probabilities of all its outcomes
+///
+/// `overround = sum(implied_probabilities) - 1`
+pub fn calculate_overround(implied_probabilities: &[Decimal]) -> Decimal {
+    implied_probabilities
+        .iter()
+        .fold(Decimal::zero(), |total, probability| total + probability)
+        .saturating_sub(Decimal::one())
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::logic::{convert_from_decimal_to_uint128, truncate_decimal};
+    use crate::error::ContractError;
+    use crate::logic::{
+        calculate_bets_cutoff, calculate_confirmable_at, calculate_handicap_settlement,
+        calculate_overround, calculate_positions_payout, calculate_possible_settlements,
+        calculate_settled_payout, calculate_settlement_amounts, calculate_totals_result,
+        convert_from_decimal_to_uint128, exp, format_odds, ln, to_decimal256, truncate_decimal,
+        PricingModel, SettlementAmounts,
+    };
+    use crate::state::{
+        Config, HalfPayouts, MarketKind, MarketResult, OddsFormat, PricingModelKind, Settlement,
+        SettlementOutcome, SideBook,
+    };
+    use cosmwasm_std::Addr;
     use cosmwasm_std::Decimal;
+    use cosmwasm_std::Decimal256;
+    use cosmwasm_std::OverflowError;
+    use cosmwasm_std::OverflowOperation;
+    use cosmwasm_std::SignedDecimal;
     use cosmwasm_std::Uint128;
 
     mod truncate_decimal {
@@ -183,17 +1023,906 @@ mod tests {
 
         #[test]
         fn it_converts_decimal_to_uint128() {
-            let decimal = Decimal::from_atomics(123_456789_u128, 6).unwrap();
-            let uint128 = convert_from_decimal_to_uint128(decimal, 6);
+            let decimal = Decimal256::from_atomics(123_456789_u128, 6).unwrap();
+            let uint128 = convert_from_decimal_to_uint128(decimal, 6).unwrap();
             assert_eq!(Uint128::from(123_456789_u128), uint128);
 
-            let decimal = Decimal::from_atomics(1_2_u128, 1).unwrap();
-            let uint128 = convert_from_decimal_to_uint128(decimal, 1);
+            let decimal = Decimal256::from_atomics(1_2_u128, 1).unwrap();
+            let uint128 = convert_from_decimal_to_uint128(decimal, 1).unwrap();
             assert_eq!(Uint128::from(1_2_u128), uint128);
 
-            let decimal = Decimal::from_atomics(2_3129321362139427_u128, 16).unwrap();
-            let uint128 = convert_from_decimal_to_uint128(decimal, 16);
+            let decimal = Decimal256::from_atomics(2_3129321362139427_u128, 16).unwrap();
+            let uint128 = convert_from_decimal_to_uint128(decimal, 16).unwrap();
             assert_eq!(Uint128::from(2_3129321362139427_u128), uint128);
+
+            let decimal = Decimal256::from_atomics(u128::MAX, 18).unwrap();
+            let uint128 = convert_from_decimal_to_uint128(decimal, 18).unwrap();
+            assert_eq!(Uint128::MAX, uint128);
+        }
+
+        #[test]
+        fn it_fails_with_a_decimal_out_of_the_uint128_range() {
+            let decimal = Decimal256::from_atomics(u128::MAX, 6).unwrap();
+            assert_eq!(
+                ContractError::Overflow(OverflowError::new(OverflowOperation::Mul)),
+                convert_from_decimal_to_uint128(decimal, 18).unwrap_err()
+            );
+        }
+    }
+
+    mod to_decimal256 {
+        use super::*;
+
+        #[test]
+        fn it_converts_an_amount_to_decimal() {
+            assert_eq!(
+                Decimal256::from_atomics(123_456789_u128, 6).unwrap(),
+                to_decimal256(123_456789, 6).unwrap()
+            );
+            assert_eq!(
+                Decimal256::from_atomics(1_u128, 18).unwrap(),
+                to_decimal256(1, 18).unwrap()
+            );
+        }
+
+        #[test]
+        fn it_converts_the_full_range_of_amounts() {
+            // Out of the range of a Decimal for any precision below 18 decimals
+            assert_eq!(
+                Decimal256::from_atomics(u128::MAX, 6).unwrap(),
+                to_decimal256(u128::MAX, 6).unwrap()
+            );
+        }
+
+        #[test]
+        fn it_fails_with_a_precision_above_18_decimals() {
+            assert_eq!(
+                ContractError::InvalidPrecision(19),
+                to_decimal256(1, 19).unwrap_err()
+            );
+        }
+    }
+
+    mod calculate_bets_cutoff {
+        use super::*;
+
+        #[test]
+        fn it_calculates_the_bets_cutoff() {
+            assert_eq!(
+                1_000_000 - 5 * 60,
+                calculate_bets_cutoff(1_000_000).unwrap()
+            );
+            assert_eq!(0, calculate_bets_cutoff(5 * 60).unwrap());
+        }
+
+        #[test]
+        fn it_fails_when_the_start_timestamp_is_before_the_cutoff() {
+            assert_eq!(
+                ContractError::InvalidTimestamp(60),
+                calculate_bets_cutoff(60).unwrap_err()
+            );
+        }
+    }
+
+    mod calculate_confirmable_at {
+        use super::*;
+
+        #[test]
+        fn it_calculates_the_confirmable_timestamp() {
+            assert_eq!(1_000_010, calculate_confirmable_at(1_000_000, 10).unwrap());
+        }
+
+        #[test]
+        fn it_fails_when_the_bet_delay_overflows_the_timestamp() {
+            assert_eq!(
+                ContractError::InvalidTimestamp(u64::MAX),
+                calculate_confirmable_at(u64::MAX, 10).unwrap_err()
+            );
+        }
+    }
+
+    mod calculate_totals_result {
+        use super::*;
+
+        #[test]
+        fn it_calculates_totals_result() {
+            let line = Decimal::from_atomics(2_5_u128, 1).unwrap();
+            assert_eq!(MarketResult::OVER, calculate_totals_result(line, 3));
+            assert_eq!(MarketResult::UNDER, calculate_totals_result(line, 2));
+
+            let line = Decimal::from_atomics(220_u128, 0).unwrap();
+            assert_eq!(MarketResult::OVER, calculate_totals_result(line, 221));
+            assert_eq!(MarketResult::UNDER, calculate_totals_result(line, 219));
+            assert_eq!(MarketResult::PUSH, calculate_totals_result(line, 220));
+        }
+    }
+
+    mod calculate_handicap_settlement {
+        use super::*;
+
+        fn handicap(atomics: i128) -> SignedDecimal {
+            SignedDecimal::from_atomics(atomics, 2).unwrap()
+        }
+
+        #[test]
+        fn it_settles_whole_and_half_lines() {
+            let settlement = calculate_handicap_settlement(handicap(-1_50), 3, 1);
+            assert_eq!(SettlementOutcome::WIN, settlement.home);
+            assert_eq!(SettlementOutcome::LOSE, settlement.away);
+
+            let settlement = calculate_handicap_settlement(handicap(-1_50), 2, 1);
+            assert_eq!(SettlementOutcome::LOSE, settlement.home);
+            assert_eq!(SettlementOutcome::WIN, settlement.away);
+
+            let settlement = calculate_handicap_settlement(handicap(-1_00), 2, 1);
+            assert_eq!(SettlementOutcome::PUSH, settlement.home);
+            assert_eq!(SettlementOutcome::PUSH, settlement.away);
+
+            let settlement = calculate_handicap_settlement(handicap(2_00), 0, 1);
+            assert_eq!(SettlementOutcome::WIN, settlement.home);
+            assert_eq!(SettlementOutcome::LOSE, settlement.away);
+        }
+
+        #[test]
+        fn it_settles_quarter_lines() {
+            let settlement = calculate_handicap_settlement(handicap(-25), 1, 1);
+            assert_eq!(SettlementOutcome::HALF_LOSE, settlement.home);
+            assert_eq!(SettlementOutcome::HALF_WIN, settlement.away);
+
+            let settlement = calculate_handicap_settlement(handicap(-75), 2, 1);
+            assert_eq!(SettlementOutcome::HALF_WIN, settlement.home);
+            assert_eq!(SettlementOutcome::HALF_LOSE, settlement.away);
+
+            let settlement = calculate_handicap_settlement(handicap(25), 1, 1);
+            assert_eq!(SettlementOutcome::HALF_WIN, settlement.home);
+            assert_eq!(SettlementOutcome::HALF_LOSE, settlement.away);
+
+            let settlement = calculate_handicap_settlement(handicap(-1_25), 3, 1);
+            assert_eq!(SettlementOutcome::WIN, settlement.home);
+            assert_eq!(SettlementOutcome::LOSE, settlement.away);
+        }
+    }
+
+    mod calculate_settled_payout {
+        use super::*;
+
+        #[test]
+        fn it_calculates_the_settled_payout() {
+            assert_eq!(
+                19_100_000,
+                calculate_settled_payout(&SettlementOutcome::WIN, 10_000_000, 19_100_000)
+            );
+            assert_eq!(
+                14_550_000,
+                calculate_settled_payout(&SettlementOutcome::HALF_WIN, 10_000_000, 19_100_000)
+            );
+            assert_eq!(
+                10_000_000,
+                calculate_settled_payout(&SettlementOutcome::PUSH, 10_000_000, 19_100_000)
+            );
+            assert_eq!(
+                5_000_000,
+                calculate_settled_payout(&SettlementOutcome::HALF_LOSE, 10_000_000, 19_100_000)
+            );
+            assert_eq!(
+                0,
+                calculate_settled_payout(&SettlementOutcome::LOSE, 10_000_000, 19_100_000)
+            );
+        }
+    }
+
+    mod calculate_positions_payout {
+        use super::*;
+
+        #[test]
+        fn it_calculates_the_payout_owed_to_the_positions_of_a_side() {
+            let side = SideBook {
+                total_bets: 10_000_001,
+                potential_payout: 28_200_001,
+                parlay_liability: 9_100_000,
+            };
+            // Two positions of (5_000_000, 9_550_000) and (5_000_001, 9_550_001)
+            let half_payouts = HalfPayouts {
+                half_win: 7_275_000 + 7_275_001,
+                half_lose: 2_500_000 + 2_500_000,
+            };
+
+            assert_eq!(
+                19_100_001,
+                calculate_positions_payout(&SettlementOutcome::WIN, &side, &half_payouts).unwrap()
+            );
+            assert_eq!(
+                14_550_001,
+                calculate_positions_payout(&SettlementOutcome::HALF_WIN, &side, &half_payouts)
+                    .unwrap()
+            );
+            assert_eq!(
+                10_000_001,
+                calculate_positions_payout(&SettlementOutcome::PUSH, &side, &half_payouts).unwrap()
+            );
+            // Rounded down on each position
+            assert_eq!(
+                5_000_000,
+                calculate_positions_payout(&SettlementOutcome::HALF_LOSE, &side, &half_payouts)
+                    .unwrap()
+            );
+            assert_eq!(
+                0,
+                calculate_positions_payout(&SettlementOutcome::LOSE, &side, &half_payouts).unwrap()
+            );
+        }
+    }
+
+    mod format_odds {
+        use super::*;
+
+        #[test]
+        fn it_formats_decimal_odds() {
+            let odds = Decimal::from_atomics(1_919_u128, 3).unwrap();
+            assert_eq!(
+                Some("1.91".to_string()),
+                format_odds(odds, &OddsFormat::DECIMAL)
+            );
+
+            let odds = Decimal::from_atomics(2_u128, 0).unwrap();
+            assert_eq!(
+                Some("2.00".to_string()),
+                format_odds(odds, &OddsFormat::DECIMAL)
+            );
+        }
+
+        #[test]
+        fn it_formats_american_odds() {
+            let odds = Decimal::from_atomics(1_91_u128, 2).unwrap();
+            assert_eq!(
+                Some("-110".to_string()),
+                format_odds(odds, &OddsFormat::AMERICAN)
+            );
+
+            let odds = Decimal::from_atomics(2_5_u128, 1).unwrap();
+            assert_eq!(
+                Some("+150".to_string()),
+                format_odds(odds, &OddsFormat::AMERICAN)
+            );
+
+            let odds = Decimal::from_atomics(2_u128, 0).unwrap();
+            assert_eq!(
+                Some("+100".to_string()),
+                format_odds(odds, &OddsFormat::AMERICAN)
+            );
+
+            assert_eq!(None, format_odds(Decimal::one(), &OddsFormat::AMERICAN));
+        }
+
+        #[test]
+        fn it_formats_fractional_odds() {
+            let odds = Decimal::from_atomics(1_91_u128, 2).unwrap();
+            assert_eq!(
+                Some("91/100".to_string()),
+                format_odds(odds, &OddsFormat::FRACTIONAL)
+            );
+
+            let odds = Decimal::from_atomics(2_5_u128, 1).unwrap();
+            assert_eq!(
+                Some("3/2".to_string()),
+                format_odds(odds, &OddsFormat::FRACTIONAL)
+            );
+
+            assert_eq!(
+                Some("0/1".to_string()),
+                format_odds(Decimal::one(), &OddsFormat::FRACTIONAL)
+            );
+        }
+    }
+
+    mod calculate_overround {
+        use super::*;
+
+        #[test]
+        fn it_calculates_the_overround() {
+            let overround = calculate_overround(&[
+                Decimal::from_atomics(55_u128, 2).unwrap(),
+                Decimal::from_atomics(55_u128, 2).unwrap(),
+            ]);
+            assert_eq!(Decimal::from_atomics(1_u128, 1).unwrap(), overround);
+
+            let overround = calculate_overround(&[Decimal::from_atomics(5_u128, 1).unwrap()]);
+            assert_eq!(Decimal::zero(), overround);
+        }
+    }
+
+    mod calculate_possible_settlements {
+        use super::*;
+
+        #[test]
+        fn it_returns_the_possible_settlements_of_each_market_kind() {
+            let results = |kind: MarketKind| -> Vec<MarketResult> {
+                calculate_possible_settlements(&kind)
+                    .into_iter()
+                    .map(|(result, _)| result)
+                    .collect()
+            };
+
+            assert_eq!(
+                vec![MarketResult::HOME, MarketResult::AWAY],
+                results(MarketKind::MONEYLINE)
+            );
+            assert_eq!(
+                vec![MarketResult::OVER, MarketResult::UNDER],
+                results(MarketKind::TOTALS {
+                    line: Decimal::from_atomics(25_u128, 1).unwrap()
+                })
+            );
+            assert_eq!(
+                vec![MarketResult::OVER, MarketResult::UNDER, MarketResult::PUSH],
+                results(MarketKind::TOTALS {
+                    line: Decimal::from_atomics(3_u128, 0).unwrap()
+                })
+            );
+        }
+
+        #[test]
+        fn it_returns_the_reachable_settlements_of_a_handicap() {
+            let home_outcomes = |handicap: SignedDecimal| -> Vec<SettlementOutcome> {
+                calculate_possible_settlements(&MarketKind::HANDICAP { handicap })
+                    .into_iter()
+                    .map(|(_, settlement)| settlement.home)
+                    .collect()
+            };
+
+            assert_eq!(
+                vec![SettlementOutcome::WIN, SettlementOutcome::LOSE],
+                home_outcomes(SignedDecimal::from_atomics(-15_i128, 1).unwrap())
+            );
+            assert_eq!(
+                vec![
+                    SettlementOutcome::WIN,
+                    SettlementOutcome::PUSH,
+                    SettlementOutcome::LOSE
+                ],
+                home_outcomes(SignedDecimal::from_atomics(-1_i128, 0).unwrap())
+            );
+            assert_eq!(
+                vec![
+                    SettlementOutcome::WIN,
+                    SettlementOutcome::HALF_LOSE,
+                    SettlementOutcome::LOSE
+                ],
+                home_outcomes(SignedDecimal::from_atomics(-25_i128, 2).unwrap())
+            );
+            assert_eq!(
+                vec![
+                    SettlementOutcome::WIN,
+                    SettlementOutcome::HALF_WIN,
+                    SettlementOutcome::LOSE
+                ],
+                home_outcomes(SignedDecimal::from_atomics(-75_i128, 2).unwrap())
+            );
+        }
+    }
+
+    mod calculate_settlement_amounts {
+        use super::*;
+
+        #[test]
+        fn it_calculates_the_settlement_amounts() {
+            let home = SideBook {
+                total_bets: 10_000_000,
+                potential_payout: 28_200_000,
+                parlay_liability: 9_100_000,
+            };
+            let away = SideBook {
+                total_bets: 10_000_000,
+                potential_payout: 16_100_000,
+                parlay_liability: 0,
+            };
+            let seed_liquidity = Uint128::new(100_000_000);
+            let market_balance = Uint128::new(120_000_000);
+
+            let amounts = calculate_settlement_amounts(
+                seed_liquidity,
+                market_balance,
+                &home,
+                &away,
+                &Settlement {
+                    home: SettlementOutcome::WIN,
+                    away: SettlementOutcome::LOSE,
+                },
+            )
+            .unwrap();
+            assert_eq!(
+                SettlementAmounts {
+                    market_payout: Uint128::new(28_200_000),
+                    parlay_payout: Uint128::new(9_100_000),
+                    outstanding_balance: Uint128::new(91_800_000),
+                    profit: Uint128::zero(),
+                    loss: Uint128::new(8_200_000),
+                },
+                amounts
+            );
+
+            let amounts = calculate_settlement_amounts(
+                seed_liquidity,
+                market_balance,
+                &home,
+                &away,
+                &Settlement {
+                    home: SettlementOutcome::LOSE,
+                    away: SettlementOutcome::WIN,
+                },
+            )
+            .unwrap();
+            assert_eq!(
+                SettlementAmounts {
+                    market_payout: Uint128::new(16_100_000),
+                    parlay_payout: Uint128::zero(),
+                    outstanding_balance: Uint128::new(103_900_000),
+                    profit: Uint128::new(3_900_000),
+                    loss: Uint128::zero(),
+                },
+                amounts
+            );
+        }
+
+        #[test]
+        fn it_fails_when_the_payout_exceeds_the_market_balance() {
+            let home = SideBook {
+                total_bets: 10_000_000,
+                potential_payout: 28_200_000,
+                parlay_liability: 0,
+            };
+            let away = SideBook {
+                total_bets: 0,
+                potential_payout: 0,
+                parlay_liability: 0,
+            };
+
+            let err = calculate_settlement_amounts(
+                Uint128::new(10_000_000),
+                Uint128::new(20_000_000),
+                &home,
+                &away,
+                &Settlement {
+                    home: SettlementOutcome::WIN,
+                    away: SettlementOutcome::LOSE,
+                },
+            )
+            .unwrap_err();
+            assert_eq!(
+                ContractError::Overflow(OverflowError::new(OverflowOperation::Sub)),
+                err
+            );
+        }
+    }
+
+    mod exp_and_ln {
+        use super::*;
+
+        fn assert_close(expected: &str, actual: Decimal256) {
+            // Within a relative error of 1e-15
+            let expected: Decimal256 = expected.parse().unwrap();
+            assert!(
+                expected.abs_diff(actual)
+                    < expected * Decimal256::from_atomics(1_u128, 15).unwrap(),
+                "expected {expected}, got {actual}"
+            );
+        }
+
+        #[test]
+        fn it_calculates_exp() {
+            assert_eq!(Decimal256::one(), exp(Decimal256::zero()));
+            assert_close("2.718281828459045235", exp(Decimal256::one()));
+            assert_close(
+                "148.413159102576603421",
+                exp(Decimal256::from_atomics(5_u128, 0).unwrap()),
+            );
+            // Capped at an exponent of 40
+            assert_eq!(
+                exp(Decimal256::from_atomics(40_u128, 0).unwrap()),
+                exp(Decimal256::from_atomics(100_u128, 0).unwrap())
+            );
+        }
+
+        #[test]
+        fn it_calculates_ln() {
+            assert_eq!(Decimal256::zero(), ln(Decimal256::one()));
+            assert_close(
+                "0.405465108108164381",
+                ln(Decimal256::from_atomics(15_u128, 1).unwrap()),
+            );
+            assert_close(
+                "2.302585092994045684",
+                ln(Decimal256::from_atomics(10_u128, 0).unwrap()),
+            );
+        }
+    }
+
+    mod pricing_models {
+        use super::*;
+
+        fn config(pricing_model: PricingModelKind) -> Config {
+            Config {
+                admin_addr: Addr::unchecked("admin"),
+                treasury_addr: Addr::unchecked("treasury"),
+                denom: "denom".to_string(),
+                denom_precision: 6,
+                fee_spread_odds: Decimal::from_atomics(1_u128, 1).unwrap(), // 0.1
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity: Uint128::new(1_000_000_000),                // 1000
+                seed_liquidity_amplifier: Decimal::one(),
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: 7 * 24 * 60 * 60,
+                postponement_period: 3 * 24 * 60 * 60,
+                pricing_model,
+                trader_pubkey: None,
+            }
+        }
+
+        fn side_book(total_bets: u128, potential_payout: u128) -> SideBook {
+            SideBook {
+                total_bets,
+                potential_payout,
+                parlay_liability: 0,
+            }
+        }
+
+        fn odds(value: &str) -> Decimal {
+            value.parse().unwrap()
+        }
+
+        #[test]
+        fn the_seed_blend_opens_at_the_initial_odds_with_the_fee_spread() {
+            let config = config(PricingModelKind::SEED_BLEND);
+            let market_balance = Uint128::new(1_000_000_000);
+
+            let (home_odds, away_odds) = config
+                .pricing_model
+                .odds(&config, market_balance, &side_book(0, 0), &side_book(0, 0))
+                .unwrap();
+            assert_eq!(odds("2"), home_odds);
+            assert_eq!(odds("1.63"), away_odds);
+
+            // The bet amount is priced along the curve, below the current odds
+            let payout = config
+                .pricing_model
+                .quote(
+                    &config,
+                    market_balance,
+                    &side_book(0, 0),
+                    &side_book(0, 0),
+                    true,
+                    Uint128::new(10_000_000),
+                )
+                .unwrap();
+            assert_eq!(Uint128::new(19_850_000), payout);
+        }
+
+        #[test]
+        fn the_seed_blend_prices_a_bet_smaller_than_the_increments_at_the_current_odds() {
+            let config = config(PricingModelKind::SEED_BLEND);
+
+            // The last increment takes the whole bet amount
+            let payout = config
+                .pricing_model
+                .quote(
+                    &config,
+                    Uint128::new(1_000_000_000),
+                    &side_book(0, 0),
+                    &side_book(0, 0),
+                    true,
+                    Uint128::new(7),
+                )
+                .unwrap();
+            assert_eq!(Uint128::new(14), payout);
+        }
+
+        #[test]
+        fn the_lmsr_opens_at_the_normalized_initial_odds_and_moves_with_the_shares() {
+            let config = config(PricingModelKind::LMSR {
+                liquidity: Decimal::from_atomics(100_u128, 0).unwrap(),
+            });
+            let market_balance = Uint128::new(1_000_000_000);
+
+            let (home_odds, away_odds) = config
+                .pricing_model
+                .odds(&config, market_balance, &side_book(0, 0), &side_book(0, 0))
+                .unwrap();
+            assert_eq!(odds("2.02"), home_odds);
+            assert_eq!(odds("1.65"), away_odds);
+
+            let (home_odds, away_odds) = config
+                .pricing_model
+                .odds(
+                    &config,
+                    market_balance,
+                    &side_book(50_000_000, 100_000_000),
+                    &side_book(0, 0),
+                )
+                .unwrap();
+            assert_eq!(odds("1.31"), home_odds);
+            assert_eq!(odds("2.93"), away_odds);
+        }
+
+        #[test]
+        fn the_lmsr_quotes_the_bet_amount_along_the_curve() {
+            let config = config(PricingModelKind::LMSR {
+                liquidity: Decimal::from_atomics(100_u128, 0).unwrap(),
+            });
+            let market_balance = Uint128::new(1_000_000_000);
+
+            let small_payout = config
+                .pricing_model
+                .quote(
+                    &config,
+                    market_balance,
+                    &side_book(0, 0),
+                    &side_book(0, 0),
+                    true,
+                    Uint128::new(10_000_000),
+                )
+                .unwrap();
+            assert_eq!(Uint128::new(19_093_494), small_payout);
+
+            // The average odds of a bigger bet are lower due to its own price impact
+            let large_payout = config
+                .pricing_model
+                .quote(
+                    &config,
+                    market_balance,
+                    &side_book(0, 0),
+                    &side_book(0, 0),
+                    true,
+                    Uint128::new(100_000_000),
+                )
+                .unwrap();
+            assert_eq!(Uint128::new(142_949_342), large_payout);
+        }
+
+        #[test]
+        fn the_constant_product_opens_at_the_normalized_initial_odds_and_quotes_along_the_curve() {
+            let config = config(PricingModelKind::CONSTANT_PRODUCT);
+            let market_balance = Uint128::new(1_000_000_000);
+
+            let (home_odds, away_odds) = config
+                .pricing_model
+                .odds(&config, market_balance, &side_book(0, 0), &side_book(0, 0))
+                .unwrap();
+            assert_eq!(odds("2.02"), home_odds);
+            assert_eq!(odds("1.65"), away_odds);
+
+            let payout = config
+                .pricing_model
+                .quote(
+                    &config,
+                    market_balance,
+                    &side_book(0, 0),
+                    &side_book(0, 0),
+                    true,
+                    Uint128::new(100_000_000),
+                )
+                .unwrap();
+            assert_eq!(Uint128::new(181_818_181), payout);
+
+            // The shares bought without the fee spread are taken out of the home reserve
+            let (home_odds, away_odds) = config
+                .pricing_model
+                .odds(
+                    &config,
+                    market_balance,
+                    &side_book(100_000_000, 200_000_000),
+                    &side_book(0, 0),
+                )
+                .unwrap();
+            assert_eq!(odds("1.65"), home_odds);
+            assert_eq!(odds("2.02"), away_odds);
+        }
+
+        #[test]
+        fn the_max_bet_is_shared_by_all_the_pricing_models() {
+            for pricing_model in [
+                PricingModelKind::SEED_BLEND,
+                PricingModelKind::LMSR {
+                    liquidity: Decimal::from_atomics(100_u128, 0).unwrap(),
+                },
+                PricingModelKind::CONSTANT_PRODUCT,
+            ] {
+                let config = config(pricing_model);
+                let max_bet = config
+                    .pricing_model
+                    .max_bet(
+                        &config,
+                        Uint128::new(1_000_000_000),
+                        Uint128::zero(),
+                        odds("2"),
+                    )
+                    .unwrap();
+                assert_eq!(Uint128::new(333_333_333), max_bet);
+            }
+        }
+
+        #[test]
+        fn the_seed_blend_fails_when_the_market_balance_is_below_the_bets() {
+            let config = config(PricingModelKind::SEED_BLEND);
+
+            let err = config
+                .pricing_model
+                .odds(
+                    &config,
+                    Uint128::new(50_000_000),
+                    &side_book(100_000_000, 200_000_000),
+                    &side_book(0, 0),
+                )
+                .unwrap_err();
+            assert_eq!(
+                ContractError::Overflow(OverflowError::new(OverflowOperation::Sub)),
+                err
+            );
+        }
+
+        #[test]
+        fn the_max_bet_fails_when_the_payouts_exceed_the_market_balance() {
+            let config = config(PricingModelKind::SEED_BLEND);
+
+            let err = config
+                .pricing_model
+                .max_bet(
+                    &config,
+                    Uint128::new(100_000_000),
+                    Uint128::new(200_000_000),
+                    odds("2"),
+                )
+                .unwrap_err();
+            assert_eq!(
+                ContractError::Overflow(OverflowError::new(OverflowOperation::Sub)),
+                err
+            );
+        }
+
+        #[test]
+        fn the_pricing_models_fail_with_a_denom_precision_above_18_decimals() {
+            for pricing_model in [
+                PricingModelKind::SEED_BLEND,
+                PricingModelKind::LMSR {
+                    liquidity: Decimal::from_atomics(100_u128, 0).unwrap(),
+                },
+                PricingModelKind::CONSTANT_PRODUCT,
+            ] {
+                let mut config = config(pricing_model);
+                config.denom_precision = 19;
+
+                let err = config
+                    .pricing_model
+                    .odds(
+                        &config,
+                        Uint128::new(1_000_000_000),
+                        &side_book(0, 0),
+                        &side_book(0, 0),
+                    )
+                    .unwrap_err();
+                assert_eq!(ContractError::InvalidPrecision(19), err);
+            }
+        }
+
+        #[test]
+        fn the_lmsr_caps_the_odds_of_a_side_with_an_underflowed_probability() {
+            let mut config = config(PricingModelKind::LMSR {
+                liquidity: Decimal::from_atomics(100_u128, 0).unwrap(),
+            });
+            config.initial_odds_home = odds("6");
+            config.initial_odds_away = odds("1.2");
+            let market_balance = Uint128::new(5_000_000_000);
+
+            let (home_odds, away_odds) = config
+                .pricing_model
+                .odds(
+                    &config,
+                    market_balance,
+                    &side_book(0, 0),
+                    &side_book(4_000_000_000, 4_500_000_000),
+                )
+                .unwrap();
+            assert_eq!(odds("1000"), home_odds);
+            assert_eq!(odds("0.9"), away_odds);
+
+            // The home side is still quoted, at the capped odds
+            let payout = config
+                .pricing_model
+                .quote(
+                    &config,
+                    market_balance,
+                    &side_book(0, 0),
+                    &side_book(4_000_000_000, 4_500_000_000),
+                    true,
+                    Uint128::new(1_000_000),
+                )
+                .unwrap();
+            assert_eq!(Uint128::new(1_000_000_000), payout);
+        }
+
+        #[test]
+        fn the_lmsr_fails_to_quote_a_side_with_an_underflowed_ratio() {
+            let mut config = config(PricingModelKind::LMSR {
+                liquidity: Decimal::from_atomics(100_u128, 0).unwrap(),
+            });
+            config.initial_odds_home = odds("1.2");
+            config.initial_odds_away = odds("6");
+            let market_balance = Uint128::new(5_000_000_000);
+
+            let (home_odds, away_odds) = config
+                .pricing_model
+                .odds(
+                    &config,
+                    market_balance,
+                    &side_book(4_000_000_000, 4_500_000_000),
+                    &side_book(0, 0),
+                )
+                .unwrap();
+            assert_eq!(odds("0.9"), home_odds);
+            assert_eq!(odds("1000"), away_odds);
+
+            let err = config
+                .pricing_model
+                .quote(
+                    &config,
+                    market_balance,
+                    &side_book(4_000_000_000, 4_500_000_000),
+                    &side_book(0, 0),
+                    false,
+                    Uint128::new(1_000_000),
+                )
+                .unwrap_err();
+            assert_eq!(ContractError::MarketNotPriceable {}, err);
+        }
+
+        #[test]
+        fn the_constant_product_caps_the_odds_of_a_side_with_a_drained_reserve() {
+            let config = config(PricingModelKind::CONSTANT_PRODUCT);
+            let market_balance = Uint128::new(1_000_000_000);
+
+            // The home payouts drain the home reserve
+            let (home_odds, away_odds) = config
+                .pricing_model
+                .odds(
+                    &config,
+                    market_balance,
+                    &side_book(0, 2_000_000_000),
+                    &side_book(0, 0),
+                )
+                .unwrap();
+            assert_eq!(odds("0.9"), home_odds);
+            assert_eq!(odds("1000"), away_odds);
+
+            let payout = config
+                .pricing_model
+                .quote(
+                    &config,
+                    market_balance,
+                    &side_book(0, 2_000_000_000),
+                    &side_book(0, 0),
+                    false,
+                    Uint128::new(1_000_000),
+                )
+                .unwrap();
+            assert_eq!(Uint128::new(410_000_000), payout);
+        }
+
+        #[test]
+        fn the_constant_product_fails_when_both_reserves_are_drained() {
+            let config = config(PricingModelKind::CONSTANT_PRODUCT);
+
+            let err = config
+                .pricing_model
+                .odds(
+                    &config,
+                    Uint128::new(1_000_000_000),
+                    &side_book(0, 2_000_000_000),
+                    &side_book(0, 2_000_000_000),
+                )
+                .unwrap_err();
+            assert_eq!(ContractError::MarketNotPriceable {}, err);
         }
     }
 }
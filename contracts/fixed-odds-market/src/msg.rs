@@ -1,7 +1,11 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Decimal};
+use cosmwasm_std::{to_json_binary, Addr, Binary, CosmosMsg, Decimal, StdResult, Uint128, WasmMsg};
+use cw_utils::Expiration;
 
-use crate::state::{BetAmount, Config, Market, MarketResult};
+use crate::state::{
+    Approval, BetAmount, Config, Ledger, LimitBet, LiveConfig, Market, MarketKind, MarketMetadata,
+    MarketResult, OddsFormat, PendingBet, PricingModelKind, Settlement, VoidReason,
+};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -11,6 +15,7 @@ pub struct InstantiateMsg {
     pub denom_precision: u32,
     pub id: String,
     pub label: String,
+    pub kind: Option<MarketKind>, // Defaults to MONEYLINE
     pub home_team: String,
     pub away_team: String,
     pub fee_spread_odds: Decimal,     // Fee spread in percentage points
@@ -19,18 +24,92 @@ pub struct InstantiateMsg {
     pub initial_odds_home: Decimal,
     pub initial_odds_away: Decimal,
     pub start_timestamp: u64,
+    pub parlay_addr: Option<Addr>,
+    pub live: Option<LiveConfig>, // Enables in play betting when defined
+    pub resolution_grace_period: Option<u64>, // Defaults to 7 days
+    pub postponement_period: Option<u64>, // Defaults to 3 days
+    pub pricing_model: Option<PricingModelKind>, // Defaults to SEED_BLEND
+    pub trader_pubkey: Option<Binary>, // Enables the quoted bets when defined
+    pub metadata: Option<MarketMetadata>,
 }
 
 #[cw_serde]
+#[allow(clippy::large_enum_variant)]
 pub enum ExecuteMsg {
     PlaceBet {
         result: MarketResult,
         min_odds: Decimal,
         receiver: Option<Addr>,
     },
+    PlaceQuotedBet {
+        quote: BetQuote,
+        signature: Binary,
+    },
+    PlaceLimitBet {
+        result: MarketResult,
+        target_odds: Decimal,
+        expires_at: u64,
+    },
+    CancelLimitBet {
+        id: u64,
+    },
+    ExecuteLimitBets {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
     ClaimWinnings {
         receiver: Option<Addr>,
     },
+    ConfirmBet {
+        id: u64,
+    },
+    ExpireMarket {},
+    WithdrawStake {
+        token_id: Option<String>, // Withdraws the stake of a bet slip instead of the position of the sender
+    },
+    TransferPosition {
+        result: MarketResult,
+        amount: u128,
+        recipient: Addr,
+    },
+    // Bet slips, following the cw721 interface
+    MintBetSlip {
+        result: MarketResult,
+        amount: u128,
+    },
+    ClaimByToken {
+        token_id: String,
+    },
+    TransferNft {
+        recipient: String,
+        token_id: String,
+    },
+    SendNft {
+        contract: String,
+        token_id: String,
+        msg: Binary,
+    },
+    Approve {
+        spender: String,
+        token_id: String,
+        expires: Option<Expiration>,
+    },
+    Revoke {
+        spender: String,
+        token_id: String,
+    },
+    // Parlay
+    ReserveParlayLiability {
+        result: MarketResult,
+        amount: u128,
+    },
+    // Trader
+    Suspend {},
+    Resume {},
+    UpdateOdds {
+        initial_odds_home: Decimal,
+        initial_odds_away: Decimal,
+    },
     // Admin
     Update {
         admin_addr: Option<Addr>,
@@ -41,11 +120,44 @@ pub enum ExecuteMsg {
         initial_odds_home: Option<Decimal>,
         initial_odds_away: Option<Decimal>,
         start_timestamp: Option<u64>,
+        parlay_addr: Option<Addr>,
+        label: Option<String>,
+        home_team: Option<String>,
+        away_team: Option<String>,
+        metadata: Option<MarketMetadata>, // Replaces the whole metadata
     },
     Score {
         result: MarketResult,
     },
+    ScoreTotal {
+        total: u64,
+    },
+    ScoreFinal {
+        home_score: u64,
+        away_score: u64,
+    },
     Cancel {},
+    Void {
+        reason: VoidReason,
+    },
+    Postpone {},
+    Reschedule {
+        start_timestamp: u64,
+    },
+    ReconcileSurplus {},
+}
+
+/// Odds quoted off-chain by the trader to a bettor, signed with the trader public key
+#[cw_serde]
+pub struct BetQuote {
+    pub market_id: String,
+    pub contract_addr: Addr, // Market contract the quote is issued for, so it can't be replayed on another
+    pub result: MarketResult,
+    pub odds: Decimal,
+    pub max_stake: BetAmount,
+    pub bettor: Addr,
+    pub expiry: u64, // Timestamp in seconds after which the quote can't be used
+    pub nonce: u64,  // Each quote can only be used once
 }
 
 #[cw_serde]
@@ -58,6 +170,11 @@ pub struct UpdateParams {
     pub initial_odds_home: Option<Decimal>,
     pub initial_odds_away: Option<Decimal>,
     pub start_timestamp: Option<u64>,
+    pub parlay_addr: Option<Addr>,
+    pub label: Option<String>,
+    pub home_team: Option<String>,
+    pub away_team: Option<String>,
+    pub metadata: Option<MarketMetadata>, // Replaces the whole metadata
 }
 
 #[cw_serde]
@@ -67,12 +184,52 @@ pub enum QueryMsg {
     Config {},
     #[returns(MarketResponse)]
     Market {},
+    #[returns(LedgerResponse)]
+    Ledger {},
     #[returns(MaxBetsResponse)]
     MaxBets {},
     #[returns(BetsResponse)]
     Bets {},
     #[returns(BetsByAddressResponse)]
     BetsByAddress { address: Addr },
+    #[returns(PendingBetResponse)]
+    PendingBet { id: u64 },
+    #[returns(LimitBetResponse)]
+    LimitBet { id: u64 },
+    #[returns(LimitBetsResponse)]
+    LimitBets {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(OddsResponse)]
+    Odds { format: OddsFormat },
+    #[returns(ExposureResponse)]
+    Exposure {},
+    #[returns(SolvencyResponse)]
+    Solvency {},
+    #[returns(QuoteResponse)]
+    Quote {
+        result: MarketResult,
+        bet_amount: BetAmount,
+    },
+    // Bet slips, following the cw721 interface
+    #[returns(OwnerOfResponse)]
+    OwnerOf {
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+    #[returns(NftInfoResponse)]
+    NftInfo { token_id: String },
+    #[returns(NumTokensResponse)]
+    NumTokens {},
+    #[returns(TokensResponse)]
+    Tokens {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(ContractInfoResponse)]
+    ContractInfo {},
 }
 
 #[cw_serde]
@@ -85,6 +242,12 @@ pub struct MarketResponse {
     pub market: Market,
 }
 
+#[cw_serde]
+pub struct LedgerResponse {
+    pub ledger: Ledger,
+    pub market_balance: Uint128,
+}
+
 #[cw_serde]
 pub struct MaxBetsResponse {
     pub home: BetAmount,
@@ -106,7 +269,8 @@ pub struct PotentialPayouts {
 #[cw_serde]
 pub struct BetsResponse {
     pub total_amounts: TotalAmounts,
-    pub potential_payouts: PotentialPayouts,
+    pub potential_payouts: PotentialPayouts, // Includes the parlay liabilities
+    pub parlay_liabilities: PotentialPayouts,
 }
 
 #[cw_serde]
@@ -128,5 +292,138 @@ pub struct BetsByAddressResponse {
     pub all_bets: AllBets,
 }
 
+#[cw_serde]
+pub struct PendingBetResponse {
+    pub pending_bet: PendingBet,
+}
+
+#[cw_serde]
+pub struct LimitBetResponse {
+    pub limit_bet: LimitBet,
+}
+
+#[cw_serde]
+pub struct LimitBetsResponse {
+    pub limit_bets: Vec<LimitBet>,
+}
+
+#[cw_serde]
+pub struct OutcomeOdds {
+    pub result: MarketResult,
+    pub odds: Option<String>, // Odds in the requested format, if they can be represented in it
+    pub implied_probability: Decimal,
+}
+
+#[cw_serde]
+pub struct OddsResponse {
+    pub format: OddsFormat,
+    pub outcomes: Vec<OutcomeOdds>,
+    pub overround: Decimal, // Bookmaker margin, ex: 0.15 for 15%
+}
+
+#[cw_serde]
+pub struct ScenarioExposure {
+    pub result: MarketResult,
+    pub settlement: Settlement,
+    pub stakes: u128,        // Stakes collected on both sides
+    pub payout: u128,        // Paid out to the bettors and the parlay contract
+    pub parlay_payout: u128, // Part of the payout paid to the parlay contract
+    pub profit: u128,        // Profit against the seed liquidity
+    pub loss: u128,          // Loss against the seed liquidity
+}
+
+#[cw_serde]
+pub struct ExposureResponse {
+    pub scenarios: Vec<ScenarioExposure>,
+    pub worst_case: Option<ScenarioExposure>, // Scenario with the highest loss or lowest profit
+}
+
+#[cw_serde]
+pub struct OutcomeLiability {
+    pub result: MarketResult,
+    pub settlement: Settlement,
+    pub liability: u128, // Paid out to the bettors and the parlay contract
+}
+
+#[cw_serde]
+pub struct SolvencyResponse {
+    pub balance: u128, // Balance of the contract in the market denom
+    pub liabilities: Vec<OutcomeLiability>, // Only while the market is active or postponed
+    pub max_liability: u128,
+    pub unclaimed: u128, // Owed to the positions not claimed yet once the market is no longer active
+    pub escrowed: u128,  // Stakes of the pending bets and the limit bets
+    pub surplus: u128,   // Balance left once everything owed is covered
+    pub solvent: bool,
+}
+
+#[cw_serde]
+pub struct QuoteResponse {
+    pub result: MarketResult,
+    pub bet_amount: BetAmount,
+    pub payout: u128,
+    pub odds: Decimal, // Average odds of the bet amount along the pricing model curve
+}
+
 #[cw_serde]
 pub struct MigrateMsg {}
+
+#[cw_serde]
+pub struct OwnerOfResponse {
+    pub owner: String,
+    pub approvals: Vec<Approval>,
+}
+
+#[cw_serde]
+pub struct BetSlipMetadata {
+    pub market_id: String,
+    pub result: MarketResult,
+    pub stake: u128,
+    pub odds: Decimal, // Locked odds of the stake
+    pub potential_payout: u128,
+}
+
+#[cw_serde]
+pub struct NftInfoResponse {
+    pub token_uri: Option<String>,
+    pub extension: BetSlipMetadata,
+}
+
+#[cw_serde]
+pub struct NumTokensResponse {
+    pub count: u64,
+}
+
+#[cw_serde]
+pub struct TokensResponse {
+    pub tokens: Vec<String>,
+}
+
+#[cw_serde]
+pub struct ContractInfoResponse {
+    pub name: String,
+    pub symbol: String,
+}
+
+/// Sent to the contract receiving a bet slip with `SendNft`, as defined by the cw721 spec
+#[cw_serde]
+pub struct Cw721ReceiveMsg {
+    pub sender: String,
+    pub token_id: String,
+    pub msg: Binary,
+}
+
+impl Cw721ReceiveMsg {
+    pub fn into_cosmos_msg(self, contract_addr: String) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&ReceiverExecuteMsg::ReceiveNft(self))?,
+            funds: vec![],
+        }
+        .into())
+    }
+}
+
+#[cw_serde]
+enum ReceiverExecuteMsg {
+    ReceiveNft(Cw721ReceiveMsg),
+}
@@ -1,17 +1,33 @@
-use cosmwasm_std::{Addr, Decimal, Deps, Env, StdResult, Uint128};
+use cosmwasm_std::{Addr, Decimal, Deps, Env, Order, StdResult, Uint128};
+use cw_storage_plus::Bound;
 
 use crate::{
-    logic::calculate_max_bet,
+    logic::{
+        calculate_overround, calculate_possible_settlements, calculate_settlement_amounts,
+        format_odds, PricingModel,
+    },
     msg::{
-        AllBets, BetRecordWithOdds, BetsByAddressResponse, BetsResponse, ConfigResponse,
-        MarketResponse, MaxBetsResponse, PotentialPayouts, TotalAmounts,
+        AllBets, BetRecordWithOdds, BetSlipMetadata, BetsByAddressResponse, BetsResponse,
+        ConfigResponse, ContractInfoResponse, ExposureResponse, LedgerResponse, LimitBetResponse,
+        LimitBetsResponse, MarketResponse, MaxBetsResponse, NftInfoResponse, NumTokensResponse,
+        OddsResponse, OutcomeLiability, OutcomeOdds, OwnerOfResponse, PendingBetResponse,
+        PotentialPayouts, QuoteResponse, ScenarioExposure, SolvencyResponse, TokensResponse,
+        TotalAmounts,
     },
     state::{
-        Status, ADDR_BETS_AWAY, ADDR_BETS_HOME, CONFIG, MARKET, POTENTIAL_PAYOUT_AWAY,
-        POTENTIAL_PAYOUT_HOME, TOTAL_BETS_AWAY, TOTAL_BETS_HOME,
+        load_side_book, MarketKind, MarketResult, OddsFormat, Status, ADDR_BETS_AWAY,
+        ADDR_BETS_HOME, BET_SLIPS, CONFIG, LEDGER, LIMIT_BETS, MARKET, PARLAY_LIABILITY_AWAY,
+        PARLAY_LIABILITY_HOME, PENDING_BETS, POTENTIAL_PAYOUT_AWAY, POTENTIAL_PAYOUT_HOME,
+        TOTAL_BETS_AWAY, TOTAL_BETS_HOME, TOTAL_LIMIT_BETS, TOTAL_PENDING_BETS,
     },
 };
 
+pub const BET_SLIP_SYMBOL: &str = "SLIP";
+const DEFAULT_TOKENS_LIMIT: u32 = 10;
+const MAX_TOKENS_LIMIT: u32 = 100;
+pub const DEFAULT_LIMIT_BETS_LIMIT: u32 = 10;
+pub const MAX_LIMIT_BETS_LIMIT: u32 = 30;
+
 /// Returns the current config of the market
 pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
     let config = CONFIG.load(deps.storage)?;
@@ -26,34 +42,31 @@ pub fn query_market(deps: Deps) -> StdResult<MarketResponse> {
 }
 
 /// Returns the market max bet for each result
-pub fn query_max_bets(deps: Deps, env: Env) -> StdResult<MaxBetsResponse> {
+pub fn query_max_bets(deps: Deps) -> StdResult<MaxBetsResponse> {
     let config = CONFIG.load(deps.storage)?;
     let market = MARKET.load(deps.storage)?;
 
-    if market.status != Status::ACTIVE {
+    if market.status != Status::ACTIVE || market.suspended {
         return Ok(MaxBetsResponse { home: 0, away: 0 });
     }
 
-    let market_balance = deps
-        .querier
-        .query_balance(env.contract.address, &config.denom)?
-        .amount;
+    let market_balance = query_market_balance(deps)?;
 
     let potential_payout_home = POTENTIAL_PAYOUT_HOME.load(deps.storage)?;
     let potential_payout_away = POTENTIAL_PAYOUT_AWAY.load(deps.storage)?;
 
-    let home_max_bet = calculate_max_bet(
+    let home_max_bet = config.pricing_model.max_bet(
         &config,
         market_balance,
         Uint128::from(potential_payout_home),
         market.home_odds,
-    );
-    let away_max_bet = calculate_max_bet(
+    )?;
+    let away_max_bet = config.pricing_model.max_bet(
         &config,
         market_balance,
         Uint128::from(potential_payout_away),
         market.away_odds,
-    );
+    )?;
 
     Ok(MaxBetsResponse {
         home: home_max_bet.into(),
@@ -61,7 +74,7 @@ pub fn query_max_bets(deps: Deps, env: Env) -> StdResult<MaxBetsResponse> {
     })
 }
 
-/// Returns the total bets and potential payouts of the market
+/// Returns the total bets, potential payouts and parlay liabilities of the market
 pub fn query_bets(deps: Deps) -> StdResult<BetsResponse> {
     let total_amounts = TotalAmounts {
         home: TOTAL_BETS_HOME.load(deps.storage)?,
@@ -73,22 +86,280 @@ pub fn query_bets(deps: Deps) -> StdResult<BetsResponse> {
         away: POTENTIAL_PAYOUT_AWAY.load(deps.storage)?,
     };
 
+    let parlay_liabilities = PotentialPayouts {
+        home: PARLAY_LIABILITY_HOME.load(deps.storage)?,
+        away: PARLAY_LIABILITY_AWAY.load(deps.storage)?,
+    };
+
     Ok(BetsResponse {
         total_amounts,
         potential_payouts,
+        parlay_liabilities,
     })
 }
 
-/// Retruns the average bets and potential payouts for a specific address
-pub fn query_bets_by_address(deps: Deps, address: Addr) -> StdResult<BetsByAddressResponse> {
+/// Returns a bet placed in play that is waiting to be confirmed
+pub fn query_pending_bet(deps: Deps, id: u64) -> StdResult<PendingBetResponse> {
+    let pending_bet = PENDING_BETS.load(deps.storage, id)?;
+
+    Ok(PendingBetResponse { pending_bet })
+}
+
+pub fn query_limit_bet(deps: Deps, id: u64) -> StdResult<LimitBetResponse> {
+    let limit_bet = LIMIT_BETS.load(deps.storage, id)?;
+
+    Ok(LimitBetResponse { limit_bet })
+}
+
+/// Returns the limit bets of the order book, oldest first
+pub fn query_limit_bets(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<LimitBetsResponse> {
+    let limit = limit
+        .unwrap_or(DEFAULT_LIMIT_BETS_LIMIT)
+        .min(MAX_LIMIT_BETS_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let limit_bets = LIMIT_BETS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, limit_bet)| limit_bet))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(LimitBetsResponse { limit_bets })
+}
+
+/// Returns the current odds of each outcome of the market in the given format,
+/// with their implied probability and the overround of the market
+///
+/// `implied_probability = 1 / odds`
+pub fn query_odds(deps: Deps, format: OddsFormat) -> StdResult<OddsResponse> {
+    let market = MARKET.load(deps.storage)?;
+
+    let (home_result, away_result) = match market.kind {
+        MarketKind::TOTALS { .. } => (MarketResult::OVER, MarketResult::UNDER),
+        _ => (MarketResult::HOME, MarketResult::AWAY),
+    };
+
+    let outcomes: Vec<OutcomeOdds> = [
+        (home_result, market.home_odds),
+        (away_result, market.away_odds),
+    ]
+    .into_iter()
+    .map(|(result, odds)| OutcomeOdds {
+        result,
+        odds: format_odds(odds, &format),
+        implied_probability: Decimal::one() / odds,
+    })
+    .collect();
+
+    let overround = calculate_overround(
+        &outcomes
+            .iter()
+            .map(|outcome| outcome.implied_probability)
+            .collect::<Vec<_>>(),
+    );
+
+    Ok(OddsResponse {
+        format,
+        outcomes,
+        overround,
+    })
+}
+
+/// Returns what each way of settling the market would cost or earn the house
+/// with the current bets, using the same arithmetic the market is scored with,
+/// see `calculate_settlement_amounts`.
+///
+/// There are no scenarios once the market is no longer active.
+pub fn query_exposure(deps: Deps) -> StdResult<ExposureResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
+
+    if !market.status.is_open() {
+        return Ok(ExposureResponse {
+            scenarios: vec![],
+            worst_case: None,
+        });
+    }
+
+    let market_balance = query_market_balance(deps)?;
+    let home = load_side_book(deps.storage, true)?;
+    let away = load_side_book(deps.storage, false)?;
+
+    let scenarios: Vec<ScenarioExposure> = calculate_possible_settlements(&market.kind)
+        .into_iter()
+        .map(|(result, settlement)| -> StdResult<_> {
+            let amounts = calculate_settlement_amounts(
+                config.seed_liquidity,
+                market_balance,
+                &home,
+                &away,
+                &settlement,
+            )?;
+            Ok(ScenarioExposure {
+                result,
+                settlement,
+                stakes: home.total_bets + away.total_bets,
+                payout: amounts.market_payout.u128(),
+                parlay_payout: amounts.parlay_payout.u128(),
+                profit: amounts.profit.u128(),
+                loss: amounts.loss.u128(),
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    let worst_case = scenarios
+        .iter()
+        .min_by_key(|scenario| scenario.profit as i128 - scenario.loss as i128)
+        .cloned();
+
+    Ok(ExposureResponse {
+        scenarios,
+        worst_case,
+    })
+}
+
+/// Returns whether the contract can pay everything it may owe with its balance
+///
+/// While the market is active it has to cover the payout of the worst way of settling
+/// it, see `calculate_settlement_amounts`, afterwards the winnings and refunds of the
+/// positions not claimed yet. The stakes of the pending and limit bets are always owed.
+///
+/// ```ignore
+/// surplus = balance - max_liability - unclaimed - escrowed
+/// ```
+pub fn query_solvency(deps: Deps, env: Env) -> StdResult<SolvencyResponse> {
     let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
+
+    let balance = deps
+        .querier
+        .query_balance(&env.contract.address, &config.denom)?
+        .amount;
 
+    let mut liabilities: Vec<OutcomeLiability> = vec![];
+    if market.status.is_open() {
+        let market_balance = query_market_balance(deps)?;
+        let home = load_side_book(deps.storage, true)?;
+        let away = load_side_book(deps.storage, false)?;
+
+        liabilities = calculate_possible_settlements(&market.kind)
+            .into_iter()
+            .map(|(result, settlement)| -> StdResult<_> {
+                let amounts = calculate_settlement_amounts(
+                    config.seed_liquidity,
+                    market_balance,
+                    &home,
+                    &away,
+                    &settlement,
+                )?;
+                Ok(OutcomeLiability {
+                    result,
+                    settlement,
+                    liability: amounts.market_payout.u128(),
+                })
+            })
+            .collect::<StdResult<_>>()?;
+    }
+
+    let max_liability = liabilities
+        .iter()
+        .map(|outcome| outcome.liability)
+        .max()
+        .unwrap_or_default();
+    let unclaimed = query_unclaimed(deps)?;
+    let escrowed = Uint128::from(TOTAL_PENDING_BETS.load(deps.storage)?)
+        .checked_add(TOTAL_LIMIT_BETS.load(deps.storage)?.into())?;
+
+    let owed = Uint128::from(max_liability)
+        .checked_add(unclaimed)?
+        .checked_add(escrowed)?;
+
+    Ok(SolvencyResponse {
+        balance: balance.u128(),
+        liabilities,
+        max_liability,
+        unclaimed: unclaimed.u128(),
+        escrowed: escrowed.u128(),
+        surplus: balance.saturating_sub(owed).u128(),
+        solvent: balance >= owed,
+    })
+}
+
+/// Returns the winnings and refunds owed to the positions that weren't claimed yet,
+/// kept as a running total in the ledger as the bets are placed, settled and claimed
+///
+/// Nothing can be claimed while the market is active or postponed.
+pub fn query_unclaimed(deps: Deps) -> StdResult<Uint128> {
+    let market = MARKET.load(deps.storage)?;
+
+    if market.status.is_open() {
+        return Ok(Uint128::zero());
+    }
+
+    Ok(LEDGER.load(deps.storage)?.unclaimed)
+}
+
+/// Returns the payout and average odds of a bet amount on a result,
+/// priced along the curve of the pricing model of the market
+pub fn query_quote(deps: Deps, result: MarketResult, bet_amount: u128) -> StdResult<QuoteResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
+
+    if market.status != Status::ACTIVE || market.suspended || bet_amount == 0 {
+        return Ok(QuoteResponse {
+            result,
+            bet_amount,
+            payout: 0,
+            odds: Decimal::zero(),
+        });
+    }
+
+    let market_balance = query_market_balance(deps)?;
+    let payout = config.pricing_model.quote(
+        &config,
+        market_balance,
+        &load_side_book(deps.storage, true)?,
+        &load_side_book(deps.storage, false)?,
+        result.is_home_side(),
+        Uint128::from(bet_amount),
+    )?;
+
+    Ok(QuoteResponse {
+        result,
+        bet_amount,
+        payout: payout.u128(),
+        odds: Decimal::from_ratio(payout, bet_amount),
+    })
+}
+
+/// Returns the balance of the market tracked by its ledger, the funds sent directly to
+/// the contract and the stakes of the pending bets and the limit bets are not part of it
+pub fn query_market_balance(deps: Deps) -> StdResult<Uint128> {
+    Ok(LEDGER.load(deps.storage)?.balance()?)
+}
+
+/// Returns the ledger of the market and the balance it tracks
+pub fn query_ledger(deps: Deps) -> StdResult<LedgerResponse> {
+    let ledger = LEDGER.load(deps.storage)?;
+    let market_balance = ledger.balance()?;
+
+    Ok(LedgerResponse {
+        ledger,
+        market_balance,
+    })
+}
+
+/// Retruns the average bets and potential payouts for a specific address
+pub fn query_bets_by_address(deps: Deps, address: Addr) -> StdResult<BetsByAddressResponse> {
     let (home_bet_amount, home_payout) = ADDR_BETS_HOME
         .may_load(deps.storage, address.clone())?
         .unwrap_or((0, 0));
     let home_odds = if home_bet_amount.gt(&0_u128) {
-        Decimal::from_atomics(home_payout, config.denom_precision).unwrap()
-            / Decimal::from_atomics(home_bet_amount, config.denom_precision).unwrap()
+        Decimal::from_ratio(home_payout, home_bet_amount)
     } else {
         Decimal::zero()
     };
@@ -96,8 +367,7 @@ pub fn query_bets_by_address(deps: Deps, address: Addr) -> StdResult<BetsByAddre
         .may_load(deps.storage, address.clone())?
         .unwrap_or((0, 0));
     let away_odds = if away_bet_amount.gt(&0_u128) {
-        Decimal::from_atomics(away_payout, config.denom_precision).unwrap()
-            / Decimal::from_atomics(away_bet_amount, config.denom_precision).unwrap()
+        Decimal::from_ratio(away_payout, away_bet_amount)
     } else {
         Decimal::zero()
     };
@@ -117,3 +387,88 @@ pub fn query_bets_by_address(deps: Deps, address: Addr) -> StdResult<BetsByAddre
 
     Ok(BetsByAddressResponse { address, all_bets })
 }
+
+/// Returns the owner of a bet slip and its approvals,
+/// the expired approvals are only included if requested
+pub fn query_owner_of(
+    deps: Deps,
+    env: Env,
+    token_id: String,
+    include_expired: Option<bool>,
+) -> StdResult<OwnerOfResponse> {
+    let bet_slip = BET_SLIPS.load(deps.storage, token_id)?;
+
+    let include_expired = include_expired.unwrap_or(false);
+    let approvals = bet_slip
+        .approvals
+        .into_iter()
+        .filter(|approval| include_expired || !approval.expires.is_expired(&env.block))
+        .collect();
+
+    Ok(OwnerOfResponse {
+        owner: bet_slip.owner.to_string(),
+        approvals,
+    })
+}
+
+/// Returns the metadata of a bet slip, with its locked odds and potential payout
+///
+/// `odds = payout / bet_amount`
+pub fn query_nft_info(deps: Deps, token_id: String) -> StdResult<NftInfoResponse> {
+    let market = MARKET.load(deps.storage)?;
+    let bet_slip = BET_SLIPS.load(deps.storage, token_id)?;
+
+    Ok(NftInfoResponse {
+        token_uri: None,
+        extension: BetSlipMetadata {
+            market_id: market.id,
+            result: bet_slip.result,
+            stake: bet_slip.bet_amount,
+            odds: Decimal::from_ratio(bet_slip.payout, bet_slip.bet_amount),
+            potential_payout: bet_slip.payout,
+        },
+    })
+}
+
+/// Returns the number of bet slips that haven't been claimed yet
+pub fn query_num_tokens(deps: Deps) -> StdResult<NumTokensResponse> {
+    let count = BET_SLIPS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .count() as u64;
+
+    Ok(NumTokensResponse { count })
+}
+
+/// Returns the ids of the bet slips owned by an address, paginated by token id
+pub fn query_tokens(
+    deps: Deps,
+    owner: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<TokensResponse> {
+    let owner = deps.api.addr_validate(&owner)?;
+    let limit = limit.unwrap_or(DEFAULT_TOKENS_LIMIT).min(MAX_TOKENS_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let tokens = BET_SLIPS
+        .range(deps.storage, start, None, Order::Ascending)
+        .filter(|item| match item {
+            Ok((_, bet_slip)) => bet_slip.owner == owner,
+            Err(_) => true,
+        })
+        .take(limit)
+        .map(|item| item.map(|(token_id, _)| token_id))
+        .collect::<StdResult<Vec<String>>>()?;
+
+    Ok(TokensResponse { tokens })
+}
+
+/// Returns the name and symbol of the bet slips collection, named after the market
+pub fn query_contract_info(deps: Deps) -> StdResult<ContractInfoResponse> {
+    let market = MARKET.load(deps.storage)?;
+
+    Ok(ContractInfoResponse {
+        name: market.label,
+        symbol: BET_SLIP_SYMBOL.to_string(),
+    })
+}
@@ -1,7 +1,10 @@
 use std::fmt;
 
-use cosmwasm_std::{Addr, Decimal, Uint128};
+use cosmwasm_std::{
+    Addr, Attribute, Binary, Decimal, OverflowError, SignedDecimal, StdResult, Storage, Uint128,
+};
 use cw_storage_plus::{Item, Map};
+use cw_utils::Expiration;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -16,6 +19,34 @@ pub const TOTAL_BETS_AWAY: Item<u128> = Item::new("total_bets_away");
 pub const ADDR_BETS_AWAY: Map<Addr, BetRecord> = Map::new("addr_bets_away");
 pub const POTENTIAL_PAYOUT_AWAY: Item<u128> = Item::new("potential_payout_away");
 
+// Half settled payouts of the positions on each side, rounded down on each position like their claims
+pub const HALF_PAYOUTS_HOME: Item<HalfPayouts> = Item::new("half_payouts_home");
+pub const HALF_PAYOUTS_AWAY: Item<HalfPayouts> = Item::new("half_payouts_away");
+
+// Part of the potential payouts reserved by the parlay contract, paid to it when the side wins
+pub const PARLAY_LIABILITY_HOME: Item<u128> = Item::new("parlay_liability_home");
+pub const PARLAY_LIABILITY_AWAY: Item<u128> = Item::new("parlay_liability_away");
+
+// Bets placed in play on a live market, their stakes are held until they are confirmed or refunded
+pub const PENDING_BET_COUNT: Item<u64> = Item::new("pending_bet_count");
+pub const PENDING_BETS: Map<u64, PendingBet> = Map::new("pending_bets");
+pub const TOTAL_PENDING_BETS: Item<u128> = Item::new("total_pending_bets");
+
+// Bet slips minted as cw721 tokens, the market contract is the collection and its minter
+pub const BET_SLIP_COUNT: Item<u64> = Item::new("bet_slip_count");
+pub const BET_SLIPS: Map<String, BetSlip> = Map::new("bet_slips");
+
+// Limit bets waiting for the odds to reach their target, their stakes are escrowed until they are filled or refunded
+pub const LIMIT_BET_COUNT: Item<u64> = Item::new("limit_bet_count");
+pub const LIMIT_BETS: Map<u64, LimitBet> = Map::new("limit_bets");
+pub const TOTAL_LIMIT_BETS: Item<u128> = Item::new("total_limit_bets");
+
+// Nonces of the quotes signed by the trader that were already used to place a bet
+pub const USED_QUOTE_NONCES: Map<u64, bool> = Map::new("used_quote_nonces");
+
+// Funds accounted by the market, the pricing and the settlement never read the bank balance
+pub const LEDGER: Item<Ledger> = Item::new("ledger");
+
 pub type BetAmount = u128;
 pub type BetPayout = u128;
 pub type BetRecord = (BetAmount, BetPayout);
@@ -32,6 +63,53 @@ pub struct Config {
     pub seed_liquidity_amplifier: Decimal, // Seed liquidity amplifier in multiplier, ex: 3x
     pub initial_odds_home: Decimal,
     pub initial_odds_away: Decimal,
+    pub parlay_addr: Option<Addr>, // Parlay contract allowed to reserve liability
+    pub live: Option<LiveConfig>,  // Enables in play betting when defined
+    pub resolution_grace_period: u64, // Seconds after the start timestamp to score the market
+    pub postponement_period: u64, // Seconds a postponed market has to be rescheduled before it is refunded
+    pub pricing_model: PricingModelKind, // Curve used to price the bets
+    pub trader_pubkey: Option<Binary>, // Secp256k1 public key signing the quoted bets, enables them when defined
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct LiveConfig {
+    pub trader_addr: Addr, // Address allowed to suspend the market and move the odds
+    pub bet_delay: u64,    // Seconds before an in play bet can be confirmed
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub enum MarketKind {
+    MONEYLINE,
+    TOTALS { line: Decimal },             // Over/under line, ex: 2.5
+    HANDICAP { handicap: SignedDecimal }, // Handicap applied to the home side, ex: -1.25
+}
+
+impl fmt::Display for MarketKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MarketKind::MONEYLINE => write!(f, "MONEYLINE"),
+            MarketKind::TOTALS { .. } => write!(f, "TOTALS"),
+            MarketKind::HANDICAP { .. } => write!(f, "HANDICAP"),
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub enum PricingModelKind {
+    SEED_BLEND, // Blend of the initial and bet derived probabilities weighted by the seed liquidity
+    LMSR { liquidity: Decimal }, // Logarithmic market scoring rule with the liquidity parameter b
+    CONSTANT_PRODUCT, // Constant product curve over the amplified seed liquidity
+}
+
+impl fmt::Display for PricingModelKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PricingModelKind::SEED_BLEND => write!(f, "SEED_BLEND"),
+            PricingModelKind::LMSR { .. } => write!(f, "LMSR"),
+            PricingModelKind::CONSTANT_PRODUCT => write!(f, "CONSTANT_PRODUCT"),
+        }
+    }
 }
 
 #[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
@@ -39,6 +117,8 @@ pub enum Status {
     ACTIVE,
     CLOSED,
     CANCELLED,
+    VOIDED,    // The match was abandoned, all the bets are refunded
+    POSTPONED, // The match was postponed, the bets are frozen until it is rescheduled
 }
 
 impl fmt::Display for Status {
@@ -47,6 +127,32 @@ impl fmt::Display for Status {
             Status::ACTIVE => write!(f, "ACTIVE"),
             Status::CLOSED => write!(f, "CLOSED"),
             Status::CANCELLED => write!(f, "CANCELLED"),
+            Status::VOIDED => write!(f, "VOIDED"),
+            Status::POSTPONED => write!(f, "POSTPONED"),
+        }
+    }
+}
+
+impl Status {
+    /// The bets of an open market are neither settled nor refundable yet
+    pub fn is_open(&self) -> bool {
+        matches!(self, Status::ACTIVE | Status::POSTPONED)
+    }
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub enum VoidReason {
+    ABANDONED, // The match started but was not completed
+    INTEGRITY, // The match integrity is compromised
+    OTHER,
+}
+
+impl fmt::Display for VoidReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VoidReason::ABANDONED => write!(f, "ABANDONED"),
+            VoidReason::INTEGRITY => write!(f, "INTEGRITY"),
+            VoidReason::OTHER => write!(f, "OTHER"),
         }
     }
 }
@@ -55,6 +161,18 @@ impl fmt::Display for Status {
 pub enum MarketResult {
     HOME,
     AWAY,
+    OVER,
+    UNDER,
+    PUSH, // Only as a settlement result, all bets are refunded
+}
+
+impl MarketResult {
+    /// Returns true if the result is taken by the home side of the market
+    /// books, on totals markets the home side records the OVER bets
+    /// and the away side the UNDER bets.
+    pub fn is_home_side(&self) -> bool {
+        matches!(self, MarketResult::HOME | MarketResult::OVER)
+    }
 }
 
 impl fmt::Display for MarketResult {
@@ -62,19 +180,228 @@ impl fmt::Display for MarketResult {
         match self {
             MarketResult::HOME => write!(f, "HOME"),
             MarketResult::AWAY => write!(f, "AWAY"),
+            MarketResult::OVER => write!(f, "OVER"),
+            MarketResult::UNDER => write!(f, "UNDER"),
+            MarketResult::PUSH => write!(f, "PUSH"),
         }
     }
 }
 
+#[allow(non_camel_case_types)]
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub enum SettlementOutcome {
+    WIN,
+    HALF_WIN,  // Half of the stake wins and the other half is refunded
+    PUSH,      // The stake is refunded
+    HALF_LOSE, // Half of the stake loses and the other half is refunded
+    LOSE,
+}
+
+impl fmt::Display for SettlementOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SettlementOutcome::WIN => write!(f, "WIN"),
+            SettlementOutcome::HALF_WIN => write!(f, "HALF_WIN"),
+            SettlementOutcome::PUSH => write!(f, "PUSH"),
+            SettlementOutcome::HALF_LOSE => write!(f, "HALF_LOSE"),
+            SettlementOutcome::LOSE => write!(f, "LOSE"),
+        }
+    }
+}
+
+/// How the bets on each side of the market are settled
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct Settlement {
+    pub home: SettlementOutcome,
+    pub away: SettlementOutcome,
+}
+
+/// Participant of the market as referenced by the data providers
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct Participant {
+    pub id: Option<String>,
+    pub logo: Option<String>, // Url of the logo, ex: https://example.com/team-a.png
+}
+
+/// Id of the event on an external data provider
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct ExternalId {
+    pub provider: String, // ex: pandascore
+    pub id: String,
+}
+
+/// Structured details about the event of the market
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema, Default)]
+pub struct MarketMetadata {
+    pub sport: Option<String>,  // ex: CS2
+    pub league: Option<String>, // ex: Test League
+    pub season: Option<String>, // ex: 2025
+    pub round: Option<String>,  // ex: Quarterfinals
+    pub venue: Option<String>,
+    pub home_participant: Option<Participant>,
+    pub away_participant: Option<Participant>,
+    pub external_ids: Vec<ExternalId>,
+}
+
+impl MarketMetadata {
+    /// Flattens the metadata into attributes, the empty fields are emitted as empty strings
+    pub fn attributes(&self) -> Vec<Attribute> {
+        let participant_attributes = |side: &str, participant: &Option<Participant>| {
+            let (id, logo) = match participant {
+                Some(participant) => (participant.id.clone(), participant.logo.clone()),
+                None => (None, None),
+            };
+            vec![
+                Attribute::new(format!("{side}_participant_id"), id.unwrap_or_default()),
+                Attribute::new(format!("{side}_participant_logo"), logo.unwrap_or_default()),
+            ]
+        };
+
+        let mut attributes = vec![
+            Attribute::new("sport", self.sport.clone().unwrap_or_default()),
+            Attribute::new("league", self.league.clone().unwrap_or_default()),
+            Attribute::new("season", self.season.clone().unwrap_or_default()),
+            Attribute::new("round", self.round.clone().unwrap_or_default()),
+            Attribute::new("venue", self.venue.clone().unwrap_or_default()),
+        ];
+        attributes.extend(participant_attributes("home", &self.home_participant));
+        attributes.extend(participant_attributes("away", &self.away_participant));
+        attributes.push(Attribute::new(
+            "external_ids",
+            self.external_ids
+                .iter()
+                .map(|external_id| format!("{}:{}", external_id.provider, external_id.id))
+                .collect::<Vec<String>>()
+                .join(","),
+        ));
+        attributes
+    }
+}
+
 #[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
 pub struct Market {
     pub id: String,
     pub label: String,
+    pub kind: MarketKind,
     pub home_team: String,
     pub away_team: String,
+    pub metadata: Option<MarketMetadata>,
     pub home_odds: Decimal,
     pub away_odds: Decimal,
     pub start_timestamp: u64,
+    pub resolution_deadline: u64, // Anyone can expire the market once passed without being scored
+    pub postponement_deadline: Option<u64>, // Refunded as if cancelled once passed without being rescheduled
     pub status: Status,
     pub result: Option<MarketResult>,
+    pub settlement: Option<Settlement>,
+    pub suspended: bool,
+    pub odds_revision: u64, // Increased each time the market is suspended or the odds are moved
+    pub void_reason: Option<VoidReason>,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub enum OddsFormat {
+    DECIMAL,    // ex: 1.91
+    AMERICAN,   // ex: -110, +150
+    FRACTIONAL, // ex: 91/100
+}
+
+/// Totals recorded on one side of the market books
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct SideBook {
+    pub total_bets: u128,
+    pub potential_payout: u128, // Includes the parlay liability
+    pub parlay_liability: u128,
+}
+
+pub fn load_side_book(storage: &dyn Storage, home_side: bool) -> StdResult<SideBook> {
+    if home_side {
+        Ok(SideBook {
+            total_bets: TOTAL_BETS_HOME.load(storage)?,
+            potential_payout: POTENTIAL_PAYOUT_HOME.load(storage)?,
+            parlay_liability: PARLAY_LIABILITY_HOME.load(storage)?,
+        })
+    } else {
+        Ok(SideBook {
+            total_bets: TOTAL_BETS_AWAY.load(storage)?,
+            potential_payout: POTENTIAL_PAYOUT_AWAY.load(storage)?,
+            parlay_liability: PARLAY_LIABILITY_AWAY.load(storage)?,
+        })
+    }
+}
+
+/// Sums of the payouts of the positions on one side for the half settlement outcomes
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct HalfPayouts {
+    pub half_win: u128,  // (bet_amount + payout) / 2 of each position
+    pub half_lose: u128, // bet_amount / 2 of each position
+}
+
+pub fn load_half_payouts(storage: &dyn Storage, home_side: bool) -> StdResult<HalfPayouts> {
+    if home_side {
+        HALF_PAYOUTS_HOME.load(storage)
+    } else {
+        HALF_PAYOUTS_AWAY.load(storage)
+    }
+}
+
+/// Funds that went in and out of the market, the stakes of the pending bets
+/// and the limit bets are held apart until they are confirmed or filled
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct Ledger {
+    pub seed: Uint128,      // Seed liquidity funded when the market was created
+    pub stakes: Uint128,    // Stakes of the accepted bets
+    pub fees: Uint128,      // Profit collected by the treasury at settlement
+    pub claimed: Uint128, // Winnings, refunds, parlay payouts and the seed liquidity returned to the admin
+    pub unclaimed: Uint128, // Stakes owed to the positions until settled, then their winnings or refunds left to claim
+}
+
+impl Ledger {
+    /// Returns the balance of the market, the funds deposited minus the funds paid out
+    pub fn balance(&self) -> Result<Uint128, OverflowError> {
+        self.seed
+            .checked_add(self.stakes)?
+            .checked_sub(self.fees)?
+            .checked_sub(self.claimed)
+    }
+}
+
+/// Position moved out of the bets of an address into a transferable token,
+/// claimed by whoever owns the token once the market is settled
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct BetSlip {
+    pub token_id: String,
+    pub owner: Addr,
+    pub approvals: Vec<Approval>,
+    pub result: MarketResult,
+    pub bet_amount: BetAmount,
+    pub payout: BetPayout, // Locked payout of the bet amount
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct Approval {
+    pub spender: Addr,
+    pub expires: Expiration,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct LimitBet {
+    pub id: u64,
+    pub addr: Addr,
+    pub result: MarketResult,
+    pub bet_amount: BetAmount,
+    pub target_odds: Decimal, // Filled once the odds of the bet amount reach the target or better
+    pub expires_at: u64,      // Refunded once passed if not filled
+    pub placed_at: u64,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct PendingBet {
+    pub id: u64,
+    pub addr: Addr,
+    pub result: MarketResult,
+    pub bet_amount: BetAmount,
+    pub odds: Decimal,
+    pub placed_at: u64,
+    pub odds_revision: u64,
 }
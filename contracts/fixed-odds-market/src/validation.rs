@@ -1,6 +1,30 @@
-use cosmwasm_std::Decimal;
+use cosmwasm_std::{to_json_vec, Addr, Api, Binary, Decimal, Decimal256, SignedDecimal};
+use sha2::{Digest, Sha256};
 
-use crate::error::ContractError;
+use crate::{
+    error::ContractError,
+    msg::BetQuote,
+    state::{Config, MarketKind, MarketMetadata, MarketResult, PricingModelKind},
+};
+
+pub const MIN_RESOLUTION_GRACE_PERIOD: u64 = 24 * 60 * 60; // 1 day
+pub const DEFAULT_RESOLUTION_GRACE_PERIOD: u64 = 7 * 24 * 60 * 60; // 7 days
+pub const MIN_POSTPONEMENT_PERIOD: u64 = 60 * 60; // 1 hour
+pub const DEFAULT_POSTPONEMENT_PERIOD: u64 = 3 * 24 * 60 * 60; // 3 days
+pub const MAX_BET_DELAY: u64 = 5 * 60; // 5 minutes
+pub const MAX_HANDICAP: u128 = 100;
+pub const MAX_TEXT_LENGTH: usize = 128;
+pub const MAX_URL_LENGTH: usize = 512;
+pub const MAX_EXTERNAL_IDS: usize = 10;
+
+/// The amounts of the denom are priced with decimals of 18 decimal places
+pub fn validate_denom_precision(denom_precision: u32) -> Result<(), ContractError> {
+    if denom_precision > Decimal256::DECIMAL_PLACES {
+        return Err(ContractError::InvalidPrecision(denom_precision));
+    }
+
+    Ok(())
+}
 
 pub fn validate_odd(odd: Decimal) -> Result<(), ContractError> {
     if odd < Decimal::one() {
@@ -43,3 +67,189 @@ pub fn validate_seed_liquidity_amplifier(
 
     Ok(())
 }
+
+pub fn validate_pricing_model(pricing_model: &PricingModelKind) -> Result<(), ContractError> {
+    if let PricingModelKind::LMSR { liquidity } = pricing_model {
+        if liquidity.is_zero() {
+            return Err(ContractError::InvalidLmsrLiquidity(*liquidity));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn validate_market_kind(kind: &MarketKind) -> Result<(), ContractError> {
+    match kind {
+        MarketKind::TOTALS { line } => {
+            // Lines need to be positive and either whole or half numbers, ex: 2, 2.5
+            let half = Decimal::from_atomics(5_u128, 1).unwrap();
+            if line.is_zero() || !(*line % half).is_zero() {
+                return Err(ContractError::InvalidLine(*line));
+            }
+        }
+        MarketKind::HANDICAP { handicap } => {
+            // Handicaps need to be in quarter steps, ex: -1, -0.5, +0.25
+            // and up to the max handicap on either side, the possible settlements are searched up to it
+            let quarter = SignedDecimal::from_atomics(25_i128, 2).unwrap();
+            if !(*handicap % quarter).is_zero()
+                || handicap.abs_diff(SignedDecimal::zero())
+                    > Decimal::from_atomics(MAX_HANDICAP, 0).unwrap()
+            {
+                return Err(ContractError::InvalidHandicap(*handicap));
+            }
+        }
+        MarketKind::MONEYLINE => (),
+    }
+
+    Ok(())
+}
+
+pub fn validate_market_result(
+    kind: &MarketKind,
+    result: &MarketResult,
+) -> Result<(), ContractError> {
+    let is_valid = match kind {
+        MarketKind::MONEYLINE | MarketKind::HANDICAP { .. } => {
+            matches!(result, MarketResult::HOME | MarketResult::AWAY)
+        }
+        MarketKind::TOTALS { .. } => matches!(result, MarketResult::OVER | MarketResult::UNDER),
+    };
+
+    if !is_valid {
+        return Err(ContractError::InvalidMarketResult(result.clone()));
+    }
+
+    Ok(())
+}
+
+pub fn validate_trader(config: &Config, sender: &Addr) -> Result<(), ContractError> {
+    match &config.live {
+        Some(live) if live.trader_addr == *sender => Ok(()),
+        Some(_) => Err(ContractError::Unauthorized {}),
+        None => Err(ContractError::LiveModeNotEnabled {}),
+    }
+}
+
+pub fn validate_trader_pubkey(trader_pubkey: &Binary) -> Result<(), ContractError> {
+    // Secp256k1 public keys are either compressed (33 bytes) or uncompressed (65 bytes)
+    let is_valid = match trader_pubkey.as_slice() {
+        [0x02 | 0x03, rest @ ..] => rest.len() == 32,
+        [0x04, rest @ ..] => rest.len() == 64,
+        _ => false,
+    };
+
+    if !is_valid {
+        return Err(ContractError::InvalidTraderPubkey {});
+    }
+
+    Ok(())
+}
+
+/// The trader signs the sha256 hash of the JSON serialized quote
+pub fn validate_quote_signature(
+    api: &dyn Api,
+    config: &Config,
+    quote: &BetQuote,
+    signature: &Binary,
+) -> Result<(), ContractError> {
+    let trader_pubkey = match &config.trader_pubkey {
+        Some(trader_pubkey) => trader_pubkey,
+        None => return Err(ContractError::QuotedBetsNotEnabled {}),
+    };
+
+    let message_hash = Sha256::digest(to_json_vec(quote)?);
+    let is_valid = api
+        .secp256k1_verify(&message_hash, signature, trader_pubkey)
+        .unwrap_or(false);
+    if !is_valid {
+        return Err(ContractError::InvalidQuoteSignature {});
+    }
+
+    Ok(())
+}
+
+pub fn validate_resolution_grace_period(resolution_grace_period: u64) -> Result<(), ContractError> {
+    if resolution_grace_period < MIN_RESOLUTION_GRACE_PERIOD {
+        return Err(ContractError::InvalidResolutionGracePeriod(
+            resolution_grace_period,
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_postponement_period(postponement_period: u64) -> Result<(), ContractError> {
+    if postponement_period < MIN_POSTPONEMENT_PERIOD {
+        return Err(ContractError::InvalidPostponementPeriod(
+            postponement_period,
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_bet_delay(bet_delay: u64) -> Result<(), ContractError> {
+    if bet_delay > MAX_BET_DELAY {
+        return Err(ContractError::InvalidBetDelay(bet_delay));
+    }
+
+    Ok(())
+}
+
+/// Descriptive texts can't be blank nor longer than the given length
+pub fn validate_text(field: &str, text: &str, max_length: usize) -> Result<(), ContractError> {
+    if text.trim().is_empty() || text.len() > max_length {
+        return Err(ContractError::InvalidMetadata(field.to_string()));
+    }
+
+    Ok(())
+}
+
+pub fn validate_metadata(metadata: &MarketMetadata) -> Result<(), ContractError> {
+    for (field, text) in [
+        ("sport", &metadata.sport),
+        ("league", &metadata.league),
+        ("season", &metadata.season),
+        ("round", &metadata.round),
+        ("venue", &metadata.venue),
+    ] {
+        if let Some(text) = text {
+            validate_text(field, text, MAX_TEXT_LENGTH)?;
+        }
+    }
+
+    for (side, participant) in [
+        ("home", &metadata.home_participant),
+        ("away", &metadata.away_participant),
+    ] {
+        if let Some(participant) = participant {
+            if let Some(id) = &participant.id {
+                validate_text(&format!("{side}_participant_id"), id, MAX_TEXT_LENGTH)?;
+            }
+            if let Some(logo) = &participant.logo {
+                validate_text(&format!("{side}_participant_logo"), logo, MAX_URL_LENGTH)?;
+            }
+        }
+    }
+
+    if metadata.external_ids.len() > MAX_EXTERNAL_IDS {
+        return Err(ContractError::InvalidMetadata("external_ids".to_string()));
+    }
+    for (index, external_id) in metadata.external_ids.iter().enumerate() {
+        validate_text("external_ids", &external_id.provider, MAX_TEXT_LENGTH)?;
+        validate_text("external_ids", &external_id.id, MAX_TEXT_LENGTH)?;
+
+        // The separators of the external ids attribute can't be part of the ids,
+        // and each provider can only be given once
+        if external_id.provider.contains([':', ','])
+            || external_id.id.contains(',')
+            || metadata.external_ids[..index]
+                .iter()
+                .any(|other| other.provider == external_id.provider)
+        {
+            return Err(ContractError::InvalidMetadata("external_ids".to_string()));
+        }
+    }
+
+    Ok(())
+}
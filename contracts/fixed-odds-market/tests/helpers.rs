@@ -1,4 +1,4 @@
-use cosmwasm_std::{Addr, Coin, Decimal, StdResult};
+use cosmwasm_std::{to_json_vec, Addr, Binary, Coin, Decimal, StdResult};
 use cw_multi_test::{
     error::{AnyError, AnyResult},
     App, AppBuilder, AppResponse, BankKeeper, ContractWrapper, Executor, MockApiBech32,
@@ -7,11 +7,15 @@ use derivative::Derivative;
 use fixed_odds_market::{
     contract::{execute, instantiate, query},
     msg::{
-        BetsByAddressResponse, BetsResponse, ConfigResponse, ExecuteMsg, InstantiateMsg,
-        MarketResponse, MaxBetsResponse, QueryMsg, UpdateParams,
+        BetQuote, BetsByAddressResponse, BetsResponse, ConfigResponse, ExecuteMsg,
+        ExposureResponse, InstantiateMsg, LedgerResponse, LimitBetResponse, LimitBetsResponse,
+        MarketResponse, MaxBetsResponse, NftInfoResponse, NumTokensResponse, OddsResponse,
+        OwnerOfResponse, PendingBetResponse, QueryMsg, QuoteResponse, SolvencyResponse,
+        TokensResponse, UpdateParams,
     },
-    state::MarketResult,
+    state::{MarketResult, OddsFormat, VoidReason},
 };
+use k256::ecdsa::{signature::Signer, Signature, SigningKey};
 
 /// BlockchainContract is a wrapper around blockchain App and contract Addr
 /// that provides a lot of helpers for working with this contract.
@@ -40,6 +44,12 @@ impl BlockchainContract {
             .query_wasm_smart(self.addr(), &QueryMsg::Market {})
     }
 
+    pub fn query_ledger(&self) -> StdResult<LedgerResponse> {
+        self.blockchain
+            .wrap()
+            .query_wasm_smart(self.addr(), &QueryMsg::Ledger {})
+    }
+
     pub fn query_max_bets(&self) -> StdResult<MaxBetsResponse> {
         self.blockchain
             .wrap()
@@ -61,11 +71,268 @@ impl BlockchainContract {
         )
     }
 
+    pub fn query_pending_bet(&self, id: u64) -> StdResult<PendingBetResponse> {
+        self.blockchain
+            .wrap()
+            .query_wasm_smart(self.addr(), &QueryMsg::PendingBet { id })
+    }
+
+    pub fn query_limit_bet(&self, id: u64) -> StdResult<LimitBetResponse> {
+        self.blockchain
+            .wrap()
+            .query_wasm_smart(self.addr(), &QueryMsg::LimitBet { id })
+    }
+
+    pub fn query_limit_bets(&self) -> StdResult<LimitBetsResponse> {
+        self.blockchain.wrap().query_wasm_smart(
+            self.addr(),
+            &QueryMsg::LimitBets {
+                start_after: None,
+                limit: None,
+            },
+        )
+    }
+
+    pub fn query_owner_of(&self, token_id: &str) -> StdResult<OwnerOfResponse> {
+        self.blockchain.wrap().query_wasm_smart(
+            self.addr(),
+            &QueryMsg::OwnerOf {
+                token_id: token_id.to_string(),
+                include_expired: None,
+            },
+        )
+    }
+
+    pub fn query_nft_info(&self, token_id: &str) -> StdResult<NftInfoResponse> {
+        self.blockchain.wrap().query_wasm_smart(
+            self.addr(),
+            &QueryMsg::NftInfo {
+                token_id: token_id.to_string(),
+            },
+        )
+    }
+
+    pub fn query_num_tokens(&self) -> StdResult<NumTokensResponse> {
+        self.blockchain
+            .wrap()
+            .query_wasm_smart(self.addr(), &QueryMsg::NumTokens {})
+    }
+
+    pub fn query_tokens(&self, owner: &Addr) -> StdResult<TokensResponse> {
+        self.blockchain.wrap().query_wasm_smart(
+            self.addr(),
+            &QueryMsg::Tokens {
+                owner: owner.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+    }
+
+    pub fn suspend_market(&mut self, sender: &Addr) -> AnyResult<AppResponse> {
+        self.blockchain
+            .execute_contract(sender.clone(), self.addr(), &ExecuteMsg::Suspend {}, &[])
+    }
+
+    pub fn resume_market(&mut self, sender: &Addr) -> AnyResult<AppResponse> {
+        self.blockchain
+            .execute_contract(sender.clone(), self.addr(), &ExecuteMsg::Resume {}, &[])
+    }
+
+    pub fn update_odds(
+        &mut self,
+        sender: &Addr,
+        initial_odds_home: Decimal,
+        initial_odds_away: Decimal,
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::UpdateOdds {
+                initial_odds_home,
+                initial_odds_away,
+            },
+            &[],
+        )
+    }
+
+    pub fn confirm_bet(&mut self, sender: &Addr, id: u64) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::ConfirmBet { id },
+            &[],
+        )
+    }
+
+    pub fn query_odds(&self, format: OddsFormat) -> StdResult<OddsResponse> {
+        self.blockchain
+            .wrap()
+            .query_wasm_smart(self.addr(), &QueryMsg::Odds { format })
+    }
+
+    pub fn query_exposure(&self) -> StdResult<ExposureResponse> {
+        self.blockchain
+            .wrap()
+            .query_wasm_smart(self.addr(), &QueryMsg::Exposure {})
+    }
+
+    pub fn query_solvency(&self) -> StdResult<SolvencyResponse> {
+        self.blockchain
+            .wrap()
+            .query_wasm_smart(self.addr(), &QueryMsg::Solvency {})
+    }
+
+    pub fn query_quote(&self, result: MarketResult, bet_amount: u128) -> StdResult<QuoteResponse> {
+        self.blockchain
+            .wrap()
+            .query_wasm_smart(self.addr(), &QueryMsg::Quote { result, bet_amount })
+    }
+
+    pub fn transfer_position(
+        &mut self,
+        sender: &Addr,
+        result: MarketResult,
+        amount: u128,
+        recipient: &Addr,
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::TransferPosition {
+                result,
+                amount,
+                recipient: recipient.clone(),
+            },
+            &[],
+        )
+    }
+
+    pub fn mint_bet_slip(
+        &mut self,
+        sender: &Addr,
+        result: MarketResult,
+        amount: u128,
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::MintBetSlip { result, amount },
+            &[],
+        )
+    }
+
+    pub fn claim_by_token(&mut self, sender: &Addr, token_id: &str) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::ClaimByToken {
+                token_id: token_id.to_string(),
+            },
+            &[],
+        )
+    }
+
+    pub fn transfer_nft(
+        &mut self,
+        sender: &Addr,
+        recipient: &Addr,
+        token_id: &str,
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::TransferNft {
+                recipient: recipient.to_string(),
+                token_id: token_id.to_string(),
+            },
+            &[],
+        )
+    }
+
+    pub fn approve_nft(
+        &mut self,
+        sender: &Addr,
+        spender: &Addr,
+        token_id: &str,
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::Approve {
+                spender: spender.to_string(),
+                token_id: token_id.to_string(),
+                expires: None,
+            },
+            &[],
+        )
+    }
+
+    pub fn expire_market(&mut self, sender: &Addr) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::ExpireMarket {},
+            &[],
+        )
+    }
+
     pub fn cancel_market(&mut self, sender: &Addr) -> AnyResult<AppResponse> {
         self.blockchain
             .execute_contract(sender.clone(), self.addr(), &ExecuteMsg::Cancel {}, &[])
     }
 
+    pub fn postpone_market(&mut self, sender: &Addr) -> AnyResult<AppResponse> {
+        self.blockchain
+            .execute_contract(sender.clone(), self.addr(), &ExecuteMsg::Postpone {}, &[])
+    }
+
+    pub fn reschedule_market(
+        &mut self,
+        sender: &Addr,
+        start_timestamp: u64,
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::Reschedule { start_timestamp },
+            &[],
+        )
+    }
+
+    pub fn withdraw_stake(
+        &mut self,
+        sender: &Addr,
+        token_id: Option<&str>,
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::WithdrawStake {
+                token_id: token_id.map(|token_id| token_id.to_string()),
+            },
+            &[],
+        )
+    }
+
+    pub fn void_market(&mut self, sender: &Addr, reason: VoidReason) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::Void { reason },
+            &[],
+        )
+    }
+
+    pub fn reconcile_surplus(&mut self, sender: &Addr) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::ReconcileSurplus {},
+            &[],
+        )
+    }
+
     pub fn update_market(&mut self, sender: &Addr, params: UpdateParams) -> AnyResult<AppResponse> {
         self.blockchain.execute_contract(
             sender.clone(),
@@ -79,6 +346,11 @@ impl BlockchainContract {
                 initial_odds_home: params.initial_odds_home,
                 initial_odds_away: params.initial_odds_away,
                 start_timestamp: params.start_timestamp,
+                parlay_addr: params.parlay_addr,
+                label: params.label,
+                home_team: params.home_team,
+                away_team: params.away_team,
+                metadata: params.metadata,
             },
             &[],
         )
@@ -93,6 +365,32 @@ impl BlockchainContract {
         )
     }
 
+    pub fn score_market_total(&mut self, sender: &Addr, total: u64) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::ScoreTotal { total },
+            &[],
+        )
+    }
+
+    pub fn score_market_final(
+        &mut self,
+        sender: &Addr,
+        home_score: u64,
+        away_score: u64,
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::ScoreFinal {
+                home_score,
+                away_score,
+            },
+            &[],
+        )
+    }
+
     pub fn place_bet(
         &mut self,
         sender: &Addr,
@@ -113,6 +411,76 @@ impl BlockchainContract {
         )
     }
 
+    pub fn place_quoted_bet(
+        &mut self,
+        sender: &Addr,
+        quote: BetQuote,
+        signature: Binary,
+        funds: &[Coin],
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::PlaceQuotedBet { quote, signature },
+            funds,
+        )
+    }
+
+    pub fn place_limit_bet(
+        &mut self,
+        sender: &Addr,
+        result: MarketResult,
+        target_odds: Decimal,
+        expires_at: u64,
+        funds: &[Coin],
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::PlaceLimitBet {
+                result,
+                target_odds,
+                expires_at,
+            },
+            funds,
+        )
+    }
+
+    pub fn cancel_limit_bet(&mut self, sender: &Addr, id: u64) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::CancelLimitBet { id },
+            &[],
+        )
+    }
+
+    pub fn execute_limit_bets(&mut self, sender: &Addr) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::ExecuteLimitBets {
+                start_after: None,
+                limit: None,
+            },
+            &[],
+        )
+    }
+
+    pub fn reserve_parlay_liability(
+        &mut self,
+        sender: &Addr,
+        result: MarketResult,
+        amount: u128,
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::ReserveParlayLiability { result, amount },
+            &[],
+        )
+    }
+
     pub fn claim_winnings(
         &mut self,
         sender: &Addr,
@@ -127,6 +495,27 @@ impl BlockchainContract {
     }
 }
 
+/// Deterministic signing key of the trader quoting the bets
+pub fn trader_signing_key() -> SigningKey {
+    SigningKey::from_bytes(&[7_u8; 32].into()).unwrap()
+}
+
+/// Compressed public key of the trader quoting the bets
+pub fn trader_pubkey() -> Binary {
+    Binary::from(
+        trader_signing_key()
+            .verifying_key()
+            .to_encoded_point(true)
+            .as_bytes(),
+    )
+}
+
+/// Signs the sha256 hash of the JSON serialized quote
+pub fn sign_quote(signing_key: &SigningKey, quote: &BetQuote) -> Binary {
+    let signature: Signature = signing_key.sign(&to_json_vec(quote).unwrap());
+    Binary::from(signature.to_bytes().as_slice())
+}
+
 pub fn setup_blockchain_and_contract(
     admin: Addr,
     initial_balances: Vec<(Addr, Vec<Coin>)>,
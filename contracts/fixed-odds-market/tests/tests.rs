@@ -3,7 +3,7 @@ use cw_multi_test::MockApiBech32;
 use fixed_odds_market::{
     error::ContractError,
     msg::InstantiateMsg,
-    state::{MarketResult, Status},
+    state::{LiveConfig, MarketResult, OddsFormat, Status},
 };
 use helpers::setup_blockchain_and_contract;
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -15,6 +15,8 @@ const ADMIN: &str = "ADMIN";
 const TREASURY: &str = "TREASURY";
 const OTHER: &str = "OTHER";
 const ANYONE: &str = "ANYONE";
+const PARLAY: &str = "PARLAY";
+const TRADER: &str = "TRADER";
 const USER_A: &str = "USER_A";
 const USER_B: &str = "USER_B";
 const USER_C: &str = "USER_C";
@@ -45,6 +47,7 @@ mod create_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -53,6 +56,13 @@ mod create_market {
                 initial_odds_home: Decimal::from_atomics(2_2_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(1_8_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -126,6 +136,7 @@ mod create_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -138,6 +149,13 @@ mod create_market {
                     .expect("Time went backwards")
                     .as_secs()
                     + 60 * 5, // 5 minutes from now
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             vec![],
         )
@@ -169,6 +187,7 @@ mod create_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(251_u128, 2).unwrap(), // 0.15
@@ -177,6 +196,13 @@ mod create_market {
                 initial_odds_home: Decimal::from_atomics(2_2_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(1_8_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -208,6 +234,7 @@ mod create_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(25_u128, 2).unwrap(), // 0.15
@@ -216,6 +243,13 @@ mod create_market {
                 initial_odds_home: Decimal::from_atomics(2_2_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(1_8_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -235,6 +269,7 @@ mod create_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(25_u128, 2).unwrap(), // 0.15
@@ -243,6 +278,13 @@ mod create_market {
                 initial_odds_home: Decimal::from_atomics(2_2_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(1_8_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -273,6 +315,7 @@ mod create_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(25_u128, 2).unwrap(), // 0.15
@@ -281,6 +324,13 @@ mod create_market {
                 initial_odds_home: Decimal::from_atomics(2_2_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(1_8_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -302,6 +352,7 @@ mod create_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(25_u128, 2).unwrap(), // 0.15
@@ -310,6 +361,13 @@ mod create_market {
                 initial_odds_home: Decimal::from_atomics(2_2_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(1_8_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -342,6 +400,7 @@ mod create_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(25_u128, 2).unwrap(), // 0.15
@@ -350,6 +409,13 @@ mod create_market {
                 initial_odds_home: Decimal::from_atomics(99_u128, 2).unwrap(), // 0.99
                 initial_odds_away: Decimal::from_atomics(1_8_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -369,6 +435,7 @@ mod create_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(25_u128, 2).unwrap(), // 0.15
@@ -377,6 +444,13 @@ mod create_market {
                 initial_odds_home: Decimal::from_atomics(1_8_u128, 1).unwrap(), // 1.8
                 initial_odds_away: Decimal::from_atomics(99_u128, 2).unwrap(), // 0.99
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -426,6 +500,7 @@ mod place_bet {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -434,6 +509,13 @@ mod place_bet {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -449,14 +531,14 @@ mod place_bet {
             .place_bet(
                 &user_a,
                 MarketResult::HOME,
-                Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                Decimal::from_atomics(1_87_u128, 2).unwrap(),
                 None,
                 &coins(10_000_000, NATIVE_DENOM),
             )
             .unwrap();
 
         let query_max_bets = blockchain_contract.query_max_bets().unwrap();
-        assert_eq!(32_934_782, query_max_bets.home);
+        assert_eq!(33_061_594, query_max_bets.home);
         assert_eq!(45_548_654, query_max_bets.away);
 
         let user_b = blockchain_contract.blockchain.api().addr_make(USER_B);
@@ -465,15 +547,15 @@ mod place_bet {
             .place_bet(
                 &user_b,
                 MarketResult::AWAY,
-                Decimal::from_atomics(1_61_u128, 2).unwrap(),
+                Decimal::from_atomics(1_59_u128, 2).unwrap(),
                 None,
                 &coins(10_000_000, NATIVE_DENOM),
             )
             .unwrap();
 
         let query_max_bets = blockchain_contract.query_max_bets().unwrap();
-        assert_eq!(35_403_508, query_max_bets.home);
-        assert_eq!(44_118_895, query_max_bets.away);
+        assert_eq!(35_526_315, query_max_bets.home);
+        assert_eq!(44_195_329, query_max_bets.away);
 
         let user_c = blockchain_contract.blockchain.api().addr_make(USER_C);
 
@@ -481,7 +563,7 @@ mod place_bet {
             .place_bet(
                 &user_c,
                 MarketResult::AWAY,
-                Decimal::from_atomics(1_57_u128, 2).unwrap(),
+                Decimal::from_atomics(1_50_u128, 2).unwrap(),
                 None,
                 &coins(40_000_000, NATIVE_DENOM),
             )
@@ -491,21 +573,21 @@ mod place_bet {
             .place_bet(
                 &user_c,
                 MarketResult::HOME,
-                Decimal::from_atomics(2_13_u128, 2).unwrap(),
+                Decimal::from_atomics(2_06_u128, 2).unwrap(),
                 None,
                 &coins(20_000_000, NATIVE_DENOM),
             )
             .unwrap();
 
         let query_max_bets = blockchain_contract.query_max_bets().unwrap();
-        assert_eq!(39_831_649, query_max_bets.home);
-        assert_eq!(44_342_105, query_max_bets.away);
+        assert_eq!(40_414_141, query_max_bets.home);
+        assert_eq!(45_526_315, query_max_bets.away);
 
         let query_bets = blockchain_contract.query_bets().unwrap();
         assert_eq!(30_000_000, query_bets.total_amounts.home);
         assert_eq!(50_000_000, query_bets.total_amounts.away);
-        assert_eq!(61_700_000, query_bets.potential_payouts.home);
-        assert_eq!(78_900_000, query_bets.potential_payouts.away);
+        assert_eq!(59_970_000, query_bets.potential_payouts.home);
+        assert_eq!(76_200_000, query_bets.potential_payouts.away);
 
         blockchain_contract.blockchain.update_block(|block| {
             block.time = Timestamp::from_seconds(
@@ -554,7 +636,7 @@ mod place_bet {
             )
             .unwrap();
         assert_eq!(
-            180_000_000_u128 - 100_000_000_u128 - 78_900_000_u128,
+            180_000_000_u128 - 100_000_000_u128 - 76_200_000_u128,
             treasury_balance.amount.into()
         );
 
@@ -570,7 +652,7 @@ mod place_bet {
             .wrap()
             .query_balance(blockchain_contract.addr().clone(), NATIVE_DENOM)
             .unwrap();
-        assert_eq!(78_900_000_u128, market_balance_before.amount.into());
+        assert_eq!(76_200_000_u128, market_balance_before.amount.into());
 
         let user_a_balance_before = blockchain_contract
             .blockchain
@@ -615,7 +697,7 @@ mod place_bet {
             .query_balance(user_b.clone(), NATIVE_DENOM)
             .unwrap();
         assert_eq!(
-            user_b_balance_before.amount + Uint128::new(16_100_000_u128),
+            user_b_balance_before.amount + Uint128::new(15_920_000_u128),
             user_b_balance_after.amount
         );
 
@@ -637,7 +719,7 @@ mod place_bet {
             .query_balance(user_c.clone(), NATIVE_DENOM)
             .unwrap();
         assert_eq!(
-            user_c_balance_before.amount + Uint128::new(62_800_000),
+            user_c_balance_before.amount + Uint128::new(60_280_000),
             user_c_balance_after.amount
         );
 
@@ -676,6 +758,7 @@ mod place_bet {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -684,6 +767,13 @@ mod place_bet {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -695,7 +785,7 @@ mod place_bet {
             .place_bet(
                 &user_a,
                 MarketResult::HOME,
-                Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                Decimal::from_atomics(1_84_u128, 2).unwrap(),
                 None,
                 &coins(20_000_000, NATIVE_DENOM),
             )
@@ -705,7 +795,7 @@ mod place_bet {
             .place_bet(
                 &user_a,
                 MarketResult::HOME,
-                Decimal::from_atomics(1_77_u128, 2).unwrap(),
+                Decimal::from_atomics(1_73_u128, 2).unwrap(),
                 None,
                 &coins(15_000_000, NATIVE_DENOM),
             )
@@ -715,13 +805,13 @@ mod place_bet {
             .place_bet(
                 &user_a,
                 MarketResult::HOME,
-                Decimal::from_atomics(1_69_u128, 2).unwrap(),
+                Decimal::from_atomics(1_64_u128, 2).unwrap(),
                 None,
                 &coins(24_560_000, NATIVE_DENOM),
             )
             .unwrap();
 
-        let user_a_payout = 38_200_000_u128 + 26_550_000_u128 + 41_506_400_u128;
+        let user_a_payout = 36_900_000_u128 + 26_070_000_u128 + 40_401_200_u128;
 
         let query_bets = blockchain_contract.query_bets().unwrap();
         assert_eq!(59_560_000, query_bets.total_amounts.home);
@@ -732,7 +822,7 @@ mod place_bet {
         let query_user_a_bets = blockchain_contract.query_bets_by_address(&user_a).unwrap();
         let home_bet_record = query_user_a_bets.all_bets.home;
         assert_eq!(
-            Decimal::from_atomics(1_78402283411685695_u128, 17).unwrap(),
+            Decimal::from_atomics(1_735580926796507723_u128, 18).unwrap(),
             home_bet_record.odds
         );
         assert_eq!(59_560_000_u128, home_bet_record.bet_amount);
@@ -809,6 +899,7 @@ mod place_bet {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(7_u128, 2).unwrap(), // 0.07
@@ -817,6 +908,13 @@ mod place_bet {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -829,7 +927,7 @@ mod place_bet {
             .place_bet(
                 &user_a,
                 MarketResult::AWAY,
-                Decimal::from_atomics(1_68_u128, 2).unwrap(),
+                Decimal::from_atomics(1_65_u128, 2).unwrap(),
                 Some(user_b.clone()),
                 &coins(10_000_000, NATIVE_DENOM),
             )
@@ -856,11 +954,11 @@ mod place_bet {
         assert_eq!(0_u128, home_bet_record.payout);
         let away_bet_record = query_user_b_bets.all_bets.away;
         assert_eq!(
-            Decimal::from_atomics(1_68_u128, 2).unwrap(),
+            Decimal::from_atomics(1_658_u128, 3).unwrap(),
             away_bet_record.odds
         );
         assert_eq!(10_000_000_u128, away_bet_record.bet_amount);
-        assert_eq!(16_800_000_u128, away_bet_record.payout);
+        assert_eq!(16_580_000_u128, away_bet_record.payout);
 
         blockchain_contract.blockchain.update_block(|block| {
             block.time = Timestamp::from_seconds(
@@ -919,7 +1017,7 @@ mod place_bet {
             .query_balance(user_b.clone(), NATIVE_DENOM)
             .unwrap();
         assert_eq!(
-            user_b_balance_before.amount + Uint128::new(16_800_000_u128),
+            user_b_balance_before.amount + Uint128::new(16_580_000_u128),
             user_b_balance_after.amount
         );
     }
@@ -951,6 +1049,7 @@ mod place_bet {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -959,6 +1058,13 @@ mod place_bet {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -1029,6 +1135,7 @@ mod place_bet {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -1037,6 +1144,13 @@ mod place_bet {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -1110,6 +1224,7 @@ mod place_bet {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -1118,6 +1233,13 @@ mod place_bet {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -1195,6 +1317,7 @@ mod place_bet {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -1203,6 +1326,13 @@ mod place_bet {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -1266,6 +1396,7 @@ mod place_bet {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -1274,6 +1405,13 @@ mod place_bet {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -1285,9 +1423,9 @@ mod place_bet {
             .place_bet(
                 &user_a,
                 MarketResult::HOME,
-                Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                Decimal::from_atomics(1_79_u128, 2).unwrap(),
                 None,
-                &coins(34_910_000, NATIVE_DENOM),
+                &coins(38_000_000, NATIVE_DENOM),
             )
             .unwrap_err();
         assert_eq!(
@@ -1345,6 +1483,7 @@ mod claim_winnings {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -1353,6 +1492,13 @@ mod claim_winnings {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -1363,7 +1509,7 @@ mod claim_winnings {
             .place_bet(
                 &user_a,
                 MarketResult::HOME,
-                Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                Decimal::from_atomics(1_87_u128, 2).unwrap(),
                 None,
                 &coins(10_000_000, NATIVE_DENOM),
             )
@@ -1374,7 +1520,7 @@ mod claim_winnings {
             .place_bet(
                 &other,
                 MarketResult::AWAY,
-                Decimal::from_atomics(1_61_u128, 2).unwrap(),
+                Decimal::from_atomics(1_60_u128, 2).unwrap(),
                 None,
                 &coins(5_000_000, NATIVE_DENOM),
             )
@@ -1419,7 +1565,7 @@ mod claim_winnings {
             .query_balance(&user_a, NATIVE_DENOM)
             .unwrap();
         assert_eq!(
-            INITIAL_BALANCE + 9_100_000_u128,
+            INITIAL_BALANCE + 8_750_000_u128,
             user_a_balance.amount.into()
         );
 
@@ -1439,7 +1585,7 @@ mod claim_winnings {
             .query_balance(MockApiBech32::new("neutron").addr_make(ADMIN), NATIVE_DENOM)
             .unwrap();
         assert_eq!(
-            INITIAL_BALANCE - (9_100_000_u128 - 5_000_000_u128),
+            INITIAL_BALANCE - (8_750_000_u128 - 5_000_000_u128),
             admin_balance.amount.into()
         );
 
@@ -1478,6 +1624,7 @@ mod claim_winnings {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -1486,6 +1633,13 @@ mod claim_winnings {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -1496,7 +1650,7 @@ mod claim_winnings {
             .place_bet(
                 &user_a,
                 MarketResult::HOME,
-                Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                Decimal::from_atomics(1_87_u128, 2).unwrap(),
                 None,
                 &coins(10_000_000, NATIVE_DENOM),
             )
@@ -1544,7 +1698,7 @@ mod claim_winnings {
             .query_balance(&user_a, NATIVE_DENOM)
             .unwrap();
         assert_eq!(
-            INITIAL_BALANCE + 9_100_000_u128,
+            INITIAL_BALANCE + 8_750_000_u128,
             user_a_balance.amount.into()
         );
     }
@@ -1580,6 +1734,7 @@ mod claim_winnings {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -1588,6 +1743,13 @@ mod claim_winnings {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -1598,7 +1760,7 @@ mod claim_winnings {
             .place_bet(
                 &user_a,
                 MarketResult::HOME,
-                Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                Decimal::from_atomics(1_87_u128, 2).unwrap(),
                 None,
                 &coins(10_000_000, NATIVE_DENOM),
             )
@@ -1609,7 +1771,7 @@ mod claim_winnings {
             .place_bet(
                 &other,
                 MarketResult::AWAY,
-                Decimal::from_atomics(1_61_u128, 2).unwrap(),
+                Decimal::from_atomics(1_60_u128, 2).unwrap(),
                 None,
                 &coins(5_000_000, NATIVE_DENOM),
             )
@@ -1620,7 +1782,7 @@ mod claim_winnings {
             .place_bet(
                 &user_a,
                 MarketResult::AWAY,
-                Decimal::from_atomics(159_u128, 2).unwrap(),
+                Decimal::from_atomics(158_u128, 2).unwrap(),
                 None,
                 &coins(5_000_000, NATIVE_DENOM),
             )
@@ -1727,6 +1889,7 @@ mod claim_winnings {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -1735,6 +1898,13 @@ mod claim_winnings {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -1745,7 +1915,7 @@ mod claim_winnings {
             .place_bet(
                 &user_a,
                 MarketResult::HOME,
-                Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                Decimal::from_atomics(1_87_u128, 2).unwrap(),
                 None,
                 &coins(10_000_000, NATIVE_DENOM),
             )
@@ -1815,6 +1985,7 @@ mod claim_winnings {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -1823,6 +1994,13 @@ mod claim_winnings {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -1833,7 +2011,7 @@ mod claim_winnings {
             .place_bet(
                 &user_a,
                 MarketResult::HOME,
-                Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                Decimal::from_atomics(1_87_u128, 2).unwrap(),
                 None,
                 &coins(10_000_000, NATIVE_DENOM),
             )
@@ -1886,7 +2064,7 @@ mod claim_winnings {
             .query_balance(&user_a, NATIVE_DENOM)
             .unwrap();
         assert_eq!(
-            INITIAL_BALANCE + 9_100_000_u128,
+            INITIAL_BALANCE + 8_750_000_u128,
             user_a_balance.amount.into()
         );
     }
@@ -1918,6 +2096,7 @@ mod claim_winnings {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -1926,6 +2105,13 @@ mod claim_winnings {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -1937,7 +2123,7 @@ mod claim_winnings {
             .place_bet(
                 &user_a,
                 MarketResult::HOME,
-                Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                Decimal::from_atomics(1_87_u128, 2).unwrap(),
                 None,
                 &coins(10_000_000, NATIVE_DENOM),
             )
@@ -2016,6 +2202,7 @@ mod update_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -2024,6 +2211,13 @@ mod update_market {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -2049,6 +2243,11 @@ mod update_market {
                     seed_liquidity_amplifier: None,
                     initial_odds_home: None,
                     initial_odds_away: None,
+                    parlay_addr: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap();
@@ -2123,6 +2322,7 @@ mod update_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -2131,6 +2331,13 @@ mod update_market {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -2156,6 +2363,11 @@ mod update_market {
                     seed_liquidity_amplifier: None,
                     initial_odds_home: None,
                     initial_odds_away: None,
+                    parlay_addr: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap();
@@ -2230,6 +2442,7 @@ mod update_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -2238,6 +2451,13 @@ mod update_market {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -2260,6 +2480,11 @@ mod update_market {
                     seed_liquidity_amplifier: None,
                     initial_odds_home: None,
                     initial_odds_away: None,
+                    parlay_addr: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap();
@@ -2337,6 +2562,7 @@ mod update_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -2345,6 +2571,13 @@ mod update_market {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -2370,6 +2603,11 @@ mod update_market {
                     seed_liquidity_amplifier: None,
                     initial_odds_home: None,
                     initial_odds_away: None,
+                    parlay_addr: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap();
@@ -2450,6 +2688,7 @@ mod update_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -2458,6 +2697,13 @@ mod update_market {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -2475,9 +2721,9 @@ mod update_market {
             .place_bet(
                 &user_a,
                 MarketResult::HOME,
-                Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                Decimal::from_atomics(1_85_u128, 2).unwrap(),
                 None,
-                &coins(15_000_000, NATIVE_DENOM),
+                &coins(16_000_000, NATIVE_DENOM),
             )
             .unwrap_err();
         assert_eq!(
@@ -2499,6 +2745,11 @@ mod update_market {
                     seed_liquidity_amplifier: None,
                     initial_odds_home: None,
                     initial_odds_away: None,
+                    parlay_addr: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap();
@@ -2507,9 +2758,9 @@ mod update_market {
             .place_bet(
                 &user_a,
                 MarketResult::HOME,
-                Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                Decimal::from_atomics(1_79_u128, 2).unwrap(),
                 None,
-                &coins(34_910_000, NATIVE_DENOM),
+                &coins(38_000_000, NATIVE_DENOM),
             )
             .unwrap_err();
         assert_eq!(
@@ -2521,7 +2772,7 @@ mod update_market {
             .place_bet(
                 &user_a,
                 MarketResult::HOME,
-                Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                Decimal::from_atomics(1_85_u128, 2).unwrap(),
                 None,
                 &coins(15_000_000, NATIVE_DENOM),
             )
@@ -2610,6 +2861,7 @@ mod update_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -2618,6 +2870,13 @@ mod update_market {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -2635,7 +2894,7 @@ mod update_market {
             .place_bet(
                 &user_a,
                 MarketResult::HOME,
-                Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                Decimal::from_atomics(1_85_u128, 2).unwrap(),
                 None,
                 &coins(15_000_000, NATIVE_DENOM),
             )
@@ -2659,6 +2918,11 @@ mod update_market {
                     seed_liquidity_amplifier: Some(new_seed_liquidity_amplifier),
                     initial_odds_home: None,
                     initial_odds_away: None,
+                    parlay_addr: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap();
@@ -2736,6 +3000,7 @@ mod update_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -2744,6 +3009,13 @@ mod update_market {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -2774,6 +3046,11 @@ mod update_market {
                     seed_liquidity_amplifier: None,
                     initial_odds_home: Some(new_initial_odds_home),
                     initial_odds_away: Some(new_initial_odds_away),
+                    parlay_addr: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap();
@@ -2845,6 +3122,7 @@ mod update_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -2853,6 +3131,13 @@ mod update_market {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -2875,6 +3160,11 @@ mod update_market {
                     seed_liquidity_amplifier: None,
                     initial_odds_home: None,
                     initial_odds_away: None,
+                    parlay_addr: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap_err();
@@ -2908,6 +3198,7 @@ mod update_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -2916,6 +3207,13 @@ mod update_market {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -2940,6 +3238,11 @@ mod update_market {
                     seed_liquidity_amplifier: None,
                     initial_odds_home: None,
                     initial_odds_away: None,
+                    parlay_addr: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap_err();
@@ -2973,6 +3276,7 @@ mod update_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -2981,6 +3285,13 @@ mod update_market {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -3004,6 +3315,11 @@ mod update_market {
                     seed_liquidity_amplifier: None,
                     initial_odds_home: None,
                     initial_odds_away: None,
+                    parlay_addr: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap_err();
@@ -3040,6 +3356,7 @@ mod update_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -3048,6 +3365,13 @@ mod update_market {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -3071,6 +3395,11 @@ mod update_market {
                     seed_liquidity_amplifier: None,
                     initial_odds_home: None,
                     initial_odds_away: None,
+                    parlay_addr: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap_err();
@@ -3097,6 +3426,11 @@ mod update_market {
                     seed_liquidity_amplifier: None,
                     initial_odds_home: None,
                     initial_odds_away: None,
+                    parlay_addr: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap_err();
@@ -3133,6 +3467,7 @@ mod update_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -3141,6 +3476,13 @@ mod update_market {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -3164,6 +3506,11 @@ mod update_market {
                     seed_liquidity_amplifier: Some(Decimal::from_atomics(99_u128, 2).unwrap()),
                     initial_odds_home: None,
                     initial_odds_away: None,
+                    parlay_addr: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap_err();
@@ -3192,6 +3539,11 @@ mod update_market {
                     seed_liquidity_amplifier: Some(Decimal::from_atomics(101_u128, 1).unwrap()),
                     initial_odds_home: None,
                     initial_odds_away: None,
+                    parlay_addr: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap_err();
@@ -3230,6 +3582,7 @@ mod update_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -3238,6 +3591,13 @@ mod update_market {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -3261,6 +3621,11 @@ mod update_market {
                     seed_liquidity_amplifier: None,
                     initial_odds_home: Some(Decimal::from_atomics(99_u128, 2).unwrap()),
                     initial_odds_away: Some(Decimal::from_atomics(199_u128, 2).unwrap()),
+                    parlay_addr: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap_err();
@@ -3293,6 +3658,11 @@ mod update_market {
                     seed_liquidity_amplifier: None,
                     initial_odds_home: Some(Decimal::from_atomics(199_u128, 2).unwrap()),
                     initial_odds_away: Some(Decimal::from_atomics(99_u128, 2).unwrap()),
+                    parlay_addr: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap_err();
@@ -3329,6 +3699,7 @@ mod update_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -3337,6 +3708,13 @@ mod update_market {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -3360,6 +3738,11 @@ mod update_market {
                     seed_liquidity_amplifier: None,
                     initial_odds_home: Some(Decimal::from_atomics(199_u128, 2).unwrap()),
                     initial_odds_away: None,
+                    parlay_addr: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap_err();
@@ -3392,6 +3775,11 @@ mod update_market {
                     seed_liquidity_amplifier: None,
                     initial_odds_home: None,
                     initial_odds_away: Some(Decimal::from_atomics(199_u128, 2).unwrap()),
+                    parlay_addr: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap_err();
@@ -3438,6 +3826,7 @@ mod score_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -3446,6 +3835,13 @@ mod score_market {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -3455,7 +3851,7 @@ mod score_market {
             .place_bet(
                 &MockApiBech32::new("neutron").addr_make(USER_A),
                 MarketResult::HOME,
-                Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                Decimal::from_atomics(1_87_u128, 2).unwrap(),
                 None,
                 &coins(10_000_000, NATIVE_DENOM),
             )
@@ -3465,7 +3861,7 @@ mod score_market {
             .place_bet(
                 &MockApiBech32::new("neutron").addr_make(USER_A),
                 MarketResult::AWAY,
-                Decimal::from_atomics(1_61_u128, 2).unwrap(),
+                Decimal::from_atomics(1_59_u128, 2).unwrap(),
                 None,
                 &coins(10_000_000, NATIVE_DENOM),
             )
@@ -3474,8 +3870,8 @@ mod score_market {
         let query_bets = blockchain_contract.query_bets().unwrap();
         assert_eq!(10_000_000, query_bets.total_amounts.home);
         assert_eq!(10_000_000, query_bets.total_amounts.away);
-        assert_eq!(19_100_000, query_bets.potential_payouts.home);
-        assert_eq!(16_100_000, query_bets.potential_payouts.away);
+        assert_eq!(18_750_000, query_bets.potential_payouts.home);
+        assert_eq!(15_920_000, query_bets.potential_payouts.away);
 
         blockchain_contract.blockchain.update_block(|block| {
             block.time = Timestamp::from_seconds(
@@ -3509,7 +3905,7 @@ mod score_market {
             )
             .unwrap();
         assert_eq!(
-            103_900_000_u128 - 100_000_000_u128,
+            104_080_000_u128 - 100_000_000_u128,
             treasury_balance.amount.into()
         );
 
@@ -3546,6 +3942,7 @@ mod score_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -3554,6 +3951,13 @@ mod score_market {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -3602,6 +4006,7 @@ mod score_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -3610,6 +4015,13 @@ mod score_market {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -3659,6 +4071,7 @@ mod score_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -3667,6 +4080,13 @@ mod score_market {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -3736,6 +4156,7 @@ mod cancel_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -3744,6 +4165,13 @@ mod cancel_market {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -3816,6 +4244,7 @@ mod cancel_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -3828,6 +4257,13 @@ mod cancel_market {
                     .expect("Time went backwards")
                     .as_secs()
                     + 60 * 5, // 5 minutes from now
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -3868,6 +4304,7 @@ mod cancel_market {
                 denom_precision: NATIVE_DENOM_PRECISION,
                 id: "game-cs2-test-league".to_string(),
                 label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
                 home_team: "Team A".to_string(),
                 away_team: "Team B".to_string(),
                 fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
@@ -3876,6 +4313,13 @@ mod cancel_market {
                 initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
                 initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
                 start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
             },
             coins(100_000_000, NATIVE_DENOM),
         )
@@ -3909,3 +4353,8919 @@ mod cancel_market {
         assert_ne!(Status::CANCELLED, query_market.market.status);
     }
 }
+
+mod totals_market {
+    use super::*;
+    use fixed_odds_market::state::MarketKind;
+
+    #[test]
+    fn it_properly_creates_a_totals_market() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![(
+                MockApiBech32::new("neutron").addr_make(ADMIN),
+                coins(INITIAL_BALANCE, NATIVE_DENOM),
+            )],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B - Total maps".to_string(),
+                kind: Some(MarketKind::TOTALS {
+                    line: Decimal::from_atomics(2_5_u128, 1).unwrap(), // 2.5
+                }),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(
+            MarketKind::TOTALS {
+                line: Decimal::from_atomics(2_5_u128, 1).unwrap()
+            },
+            query_market.market.kind
+        );
+        assert_eq!(Status::ACTIVE, query_market.market.status);
+        assert_eq!(
+            Decimal::from_atomics(1_91_u128, 2).unwrap(),
+            query_market.market.home_odds
+        );
+        assert_eq!(
+            Decimal::from_atomics(1_56_u128, 2).unwrap(),
+            query_market.market.away_odds
+        );
+    }
+
+    #[test]
+    fn it_cant_create_a_totals_market_with_invalid_line() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let err = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![(
+                MockApiBech32::new("neutron").addr_make(ADMIN),
+                coins(INITIAL_BALANCE, NATIVE_DENOM),
+            )],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B - Total maps".to_string(),
+                kind: Some(MarketKind::TOTALS {
+                    line: Decimal::from_atomics(2_25_u128, 2).unwrap(), // 2.25
+                }),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidLine(Decimal::from_atomics(2_25_u128, 2).unwrap()),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_properly_scores_a_totals_market_and_claims_winnings() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(OTHER),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B - Total maps".to_string(),
+                kind: Some(MarketKind::TOTALS {
+                    line: Decimal::from_atomics(2_5_u128, 1).unwrap(), // 2.5
+                }),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let user_a = blockchain_contract.blockchain.api().addr_make(USER_A);
+        blockchain_contract
+            .place_bet(
+                &user_a,
+                MarketResult::OVER,
+                Decimal::from_atomics(1_87_u128, 2).unwrap(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let other = blockchain_contract.blockchain.api().addr_make(OTHER);
+        blockchain_contract
+            .place_bet(
+                &other,
+                MarketResult::UNDER,
+                Decimal::from_atomics(1_60_u128, 2).unwrap(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(10_000_000, query_bets.total_amounts.home);
+        assert_eq!(5_000_000, query_bets.total_amounts.away);
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market_total(&MockApiBech32::new("neutron").addr_make(ADMIN), 3)
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::CLOSED, query_market.market.status);
+        assert_eq!(MarketResult::OVER, query_market.market.result.unwrap());
+
+        blockchain_contract.claim_winnings(&user_a, None).unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(&user_a, NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(
+            INITIAL_BALANCE + 8_750_000_u128,
+            user_a_balance.amount.into()
+        );
+
+        let err = blockchain_contract
+            .claim_winnings(&other, None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NoWinnings {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let admin_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(MockApiBech32::new("neutron").addr_make(ADMIN), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(
+            INITIAL_BALANCE - (8_750_000_u128 - 5_000_000_u128),
+            admin_balance.amount.into()
+        );
+
+        let contract_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(blockchain_contract.addr(), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(0_u128, contract_balance.amount.into());
+    }
+
+    #[test]
+    fn it_refunds_all_bets_when_the_total_lands_on_the_line() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(OTHER),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B - Total maps".to_string(),
+                kind: Some(MarketKind::TOTALS {
+                    line: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                }),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let user_a = blockchain_contract.blockchain.api().addr_make(USER_A);
+        blockchain_contract
+            .place_bet(
+                &user_a,
+                MarketResult::OVER,
+                Decimal::from_atomics(1_87_u128, 2).unwrap(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let other = blockchain_contract.blockchain.api().addr_make(OTHER);
+        blockchain_contract
+            .place_bet(
+                &other,
+                MarketResult::UNDER,
+                Decimal::from_atomics(1_60_u128, 2).unwrap(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market_total(&MockApiBech32::new("neutron").addr_make(ADMIN), 3)
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::CLOSED, query_market.market.status);
+        assert_eq!(MarketResult::PUSH, query_market.market.result.unwrap());
+
+        let admin_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(MockApiBech32::new("neutron").addr_make(ADMIN), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, admin_balance.amount.into());
+
+        blockchain_contract.claim_winnings(&user_a, None).unwrap();
+        blockchain_contract.claim_winnings(&other, None).unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(&user_a, NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_a_balance.amount.into());
+
+        let other_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(&other, NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, other_balance.amount.into());
+
+        let contract_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(blockchain_contract.addr(), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(0_u128, contract_balance.amount.into());
+    }
+
+    #[test]
+    fn it_cant_place_bet_on_a_result_of_another_market_kind() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B - Total maps".to_string(),
+                kind: Some(MarketKind::TOTALS {
+                    line: Decimal::from_atomics(2_5_u128, 1).unwrap(), // 2.5
+                }),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let err = blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidMarketResult(MarketResult::HOME),
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::PUSH,
+                Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidMarketResult(MarketResult::PUSH),
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        let err = blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::HOME,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidMarketResult(MarketResult::HOME),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_score_a_moneyline_market_with_a_total() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![(
+                MockApiBech32::new("neutron").addr_make(ADMIN),
+                coins(INITIAL_BALANCE, NATIVE_DENOM),
+            )],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        let err = blockchain_contract
+            .score_market_total(&MockApiBech32::new("neutron").addr_make(ADMIN), 3)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidMarketKind {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod handicap_market {
+    use super::*;
+    use cosmwasm_std::SignedDecimal;
+    use fixed_odds_market::state::{MarketKind, SettlementOutcome};
+
+    #[test]
+    fn it_cant_create_a_handicap_market_with_invalid_handicap() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let err = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![(
+                MockApiBech32::new("neutron").addr_make(ADMIN),
+                coins(INITIAL_BALANCE, NATIVE_DENOM),
+            )],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B - Handicap".to_string(),
+                kind: Some(MarketKind::HANDICAP {
+                    handicap: SignedDecimal::from_atomics(-30_i128, 2).unwrap(), // -0.30
+                }),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidHandicap(SignedDecimal::from_atomics(-30_i128, 2).unwrap()),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_create_a_handicap_market_with_a_handicap_above_the_max() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        // -100.25, +101
+        for handicap in [-10025_i128, 10100_i128] {
+            let err = setup_blockchain_and_contract(
+                MockApiBech32::new("neutron").addr_make(ADMIN),
+                vec![(
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                )],
+                InstantiateMsg {
+                    admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                    treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                    denom: NATIVE_DENOM.to_string(),
+                    denom_precision: NATIVE_DENOM_PRECISION,
+                    id: "game-cs2-test-league".to_string(),
+                    label: "CS2 - Test League - Team A vs Team B - Handicap".to_string(),
+                    kind: Some(MarketKind::HANDICAP {
+                        handicap: SignedDecimal::from_atomics(handicap, 2).unwrap(),
+                    }),
+                    home_team: "Team A".to_string(),
+                    away_team: "Team B".to_string(),
+                    fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                    max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                    seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                    initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                    initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                    start_timestamp,
+                    parlay_addr: None,
+                    live: None,
+                    resolution_grace_period: None,
+                    postponement_period: None,
+                    pricing_model: None,
+                    trader_pubkey: None,
+                    metadata: None,
+                },
+                coins(100_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+
+            assert_eq!(
+                ContractError::InvalidHandicap(SignedDecimal::from_atomics(handicap, 2).unwrap()),
+                err.downcast::<ContractError>().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn it_properly_settles_half_win_and_half_lose_on_quarter_lines() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(OTHER),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B - Handicap".to_string(),
+                kind: Some(MarketKind::HANDICAP {
+                    handicap: SignedDecimal::from_atomics(-75_i128, 2).unwrap(), // -0.75
+                }),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let user_a = blockchain_contract.blockchain.api().addr_make(USER_A);
+        blockchain_contract
+            .place_bet(
+                &user_a,
+                MarketResult::HOME,
+                Decimal::from_atomics(1_87_u128, 2).unwrap(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let other = blockchain_contract.blockchain.api().addr_make(OTHER);
+        blockchain_contract
+            .place_bet(
+                &other,
+                MarketResult::AWAY,
+                Decimal::from_atomics(1_60_u128, 2).unwrap(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market_final(&MockApiBech32::new("neutron").addr_make(ADMIN), 2, 1)
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::CLOSED, query_market.market.status);
+        assert_eq!(MarketResult::HOME, query_market.market.result.unwrap());
+        let settlement = query_market.market.settlement.unwrap();
+        assert_eq!(SettlementOutcome::HALF_WIN, settlement.home);
+        assert_eq!(SettlementOutcome::HALF_LOSE, settlement.away);
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        // Half of 10_000_000 paid at 1.875 and half refunded, half of 5_000_000 refunded
+        let admin_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(&admin, NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(
+            INITIAL_BALANCE - (14_375_000_u128 + 2_500_000_u128 - 15_000_000_u128),
+            admin_balance.amount.into()
+        );
+
+        blockchain_contract.claim_winnings(&user_a, None).unwrap();
+        blockchain_contract.claim_winnings(&other, None).unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(&user_a, NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(
+            INITIAL_BALANCE + 4_375_000_u128,
+            user_a_balance.amount.into()
+        );
+
+        let other_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(&other, NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(
+            INITIAL_BALANCE - 2_500_000_u128,
+            other_balance.amount.into()
+        );
+
+        let contract_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(blockchain_contract.addr(), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(0_u128, contract_balance.amount.into());
+    }
+
+    #[test]
+    fn it_refunds_all_bets_when_the_handicap_is_pushed() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(OTHER),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B - Handicap".to_string(),
+                kind: Some(MarketKind::HANDICAP {
+                    handicap: SignedDecimal::from_atomics(-1_00_i128, 2).unwrap(), // -1
+                }),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let user_a = blockchain_contract.blockchain.api().addr_make(USER_A);
+        blockchain_contract
+            .place_bet(
+                &user_a,
+                MarketResult::HOME,
+                Decimal::from_atomics(1_87_u128, 2).unwrap(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let other = blockchain_contract.blockchain.api().addr_make(OTHER);
+        blockchain_contract
+            .place_bet(
+                &other,
+                MarketResult::AWAY,
+                Decimal::from_atomics(1_60_u128, 2).unwrap(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market_final(&MockApiBech32::new("neutron").addr_make(ADMIN), 2, 1)
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(MarketResult::PUSH, query_market.market.result.unwrap());
+        let settlement = query_market.market.settlement.unwrap();
+        assert_eq!(SettlementOutcome::PUSH, settlement.home);
+        assert_eq!(SettlementOutcome::PUSH, settlement.away);
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+
+        let admin_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(&admin, NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, admin_balance.amount.into());
+
+        blockchain_contract.claim_winnings(&user_a, None).unwrap();
+        blockchain_contract.claim_winnings(&other, None).unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(&user_a, NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_a_balance.amount.into());
+
+        let other_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(&other, NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, other_balance.amount.into());
+    }
+
+    #[test]
+    fn it_properly_settles_full_wins_and_losses() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(OTHER),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B - Handicap".to_string(),
+                kind: Some(MarketKind::HANDICAP {
+                    handicap: SignedDecimal::from_atomics(1_50_i128, 2).unwrap(), // +1.5
+                }),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let user_a = blockchain_contract.blockchain.api().addr_make(USER_A);
+        blockchain_contract
+            .place_bet(
+                &user_a,
+                MarketResult::HOME,
+                Decimal::from_atomics(1_87_u128, 2).unwrap(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let other = blockchain_contract.blockchain.api().addr_make(OTHER);
+        blockchain_contract
+            .place_bet(
+                &other,
+                MarketResult::AWAY,
+                Decimal::from_atomics(1_60_u128, 2).unwrap(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market_final(&MockApiBech32::new("neutron").addr_make(ADMIN), 0, 1)
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(MarketResult::HOME, query_market.market.result.unwrap());
+
+        blockchain_contract.claim_winnings(&user_a, None).unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(&user_a, NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(
+            INITIAL_BALANCE + 8_750_000_u128,
+            user_a_balance.amount.into()
+        );
+
+        let err = blockchain_contract
+            .claim_winnings(&other, None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NoWinnings {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_can_only_score_a_handicap_market_with_the_final_score() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![(
+                MockApiBech32::new("neutron").addr_make(ADMIN),
+                coins(INITIAL_BALANCE, NATIVE_DENOM),
+            )],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B - Handicap".to_string(),
+                kind: Some(MarketKind::HANDICAP {
+                    handicap: SignedDecimal::from_atomics(-1_50_i128, 2).unwrap(), // -1.5
+                }),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        let err = blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::HOME,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidMarketKind {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .score_market_total(&MockApiBech32::new("neutron").addr_make(ADMIN), 3)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidMarketKind {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_score_a_moneyline_market_with_the_final_score() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![(
+                MockApiBech32::new("neutron").addr_make(ADMIN),
+                coins(INITIAL_BALANCE, NATIVE_DENOM),
+            )],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        let err = blockchain_contract
+            .score_market_final(&MockApiBech32::new("neutron").addr_make(ADMIN), 2, 1)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidMarketKind {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod parlay_liability {
+    use super::*;
+    use fixed_odds_market::msg::UpdateParams;
+
+    #[test]
+    fn it_properly_reserves_parlay_liability_and_pays_it_when_the_side_wins() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![(
+                MockApiBech32::new("neutron").addr_make(ADMIN),
+                coins(INITIAL_BALANCE, NATIVE_DENOM),
+            )],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: Some(MockApiBech32::new("neutron").addr_make(PARLAY)),
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .reserve_parlay_liability(
+                &MockApiBech32::new("neutron").addr_make(PARLAY),
+                MarketResult::HOME,
+                9_100_000,
+            )
+            .unwrap();
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(0, query_bets.total_amounts.home);
+        assert_eq!(9_100_000, query_bets.potential_payouts.home);
+        assert_eq!(9_100_000, query_bets.parlay_liabilities.home);
+        assert_eq!(0, query_bets.potential_payouts.away);
+        assert_eq!(0, query_bets.parlay_liabilities.away);
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::HOME,
+            )
+            .unwrap();
+
+        let parlay_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(PARLAY),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(9_100_000_u128, parlay_balance.amount.into());
+
+        let admin_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(MockApiBech32::new("neutron").addr_make(ADMIN), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE - 9_100_000, admin_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_doesnt_pay_parlay_liability_when_the_side_loses() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![(
+                MockApiBech32::new("neutron").addr_make(ADMIN),
+                coins(INITIAL_BALANCE, NATIVE_DENOM),
+            )],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: Some(MockApiBech32::new("neutron").addr_make(PARLAY)),
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .reserve_parlay_liability(
+                &MockApiBech32::new("neutron").addr_make(PARLAY),
+                MarketResult::HOME,
+                9_100_000,
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::AWAY,
+            )
+            .unwrap();
+
+        let parlay_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(PARLAY),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(0_u128, parlay_balance.amount.into());
+
+        let admin_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(MockApiBech32::new("neutron").addr_make(ADMIN), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, admin_balance.amount.u128());
+    }
+
+    #[test]
+    fn unauthorized() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![(
+                MockApiBech32::new("neutron").addr_make(ADMIN),
+                coins(INITIAL_BALANCE, NATIVE_DENOM),
+            )],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: Some(MockApiBech32::new("neutron").addr_make(PARLAY)),
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let err = blockchain_contract
+            .reserve_parlay_liability(
+                &MockApiBech32::new("neutron").addr_make(OTHER),
+                MarketResult::HOME,
+                9_100_000,
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::Unauthorized {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_reserve_more_than_the_max_liability() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![(
+                MockApiBech32::new("neutron").addr_make(ADMIN),
+                coins(INITIAL_BALANCE, NATIVE_DENOM),
+            )],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: Some(MockApiBech32::new("neutron").addr_make(PARLAY)),
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let err = blockchain_contract
+            .reserve_parlay_liability(
+                &MockApiBech32::new("neutron").addr_make(PARLAY),
+                MarketResult::HOME,
+                100_000_000,
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::MaxLiabilityExceeded {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_change_the_parlay_contract_while_parlay_liability_is_reserved() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![(
+                MockApiBech32::new("neutron").addr_make(ADMIN),
+                coins(INITIAL_BALANCE, NATIVE_DENOM),
+            )],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: Some(MockApiBech32::new("neutron").addr_make(PARLAY)),
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .reserve_parlay_liability(
+                &MockApiBech32::new("neutron").addr_make(PARLAY),
+                MarketResult::HOME,
+                9_100_000,
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .update_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                UpdateParams {
+                    admin_addr: None,
+                    treasury_addr: None,
+                    start_timestamp: None,
+                    fee_spread_odds: None,
+                    max_bet_risk_factor: None,
+                    seed_liquidity_amplifier: None,
+                    initial_odds_home: None,
+                    initial_odds_away: None,
+                    parlay_addr: Some(MockApiBech32::new("neutron").addr_make(OTHER)),
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::ParlayLiabilityReserved {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let query_config = blockchain_contract.query_config().unwrap();
+        assert_eq!(
+            Some(MockApiBech32::new("neutron").addr_make(PARLAY)),
+            query_config.config.parlay_addr
+        );
+    }
+}
+
+mod live_market {
+    use super::*;
+    use fixed_odds_market::msg::UpdateParams;
+
+    #[test]
+    fn it_properly_places_and_confirms_an_in_play_bet_after_the_bet_delay() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: Some(LiveConfig {
+                    trader_addr: MockApiBech32::new("neutron").addr_make(TRADER),
+                    bet_delay: 10,
+                }),
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(start_timestamp); // In play
+        });
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::from_atomics(1_87_u128, 2).unwrap(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_pending_bet = blockchain_contract.query_pending_bet(1).unwrap();
+        assert_eq!(
+            MockApiBech32::new("neutron").addr_make(USER_A),
+            query_pending_bet.pending_bet.addr
+        );
+        assert_eq!(MarketResult::HOME, query_pending_bet.pending_bet.result);
+        assert_eq!(10_000_000, query_pending_bet.pending_bet.bet_amount);
+        assert_eq!(
+            Decimal::from_atomics(1_875_u128, 3).unwrap(),
+            query_pending_bet.pending_bet.odds
+        );
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(0, query_bets.total_amounts.home);
+        assert_eq!(0, query_bets.potential_payouts.home);
+
+        let err = blockchain_contract
+            .confirm_bet(&MockApiBech32::new("neutron").addr_make(ANYONE), 1)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::BetDelayNotElapsed {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(start_timestamp + 10); // After the bet delay
+        });
+
+        blockchain_contract
+            .confirm_bet(&MockApiBech32::new("neutron").addr_make(ANYONE), 1)
+            .unwrap();
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(10_000_000, query_bets.total_amounts.home);
+        assert_eq!(18_750_000, query_bets.potential_payouts.home);
+
+        let query_bets_by_address = blockchain_contract
+            .query_bets_by_address(&MockApiBech32::new("neutron").addr_make(USER_A))
+            .unwrap();
+        assert_eq!(10_000_000, query_bets_by_address.all_bets.home.bet_amount);
+        assert_eq!(18_750_000, query_bets_by_address.all_bets.home.payout);
+        assert!(blockchain_contract.query_pending_bet(1).is_err());
+    }
+
+    #[test]
+    fn it_refunds_the_pending_bet_when_the_market_was_suspended_in_the_meantime() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: Some(LiveConfig {
+                    trader_addr: MockApiBech32::new("neutron").addr_make(TRADER),
+                    bet_delay: 10,
+                }),
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(start_timestamp); // In play
+        });
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::from_atomics(1_87_u128, 2).unwrap(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .suspend_market(&MockApiBech32::new("neutron").addr_make(TRADER))
+            .unwrap();
+        blockchain_contract
+            .resume_market(&MockApiBech32::new("neutron").addr_make(TRADER))
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(start_timestamp + 10); // After the bet delay
+        });
+
+        blockchain_contract
+            .confirm_bet(&MockApiBech32::new("neutron").addr_make(ANYONE), 1)
+            .unwrap();
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(0, query_bets.total_amounts.home);
+        assert_eq!(0, query_bets.potential_payouts.home);
+        assert_eq!(
+            INITIAL_BALANCE,
+            blockchain_contract
+                .blockchain
+                .wrap()
+                .query_balance(
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    NATIVE_DENOM
+                )
+                .unwrap()
+                .amount
+                .u128()
+        );
+        assert!(blockchain_contract.query_pending_bet(1).is_err());
+    }
+
+    #[test]
+    fn it_refunds_the_pending_bet_when_the_odds_moved_in_the_meantime() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: Some(LiveConfig {
+                    trader_addr: MockApiBech32::new("neutron").addr_make(TRADER),
+                    bet_delay: 10,
+                }),
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(start_timestamp); // In play
+        });
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::from_atomics(1_87_u128, 2).unwrap(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .update_odds(
+                &MockApiBech32::new("neutron").addr_make(TRADER),
+                Decimal::from_atomics(3_u128, 0).unwrap(),
+                Decimal::from_atomics(15_u128, 1).unwrap(),
+            )
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(1, query_market.market.odds_revision);
+        assert_eq!(
+            Decimal::from_atomics(3_u128, 0).unwrap(),
+            blockchain_contract
+                .query_config()
+                .unwrap()
+                .config
+                .initial_odds_home
+        );
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(start_timestamp + 10); // After the bet delay
+        });
+
+        blockchain_contract
+            .confirm_bet(&MockApiBech32::new("neutron").addr_make(ANYONE), 1)
+            .unwrap();
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(0, query_bets.total_amounts.home);
+        assert_eq!(0, query_bets.potential_payouts.home);
+        assert_eq!(
+            INITIAL_BALANCE,
+            blockchain_contract
+                .blockchain
+                .wrap()
+                .query_balance(
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    NATIVE_DENOM
+                )
+                .unwrap()
+                .amount
+                .u128()
+        );
+        assert!(blockchain_contract.query_pending_bet(1).is_err());
+    }
+
+    #[test]
+    fn it_refunds_the_pending_bet_when_the_admin_updated_the_odds_in_the_meantime() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: Some(LiveConfig {
+                    trader_addr: MockApiBech32::new("neutron").addr_make(TRADER),
+                    bet_delay: 10,
+                }),
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(start_timestamp); // In play
+        });
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::from_atomics(1_87_u128, 2).unwrap(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .update_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                UpdateParams {
+                    admin_addr: None,
+                    treasury_addr: None,
+                    fee_spread_odds: None,
+                    max_bet_risk_factor: None,
+                    seed_liquidity_amplifier: None,
+                    initial_odds_home: Some(Decimal::from_atomics(3_u128, 0).unwrap()),
+                    initial_odds_away: Some(Decimal::from_atomics(15_u128, 1).unwrap()),
+                    start_timestamp: None,
+                    parlay_addr: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
+                },
+            )
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(1, query_market.market.odds_revision);
+        assert_eq!(
+            Decimal::from_atomics(3_u128, 0).unwrap(),
+            blockchain_contract
+                .query_config()
+                .unwrap()
+                .config
+                .initial_odds_home
+        );
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(start_timestamp + 10); // After the bet delay
+        });
+
+        let response = blockchain_contract
+            .confirm_bet(&MockApiBech32::new("neutron").addr_make(ANYONE), 1)
+            .unwrap();
+        assert!(response.events.iter().any(|event| event
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "status" && attribute.value == "REFUNDED")));
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(0, query_bets.total_amounts.home);
+        assert_eq!(0, query_bets.potential_payouts.home);
+        assert_eq!(
+            INITIAL_BALANCE,
+            blockchain_contract
+                .blockchain
+                .wrap()
+                .query_balance(
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    NATIVE_DENOM
+                )
+                .unwrap()
+                .amount
+                .u128()
+        );
+        assert!(blockchain_contract.query_pending_bet(1).is_err());
+    }
+
+    #[test]
+    fn pending_bets_are_not_part_of_the_market_balance() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: Some(LiveConfig {
+                    trader_addr: MockApiBech32::new("neutron").addr_make(TRADER),
+                    bet_delay: 10,
+                }),
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(start_timestamp); // In play
+        });
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::from_atomics(1_87_u128, 2).unwrap(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::HOME,
+            )
+            .unwrap();
+
+        assert_eq!(
+            INITIAL_BALANCE,
+            blockchain_contract
+                .blockchain
+                .wrap()
+                .query_balance(MockApiBech32::new("neutron").addr_make(ADMIN), NATIVE_DENOM)
+                .unwrap()
+                .amount
+                .u128()
+        );
+        assert_eq!(
+            10_000_000,
+            blockchain_contract
+                .blockchain
+                .wrap()
+                .query_balance(blockchain_contract.addr(), NATIVE_DENOM)
+                .unwrap()
+                .amount
+                .u128()
+        );
+
+        blockchain_contract
+            .confirm_bet(&MockApiBech32::new("neutron").addr_make(ANYONE), 1)
+            .unwrap();
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(0, query_bets.total_amounts.home);
+        assert_eq!(0, query_bets.potential_payouts.home);
+        assert_eq!(
+            INITIAL_BALANCE,
+            blockchain_contract
+                .blockchain
+                .wrap()
+                .query_balance(
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    NATIVE_DENOM
+                )
+                .unwrap()
+                .amount
+                .u128()
+        );
+        assert!(blockchain_contract.query_pending_bet(1).is_err());
+    }
+
+    #[test]
+    fn it_cant_place_bets_while_the_market_is_suspended() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: Some(LiveConfig {
+                    trader_addr: MockApiBech32::new("neutron").addr_make(TRADER),
+                    bet_delay: 10,
+                }),
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .suspend_market(&MockApiBech32::new("neutron").addr_make(TRADER))
+            .unwrap();
+
+        let query_max_bets = blockchain_contract.query_max_bets().unwrap();
+        assert_eq!(0, query_max_bets.home);
+        assert_eq!(0, query_max_bets.away);
+
+        let err = blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketSuspended {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .suspend_market(&MockApiBech32::new("neutron").addr_make(TRADER))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketSuspended {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract
+            .resume_market(&MockApiBech32::new("neutron").addr_make(TRADER))
+            .unwrap();
+
+        let err = blockchain_contract
+            .resume_market(&MockApiBech32::new("neutron").addr_make(TRADER))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotSuspended {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn unauthorized() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: Some(LiveConfig {
+                    trader_addr: MockApiBech32::new("neutron").addr_make(TRADER),
+                    bet_delay: 10,
+                }),
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let err = blockchain_contract
+            .suspend_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .update_odds(
+                &MockApiBech32::new("neutron").addr_make(OTHER),
+                Decimal::from_atomics(3_u128, 0).unwrap(),
+                Decimal::from_atomics(15_u128, 1).unwrap(),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn live_mode_not_enabled() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(start_timestamp); // In play
+        });
+
+        let err = blockchain_contract
+            .suspend_market(&MockApiBech32::new("neutron").addr_make(TRADER))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::LiveModeNotEnabled {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::BetsNotAccepted {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_create_a_market_with_an_invalid_bet_delay() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let err = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![(
+                MockApiBech32::new("neutron").addr_make(ADMIN),
+                coins(INITIAL_BALANCE, NATIVE_DENOM),
+            )],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: Some(LiveConfig {
+                    trader_addr: MockApiBech32::new("neutron").addr_make(TRADER),
+                    bet_delay: 10 * 60, // 10 minutes
+                }),
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidBetDelay(10 * 60),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod odds {
+    use super::*;
+    use fixed_odds_market::state::MarketKind;
+
+    #[test]
+    fn it_properly_returns_the_odds_in_each_format() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![(
+                MockApiBech32::new("neutron").addr_make(ADMIN),
+                coins(INITIAL_BALANCE, NATIVE_DENOM),
+            )],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let home_implied_probability =
+            Decimal::one() / Decimal::from_atomics(1_91_u128, 2).unwrap();
+        let away_implied_probability =
+            Decimal::one() / Decimal::from_atomics(1_56_u128, 2).unwrap();
+
+        let query_odds = blockchain_contract.query_odds(OddsFormat::DECIMAL).unwrap();
+        assert_eq!(OddsFormat::DECIMAL, query_odds.format);
+        assert_eq!(2, query_odds.outcomes.len());
+        assert_eq!(MarketResult::HOME, query_odds.outcomes[0].result);
+        assert_eq!(Some("1.91".to_string()), query_odds.outcomes[0].odds);
+        assert_eq!(
+            home_implied_probability,
+            query_odds.outcomes[0].implied_probability
+        );
+        assert_eq!(MarketResult::AWAY, query_odds.outcomes[1].result);
+        assert_eq!(Some("1.56".to_string()), query_odds.outcomes[1].odds);
+        assert_eq!(
+            away_implied_probability,
+            query_odds.outcomes[1].implied_probability
+        );
+        assert_eq!(
+            home_implied_probability + away_implied_probability - Decimal::one(),
+            query_odds.overround
+        );
+
+        let query_odds = blockchain_contract
+            .query_odds(OddsFormat::AMERICAN)
+            .unwrap();
+        assert_eq!(Some("-110".to_string()), query_odds.outcomes[0].odds);
+        assert_eq!(Some("-179".to_string()), query_odds.outcomes[1].odds);
+
+        let query_odds = blockchain_contract
+            .query_odds(OddsFormat::FRACTIONAL)
+            .unwrap();
+        assert_eq!(Some("91/100".to_string()), query_odds.outcomes[0].odds);
+        assert_eq!(Some("14/25".to_string()), query_odds.outcomes[1].odds);
+    }
+
+    #[test]
+    fn it_returns_the_over_and_under_odds_on_a_totals_market() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![(
+                MockApiBech32::new("neutron").addr_make(ADMIN),
+                coins(INITIAL_BALANCE, NATIVE_DENOM),
+            )],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: Some(MarketKind::TOTALS {
+                    line: Decimal::from_atomics(25_u128, 1).unwrap(),
+                }),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let query_odds = blockchain_contract.query_odds(OddsFormat::DECIMAL).unwrap();
+        assert_eq!(MarketResult::OVER, query_odds.outcomes[0].result);
+        assert_eq!(MarketResult::UNDER, query_odds.outcomes[1].result);
+    }
+}
+
+mod exposure {
+    use super::*;
+    use fixed_odds_market::state::{MarketKind, Settlement, SettlementOutcome};
+
+    #[test]
+    fn it_properly_reports_scenarios_matching_the_score_amounts() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(30_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        let query_exposure = blockchain_contract.query_exposure().unwrap();
+        assert_eq!(2, query_exposure.scenarios.len());
+
+        let home_scenario = query_exposure.scenarios[0].clone();
+        assert_eq!(MarketResult::HOME, home_scenario.result);
+        assert_eq!(
+            Settlement {
+                home: SettlementOutcome::WIN,
+                away: SettlementOutcome::LOSE,
+            },
+            home_scenario.settlement
+        );
+        assert_eq!(40_000_000, home_scenario.stakes);
+        assert_eq!(query_bets.potential_payouts.home, home_scenario.payout);
+        assert_eq!(0, home_scenario.parlay_payout);
+        assert_eq!(
+            40_000_000 - query_bets.potential_payouts.home,
+            home_scenario.profit
+        );
+        assert_eq!(0, home_scenario.loss);
+
+        let away_scenario = query_exposure.scenarios[1].clone();
+        assert_eq!(MarketResult::AWAY, away_scenario.result);
+        assert_eq!(40_000_000, away_scenario.stakes);
+        assert_eq!(query_bets.potential_payouts.away, away_scenario.payout);
+        assert_eq!(0, away_scenario.profit);
+        assert_eq!(
+            query_bets.potential_payouts.away - 40_000_000,
+            away_scenario.loss
+        );
+
+        assert_eq!(Some(away_scenario.clone()), query_exposure.worst_case);
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::AWAY,
+            )
+            .unwrap();
+
+        let treasury_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(TREASURY),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(away_scenario.profit, treasury_balance.amount.u128());
+
+        let admin_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(MockApiBech32::new("neutron").addr_make(ADMIN), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(
+            INITIAL_BALANCE - away_scenario.loss,
+            admin_balance.amount.u128()
+        );
+
+        let contract_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(blockchain_contract.addr(), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(away_scenario.payout, contract_balance.amount.u128());
+
+        let query_exposure = blockchain_contract.query_exposure().unwrap();
+        assert!(query_exposure.scenarios.is_empty());
+        assert_eq!(None, query_exposure.worst_case);
+    }
+
+    #[test]
+    fn it_properly_reports_the_push_scenario_on_whole_totals_lines() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: Some(MarketKind::TOTALS {
+                    line: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                }),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::OVER,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_exposure = blockchain_contract.query_exposure().unwrap();
+        let results: Vec<MarketResult> = query_exposure
+            .scenarios
+            .iter()
+            .map(|scenario| scenario.result.clone())
+            .collect();
+        assert_eq!(
+            vec![MarketResult::OVER, MarketResult::UNDER, MarketResult::PUSH],
+            results
+        );
+
+        let push_scenario = query_exposure.scenarios[2].clone();
+        assert_eq!(10_000_000, push_scenario.payout);
+        assert_eq!(0, push_scenario.profit);
+        assert_eq!(0, push_scenario.loss);
+
+        let under_scenario = query_exposure.scenarios[1].clone();
+        assert_eq!(0, under_scenario.payout);
+        assert_eq!(10_000_000, under_scenario.profit);
+
+        assert_eq!(
+            Some(query_exposure.scenarios[0].clone()),
+            query_exposure.worst_case
+        );
+    }
+}
+
+mod void_market {
+    use super::*;
+    use fixed_odds_market::state::VoidReason;
+
+    #[test]
+    fn it_properly_voids_the_market_refunds_the_bets_and_returns_the_seed() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        let response = blockchain_contract
+            .void_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                VoidReason::ABANDONED,
+            )
+            .unwrap();
+        assert!(response.events.iter().any(|event| event
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "reason" && attribute.value == "ABANDONED")));
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::VOIDED, query_market.market.status);
+        assert_eq!(Some(VoidReason::ABANDONED), query_market.market.void_reason);
+        assert_eq!(None, query_market.market.result);
+
+        let admin_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(MockApiBech32::new("neutron").addr_make(ADMIN), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, admin_balance.amount.u128());
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_B), None)
+            .unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_a_balance.amount.u128());
+        let user_b_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_B),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_b_balance.amount.u128());
+
+        let contract_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(blockchain_contract.addr(), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(0, contract_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_properly_voids_a_postponed_market() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .postpone_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap();
+
+        let response = blockchain_contract
+            .void_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                VoidReason::OTHER,
+            )
+            .unwrap();
+        assert!(response.events.iter().any(|event| event
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "reason" && attribute.value == "OTHER")));
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::VOIDED, query_market.market.status);
+        assert_eq!(Some(VoidReason::OTHER), query_market.market.void_reason);
+        assert_eq!(None, query_market.market.result);
+
+        let admin_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(MockApiBech32::new("neutron").addr_make(ADMIN), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, admin_balance.amount.u128());
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_B), None)
+            .unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_a_balance.amount.u128());
+        let user_b_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_B),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_b_balance.amount.u128());
+
+        let contract_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(blockchain_contract.addr(), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(0, contract_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_cant_void_the_market_if_sender_isnt_the_admin() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let err = blockchain_contract
+            .void_market(
+                &MockApiBech32::new("neutron").addr_make(ANYONE),
+                VoidReason::ABANDONED,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_score_a_voided_market() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .void_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                VoidReason::INTEGRITY,
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::HOME,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotActive {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::VOIDED, query_market.market.status);
+        assert_eq!(Some(VoidReason::INTEGRITY), query_market.market.void_reason);
+    }
+}
+
+mod expire_market {
+    use super::*;
+
+    #[test]
+    fn it_lets_anyone_expire_the_market_and_returns_the_seed_to_the_admin() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(
+            start_timestamp + 7 * 24 * 60 * 60,
+            query_market.market.resolution_deadline
+        );
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                query_market.market.resolution_deadline, // At the resolution deadline
+            );
+        });
+
+        blockchain_contract
+            .expire_market(&MockApiBech32::new("neutron").addr_make(ANYONE))
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::CANCELLED, query_market.market.status);
+
+        let admin_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(MockApiBech32::new("neutron").addr_make(ADMIN), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, admin_balance.amount.u128());
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_B), None)
+            .unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_a_balance.amount.u128());
+        let user_b_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_B),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_b_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_cant_expire_the_market_before_the_resolution_deadline() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: Some(2 * 24 * 60 * 60), // 2 days,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(
+            start_timestamp + 2 * 24 * 60 * 60,
+            query_market.market.resolution_deadline
+        );
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                query_market.market.resolution_deadline - 1, // 1 second before the resolution deadline
+            );
+        });
+
+        let err = blockchain_contract
+            .expire_market(&MockApiBech32::new("neutron").addr_make(ANYONE))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotExpired {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::ACTIVE, query_market.market.status);
+    }
+
+    #[test]
+    fn it_cant_expire_a_market_that_isnt_active() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::HOME,
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 8 * 24 * 60 * 60, // 8 days after the start timestamp
+            );
+        });
+
+        let err = blockchain_contract
+            .expire_market(&MockApiBech32::new("neutron").addr_make(ANYONE))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotActive {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_create_a_market_with_an_invalid_resolution_grace_period() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let err = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![(
+                MockApiBech32::new("neutron").addr_make(ADMIN),
+                coins(INITIAL_BALANCE, NATIVE_DENOM),
+            )],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: Some(60 * 60), // 1 hour,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidResolutionGracePeriod(60 * 60),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod postpone {
+    use super::*;
+
+    #[test]
+    fn it_properly_postpones_and_reschedules_the_market() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let block_time = blockchain_contract.blockchain.block_info().time.seconds();
+        let query_market = blockchain_contract.query_market().unwrap();
+        let odds_revision = query_market.market.odds_revision;
+
+        blockchain_contract
+            .postpone_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::POSTPONED, query_market.market.status);
+        assert_eq!(
+            Some(block_time + 3 * 24 * 60 * 60),
+            query_market.market.postponement_deadline
+        );
+        assert_eq!(odds_revision + 1, query_market.market.odds_revision);
+
+        let err = blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(1_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotActive {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotClosed {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract
+            .reschedule_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                start_timestamp + 24 * 60 * 60,
+            )
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::ACTIVE, query_market.market.status);
+        assert_eq!(
+            start_timestamp + 24 * 60 * 60,
+            query_market.market.start_timestamp
+        );
+        assert_eq!(
+            start_timestamp + 24 * 60 * 60 + 7 * 24 * 60 * 60,
+            query_market.market.resolution_deadline
+        );
+        assert_eq!(None, query_market.market.postponement_deadline);
+        assert_eq!(odds_revision + 2, query_market.market.odds_revision);
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(1_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(11_000_000, query_bets.total_amounts.home);
+        assert_eq!(5_000_000, query_bets.total_amounts.away);
+    }
+
+    #[test]
+    fn it_properly_withdraws_the_stake_while_postponed() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .mint_bet_slip(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                2_000_000,
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .withdraw_stake(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotPostponed {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract
+            .postpone_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap();
+
+        blockchain_contract
+            .withdraw_stake(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_a_balance.amount.u128());
+
+        let err = blockchain_contract
+            .withdraw_stake(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NoStakeToWithdraw {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .withdraw_stake(&MockApiBech32::new("neutron").addr_make(USER_A), Some("1"))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract
+            .withdraw_stake(&MockApiBech32::new("neutron").addr_make(USER_B), Some("1"))
+            .unwrap();
+
+        let user_b_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_B),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE - 3_000_000, user_b_balance.amount.u128());
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(0, query_bets.total_amounts.home);
+        assert_eq!(3_000_000, query_bets.total_amounts.away);
+        assert_eq!(0, query_bets.potential_payouts.home);
+        assert_eq!(4_806_000, query_bets.potential_payouts.away);
+
+        let query_solvency = blockchain_contract.query_solvency().unwrap();
+        assert_eq!(103_000_000, query_solvency.balance);
+
+        blockchain_contract
+            .cancel_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap();
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_B), None)
+            .unwrap();
+
+        let user_b_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_B),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_b_balance.amount.u128());
+
+        let admin_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(MockApiBech32::new("neutron").addr_make(ADMIN), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, admin_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_properly_refunds_the_bets_once_the_postponement_deadline_passed() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: Some(24 * 60 * 60), // 1 day,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .mint_bet_slip(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                2_000_000,
+            )
+            .unwrap();
+        blockchain_contract
+            .postpone_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        let postponement_deadline = query_market.market.postponement_deadline.unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                postponement_deadline - 1, // 1 second before the postponement deadline
+            );
+        });
+
+        let err = blockchain_contract
+            .expire_market(&MockApiBech32::new("neutron").addr_make(ANYONE))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotExpired {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotClosed {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                postponement_deadline, // At the postponement deadline
+            );
+        });
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::CANCELLED, query_market.market.status);
+
+        let admin_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(MockApiBech32::new("neutron").addr_make(ADMIN), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, admin_balance.amount.u128());
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_B), None)
+            .unwrap();
+        blockchain_contract
+            .claim_by_token(&MockApiBech32::new("neutron").addr_make(USER_B), "1")
+            .unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_a_balance.amount.u128());
+
+        let user_b_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_B),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_b_balance.amount.u128());
+
+        let query_solvency = blockchain_contract.query_solvency().unwrap();
+        assert_eq!(0, query_solvency.balance);
+    }
+
+    #[test]
+    fn it_lets_anyone_expire_the_market_after_the_postponement_deadline() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .postpone_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                query_market.market.postponement_deadline.unwrap(), // At the postponement deadline
+            );
+        });
+
+        let err = blockchain_contract
+            .reschedule_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                start_timestamp + 24 * 60 * 60,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::PostponementExpired {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .withdraw_stake(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::PostponementExpired {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract
+            .expire_market(&MockApiBech32::new("neutron").addr_make(ANYONE))
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::CANCELLED, query_market.market.status);
+
+        let admin_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(MockApiBech32::new("neutron").addr_make(ADMIN), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, admin_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_cant_postpone_if_sender_isnt_the_admin() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let err = blockchain_contract
+            .postpone_market(&MockApiBech32::new("neutron").addr_make(USER_A))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .reschedule_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                start_timestamp + 24 * 60 * 60,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotPostponed {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract
+            .postpone_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap();
+
+        let err = blockchain_contract
+            .postpone_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotActive {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .reschedule_market(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                start_timestamp + 24 * 60 * 60,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .reschedule_market(&MockApiBech32::new("neutron").addr_make(ADMIN), 0)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidTimestamp(0),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_create_a_market_with_an_invalid_postponement_period() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let err = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![(
+                MockApiBech32::new("neutron").addr_make(ADMIN),
+                coins(INITIAL_BALANCE, NATIVE_DENOM),
+            )],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: Some(30 * 60), // 30 minutes
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidPostponementPeriod(30 * 60),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod transfer_position {
+    use super::*;
+    use fixed_odds_market::state::VoidReason;
+
+    #[test]
+    fn it_properly_transfers_part_of_a_position_with_its_share_of_the_payout() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let user_a_bets = blockchain_contract
+            .query_bets_by_address(&MockApiBech32::new("neutron").addr_make(USER_A))
+            .unwrap();
+        let payout = user_a_bets.all_bets.home.payout;
+
+        blockchain_contract
+            .transfer_position(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                4_000_000,
+                &MockApiBech32::new("neutron").addr_make(USER_C),
+            )
+            .unwrap();
+
+        let transferred_payout = Uint128::from(payout)
+            .multiply_ratio(4_000_000_u128, 10_000_000_u128)
+            .u128();
+
+        let user_a_bets = blockchain_contract
+            .query_bets_by_address(&MockApiBech32::new("neutron").addr_make(USER_A))
+            .unwrap();
+        assert_eq!(6_000_000, user_a_bets.all_bets.home.bet_amount);
+        assert_eq!(
+            payout - transferred_payout,
+            user_a_bets.all_bets.home.payout
+        );
+        let user_c_bets = blockchain_contract
+            .query_bets_by_address(&MockApiBech32::new("neutron").addr_make(USER_C))
+            .unwrap();
+        assert_eq!(4_000_000, user_c_bets.all_bets.home.bet_amount);
+        assert_eq!(transferred_payout, user_c_bets.all_bets.home.payout);
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(10_000_000, query_bets.total_amounts.home);
+        assert_eq!(payout, query_bets.potential_payouts.home);
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::HOME,
+            )
+            .unwrap();
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_C), None)
+            .unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(
+            INITIAL_BALANCE - 10_000_000 + payout - transferred_payout,
+            user_a_balance.amount.u128()
+        );
+        let user_c_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_C),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(transferred_payout, user_c_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_properly_transfers_a_whole_position_and_the_claim_follows_the_new_owner() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let user_a_bets = blockchain_contract
+            .query_bets_by_address(&MockApiBech32::new("neutron").addr_make(USER_A))
+            .unwrap();
+        let payout = user_a_bets.all_bets.home.payout;
+
+        blockchain_contract
+            .transfer_position(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                10_000_000,
+                &MockApiBech32::new("neutron").addr_make(USER_C),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::HOME,
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NoWinnings {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_C), None)
+            .unwrap();
+
+        let user_c_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_C),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(payout, user_c_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_properly_transfers_a_position_while_the_market_is_postponed() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .postpone_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap();
+
+        blockchain_contract
+            .transfer_position(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                4_000_000,
+                &MockApiBech32::new("neutron").addr_make(USER_C),
+            )
+            .unwrap();
+
+        let user_a_bets = blockchain_contract
+            .query_bets_by_address(&MockApiBech32::new("neutron").addr_make(USER_A))
+            .unwrap();
+        assert_eq!(6_000_000, user_a_bets.all_bets.home.bet_amount);
+        let user_c_bets = blockchain_contract
+            .query_bets_by_address(&MockApiBech32::new("neutron").addr_make(USER_C))
+            .unwrap();
+        assert_eq!(4_000_000, user_c_bets.all_bets.home.bet_amount);
+
+        blockchain_contract
+            .void_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                VoidReason::OTHER,
+            )
+            .unwrap();
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_C), None)
+            .unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE - 4_000_000, user_a_balance.amount.u128());
+        let user_c_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_C),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(4_000_000, user_c_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_cant_transfer_more_than_the_position() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let user_a_bets = blockchain_contract
+            .query_bets_by_address(&MockApiBech32::new("neutron").addr_make(USER_A))
+            .unwrap();
+
+        let err = blockchain_contract
+            .transfer_position(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                10_000_001,
+                &MockApiBech32::new("neutron").addr_make(USER_C),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidTransferAmount {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .transfer_position(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                0,
+                &MockApiBech32::new("neutron").addr_make(USER_C),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidTransferAmount {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .transfer_position(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::AWAY,
+                1_000,
+                &MockApiBech32::new("neutron").addr_make(USER_C),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidTransferAmount {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        assert_eq!(10_000_000, user_a_bets.all_bets.home.bet_amount);
+    }
+
+    #[test]
+    fn it_cant_transfer_a_position_to_the_sender() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .transfer_position(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                1_000_000,
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidTransferRecipient {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_transfer_a_position_once_the_market_is_settled() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::HOME,
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .transfer_position(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                1_000_000,
+                &MockApiBech32::new("neutron").addr_make(USER_C),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotActive {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod bet_slip {
+    use super::*;
+
+    #[test]
+    fn it_properly_mints_a_bet_slip_with_its_metadata() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let user_a_bets = blockchain_contract
+            .query_bets_by_address(&MockApiBech32::new("neutron").addr_make(USER_A))
+            .unwrap();
+        let payout = user_a_bets.all_bets.home.payout;
+
+        blockchain_contract
+            .mint_bet_slip(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                4_000_000,
+            )
+            .unwrap();
+
+        let slip_payout = Uint128::from(payout)
+            .multiply_ratio(4_000_000_u128, 10_000_000_u128)
+            .u128();
+
+        let query_owner_of = blockchain_contract.query_owner_of("1").unwrap();
+        assert_eq!(
+            MockApiBech32::new("neutron").addr_make(USER_A).to_string(),
+            query_owner_of.owner
+        );
+        assert!(query_owner_of.approvals.is_empty());
+
+        let query_nft_info = blockchain_contract.query_nft_info("1").unwrap();
+        assert_eq!(None, query_nft_info.token_uri);
+        assert_eq!("game-cs2-test-league", query_nft_info.extension.market_id);
+        assert_eq!(MarketResult::HOME, query_nft_info.extension.result);
+        assert_eq!(4_000_000, query_nft_info.extension.stake);
+        assert_eq!(
+            Decimal::from_ratio(slip_payout, 4_000_000_u128),
+            query_nft_info.extension.odds
+        );
+        assert_eq!(slip_payout, query_nft_info.extension.potential_payout);
+
+        let user_a_bets = blockchain_contract
+            .query_bets_by_address(&MockApiBech32::new("neutron").addr_make(USER_A))
+            .unwrap();
+        assert_eq!(6_000_000, user_a_bets.all_bets.home.bet_amount);
+        assert_eq!(payout - slip_payout, user_a_bets.all_bets.home.payout);
+
+        let query_num_tokens = blockchain_contract.query_num_tokens().unwrap();
+        assert_eq!(1, query_num_tokens.count);
+        let query_tokens = blockchain_contract
+            .query_tokens(&MockApiBech32::new("neutron").addr_make(USER_A))
+            .unwrap();
+        assert_eq!(vec!["1".to_string()], query_tokens.tokens);
+    }
+
+    #[test]
+    fn it_lets_the_holder_of_the_bet_slip_claim_it() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let user_a_bets = blockchain_contract
+            .query_bets_by_address(&MockApiBech32::new("neutron").addr_make(USER_A))
+            .unwrap();
+        let payout = user_a_bets.all_bets.home.payout;
+
+        blockchain_contract
+            .mint_bet_slip(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                4_000_000,
+            )
+            .unwrap();
+
+        let slip_payout = Uint128::from(payout)
+            .multiply_ratio(4_000_000_u128, 10_000_000_u128)
+            .u128();
+
+        blockchain_contract
+            .transfer_nft(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                &MockApiBech32::new("neutron").addr_make(USER_C),
+                "1",
+            )
+            .unwrap();
+
+        let query_owner_of = blockchain_contract.query_owner_of("1").unwrap();
+        assert_eq!(
+            MockApiBech32::new("neutron").addr_make(USER_C).to_string(),
+            query_owner_of.owner
+        );
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::HOME,
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .claim_by_token(&MockApiBech32::new("neutron").addr_make(USER_A), "1")
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract
+            .claim_by_token(&MockApiBech32::new("neutron").addr_make(USER_C), "1")
+            .unwrap();
+
+        let user_c_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_C),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(slip_payout, user_c_balance.amount.u128());
+
+        let query_num_tokens = blockchain_contract.query_num_tokens().unwrap();
+        assert_eq!(0, query_num_tokens.count);
+
+        let err = blockchain_contract
+            .claim_by_token(&MockApiBech32::new("neutron").addr_make(USER_C), "1")
+            .unwrap_err();
+        assert_eq!(
+            ContractError::BetSlipNotFound {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(
+            INITIAL_BALANCE - 10_000_000 + payout - slip_payout,
+            user_a_balance.amount.u128()
+        );
+    }
+
+    #[test]
+    fn it_lets_an_approved_spender_transfer_the_bet_slip() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .mint_bet_slip(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                4_000_000,
+            )
+            .unwrap();
+
+        blockchain_contract
+            .approve_nft(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                "1",
+            )
+            .unwrap();
+
+        let query_owner_of = blockchain_contract.query_owner_of("1").unwrap();
+        assert_eq!(1, query_owner_of.approvals.len());
+        assert_eq!(
+            MockApiBech32::new("neutron").addr_make(USER_B),
+            query_owner_of.approvals[0].spender
+        );
+
+        blockchain_contract
+            .transfer_nft(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                &MockApiBech32::new("neutron").addr_make(USER_C),
+                "1",
+            )
+            .unwrap();
+
+        let query_owner_of = blockchain_contract.query_owner_of("1").unwrap();
+        assert_eq!(
+            MockApiBech32::new("neutron").addr_make(USER_C).to_string(),
+            query_owner_of.owner
+        );
+        assert!(query_owner_of.approvals.is_empty());
+
+        let err = blockchain_contract
+            .transfer_nft(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                "1",
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_refunds_the_bet_slip_when_the_market_is_cancelled() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .mint_bet_slip(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                4_000_000,
+            )
+            .unwrap();
+
+        blockchain_contract
+            .cancel_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap();
+
+        blockchain_contract
+            .claim_by_token(&MockApiBech32::new("neutron").addr_make(USER_A), "1")
+            .unwrap();
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_a_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_cant_mint_a_bet_slip_for_more_than_the_position() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let user_a_bets = blockchain_contract
+            .query_bets_by_address(&MockApiBech32::new("neutron").addr_make(USER_A))
+            .unwrap();
+        let payout = user_a_bets.all_bets.home.payout;
+
+        let err = blockchain_contract
+            .mint_bet_slip(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                10_000_001,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidBetSlipAmount {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .mint_bet_slip(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                0,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidBetSlipAmount {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        assert_eq!(10_000_000, user_a_bets.all_bets.home.bet_amount);
+        assert_eq!(payout, user_a_bets.all_bets.home.payout);
+    }
+
+    #[test]
+    fn it_cant_claim_a_bet_slip_before_the_market_is_closed() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(ADMIN),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .mint_bet_slip(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                4_000_000,
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .claim_by_token(&MockApiBech32::new("neutron").addr_make(USER_A), "1")
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotClosed {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod pricing_model {
+    use super::*;
+    use fixed_odds_market::state::PricingModelKind;
+
+    #[test]
+    fn it_defaults_to_the_seed_blend_pricing_model_and_quotes_along_the_curve() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(2_2_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(1_8_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let query_config = blockchain_contract.query_config().unwrap();
+        assert_eq!(
+            PricingModelKind::SEED_BLEND,
+            query_config.config.pricing_model
+        );
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(
+            Decimal::from_atomics(1_91_u128, 2).unwrap(),
+            query_market.market.home_odds
+        );
+        assert_eq!(
+            Decimal::from_atomics(1_56_u128, 2).unwrap(),
+            query_market.market.away_odds
+        );
+
+        let query_quote = blockchain_contract
+            .query_quote(MarketResult::HOME, 10_000_000)
+            .unwrap();
+        assert_eq!(18_750_000, query_quote.payout);
+        assert_eq!(
+            Decimal::from_atomics(1_875_u128, 3).unwrap(),
+            query_quote.odds
+        );
+    }
+
+    #[test]
+    fn it_prices_the_market_with_the_lmsr_pricing_model() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(2_2_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(1_8_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: Some(PricingModelKind::LMSR {
+                    liquidity: Decimal::from_atomics(100_u128, 0).unwrap(), // 100
+                }),
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(
+            Decimal::from_atomics(1_93_u128, 2).unwrap(),
+            query_market.market.home_odds
+        );
+        assert_eq!(
+            Decimal::from_atomics(1_58_u128, 2).unwrap(),
+            query_market.market.away_odds
+        );
+
+        blockchain_contract
+            .place_bet(
+                &user_a,
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(
+            Decimal::from_atomics(1_75_u128, 2).unwrap(),
+            query_market.market.home_odds
+        );
+        assert_eq!(
+            Decimal::from_atomics(1_72_u128, 2).unwrap(),
+            query_market.market.away_odds
+        );
+
+        // The quote is priced along the curve, below the current odds
+
+        let query_quote = blockchain_contract
+            .query_quote(MarketResult::HOME, 10_000_000)
+            .unwrap();
+        assert_eq!(16_737_662, query_quote.payout);
+        assert_eq!(
+            Decimal::from_ratio(16_737_662_u128, 10_000_000_u128),
+            query_quote.odds
+        );
+    }
+
+    #[test]
+    fn it_prices_the_market_with_the_constant_product_pricing_model() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(2_2_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(1_8_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: Some(PricingModelKind::CONSTANT_PRODUCT),
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(
+            Decimal::from_atomics(1_93_u128, 2).unwrap(),
+            query_market.market.home_odds
+        );
+        assert_eq!(
+            Decimal::from_atomics(1_58_u128, 2).unwrap(),
+            query_market.market.away_odds
+        );
+
+        blockchain_contract
+            .place_bet(
+                &user_a,
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(
+            Decimal::from_atomics(1_80_u128, 2).unwrap(),
+            query_market.market.home_odds
+        );
+        assert_eq!(
+            Decimal::from_atomics(1_67_u128, 2).unwrap(),
+            query_market.market.away_odds
+        );
+
+        // The quote is priced along the curve, below the current odds
+
+        let query_quote = blockchain_contract
+            .query_quote(MarketResult::HOME, 10_000_000)
+            .unwrap();
+        assert_eq!(17_470_367, query_quote.payout);
+        assert_eq!(
+            Decimal::from_ratio(17_470_367_u128, 10_000_000_u128),
+            query_quote.odds
+        );
+    }
+
+    #[test]
+    fn it_cant_create_a_market_with_an_invalid_lmsr_liquidity() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let err = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(2_2_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(1_8_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: Some(PricingModelKind::LMSR {
+                    liquidity: Decimal::zero(),
+                }),
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidLmsrLiquidity(Decimal::zero()),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod price_impact {
+    use super::*;
+
+    #[test]
+    fn it_locks_a_large_bet_at_the_average_odds_of_its_price_impact() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(
+            Decimal::from_atomics(1_91_u128, 2).unwrap(),
+            query_market.market.home_odds
+        );
+
+        let query_quote = blockchain_contract
+            .query_quote(MarketResult::HOME, 30_000_000)
+            .unwrap();
+
+        let response = blockchain_contract
+            .place_bet(
+                &user_a,
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(30_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        assert!(response.events.iter().any(|event| event
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "odds"
+                && attribute.value == query_quote.odds.to_string())));
+
+        // The bet is locked between the odds before and after it
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert!(query_quote.odds < Decimal::from_atomics(1_91_u128, 2).unwrap());
+        assert!(query_quote.odds > query_market.market.home_odds);
+
+        let query_bets_by_address = blockchain_contract.query_bets_by_address(&user_a).unwrap();
+        assert_eq!(query_quote.odds, query_bets_by_address.all_bets.home.odds);
+        assert_eq!(
+            query_quote.payout,
+            query_bets_by_address.all_bets.home.payout
+        );
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(query_quote.payout, query_bets.potential_payouts.home);
+    }
+
+    #[test]
+    fn it_checks_the_min_odds_against_the_average_odds_of_the_bet() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        // The current odds are not kept over the whole bet amount
+        let err = blockchain_contract
+            .place_bet(
+                &user_a,
+                MarketResult::HOME,
+                Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MinimumOddsNotKept {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        // A smaller bet moves the price less
+        blockchain_contract
+            .place_bet(
+                &user_a,
+                MarketResult::HOME,
+                Decimal::from_atomics(1_90_u128, 2).unwrap(),
+                None,
+                &coins(1_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &user_a,
+                MarketResult::HOME,
+                Decimal::from_atomics(1_86_u128, 2).unwrap(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(11_000_000, query_bets.total_amounts.home);
+    }
+}
+
+mod quoted_bet {
+    use super::*;
+    use cosmwasm_std::Binary;
+    use fixed_odds_market::msg::BetQuote;
+    use helpers::{sign_quote, trader_pubkey, trader_signing_key};
+    use k256::ecdsa::SigningKey;
+
+    #[test]
+    fn it_properly_places_a_quoted_bet_at_the_quoted_odds() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: Some(trader_pubkey()),
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let quote = BetQuote {
+            market_id: "game-cs2-test-league".to_string(),
+            contract_addr: blockchain_contract.addr(),
+            result: MarketResult::HOME,
+            odds: Decimal::from_atomics(25_u128, 1).unwrap(), // 2.5
+            max_stake: 20_000_000,
+            bettor: user_a.clone(),
+            expiry: start_timestamp,
+            nonce: 1,
+        };
+        let signature = sign_quote(&trader_signing_key(), &quote);
+
+        blockchain_contract
+            .place_quoted_bet(
+                &user_a,
+                quote.clone(),
+                signature.clone(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_bets_by_address = blockchain_contract.query_bets_by_address(&user_a).unwrap();
+        assert_eq!(10_000_000, query_bets_by_address.all_bets.home.bet_amount);
+        assert_eq!(25_000_000, query_bets_by_address.all_bets.home.payout);
+        assert_eq!(
+            Decimal::from_atomics(25_u128, 1).unwrap(),
+            query_bets_by_address.all_bets.home.odds
+        );
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(10_000_000, query_bets.total_amounts.home);
+        assert_eq!(25_000_000, query_bets.potential_payouts.home);
+
+        // The quoted bet moves the odds of the market as any other bet
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert!(query_market.market.home_odds < Decimal::from_atomics(1_91_u128, 2).unwrap());
+    }
+
+    #[test]
+    fn it_cant_use_a_quote_twice() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: Some(trader_pubkey()),
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let quote = BetQuote {
+            market_id: "game-cs2-test-league".to_string(),
+            contract_addr: blockchain_contract.addr(),
+            result: MarketResult::HOME,
+            odds: Decimal::from_atomics(25_u128, 1).unwrap(), // 2.5
+            max_stake: 20_000_000,
+            bettor: user_a.clone(),
+            expiry: start_timestamp,
+            nonce: 1,
+        };
+        let signature = sign_quote(&trader_signing_key(), &quote);
+
+        blockchain_contract
+            .place_quoted_bet(
+                &user_a,
+                quote.clone(),
+                signature.clone(),
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .place_quoted_bet(
+                &user_a,
+                quote.clone(),
+                signature.clone(),
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::QuoteAlreadyUsed {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(5_000_000, query_bets.total_amounts.home);
+    }
+
+    #[test]
+    fn it_cant_place_a_quoted_bet_with_an_invalid_signature() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: Some(trader_pubkey()),
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let quote = BetQuote {
+            market_id: "game-cs2-test-league".to_string(),
+            contract_addr: blockchain_contract.addr(),
+            result: MarketResult::HOME,
+            odds: Decimal::from_atomics(25_u128, 1).unwrap(), // 2.5
+            max_stake: 20_000_000,
+            bettor: user_a.clone(),
+            expiry: start_timestamp,
+            nonce: 1,
+        };
+
+        let other_signing_key = SigningKey::from_bytes(&[9_u8; 32].into()).unwrap();
+        let signature = sign_quote(&other_signing_key, &quote);
+
+        let err = blockchain_contract
+            .place_quoted_bet(
+                &user_a,
+                quote.clone(),
+                signature.clone(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidQuoteSignature {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        // The quote can't be changed once signed
+        let signature = sign_quote(&trader_signing_key(), &quote);
+        let mut tampered_quote = quote.clone();
+        tampered_quote.odds = Decimal::from_atomics(3_u128, 0).unwrap();
+
+        let err = blockchain_contract
+            .place_quoted_bet(
+                &user_a,
+                tampered_quote,
+                signature.clone(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidQuoteSignature {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_place_a_quoted_bet_issued_to_another_bettor() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_b.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: Some(trader_pubkey()),
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let quote = BetQuote {
+            market_id: "game-cs2-test-league".to_string(),
+            contract_addr: blockchain_contract.addr(),
+            result: MarketResult::HOME,
+            odds: Decimal::from_atomics(25_u128, 1).unwrap(), // 2.5
+            max_stake: 20_000_000,
+            bettor: user_a.clone(),
+            expiry: start_timestamp,
+            nonce: 1,
+        };
+        let signature = sign_quote(&trader_signing_key(), &quote);
+
+        let err = blockchain_contract
+            .place_quoted_bet(
+                &user_b,
+                quote.clone(),
+                signature.clone(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidQuote {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_place_a_quoted_bet_issued_for_another_market_contract() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: Some(trader_pubkey()),
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let quote = BetQuote {
+            market_id: "game-cs2-test-league".to_string(),
+            contract_addr: MockApiBech32::new("neutron").addr_make(OTHER),
+            result: MarketResult::HOME,
+            odds: Decimal::from_atomics(25_u128, 1).unwrap(), // 2.5
+            max_stake: 20_000_000,
+            bettor: user_a.clone(),
+            expiry: start_timestamp,
+            nonce: 1,
+        };
+        let signature = sign_quote(&trader_signing_key(), &quote);
+
+        let err = blockchain_contract
+            .place_quoted_bet(
+                &user_a,
+                quote.clone(),
+                signature.clone(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidQuote {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_place_a_quoted_bet_with_an_expired_quote() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: Some(trader_pubkey()),
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let quote = BetQuote {
+            market_id: "game-cs2-test-league".to_string(),
+            contract_addr: blockchain_contract.addr(),
+            result: MarketResult::HOME,
+            odds: Decimal::from_atomics(25_u128, 1).unwrap(), // 2.5
+            max_stake: 20_000_000,
+            bettor: user_a.clone(),
+            expiry: start_timestamp - 60 * 60, // 1 hour before the start timestamp
+            nonce: 1,
+        };
+        let signature = sign_quote(&trader_signing_key(), &quote);
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp - 30 * 60, // 30 minutes before the start timestamp
+            );
+        });
+
+        let err = blockchain_contract
+            .place_quoted_bet(
+                &user_a,
+                quote.clone(),
+                signature.clone(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::QuoteExpired {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_place_a_quoted_bet_above_the_max_stake_of_the_quote() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: Some(trader_pubkey()),
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let quote = BetQuote {
+            market_id: "game-cs2-test-league".to_string(),
+            contract_addr: blockchain_contract.addr(),
+            result: MarketResult::HOME,
+            odds: Decimal::from_atomics(25_u128, 1).unwrap(), // 2.5
+            max_stake: 20_000_000,
+            bettor: user_a.clone(),
+            expiry: start_timestamp,
+            nonce: 1,
+        };
+        let signature = sign_quote(&trader_signing_key(), &quote);
+
+        let err = blockchain_contract
+            .place_quoted_bet(
+                &user_a,
+                quote.clone(),
+                signature.clone(),
+                &coins(20_000_001, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::QuoteMaxStakeExceeded {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_place_a_quoted_bet_above_the_max_allowed_bet() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: Some(trader_pubkey()),
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let quote = BetQuote {
+            market_id: "game-cs2-test-league".to_string(),
+            contract_addr: blockchain_contract.addr(),
+            result: MarketResult::HOME,
+            odds: Decimal::from_atomics(10_u128, 0).unwrap(), // 10
+            max_stake: 100_000_000,
+            bettor: user_a.clone(),
+            expiry: start_timestamp,
+            nonce: 1,
+        };
+        let signature = sign_quote(&trader_signing_key(), &quote);
+
+        let err = blockchain_contract
+            .place_quoted_bet(
+                &user_a,
+                quote.clone(),
+                signature.clone(),
+                &coins(20_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MaxBetExceeded {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_place_a_quoted_bet_when_quoted_bets_are_not_enabled() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let quote = BetQuote {
+            market_id: "game-cs2-test-league".to_string(),
+            contract_addr: blockchain_contract.addr(),
+            result: MarketResult::HOME,
+            odds: Decimal::from_atomics(25_u128, 1).unwrap(), // 2.5
+            max_stake: 20_000_000,
+            bettor: user_a.clone(),
+            expiry: start_timestamp,
+            nonce: 1,
+        };
+        let signature = sign_quote(&trader_signing_key(), &quote);
+
+        let err = blockchain_contract
+            .place_quoted_bet(
+                &user_a,
+                quote.clone(),
+                signature.clone(),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::QuotedBetsNotEnabled {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_create_a_market_with_an_invalid_trader_pubkey() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+
+        let err = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![(admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM))],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: Some(Binary::from(vec![0x02; 32])),
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidTraderPubkey {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod limit_bet {
+    use super::*;
+
+    #[test]
+    fn it_escrows_the_stake_of_a_limit_bet_apart_from_the_market() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 3 * 60 * 60; // 3 hours from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp - 2 * 60 * 60, // 2 hours before the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .place_limit_bet(
+                &user_a,
+                MarketResult::HOME,
+                Decimal::from_atomics(2_00_u128, 2).unwrap(),
+                start_timestamp,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_limit_bet = blockchain_contract.query_limit_bet(1).unwrap();
+        assert_eq!(user_a, query_limit_bet.limit_bet.addr);
+        assert_eq!(MarketResult::HOME, query_limit_bet.limit_bet.result);
+        assert_eq!(5_000_000, query_limit_bet.limit_bet.bet_amount);
+        assert_eq!(
+            Decimal::from_atomics(2_00_u128, 2).unwrap(),
+            query_limit_bet.limit_bet.target_odds
+        );
+        assert_eq!(start_timestamp, query_limit_bet.limit_bet.expires_at);
+
+        // The stake is held by the contract but it isn't part of the market
+        let market_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(blockchain_contract.addr(), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(105_000_000_u128, market_balance.amount.u128());
+
+        let query_max_bets = blockchain_contract.query_max_bets().unwrap();
+        assert_eq!(34_904_013, query_max_bets.home);
+        assert_eq!(42_735_042, query_max_bets.away);
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(0, query_bets.total_amounts.home);
+        assert_eq!(0, query_bets.potential_payouts.home);
+    }
+
+    #[test]
+    fn it_fills_a_limit_bet_once_the_odds_reach_the_target() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 3 * 60 * 60; // 3 hours from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+        let anyone = MockApiBech32::new("neutron").addr_make(ANYONE);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_b.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp - 2 * 60 * 60, // 2 hours before the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .place_limit_bet(
+                &user_a,
+                MarketResult::HOME,
+                Decimal::from_atomics(2_00_u128, 2).unwrap(),
+                start_timestamp,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        // The odds are still below the target
+        blockchain_contract.execute_limit_bets(&anyone).unwrap();
+        assert!(blockchain_contract.query_limit_bet(1).is_ok());
+
+        blockchain_contract
+            .place_bet(
+                &user_b,
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(30_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let response = blockchain_contract.execute_limit_bets(&anyone).unwrap();
+        assert!(response.events.iter().any(|event| event
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "filled_limit_bets" && attribute.value == "1")));
+        assert!(blockchain_contract.query_limit_bet(1).is_err());
+
+        let query_bets_by_address = blockchain_contract.query_bets_by_address(&user_a).unwrap();
+        assert_eq!(5_000_000, query_bets_by_address.all_bets.home.bet_amount);
+        assert!(
+            query_bets_by_address.all_bets.home.odds
+                >= Decimal::from_atomics(2_00_u128, 2).unwrap()
+        );
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(5_000_000, query_bets.total_amounts.home);
+        assert_eq!(30_000_000, query_bets.total_amounts.away);
+        assert!(blockchain_contract
+            .query_limit_bets()
+            .unwrap()
+            .limit_bets
+            .is_empty());
+    }
+
+    #[test]
+    fn it_lets_the_bettor_cancel_a_limit_bet_and_refunds_it() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 3 * 60 * 60; // 3 hours from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_b.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp - 2 * 60 * 60, // 2 hours before the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .place_limit_bet(
+                &user_a,
+                MarketResult::HOME,
+                Decimal::from_atomics(2_00_u128, 2).unwrap(),
+                start_timestamp,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .cancel_limit_bet(&user_b, 1)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract.cancel_limit_bet(&user_a, 1).unwrap();
+        assert!(blockchain_contract.query_limit_bet(1).is_err());
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(&user_a, NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_a_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_refunds_the_unfilled_limit_bets_once_expired() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 3 * 60 * 60; // 3 hours from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let anyone = MockApiBech32::new("neutron").addr_make(ANYONE);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp - 2 * 60 * 60, // 2 hours before the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .place_limit_bet(
+                &user_a,
+                MarketResult::HOME,
+                Decimal::from_atomics(2_00_u128, 2).unwrap(),
+                start_timestamp - 90 * 60, // 90 minutes before the start timestamp,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_limit_bet(
+                &user_a,
+                MarketResult::HOME,
+                Decimal::from_atomics(2_00_u128, 2).unwrap(),
+                start_timestamp,
+                &coins(7_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp - 60 * 60, // 1 hour before the start timestamp
+            );
+        });
+
+        blockchain_contract.execute_limit_bets(&anyone).unwrap();
+        assert!(blockchain_contract.query_limit_bet(1).is_err());
+        assert!(blockchain_contract.query_limit_bet(2).is_ok());
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(&user_a, NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE - 7_000_000, user_a_balance.amount.u128());
+
+        // The limit bets are refunded at the cutoff of the bets
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp - 4 * 60, // 4 minutes before the start timestamp
+            );
+        });
+
+        blockchain_contract.execute_limit_bets(&anyone).unwrap();
+        assert!(blockchain_contract
+            .query_limit_bets()
+            .unwrap()
+            .limit_bets
+            .is_empty());
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(&user_a, NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_a_balance.amount.u128());
+
+        let market_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(blockchain_contract.addr(), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(100_000_000_u128, market_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_cant_place_a_limit_bet_with_an_invalid_expiration() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 3 * 60 * 60; // 3 hours from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp - 2 * 60 * 60, // 2 hours before the start timestamp
+            );
+        });
+
+        let err = blockchain_contract
+            .place_limit_bet(
+                &user_a,
+                MarketResult::HOME,
+                Decimal::from_atomics(2_00_u128, 2).unwrap(),
+                start_timestamp - 2 * 60 * 60,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidLimitBetExpiration(start_timestamp - 2 * 60 * 60),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod checked_math {
+    use super::*;
+
+    #[test]
+    fn it_cant_place_a_bet_when_the_start_timestamp_leaves_no_room_for_the_cutoff() {
+        let start_timestamp = 60; // 1 minute after the epoch
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let err = blockchain_contract
+            .place_bet(
+                &user_a,
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(1_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidTimestamp(start_timestamp),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_create_a_market_with_a_resolution_deadline_out_of_range() {
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let err = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp: u64::MAX,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidTimestamp(u64::MAX),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_create_a_market_with_a_denom_precision_above_18_decimals() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+
+        let err = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: 19,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidPrecision(19),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod denom_precision {
+    use super::*;
+
+    #[test]
+    fn it_properly_prices_and_settles_a_market_with_a_6_decimals_denom() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let unit = 1_000_000_u128; // 1 token with 6 decimals
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(1_000 * unit, NATIVE_DENOM)),
+                (user_a.clone(), coins(1_000 * unit, NATIVE_DENOM)),
+                (user_b.clone(), coins(1_000 * unit, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: 6,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100 * unit, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let query_max_bets = blockchain_contract.query_max_bets().unwrap();
+        assert_eq!(34_904_013, query_max_bets.home);
+        assert_eq!(42_735_042, query_max_bets.away);
+
+        blockchain_contract
+            .place_bet(
+                &user_a,
+                MarketResult::HOME,
+                Decimal::from_atomics(187_u128, 2).unwrap(), // 1.87
+                None,
+                &coins(10 * unit, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &user_b,
+                MarketResult::AWAY,
+                Decimal::from_atomics(160_u128, 2).unwrap(), // 1.60
+                None,
+                &coins(5 * unit, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_bets_by_address = blockchain_contract.query_bets_by_address(&user_a).unwrap();
+        assert_eq!(
+            18_750_000 * unit / 1_000_000,
+            query_bets_by_address.all_bets.home.payout
+        );
+        assert_eq!(
+            Decimal::from_atomics(1_875_u128, 3).unwrap(),
+            query_bets_by_address.all_bets.home.odds
+        );
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(
+            Decimal::from_atomics(1_87_u128, 2).unwrap(),
+            query_market.market.home_odds
+        );
+        assert_eq!(
+            Decimal::from_atomics(1_59_u128, 2).unwrap(),
+            query_market.market.away_odds
+        );
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(&admin, MarketResult::HOME)
+            .unwrap();
+        blockchain_contract.claim_winnings(&user_a, None).unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(&user_a, NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(
+            1_000 * unit + 8_750_000 * unit / 1_000_000,
+            user_a_balance.amount.u128()
+        );
+    }
+
+    #[test]
+    fn it_properly_prices_and_settles_a_market_with_an_18_decimals_denom() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let unit = 1_000_000_000_000_000_000_u128; // 1 token with 18 decimals
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(1_000 * unit, NATIVE_DENOM)),
+                (user_a.clone(), coins(1_000 * unit, NATIVE_DENOM)),
+                (user_b.clone(), coins(1_000 * unit, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: 18,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100 * unit, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        // The max bets keep the 18 decimals of the denom
+        let query_max_bets = blockchain_contract.query_max_bets().unwrap();
+        assert_eq!(34_904_013_961_605_584_642, query_max_bets.home);
+        assert_eq!(42_735_042_735_042_735_042, query_max_bets.away);
+
+        blockchain_contract
+            .place_bet(
+                &user_a,
+                MarketResult::HOME,
+                Decimal::from_atomics(187_u128, 2).unwrap(), // 1.87
+                None,
+                &coins(10 * unit, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &user_b,
+                MarketResult::AWAY,
+                Decimal::from_atomics(160_u128, 2).unwrap(), // 1.60
+                None,
+                &coins(5 * unit, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_bets_by_address = blockchain_contract.query_bets_by_address(&user_a).unwrap();
+        assert_eq!(
+            18_750_000 * unit / 1_000_000,
+            query_bets_by_address.all_bets.home.payout
+        );
+        assert_eq!(
+            Decimal::from_atomics(1_875_u128, 3).unwrap(),
+            query_bets_by_address.all_bets.home.odds
+        );
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(
+            Decimal::from_atomics(1_87_u128, 2).unwrap(),
+            query_market.market.home_odds
+        );
+        assert_eq!(
+            Decimal::from_atomics(1_59_u128, 2).unwrap(),
+            query_market.market.away_odds
+        );
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(&admin, MarketResult::HOME)
+            .unwrap();
+        blockchain_contract.claim_winnings(&user_a, None).unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(&user_a, NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(
+            1_000 * unit + 8_750_000 * unit / 1_000_000,
+            user_a_balance.amount.u128()
+        );
+    }
+}
+
+mod ledger {
+    use super::*;
+    use cw_multi_test::Executor;
+
+    #[test]
+    fn coins_sent_directly_to_the_contract_dont_move_the_odds_nor_the_max_bets() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let treasury = MockApiBech32::new("neutron").addr_make(TREASURY);
+        let other = MockApiBech32::new("neutron").addr_make(OTHER);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (other.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: treasury.clone(),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        let query_max_bets = blockchain_contract.query_max_bets().unwrap();
+
+        blockchain_contract
+            .blockchain
+            .send_tokens(
+                other.clone(),
+                blockchain_contract.addr(),
+                &coins(500_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_market_after = blockchain_contract.query_market().unwrap();
+        assert_eq!(
+            query_market.market.home_odds,
+            query_market_after.market.home_odds
+        );
+        assert_eq!(
+            query_market.market.away_odds,
+            query_market_after.market.away_odds
+        );
+        let query_max_bets_after = blockchain_contract.query_max_bets().unwrap();
+        assert_eq!(query_max_bets.home, query_max_bets_after.home);
+        assert_eq!(query_max_bets.away, query_max_bets_after.away);
+
+        let query_ledger = blockchain_contract.query_ledger().unwrap();
+        assert_eq!(Uint128::new(100_000_000), query_ledger.ledger.seed);
+        assert_eq!(Uint128::new(100_000_000), query_ledger.market_balance);
+    }
+
+    #[test]
+    fn it_properly_tracks_the_stakes_and_the_settlement_in_the_ledger() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let treasury = MockApiBech32::new("neutron").addr_make(TREASURY);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+        let other = MockApiBech32::new("neutron").addr_make(OTHER);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_b.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (other.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: treasury.clone(),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &user_a,
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &user_b,
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_ledger = blockchain_contract.query_ledger().unwrap();
+        assert_eq!(Uint128::new(15_000_000), query_ledger.ledger.stakes);
+        assert_eq!(Uint128::new(15_000_000), query_ledger.ledger.unclaimed);
+        assert_eq!(Uint128::new(115_000_000), query_ledger.market_balance);
+
+        blockchain_contract
+            .blockchain
+            .send_tokens(
+                other.clone(),
+                blockchain_contract.addr(),
+                &coins(500_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(&admin, MarketResult::AWAY)
+            .unwrap();
+
+        // The coins sent directly to the contract are not collected as profit
+        let query_ledger = blockchain_contract.query_ledger().unwrap();
+        let treasury_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(&treasury, NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(query_ledger.ledger.fees, treasury_balance.amount);
+        let admin_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(&admin, NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, admin_balance.amount.u128());
+
+        let query_ledger = blockchain_contract.query_ledger().unwrap();
+        assert_eq!(query_ledger.market_balance, query_ledger.ledger.unclaimed);
+
+        blockchain_contract.claim_winnings(&user_b, None).unwrap();
+
+        let query_ledger = blockchain_contract.query_ledger().unwrap();
+        assert_eq!(Uint128::zero(), query_ledger.ledger.unclaimed);
+        assert_eq!(Uint128::zero(), query_ledger.market_balance);
+
+        let contract_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(blockchain_contract.addr(), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(500_000_000, contract_balance.amount.u128());
+    }
+}
+
+mod reconcile_surplus {
+    use super::*;
+    use cw_multi_test::Executor;
+
+    #[test]
+    fn it_properly_sweeps_the_surplus_to_the_treasury() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let treasury = MockApiBech32::new("neutron").addr_make(TREASURY);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let other = MockApiBech32::new("neutron").addr_make(OTHER);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (other.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: treasury.clone(),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &user_a,
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_limit_bet(
+                &user_a,
+                MarketResult::AWAY,
+                Decimal::from_atomics(5_u128, 0).unwrap(), // 5
+                start_timestamp,
+                &coins(2_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .blockchain
+            .send_tokens(
+                other.clone(),
+                blockchain_contract.addr(),
+                &coins(500_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let response = blockchain_contract.reconcile_surplus(&admin).unwrap();
+        assert!(response.events.iter().any(|event| event
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "surplus" && attribute.value == "500000")));
+
+        let treasury_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(&treasury, NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(500_000, treasury_balance.amount.u128());
+
+        // The market balance and the stake of the limit bet are left in the contract
+        let contract_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(blockchain_contract.addr(), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(112_000_000, contract_balance.amount.u128());
+
+        let err = blockchain_contract.reconcile_surplus(&admin).unwrap_err();
+        assert_eq!(
+            ContractError::NoSurplus {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_reconcile_the_surplus_if_sender_isnt_the_admin() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let treasury = MockApiBech32::new("neutron").addr_make(TREASURY);
+        let other = MockApiBech32::new("neutron").addr_make(OTHER);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (other.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: treasury.clone(),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .blockchain
+            .send_tokens(
+                other.clone(),
+                blockchain_contract.addr(),
+                &coins(500_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let err = blockchain_contract.reconcile_surplus(&other).unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod solvency {
+    use super::*;
+    use cosmwasm_std::SignedDecimal;
+    use cw_multi_test::Executor;
+    use fixed_odds_market::{
+        msg::UpdateParams,
+        state::{MarketKind, VoidReason},
+    };
+    use helpers::BlockchainContract;
+    use proptest::prelude::*;
+
+    #[test]
+    fn it_properly_reports_the_liability_of_each_result() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let treasury = MockApiBech32::new("neutron").addr_make(TREASURY);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_b.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: treasury.clone(),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &user_a,
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &user_b,
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_limit_bet(
+                &user_a,
+                MarketResult::AWAY,
+                Decimal::from_atomics(5_u128, 0).unwrap(), // 5
+                start_timestamp,
+                &coins(2_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_solvency = blockchain_contract.query_solvency().unwrap();
+        assert_eq!(117_000_000, query_solvency.balance);
+        assert_eq!(2, query_solvency.liabilities.len());
+        assert_eq!(MarketResult::HOME, query_solvency.liabilities[0].result);
+        assert_eq!(18_750_000, query_solvency.liabilities[0].liability);
+        assert_eq!(MarketResult::AWAY, query_solvency.liabilities[1].result);
+        assert_eq!(8_010_000, query_solvency.liabilities[1].liability);
+        assert_eq!(18_750_000, query_solvency.max_liability);
+        assert_eq!(0, query_solvency.unclaimed);
+        assert_eq!(2_000_000, query_solvency.escrowed);
+        assert_eq!(96_250_000, query_solvency.surplus);
+        assert!(query_solvency.solvent);
+    }
+
+    #[test]
+    fn it_leaves_nothing_in_the_contract_once_claimed_and_reconciled() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let treasury = MockApiBech32::new("neutron").addr_make(TREASURY);
+        let user_a = MockApiBech32::new("neutron").addr_make(USER_A);
+        let user_b = MockApiBech32::new("neutron").addr_make(USER_B);
+        let other = MockApiBech32::new("neutron").addr_make(OTHER);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![
+                (admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_a.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (user_b.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+                (other.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM)),
+            ],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: treasury.clone(),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B - Handicap".to_string(),
+                kind: Some(MarketKind::HANDICAP {
+                    handicap: SignedDecimal::from_atomics(-25_i128, 2).unwrap(), // -0.25
+                }),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &user_a,
+                MarketResult::HOME,
+                Decimal::one(),
+                None,
+                &coins(3_333_333, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &user_b,
+                MarketResult::AWAY,
+                Decimal::one(),
+                None,
+                &coins(7_777_777, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .blockchain
+            .send_tokens(
+                other.clone(),
+                blockchain_contract.addr(),
+                &coins(500_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        // A draw is a half loss for the home side and a half win for the away side
+        blockchain_contract
+            .score_market_final(&admin, 1, 1)
+            .unwrap();
+
+        let query_solvency = blockchain_contract.query_solvency().unwrap();
+        assert!(query_solvency.liabilities.is_empty());
+        assert_eq!(11_633_885, query_solvency.unclaimed);
+        assert_eq!(500_000, query_solvency.surplus);
+        assert!(query_solvency.solvent);
+
+        blockchain_contract.claim_winnings(&user_a, None).unwrap();
+        blockchain_contract.claim_winnings(&user_b, None).unwrap();
+
+        let query_solvency = blockchain_contract.query_solvency().unwrap();
+        assert_eq!(0, query_solvency.unclaimed);
+        assert_eq!(query_solvency.balance, query_solvency.surplus);
+
+        blockchain_contract.reconcile_surplus(&admin).unwrap();
+
+        let query_solvency = blockchain_contract.query_solvency().unwrap();
+        assert_eq!(0, query_solvency.balance);
+        assert_eq!(0, query_solvency.surplus);
+        assert!(query_solvency.solvent);
+    }
+
+    #[derive(Debug, Clone)]
+    enum Action {
+        PlaceBet {
+            user: usize,
+            home: bool,
+            amount: u128,
+        },
+        Donate {
+            amount: u128,
+        },
+        Update {
+            max_bet_risk_factor: u64,
+            seed_liquidity_amplifier: u64,
+            fee_spread_odds: u64,
+        },
+        Score {
+            home_score: u64,
+            away_score: u64,
+        },
+        Cancel,
+        Void,
+        Expire,
+        Postpone,
+        Reschedule {
+            delay: u64,
+        },
+        WithdrawStake {
+            user: usize,
+        },
+        Claim {
+            user: usize,
+        },
+        Reconcile,
+    }
+
+    const USERS: [&str; 3] = [USER_A, USER_B, USER_C];
+
+    fn action_strategy() -> impl Strategy<Value = Action> {
+        prop_oneof![
+            8 => (0..USERS.len(), any::<bool>(), 1..20_000_000_u128)
+                .prop_map(|(user, home, amount)| Action::PlaceBet { user, home, amount }),
+            1 => (1..1_000_000_u128).prop_map(|amount| Action::Donate { amount }),
+            2 => (5..30_u64, 1..6_u64, 0..30_u64).prop_map(
+                |(max_bet_risk_factor, seed_liquidity_amplifier, fee_spread_odds)| Action::Update {
+                    max_bet_risk_factor,
+                    seed_liquidity_amplifier,
+                    fee_spread_odds,
+                }
+            ),
+            1 => (0..4_u64, 0..4_u64)
+                .prop_map(|(home_score, away_score)| Action::Score { home_score, away_score }),
+            1 => Just(Action::Cancel),
+            1 => Just(Action::Void),
+            1 => Just(Action::Expire),
+            1 => Just(Action::Postpone),
+            1 => (60..24 * 60 * 60_u64).prop_map(|delay| Action::Reschedule { delay }),
+            1 => (0..USERS.len()).prop_map(|user| Action::WithdrawStake { user }),
+            3 => (0..USERS.len()).prop_map(|user| Action::Claim { user }),
+            1 => Just(Action::Reconcile),
+        ]
+    }
+
+    /// Runs the action on the market, the actions rejected by the contract are ignored
+    fn run_action(blockchain_contract: &mut BlockchainContract, action: &Action) {
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let _ = match action {
+            Action::PlaceBet { user, home, amount } => blockchain_contract.place_bet(
+                &MockApiBech32::new("neutron").addr_make(USERS[*user]),
+                if *home {
+                    MarketResult::HOME
+                } else {
+                    MarketResult::AWAY
+                },
+                Decimal::one(),
+                None,
+                &coins(*amount, NATIVE_DENOM),
+            ),
+            Action::Donate { amount } => {
+                let contract_addr = blockchain_contract.addr();
+                blockchain_contract.blockchain.send_tokens(
+                    MockApiBech32::new("neutron").addr_make(OTHER),
+                    contract_addr,
+                    &coins(*amount, NATIVE_DENOM),
+                )
+            }
+            Action::Update {
+                max_bet_risk_factor,
+                seed_liquidity_amplifier,
+                fee_spread_odds,
+            } => blockchain_contract.update_market(
+                &admin,
+                UpdateParams {
+                    admin_addr: None,
+                    treasury_addr: None,
+                    fee_spread_odds: Some(Decimal::percent(*fee_spread_odds)),
+                    max_bet_risk_factor: Some(
+                        Decimal::from_atomics(*max_bet_risk_factor, 1).unwrap(),
+                    ),
+                    seed_liquidity_amplifier: Some(
+                        Decimal::from_atomics(*seed_liquidity_amplifier, 0).unwrap(),
+                    ),
+                    initial_odds_home: None,
+                    initial_odds_away: None,
+                    start_timestamp: None,
+                    parlay_addr: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
+                },
+            ),
+            Action::Score {
+                home_score,
+                away_score,
+            } => {
+                let market = blockchain_contract.query_market().unwrap().market;
+                blockchain_contract.blockchain.update_block(|block| {
+                    block.time = Timestamp::from_seconds(market.start_timestamp + 60 * 30);
+                });
+                match market.kind {
+                    MarketKind::MONEYLINE => blockchain_contract.score_market(
+                        &admin,
+                        if home_score >= away_score {
+                            MarketResult::HOME
+                        } else {
+                            MarketResult::AWAY
+                        },
+                    ),
+                    _ => blockchain_contract.score_market_final(&admin, *home_score, *away_score),
+                }
+            }
+            Action::Cancel => blockchain_contract.cancel_market(&admin),
+            Action::Void => blockchain_contract.void_market(&admin, VoidReason::ABANDONED),
+            Action::Expire => {
+                blockchain_contract.expire_market(&MockApiBech32::new("neutron").addr_make(ANYONE))
+            }
+            Action::Postpone => blockchain_contract.postpone_market(&admin),
+            Action::Reschedule { delay } => {
+                let block_time = blockchain_contract.blockchain.block_info().time.seconds();
+                blockchain_contract.reschedule_market(&admin, block_time + delay)
+            }
+            Action::WithdrawStake { user } => blockchain_contract
+                .withdraw_stake(&MockApiBech32::new("neutron").addr_make(USERS[*user]), None),
+            Action::Claim { user } => blockchain_contract
+                .claim_winnings(&MockApiBech32::new("neutron").addr_make(USERS[*user]), None),
+            Action::Reconcile => blockchain_contract.reconcile_surplus(&admin),
+        };
+    }
+
+    /// Checks that the market can pay everything it may owe
+    fn assert_solvency_invariants(blockchain_contract: &BlockchainContract) {
+        let query_solvency = blockchain_contract.query_solvency().unwrap();
+        let balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(blockchain_contract.addr(), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(balance.amount.u128(), query_solvency.balance);
+        assert!(query_solvency.solvent, "{query_solvency:?}");
+        assert_eq!(
+            query_solvency.balance
+                - query_solvency.max_liability
+                - query_solvency.unclaimed
+                - query_solvency.escrowed,
+            query_solvency.surplus
+        );
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn the_market_stays_solvent_through_any_sequence_of_actions(
+            actions in prop::collection::vec(action_strategy(), 1..40),
+            handicap in prop_oneof![Just(None), Just(Some(-25_i128)), Just(Some(-75_i128))],
+        ) {
+            let start_timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("Time went backwards")
+                .as_secs()
+                + 60 * 5; // 5 minutes from now
+
+            let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+            let mut balances: Vec<_> = USERS
+                .iter()
+                .chain([ADMIN, OTHER].iter())
+                .map(|user| {
+                    (
+                        MockApiBech32::new("neutron").addr_make(user),
+                        coins(INITIAL_BALANCE, NATIVE_DENOM),
+                    )
+                })
+                .collect();
+            balances.dedup();
+
+            let mut blockchain_contract = setup_blockchain_and_contract(
+                admin.clone(),
+                balances,
+                InstantiateMsg {
+                    admin_addr: admin.clone(),
+                    treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                    denom: NATIVE_DENOM.to_string(),
+                    denom_precision: NATIVE_DENOM_PRECISION,
+                    id: "game-cs2-test-league".to_string(),
+                    label: "CS2 - Test League - Team A vs Team B".to_string(),
+                    kind: handicap.map(|handicap| MarketKind::HANDICAP {
+                        handicap: SignedDecimal::from_atomics(handicap, 2).unwrap(),
+                    }),
+                    home_team: "Team A".to_string(),
+                    away_team: "Team B".to_string(),
+                    fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                    max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                    seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                    initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                    initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                    start_timestamp,
+                    parlay_addr: None,
+                    live: None,
+                    resolution_grace_period: None,
+                    postponement_period: None,
+                    pricing_model: None,
+                    trader_pubkey: None,
+                    metadata: None,
+                },
+                coins(100_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+            for action in actions.iter() {
+                run_action(&mut blockchain_contract, action);
+                assert_solvency_invariants(&blockchain_contract);
+            }
+
+            // A market left postponed is rescheduled first so it can be scored,
+            // or expired once its postponement deadline passed
+            run_action(&mut blockchain_contract, &Action::Reschedule { delay: 60 * 60 });
+            run_action(
+                &mut blockchain_contract,
+                &Action::Score {
+                    home_score: 1,
+                    away_score: 1,
+                },
+            );
+            run_action(&mut blockchain_contract, &Action::Expire);
+            assert_solvency_invariants(&blockchain_contract);
+            for user in 0..USERS.len() {
+                run_action(&mut blockchain_contract, &Action::Claim { user });
+                assert_solvency_invariants(&blockchain_contract);
+            }
+            run_action(&mut blockchain_contract, &Action::Reconcile);
+            assert_solvency_invariants(&blockchain_contract);
+
+            // Once everything is claimed and reconciled not a single unit is left in the contract
+            let query_solvency = blockchain_contract.query_solvency().unwrap();
+            prop_assert_eq!(0, query_solvency.unclaimed);
+            prop_assert_eq!(0, query_solvency.balance);
+        }
+    }
+}
+
+mod metadata {
+    use super::*;
+    use fixed_odds_market::{
+        msg::UpdateParams,
+        state::{ExternalId, MarketMetadata, Participant},
+    };
+
+    fn metadata() -> MarketMetadata {
+        MarketMetadata {
+            sport: Some("CS2".to_string()),
+            league: Some("Test League".to_string()),
+            season: Some("2025".to_string()),
+            round: Some("Quarterfinals".to_string()),
+            venue: None,
+            home_participant: Some(Participant {
+                id: Some("team-a".to_string()),
+                logo: Some("https://example.com/team-a.png".to_string()),
+            }),
+            away_participant: Some(Participant {
+                id: Some("team-b".to_string()),
+                logo: None,
+            }),
+            external_ids: vec![ExternalId {
+                provider: "pandascore".to_string(),
+                id: "1234".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn it_properly_creates_a_market_with_metadata() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+
+        let blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![(admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM))],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: Some(metadata()),
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Some(metadata()), query_market.market.metadata);
+    }
+
+    #[test]
+    fn it_properly_updates_the_team_names_and_the_metadata() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![(admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM))],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Taem A vs Team B".to_string(),
+                kind: None,
+                home_team: "Taem A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(None, query_market.market.metadata);
+
+        let response = blockchain_contract
+            .update_market(
+                &admin,
+                UpdateParams {
+                    admin_addr: None,
+                    treasury_addr: None,
+                    fee_spread_odds: None,
+                    max_bet_risk_factor: None,
+                    seed_liquidity_amplifier: None,
+                    initial_odds_home: None,
+                    initial_odds_away: None,
+                    start_timestamp: None,
+                    parlay_addr: None,
+                    label: Some("CS2 - Test League - Team A vs Team B".to_string()),
+                    home_team: Some("Team A".to_string()),
+                    away_team: None,
+                    metadata: Some(metadata()),
+                },
+            )
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(
+            "CS2 - Test League - Team A vs Team B",
+            query_market.market.label
+        );
+        assert_eq!("Team A", query_market.market.home_team);
+        assert_eq!("Team B", query_market.market.away_team);
+        assert_eq!(Some(metadata()), query_market.market.metadata);
+
+        let attributes = &response
+            .events
+            .iter()
+            .find(|event| event.ty == "wasm")
+            .unwrap()
+            .attributes;
+        for (key, value) in [
+            ("home_team", "Team A"),
+            ("away_team", ""),
+            ("sport", "CS2"),
+            ("league", "Test League"),
+            ("venue", ""),
+            ("home_participant_id", "team-a"),
+            ("home_participant_logo", "https://example.com/team-a.png"),
+            ("away_participant_logo", ""),
+            ("external_ids", "pandascore:1234"),
+        ] {
+            assert!(attributes
+                .iter()
+                .any(|attribute| attribute.key == key && attribute.value == value));
+        }
+    }
+
+    #[test]
+    fn it_cant_update_the_metadata_if_market_isnt_active() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![(admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM))],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        blockchain_contract.cancel_market(&admin).unwrap();
+
+        let err = blockchain_contract
+            .update_market(
+                &admin,
+                UpdateParams {
+                    admin_addr: None,
+                    treasury_addr: None,
+                    fee_spread_odds: None,
+                    max_bet_risk_factor: None,
+                    seed_liquidity_amplifier: None,
+                    initial_odds_home: None,
+                    initial_odds_away: None,
+                    start_timestamp: None,
+                    parlay_addr: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: Some(metadata()),
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::MarketNotActive {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_update_the_team_names_with_blank_names() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![(admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM))],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: None,
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+
+        let err = blockchain_contract
+            .update_market(
+                &admin,
+                UpdateParams {
+                    admin_addr: None,
+                    treasury_addr: None,
+                    fee_spread_odds: None,
+                    max_bet_risk_factor: None,
+                    seed_liquidity_amplifier: None,
+                    initial_odds_home: None,
+                    initial_odds_away: None,
+                    start_timestamp: None,
+                    parlay_addr: None,
+                    label: None,
+                    home_team: None,
+                    away_team: Some("  ".to_string()),
+                    metadata: None,
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidMetadata("away_team".to_string()),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_create_a_market_with_duplicated_external_ids() {
+        let mut metadata = metadata();
+        metadata.external_ids.push(ExternalId {
+            provider: "pandascore".to_string(),
+            id: "5678".to_string(),
+        });
+
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+
+        let err = setup_blockchain_and_contract(
+            admin.clone(),
+            vec![(admin.clone(), coins(INITIAL_BALANCE, NATIVE_DENOM))],
+            InstantiateMsg {
+                admin_addr: admin.clone(),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                kind: None,
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+                max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+                seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+                initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+                initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+                start_timestamp,
+                parlay_addr: None,
+                live: None,
+                resolution_grace_period: None,
+                postponement_period: None,
+                pricing_model: None,
+                trader_pubkey: None,
+                metadata: Some(metadata),
+            },
+            coins(100_000_000, NATIVE_DENOM),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidMetadata("external_ids".to_string()),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
@@ -6,14 +6,27 @@ use cw2::set_contract_version;
 use crate::{
     error::ContractError,
     execute::{
-        execute_cancel, execute_claim_winnings, execute_place_bet, execute_score, execute_update,
+        execute_approve, execute_cancel, execute_claim_by_token, execute_claim_winnings,
+        execute_expire_market, execute_mint_bet_slip, execute_place_bet, execute_postpone,
+        execute_reschedule, execute_revoke, execute_score, execute_send_nft, execute_transfer_nft,
+        execute_transfer_position, execute_update, execute_void, execute_withdraw_bet,
+        execute_withdraw_stake,
     },
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg, UpdateParams},
     queries::{
-        query_bets, query_bets_by_address, query_config, query_estimate_winnings, query_market,
+        query_bets, query_bets_by_address, query_config, query_contract_info,
+        query_estimate_winnings, query_market, query_nft_info, query_num_tokens, query_odds,
+        query_owner_of, query_solvency, query_tokens,
+    },
+    state::{
+        Config, Market, Status, BET_SLIP_COUNT, CONFIG, MARKET, POOL_PENALTIES, TOTAL_AWAY,
+        TOTAL_DRAW, TOTAL_HOME, TOTAL_SHARES_AWAY, TOTAL_SHARES_DRAW, TOTAL_SHARES_HOME,
+    },
+    validation::{
+        validate_early_bird, validate_fee_bps, validate_metadata, validate_postponement_period,
+        validate_resolution_grace_period, validate_text, validate_withdrawal,
+        DEFAULT_POSTPONEMENT_PERIOD, DEFAULT_RESOLUTION_GRACE_PERIOD, MAX_TEXT_LENGTH,
     },
-    state::{Config, Market, Status, CONFIG, MARKET, TOTAL_AWAY, TOTAL_DRAW, TOTAL_HOME},
-    validation::validate_fee_bps,
 };
 
 pub const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
@@ -28,6 +41,31 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     validate_fee_bps(msg.fee_bps)?;
 
+    let resolution_grace_period = msg
+        .resolution_grace_period
+        .unwrap_or(DEFAULT_RESOLUTION_GRACE_PERIOD);
+    validate_resolution_grace_period(resolution_grace_period)?;
+
+    let postponement_period = msg
+        .postponement_period
+        .unwrap_or(DEFAULT_POSTPONEMENT_PERIOD);
+    validate_postponement_period(postponement_period)?;
+
+    if let Some(early_bird) = &msg.early_bird {
+        validate_early_bird(early_bird)?;
+    }
+
+    if let Some(withdrawal) = &msg.withdrawal {
+        validate_withdrawal(withdrawal)?;
+    }
+
+    validate_text("label", &msg.label, MAX_TEXT_LENGTH)?;
+    validate_text("home_team", &msg.home_team, MAX_TEXT_LENGTH)?;
+    validate_text("away_team", &msg.away_team, MAX_TEXT_LENGTH)?;
+    if let Some(metadata) = &msg.metadata {
+        validate_metadata(metadata)?;
+    }
+
     set_contract_version(
         deps.storage,
         format!("crates.io:{CONTRACT_NAME}"),
@@ -40,6 +78,10 @@ pub fn instantiate(
         fee_bps: msg.fee_bps,
         denom: msg.denom.clone(),
         denom_precision: msg.denom_precision,
+        resolution_grace_period,
+        postponement_period,
+        early_bird: msg.early_bird.clone(),
+        withdrawal: msg.withdrawal.clone(),
     };
     CONFIG.save(deps.storage, &state)?;
 
@@ -48,16 +90,29 @@ pub fn instantiate(
         label: msg.label,
         home_team: msg.home_team,
         away_team: msg.away_team,
+        metadata: msg.metadata,
         start_timestamp: msg.start_timestamp,
+        resolution_deadline: msg
+            .start_timestamp
+            .checked_add(resolution_grace_period)
+            .ok_or(ContractError::InvalidTimestamp(msg.start_timestamp))?,
+        postponement_deadline: None,
         status: Status::ACTIVE,
-        result: None,
+        results: vec![],
         is_drawable: msg.is_drawable,
+        void_reason: None,
+        is_refund: false,
     };
     MARKET.save(deps.storage, &market)?;
 
     TOTAL_HOME.save(deps.storage, &0)?;
     TOTAL_AWAY.save(deps.storage, &0)?;
     TOTAL_DRAW.save(deps.storage, &0)?;
+    POOL_PENALTIES.save(deps.storage, &0)?;
+    TOTAL_SHARES_HOME.save(deps.storage, &0)?;
+    TOTAL_SHARES_AWAY.save(deps.storage, &0)?;
+    TOTAL_SHARES_DRAW.save(deps.storage, &0)?;
+    BET_SLIP_COUNT.save(deps.storage, &0)?;
 
     Ok(Response::new()
         .add_attribute("protocol", "vendetta-markets")
@@ -74,12 +129,17 @@ pub fn instantiate(
         .add_attribute("home_team", market.home_team)
         .add_attribute("away_team", market.away_team)
         .add_attribute("start_timestamp", market.start_timestamp.to_string())
+        .add_attribute(
+            "resolution_deadline",
+            market.resolution_deadline.to_string(),
+        )
         .add_attribute("is_drawable", msg.is_drawable.to_string())
-        .add_attribute("status", Status::ACTIVE.to_string()))
+        .add_attribute("status", Status::ACTIVE.to_string())
+        .add_attributes(market.metadata.unwrap_or_default().attributes()))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
         QueryMsg::Market {} => to_json_binary(&query_market(deps)?),
@@ -87,9 +147,23 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::BetsByAddress { address } => {
             to_json_binary(&query_bets_by_address(deps, address)?)
         }
-        QueryMsg::EstimateWinnings { address, result } => {
-            to_json_binary(&query_estimate_winnings(deps, address, result)?)
+        QueryMsg::EstimateWinnings { address, results } => {
+            to_json_binary(&query_estimate_winnings(deps, address, results)?)
         }
+        QueryMsg::Odds { format } => to_json_binary(&query_odds(deps, format)?),
+        QueryMsg::Solvency {} => to_json_binary(&query_solvency(deps, env)?),
+        QueryMsg::OwnerOf {
+            token_id,
+            include_expired,
+        } => to_json_binary(&query_owner_of(deps, env, token_id, include_expired)?),
+        QueryMsg::NftInfo { token_id } => to_json_binary(&query_nft_info(deps, token_id)?),
+        QueryMsg::NumTokens {} => to_json_binary(&query_num_tokens(deps)?),
+        QueryMsg::Tokens {
+            owner,
+            start_after,
+            limit,
+        } => to_json_binary(&query_tokens(deps, owner, start_after, limit)?),
+        QueryMsg::ContractInfo {} => to_json_binary(&query_contract_info(deps)?),
     }
 }
 
@@ -104,12 +178,45 @@ pub fn execute(
         ExecuteMsg::PlaceBet { result, receiver } => {
             execute_place_bet(deps, env, info, result, receiver)
         }
-        ExecuteMsg::ClaimWinnings { receiver } => execute_claim_winnings(deps, info, receiver),
+        ExecuteMsg::ClaimWinnings { receiver } => execute_claim_winnings(deps, env, info, receiver),
+        ExecuteMsg::WithdrawBet { result, amount } => {
+            execute_withdraw_bet(deps, env, info, result, amount)
+        }
+        ExecuteMsg::ExpireMarket {} => execute_expire_market(deps, env, info),
+        ExecuteMsg::WithdrawStake { token_id } => execute_withdraw_stake(deps, env, info, token_id),
+        ExecuteMsg::TransferPosition {
+            result,
+            amount,
+            recipient,
+        } => execute_transfer_position(deps, info, result, amount, recipient),
+        ExecuteMsg::MintBetSlip { result, amount } => {
+            execute_mint_bet_slip(deps, info, result, amount)
+        }
+        ExecuteMsg::ClaimByToken { token_id } => execute_claim_by_token(deps, env, info, token_id),
+        ExecuteMsg::TransferNft {
+            recipient,
+            token_id,
+        } => execute_transfer_nft(deps, env, info, recipient, token_id),
+        ExecuteMsg::SendNft {
+            contract,
+            token_id,
+            msg,
+        } => execute_send_nft(deps, env, info, contract, token_id, msg),
+        ExecuteMsg::Approve {
+            spender,
+            token_id,
+            expires,
+        } => execute_approve(deps, env, info, spender, token_id, expires),
+        ExecuteMsg::Revoke { spender, token_id } => execute_revoke(deps, info, spender, token_id),
         ExecuteMsg::Update {
             admin_addr,
             treasury_addr,
             start_timestamp,
             fee_bps,
+            label,
+            home_team,
+            away_team,
+            metadata,
         } => execute_update(
             deps,
             info,
@@ -118,9 +225,18 @@ pub fn execute(
                 treasury_addr,
                 start_timestamp,
                 fee_bps,
+                label,
+                home_team,
+                away_team,
+                metadata,
             },
         ),
-        ExecuteMsg::Score { result } => execute_score(deps, env, info, result),
+        ExecuteMsg::Score { results } => execute_score(deps, env, info, results),
         ExecuteMsg::Cancel {} => execute_cancel(deps, info),
+        ExecuteMsg::Void { reason } => execute_void(deps, info, reason),
+        ExecuteMsg::Postpone {} => execute_postpone(deps, env, info),
+        ExecuteMsg::Reschedule { start_timestamp } => {
+            execute_reschedule(deps, env, info, start_timestamp)
+        }
     }
 }
@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{Decimal, OverflowError, StdError};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -6,6 +6,12 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("Invalid timestamp: {0}")]
+    InvalidTimestamp(u64),
+
     #[error("Invalid address: {0}")]
     InvalidAddress(String),
 
@@ -15,6 +21,24 @@ pub enum ContractError {
     #[error("Invalid fee bps: {0}")]
     InvalidFeeBps(u64),
 
+    #[error("Invalid resolution grace period: {0}")]
+    InvalidResolutionGracePeriod(u64),
+
+    #[error("Invalid postponement period: {0}")]
+    InvalidPostponementPeriod(u64),
+
+    #[error("Invalid metadata: {0}")]
+    InvalidMetadata(String),
+
+    #[error("Invalid early bird multiplier: {0}")]
+    InvalidEarlyBirdMultiplier(Decimal),
+
+    #[error("Invalid early bird decay period: {0}")]
+    InvalidEarlyBirdDecayPeriod(u64),
+
+    #[error("Invalid withdrawal penalty bps: {0}")]
+    InvalidWithdrawalPenaltyBps(u64),
+
     #[error("Market not active")]
     MarketNotActive {},
 
@@ -27,6 +51,18 @@ pub enum ContractError {
     #[error("Market not scoreable")]
     MarketNotScoreable {},
 
+    #[error("Market not expired")]
+    MarketNotExpired {},
+
+    #[error("Market not postponed")]
+    MarketNotPostponed {},
+
+    #[error("Postponement expired")]
+    PostponementExpired {},
+
+    #[error("No stake to withdraw")]
+    NoStakeToWithdraw {},
+
     #[error("Bets no longer accepted")]
     BetsNotAccepted {},
 
@@ -39,6 +75,30 @@ pub enum ContractError {
     #[error("Claim already made")]
     ClaimAlreadyMade {},
 
+    #[error("Invalid results")]
+    InvalidResults {},
+
+    #[error("Withdrawals not enabled")]
+    WithdrawalsNotEnabled {},
+
+    #[error("Invalid withdrawal amount")]
+    InvalidWithdrawalAmount {},
+
+    #[error("Invalid transfer amount")]
+    InvalidTransferAmount {},
+
+    #[error("Invalid transfer recipient")]
+    InvalidTransferRecipient {},
+
+    #[error("Invalid bet slip amount")]
+    InvalidBetSlipAmount {},
+
+    #[error("Bet slip not found")]
+    BetSlipNotFound {},
+
+    #[error("Approval expired")]
+    ApprovalExpired {},
+
     #[error("No winnings")]
     NoWinnings {},
 }
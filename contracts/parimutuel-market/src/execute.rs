@@ -1,21 +1,32 @@
 use cosmwasm_std::{
-    coin, Addr, BankMsg, CosmosMsg, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
+    coin, Addr, BankMsg, Binary, CosmosMsg, DepsMut, Env, MessageInfo, OverflowError,
+    OverflowOperation, Response, StdResult, Storage, Uint128,
 };
+use cw_utils::Expiration;
 
 use crate::{
     error::ContractError,
-    logic::calculate_parimutuel_winnings,
-    msg::UpdateParams,
+    logic::{
+        calculate_bets_cutoff, calculate_early_bird_multiplier, calculate_parimutuel_winnings,
+        calculate_total_bets,
+    },
+    msg::{Cw721ReceiveMsg, UpdateParams},
     state::{
-        MarketResult, Status, CLAIMS, CONFIG, MARKET, POOL_AWAY, POOL_DRAW, POOL_HOME, TOTAL_AWAY,
-        TOTAL_DRAW, TOTAL_HOME,
+        load_pot, load_winning_results, Approval, BetSlip, Config, Market, MarketMetadata,
+        MarketResult, PenaltyDestination, Status, VoidReason, BET_SLIPS, BET_SLIP_COUNT, CLAIMS,
+        CONFIG, MARKET, POOL_AWAY, POOL_DRAW, POOL_HOME, POOL_PENALTIES, SHARES_AWAY, SHARES_DRAW,
+        SHARES_HOME, TOTAL_AWAY, TOTAL_DRAW, TOTAL_HOME, TOTAL_SHARES_AWAY, TOTAL_SHARES_DRAW,
+        TOTAL_SHARES_HOME,
+    },
+    validation::{
+        validate_fee_bps, validate_metadata, validate_results, validate_text, MAX_TEXT_LENGTH,
     },
-    validation::validate_fee_bps,
 };
 
 /// Places a bet on the market
 ///
 /// The total result is updated and the pool result that records the bet amount per address is updated.
+/// The shares of the address are updated with the bet amount weighted by the early bird multiplier.
 ///
 /// It will make the following checks:
 /// - If the bet is on draw, the market needs to be drawable
@@ -46,7 +57,7 @@ pub fn execute_place_bet(
     }
 
     // Bets are accepted up until 5 minutes before the start of the match
-    if market.start_timestamp - 5 * 60 < env.block.time.seconds() {
+    if calculate_bets_cutoff(market.start_timestamp)? < env.block.time.seconds() {
         return Err(ContractError::BetsNotAccepted {});
     }
 
@@ -56,17 +67,42 @@ pub fn execute_place_bet(
     }
     let bet_amount = bet_amount.unwrap();
 
+    let multiplier = calculate_early_bird_multiplier(
+        &config.early_bird,
+        market.start_timestamp,
+        env.block.time.seconds(),
+    );
+    let shares = bet_amount
+        .checked_mul_floor(multiplier)
+        .map_err(|_| OverflowError::new(OverflowOperation::Mul))?;
+
     match result {
         MarketResult::HOME => {
             if !POOL_HOME.has(deps.storage, addr.clone()) {
                 POOL_HOME.save(deps.storage, addr.clone(), &0)?;
             }
 
-            POOL_HOME.update(deps.storage, addr.clone(), |pool| -> StdResult<_> {
-                Ok(pool.unwrap() + bet_amount.u128())
+            POOL_HOME.update(
+                deps.storage,
+                addr.clone(),
+                |pool| -> Result<_, ContractError> {
+                    Ok(Uint128::from(pool.unwrap()).checked_add(bet_amount)?.u128())
+                },
+            )?;
+            TOTAL_HOME.update(deps.storage, |total| -> Result<_, ContractError> {
+                Ok(Uint128::from(total).checked_add(bet_amount)?.u128())
             })?;
-            TOTAL_HOME.update(deps.storage, |total| -> StdResult<_> {
-                Ok(total + bet_amount.u128())
+            SHARES_HOME.update(
+                deps.storage,
+                addr.clone(),
+                |pool| -> Result<_, ContractError> {
+                    Ok(Uint128::from(pool.unwrap_or_default())
+                        .checked_add(shares)?
+                        .u128())
+                },
+            )?;
+            TOTAL_SHARES_HOME.update(deps.storage, |total| -> Result<_, ContractError> {
+                Ok(Uint128::from(total).checked_add(shares)?.u128())
             })?
         }
         MarketResult::AWAY => {
@@ -74,11 +110,27 @@ pub fn execute_place_bet(
                 POOL_AWAY.save(deps.storage, addr.clone(), &0)?;
             }
 
-            POOL_AWAY.update(deps.storage, addr.clone(), |pool| -> StdResult<_> {
-                Ok(pool.unwrap() + bet_amount.u128())
+            POOL_AWAY.update(
+                deps.storage,
+                addr.clone(),
+                |pool| -> Result<_, ContractError> {
+                    Ok(Uint128::from(pool.unwrap()).checked_add(bet_amount)?.u128())
+                },
+            )?;
+            TOTAL_AWAY.update(deps.storage, |total| -> Result<_, ContractError> {
+                Ok(Uint128::from(total).checked_add(bet_amount)?.u128())
             })?;
-            TOTAL_AWAY.update(deps.storage, |total| -> StdResult<_> {
-                Ok(total + bet_amount.u128())
+            SHARES_AWAY.update(
+                deps.storage,
+                addr.clone(),
+                |pool| -> Result<_, ContractError> {
+                    Ok(Uint128::from(pool.unwrap_or_default())
+                        .checked_add(shares)?
+                        .u128())
+                },
+            )?;
+            TOTAL_SHARES_AWAY.update(deps.storage, |total| -> Result<_, ContractError> {
+                Ok(Uint128::from(total).checked_add(shares)?.u128())
             })?
         }
         MarketResult::DRAW => {
@@ -86,11 +138,27 @@ pub fn execute_place_bet(
                 POOL_DRAW.save(deps.storage, addr.clone(), &0)?;
             }
 
-            POOL_DRAW.update(deps.storage, addr.clone(), |pool| -> StdResult<_> {
-                Ok(pool.unwrap() + bet_amount.u128())
+            POOL_DRAW.update(
+                deps.storage,
+                addr.clone(),
+                |pool| -> Result<_, ContractError> {
+                    Ok(Uint128::from(pool.unwrap()).checked_add(bet_amount)?.u128())
+                },
+            )?;
+            TOTAL_DRAW.update(deps.storage, |total| -> Result<_, ContractError> {
+                Ok(Uint128::from(total).checked_add(bet_amount)?.u128())
             })?;
-            TOTAL_DRAW.update(deps.storage, |total| -> StdResult<_> {
-                Ok(total + bet_amount.u128())
+            SHARES_DRAW.update(
+                deps.storage,
+                addr.clone(),
+                |pool| -> Result<_, ContractError> {
+                    Ok(Uint128::from(pool.unwrap_or_default())
+                        .checked_add(shares)?
+                        .u128())
+                },
+            )?;
+            TOTAL_SHARES_DRAW.update(deps.storage, |total| -> Result<_, ContractError> {
+                Ok(Uint128::from(total).checked_add(shares)?.u128())
             })?
         }
     };
@@ -102,14 +170,656 @@ pub fn execute_place_bet(
         .add_attribute("sender", info.sender)
         .add_attribute("receiver", addr)
         .add_attribute("bet_amount", bet_amount.to_string())
+        .add_attribute("multiplier", multiplier.to_string())
+        .add_attribute("shares", shares.to_string())
         .add_attribute("result", result.to_string())
         .add_attribute("total_home", TOTAL_HOME.load(deps.storage)?.to_string())
         .add_attribute("total_away", TOTAL_AWAY.load(deps.storage)?.to_string())
         .add_attribute("total_draw", TOTAL_DRAW.load(deps.storage)?.to_string()))
 }
 
+/// Withdraws an amount of a bet before the cutoff
+///
+/// The pool of the address and the total of the result are decreased by the amount,
+/// as well as its shares in proportion. The amount is refunded minus the withdrawal penalty,
+/// which is sent to the treasury or left in the pool, added to the pot of the winners.
+/// The penalties left in the pool are sent to the treasury if the bets are refunded.
+///
+/// It will make the following checks:
+/// - The withdrawals need to be enabled
+/// - The market needs to be active
+/// - The current block timestamp needs to be at least 5 minutes before the start timestamp
+/// - The amount needs to be greater than zero and at most the bet amount of the address
+pub fn execute_withdraw_bet(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    result: MarketResult,
+    amount: u128,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
+
+    let withdrawal = match config.withdrawal {
+        Some(withdrawal) => withdrawal,
+        None => return Err(ContractError::WithdrawalsNotEnabled {}),
+    };
+
+    if market.status != Status::ACTIVE {
+        return Err(ContractError::MarketNotActive {});
+    }
+
+    // Bets can be withdrawn up until 5 minutes before the start of the match
+    if calculate_bets_cutoff(market.start_timestamp)? < env.block.time.seconds() {
+        return Err(ContractError::BetsNotAccepted {});
+    }
+
+    let (pool, shares, total, total_shares) = match result {
+        MarketResult::HOME => (POOL_HOME, SHARES_HOME, TOTAL_HOME, TOTAL_SHARES_HOME),
+        MarketResult::AWAY => (POOL_AWAY, SHARES_AWAY, TOTAL_AWAY, TOTAL_SHARES_AWAY),
+        MarketResult::DRAW => (POOL_DRAW, SHARES_DRAW, TOTAL_DRAW, TOTAL_SHARES_DRAW),
+    };
+
+    let addr_pool = pool
+        .may_load(deps.storage, info.sender.clone())?
+        .unwrap_or_default();
+    if amount == 0 || amount > addr_pool {
+        return Err(ContractError::InvalidWithdrawalAmount {});
+    }
+
+    let addr_shares = shares
+        .may_load(deps.storage, info.sender.clone())?
+        .unwrap_or_default();
+    let withdrawn_shares = if amount == addr_pool {
+        addr_shares
+    } else {
+        Uint128::from(addr_shares)
+            .multiply_ratio(amount, addr_pool)
+            .u128()
+    };
+
+    let penalty = Uint128::from(amount)
+        .multiply_ratio(withdrawal.penalty_bps, 10000_u128)
+        .u128();
+    let refund = amount - penalty;
+
+    pool.save(deps.storage, info.sender.clone(), &(addr_pool - amount))?;
+    shares.save(
+        deps.storage,
+        info.sender.clone(),
+        &(addr_shares - withdrawn_shares),
+    )?;
+    total_shares.update(deps.storage, |total| -> StdResult<_> {
+        Ok(total - withdrawn_shares)
+    })?;
+
+    let mut messages: Vec<CosmosMsg> = vec![BankMsg::Send {
+        to_address: info.sender.to_string(),
+        amount: vec![coin(refund, &config.denom)],
+    }
+    .into()];
+
+    total.update(deps.storage, |total| -> StdResult<_> { Ok(total - amount) })?;
+
+    match withdrawal.penalty_destination {
+        PenaltyDestination::TREASURY => {
+            if penalty > 0 {
+                messages.push(
+                    BankMsg::Send {
+                        to_address: config.treasury_addr.to_string(),
+                        amount: vec![coin(penalty, &config.denom)],
+                    }
+                    .into(),
+                );
+            }
+        }
+        PenaltyDestination::POOL => {
+            POOL_PENALTIES.update(deps.storage, |pool_penalties| -> StdResult<_> {
+                Ok(Uint128::from(pool_penalties)
+                    .checked_add(penalty.into())?
+                    .u128())
+            })?;
+        }
+    }
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "parimutuel")
+        .add_attribute("action", "withdraw_bet")
+        .add_attribute("sender", info.sender)
+        .add_attribute("result", result.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("refund", refund.to_string())
+        .add_attribute("penalty", penalty.to_string())
+        .add_attribute(
+            "penalty_destination",
+            withdrawal.penalty_destination.to_string(),
+        )
+        .add_attribute("withdrawn_shares", withdrawn_shares.to_string())
+        .add_attribute("total_home", TOTAL_HOME.load(deps.storage)?.to_string())
+        .add_attribute("total_away", TOTAL_AWAY.load(deps.storage)?.to_string())
+        .add_attribute("total_draw", TOTAL_DRAW.load(deps.storage)?.to_string()))
+}
+
+/// Withdraws the whole stake of the sender while the market is postponed,
+/// or the stake of one of its bet slips when a token id is given
+///
+/// Unlike `execute_withdraw_bet` the stake is refunded without any penalty, the pools,
+/// the shares and the totals of each result are decreased by the withdrawn bets.
+///
+/// It will make the following checks:
+/// - The market needs to be postponed and its postponement deadline can't have passed
+/// - The bet slip needs to exist and the sender needs to be its owner
+/// - The sender needs to have some stake to withdraw
+pub fn execute_withdraw_stake(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
+
+    if market.status != Status::POSTPONED {
+        return Err(ContractError::MarketNotPostponed {});
+    }
+
+    if is_postponement_expired(&market, &env) {
+        return Err(ContractError::PostponementExpired {});
+    }
+
+    let mut refund = 0;
+    if let Some(token_id) = &token_id {
+        let bet_slip = match BET_SLIPS.may_load(deps.storage, token_id.clone())? {
+            Some(bet_slip) => bet_slip,
+            None => return Err(ContractError::BetSlipNotFound {}),
+        };
+
+        if bet_slip.owner != info.sender {
+            return Err(ContractError::Unauthorized {});
+        }
+
+        let (total, total_shares) = match bet_slip.result {
+            MarketResult::HOME => (TOTAL_HOME, TOTAL_SHARES_HOME),
+            MarketResult::AWAY => (TOTAL_AWAY, TOTAL_SHARES_AWAY),
+            MarketResult::DRAW => (TOTAL_DRAW, TOTAL_SHARES_DRAW),
+        };
+        total.update(deps.storage, |total| -> StdResult<_> {
+            Ok(total - bet_slip.bet_amount)
+        })?;
+        total_shares.update(deps.storage, |total| -> StdResult<_> {
+            Ok(total - bet_slip.shares)
+        })?;
+        BET_SLIPS.remove(deps.storage, token_id.clone());
+
+        refund = bet_slip.bet_amount;
+    } else {
+        for (pool, shares, total, total_shares) in [
+            (POOL_HOME, SHARES_HOME, TOTAL_HOME, TOTAL_SHARES_HOME),
+            (POOL_AWAY, SHARES_AWAY, TOTAL_AWAY, TOTAL_SHARES_AWAY),
+            (POOL_DRAW, SHARES_DRAW, TOTAL_DRAW, TOTAL_SHARES_DRAW),
+        ] {
+            let addr_pool = pool
+                .may_load(deps.storage, info.sender.clone())?
+                .unwrap_or_default();
+            let addr_shares = shares
+                .may_load(deps.storage, info.sender.clone())?
+                .unwrap_or_default();
+
+            pool.remove(deps.storage, info.sender.clone());
+            shares.remove(deps.storage, info.sender.clone());
+            total.update(deps.storage, |total| -> StdResult<_> {
+                Ok(total - addr_pool)
+            })?;
+            total_shares.update(deps.storage, |total| -> StdResult<_> {
+                Ok(total - addr_shares)
+            })?;
+
+            refund += addr_pool;
+        }
+    }
+
+    if refund == 0 {
+        return Err(ContractError::NoStakeToWithdraw {});
+    }
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![coin(refund, &config.denom)],
+        })
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "parimutuel")
+        .add_attribute("action", "withdraw_stake")
+        .add_attribute("sender", info.sender)
+        .add_attribute("token_id", token_id.unwrap_or_default())
+        .add_attribute("refund", refund.to_string())
+        .add_attribute("total_home", TOTAL_HOME.load(deps.storage)?.to_string())
+        .add_attribute("total_away", TOTAL_AWAY.load(deps.storage)?.to_string())
+        .add_attribute("total_draw", TOTAL_DRAW.load(deps.storage)?.to_string()))
+}
+
+/// Transfers part or all of the bet amount of the sender on a result to the recipient,
+/// so the recipient can claim it once the market is settled
+///
+/// The shares of the sender are moved in proportion to the transferred amount,
+/// the totals of the market are left untouched.
+///
+/// It will make the following checks:
+/// - The market needs to be active or postponed
+/// - The recipient can't be the sender
+/// - The amount needs to be greater than zero and at most the bet amount of the sender
+pub fn execute_transfer_position(
+    deps: DepsMut,
+    info: MessageInfo,
+    result: MarketResult,
+    amount: u128,
+    recipient: Addr,
+) -> Result<Response, ContractError> {
+    let market = MARKET.load(deps.storage)?;
+
+    if !market.status.is_open() {
+        return Err(ContractError::MarketNotActive {});
+    }
+
+    let recipient = deps.api.addr_validate(recipient.as_str())?;
+    if recipient == info.sender {
+        return Err(ContractError::InvalidTransferRecipient {});
+    }
+
+    let transferred_shares = match take_position(deps.storage, &info.sender, &result, amount)? {
+        Some(shares) => shares,
+        None => return Err(ContractError::InvalidTransferAmount {}),
+    };
+    add_position(
+        deps.storage,
+        &recipient,
+        &result,
+        amount,
+        transferred_shares,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "parimutuel")
+        .add_attribute("action", "transfer_position")
+        .add_attribute("sender", info.sender)
+        .add_attribute("recipient", recipient)
+        .add_attribute("result", result.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("shares", transferred_shares.to_string()))
+}
+
+/// Mints part or all of the bet amount of the sender on a result as a bet slip token
+/// owned by the sender
+///
+/// The bet amount and its shares are moved out of the pool of the sender into the bet slip,
+/// which can be transferred or sold following the cw721 interface and is claimed by its
+/// owner with `execute_claim_by_token`.
+///
+/// It will make the following checks:
+/// - The market needs to be active
+/// - The amount needs to be greater than zero and at most the bet amount of the sender
+pub fn execute_mint_bet_slip(
+    deps: DepsMut,
+    info: MessageInfo,
+    result: MarketResult,
+    amount: u128,
+) -> Result<Response, ContractError> {
+    let market = MARKET.load(deps.storage)?;
+
+    if market.status != Status::ACTIVE {
+        return Err(ContractError::MarketNotActive {});
+    }
+
+    let shares = match take_position(deps.storage, &info.sender, &result, amount)? {
+        Some(shares) => shares,
+        None => return Err(ContractError::InvalidBetSlipAmount {}),
+    };
+
+    let token_id = (BET_SLIP_COUNT.load(deps.storage)? + 1).to_string();
+    BET_SLIP_COUNT.update(deps.storage, |count| -> StdResult<_> { Ok(count + 1) })?;
+    BET_SLIPS.save(
+        deps.storage,
+        token_id.clone(),
+        &BetSlip {
+            token_id: token_id.clone(),
+            owner: info.sender.clone(),
+            approvals: vec![],
+            result: result.clone(),
+            bet_amount: amount,
+            shares,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "parimutuel")
+        .add_attribute("action", "mint_bet_slip")
+        .add_attribute("sender", info.sender)
+        .add_attribute("token_id", token_id)
+        .add_attribute("result", result.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("shares", shares.to_string()))
+}
+
+/// Claims the winnings of a bet slip for its owner and burns the bet slip, or returns
+/// its bet amount if the market was cancelled, voided or scored without winners or losers
+///
+/// On a dead heat the winnings are split the same way as in `execute_claim_winnings`.
+///
+/// It will make the following checks:
+/// - The bet slip needs to exist
+/// - The sender needs to be the owner of the bet slip
+/// - The market needs to be closed
+/// - The bet slip needs to have some amount to claim
+pub fn execute_claim_by_token(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let (market, mut messages) = expire_postponement(deps.storage, &config, &env)?;
+
+    let bet_slip = match BET_SLIPS.may_load(deps.storage, token_id.clone())? {
+        Some(bet_slip) => bet_slip,
+        None => return Err(ContractError::BetSlipNotFound {}),
+    };
+
+    if bet_slip.owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if market.status.is_open() {
+        return Err(ContractError::MarketNotClosed {});
+    }
+
+    let mut payout = 0;
+    if market.status == Status::CANCELLED || market.status == Status::VOIDED || market.is_refund {
+        payout = bet_slip.bet_amount;
+    } else if market.results.contains(&bet_slip.result) {
+        let pot = load_pot(deps.storage)?;
+
+        let mut fee_amount = Uint128::zero();
+        if config.fee_bps > 0 {
+            fee_amount = Uint128::from(pot)
+                .multiply_ratio(Uint128::from(config.fee_bps), Uint128::from(10000_u128));
+        }
+
+        let team_shares = match bet_slip.result {
+            MarketResult::HOME => TOTAL_SHARES_HOME.load(deps.storage)?,
+            MarketResult::AWAY => TOTAL_SHARES_AWAY.load(deps.storage)?,
+            MarketResult::DRAW => TOTAL_SHARES_DRAW.load(deps.storage)?,
+        };
+        payout = calculate_parimutuel_winnings(
+            pot - fee_amount.u128(),
+            load_winning_results(deps.storage, &market.results)?,
+            team_shares,
+            bet_slip.shares,
+        )?;
+    }
+
+    if payout == 0 {
+        return Err(ContractError::NoWinnings {});
+    }
+
+    BET_SLIPS.remove(deps.storage, token_id.clone());
+
+    messages.push(
+        BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![coin(payout, config.denom)],
+        }
+        .into(),
+    );
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "parimutuel")
+        .add_attribute("action", "claim_by_token")
+        .add_attribute("sender", info.sender)
+        .add_attribute("token_id", token_id)
+        .add_attribute("payout", payout.to_string()))
+}
+
+/// Transfers a bet slip to the recipient, clearing its approvals
+///
+/// It will make the following checks:
+/// - The bet slip needs to exist
+/// - The sender needs to be the owner of the bet slip or have an approval on it that hasn't expired
+pub fn execute_transfer_nft(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+    transfer_bet_slip(deps, &env, &info.sender, &recipient, &token_id)?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "parimutuel")
+        .add_attribute("action", "transfer_nft")
+        .add_attribute("sender", info.sender)
+        .add_attribute("recipient", recipient)
+        .add_attribute("token_id", token_id))
+}
+
+/// Transfers a bet slip to a contract and notifies it with a `ReceiveNft` message
+///
+/// It will make the following checks:
+/// - The bet slip needs to exist
+/// - The sender needs to be the owner of the bet slip or have an approval on it that hasn't expired
+pub fn execute_send_nft(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    contract: String,
+    token_id: String,
+    msg: Binary,
+) -> Result<Response, ContractError> {
+    let contract = deps.api.addr_validate(&contract)?;
+    transfer_bet_slip(deps, &env, &info.sender, &contract, &token_id)?;
+
+    let receive_msg = Cw721ReceiveMsg {
+        sender: info.sender.to_string(),
+        token_id: token_id.clone(),
+        msg,
+    };
+
+    Ok(Response::new()
+        .add_message(receive_msg.into_cosmos_msg(contract.to_string())?)
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "parimutuel")
+        .add_attribute("action", "send_nft")
+        .add_attribute("sender", info.sender)
+        .add_attribute("recipient", contract)
+        .add_attribute("token_id", token_id))
+}
+
+/// Approves the spender to transfer a bet slip until the expiration, replacing
+/// any previous approval of the spender on it
+///
+/// It will make the following checks:
+/// - The bet slip needs to exist
+/// - The sender needs to be the owner of the bet slip
+/// - The expiration can't be already expired
+pub fn execute_approve(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    spender: String,
+    token_id: String,
+    expires: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    let spender = deps.api.addr_validate(&spender)?;
+
+    let mut bet_slip = match BET_SLIPS.may_load(deps.storage, token_id.clone())? {
+        Some(bet_slip) => bet_slip,
+        None => return Err(ContractError::BetSlipNotFound {}),
+    };
+
+    if bet_slip.owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let expires = expires.unwrap_or_default();
+    if expires.is_expired(&env.block) {
+        return Err(ContractError::ApprovalExpired {});
+    }
+
+    bet_slip
+        .approvals
+        .retain(|approval| approval.spender != spender);
+    bet_slip.approvals.push(Approval {
+        spender: spender.clone(),
+        expires,
+    });
+    BET_SLIPS.save(deps.storage, token_id.clone(), &bet_slip)?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "parimutuel")
+        .add_attribute("action", "approve")
+        .add_attribute("sender", info.sender)
+        .add_attribute("spender", spender)
+        .add_attribute("token_id", token_id))
+}
+
+/// Revokes the approval of the spender on a bet slip
+///
+/// It will make the following checks:
+/// - The bet slip needs to exist
+/// - The sender needs to be the owner of the bet slip
+pub fn execute_revoke(
+    deps: DepsMut,
+    info: MessageInfo,
+    spender: String,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    let spender = deps.api.addr_validate(&spender)?;
+
+    let mut bet_slip = match BET_SLIPS.may_load(deps.storage, token_id.clone())? {
+        Some(bet_slip) => bet_slip,
+        None => return Err(ContractError::BetSlipNotFound {}),
+    };
+
+    if bet_slip.owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    bet_slip
+        .approvals
+        .retain(|approval| approval.spender != spender);
+    BET_SLIPS.save(deps.storage, token_id.clone(), &bet_slip)?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "parimutuel")
+        .add_attribute("action", "revoke")
+        .add_attribute("sender", info.sender)
+        .add_attribute("spender", spender)
+        .add_attribute("token_id", token_id))
+}
+
+/// Moves a bet slip to the recipient if the sender is its owner
+/// or has an approval on it that hasn't expired
+fn transfer_bet_slip(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    recipient: &Addr,
+    token_id: &str,
+) -> Result<(), ContractError> {
+    let mut bet_slip = match BET_SLIPS.may_load(deps.storage, token_id.to_string())? {
+        Some(bet_slip) => bet_slip,
+        None => return Err(ContractError::BetSlipNotFound {}),
+    };
+
+    let is_approved = bet_slip
+        .approvals
+        .iter()
+        .any(|approval| approval.spender == *sender && !approval.expires.is_expired(&env.block));
+    if bet_slip.owner != *sender && !is_approved {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    bet_slip.owner = recipient.clone();
+    bet_slip.approvals = vec![];
+    BET_SLIPS.save(deps.storage, token_id.to_string(), &bet_slip)?;
+
+    Ok(())
+}
+
+/// Removes part or all of the bet amount of the address on a result from its pool
+///
+/// Returns the shares moved in proportion to the amount, or `None` if the amount
+/// isn't greater than zero and at most the bet amount of the address
+fn take_position(
+    storage: &mut dyn Storage,
+    addr: &Addr,
+    result: &MarketResult,
+    amount: u128,
+) -> StdResult<Option<u128>> {
+    let (pool, shares) = match result {
+        MarketResult::HOME => (POOL_HOME, SHARES_HOME),
+        MarketResult::AWAY => (POOL_AWAY, SHARES_AWAY),
+        MarketResult::DRAW => (POOL_DRAW, SHARES_DRAW),
+    };
+
+    let addr_pool = pool.may_load(storage, addr.clone())?.unwrap_or_default();
+    if amount == 0 || amount > addr_pool {
+        return Ok(None);
+    }
+
+    let addr_shares = shares.may_load(storage, addr.clone())?.unwrap_or_default();
+    let moved_shares = if amount == addr_pool {
+        addr_shares
+    } else {
+        Uint128::from(addr_shares)
+            .multiply_ratio(amount, addr_pool)
+            .u128()
+    };
+
+    pool.save(storage, addr.clone(), &(addr_pool - amount))?;
+    shares.save(storage, addr.clone(), &(addr_shares - moved_shares))?;
+
+    Ok(Some(moved_shares))
+}
+
+/// Adds the bet amount and its shares to the pool of the address on a result
+fn add_position(
+    storage: &mut dyn Storage,
+    addr: &Addr,
+    result: &MarketResult,
+    amount: u128,
+    moved_shares: u128,
+) -> StdResult<()> {
+    let (pool, shares) = match result {
+        MarketResult::HOME => (POOL_HOME, SHARES_HOME),
+        MarketResult::AWAY => (POOL_AWAY, SHARES_AWAY),
+        MarketResult::DRAW => (POOL_DRAW, SHARES_DRAW),
+    };
+
+    pool.update(storage, addr.clone(), |bet| -> StdResult<_> {
+        Ok(bet.unwrap_or_default() + amount)
+    })?;
+    shares.update(storage, addr.clone(), |bet| -> StdResult<_> {
+        Ok(bet.unwrap_or_default() + moved_shares)
+    })?;
+
+    Ok(())
+}
+
 /// Claims winnings for the sender or the receiver if defined
-/// or returns all bets made if the market was cancelled
+/// or returns all bets made if the market was cancelled, voided
+/// or scored without winners or losers
+///
+/// On a dead heat the winnings of each winning result are added up.
 ///
 /// It will make the following checks:
 /// - The market needs to be closed
@@ -117,18 +827,19 @@ pub fn execute_place_bet(
 /// - The address needs to have some amount to claim
 pub fn execute_claim_winnings(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     receiver: Option<Addr>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    let market = MARKET.load(deps.storage)?;
+    let (market, mut messages) = expire_postponement(deps.storage, &config, &env)?;
 
     let addr = match receiver {
         Some(receiver) => deps.api.addr_validate(receiver.as_str())?,
         None => info.sender.clone(),
     };
 
-    if market.status == Status::ACTIVE {
+    if market.status.is_open() {
         return Err(ContractError::MarketNotClosed {});
     }
 
@@ -136,7 +847,7 @@ pub fn execute_claim_winnings(
         return Err(ContractError::ClaimAlreadyMade {});
     }
 
-    let payout;
+    let mut payout;
 
     let addr_pool_home = if POOL_HOME.has(deps.storage, addr.clone()) {
         POOL_HOME.load(deps.storage, addr.clone())?
@@ -156,42 +867,43 @@ pub fn execute_claim_winnings(
         0
     };
 
-    if market.status == Status::CANCELLED {
+    if market.status == Status::CANCELLED || market.status == Status::VOIDED || market.is_refund {
         payout = addr_pool_home + addr_pool_away + addr_pool_draw;
     } else {
-        let bet_amount = match market.result {
-            Some(MarketResult::HOME) => addr_pool_home,
-            Some(MarketResult::AWAY) => addr_pool_away,
-            Some(MarketResult::DRAW) => addr_pool_draw,
-            None => 0,
-        };
-
-        let total_home = TOTAL_HOME.load(deps.storage)?;
-        let total_away = TOTAL_AWAY.load(deps.storage)?;
-        let total_draw = TOTAL_DRAW.load(deps.storage)?;
-
-        let team_bets = match market.result {
-            Some(MarketResult::HOME) => total_home,
-            Some(MarketResult::AWAY) => total_away,
-            Some(MarketResult::DRAW) => total_draw,
-            None => 0,
-        };
+        let pot = load_pot(deps.storage)?;
 
         let mut fee_amount = Uint128::zero();
         if config.fee_bps > 0 {
-            fee_amount = Uint128::from(total_home + total_away + total_draw)
+            fee_amount = Uint128::from(pot)
                 .multiply_ratio(Uint128::from(config.fee_bps), Uint128::from(10000_u128));
         }
 
-        payout = calculate_parimutuel_winnings(
-            total_home + total_away + total_draw - fee_amount.u128(),
-            team_bets,
-            bet_amount,
-        );
+        let winning_results = load_winning_results(deps.storage, &market.results)?;
+        payout = 0;
+        for result in market.results.iter() {
+            let (team_shares, shares) = match result {
+                MarketResult::HOME => (
+                    TOTAL_SHARES_HOME.load(deps.storage)?,
+                    SHARES_HOME.may_load(deps.storage, addr.clone())?,
+                ),
+                MarketResult::AWAY => (
+                    TOTAL_SHARES_AWAY.load(deps.storage)?,
+                    SHARES_AWAY.may_load(deps.storage, addr.clone())?,
+                ),
+                MarketResult::DRAW => (
+                    TOTAL_SHARES_DRAW.load(deps.storage)?,
+                    SHARES_DRAW.may_load(deps.storage, addr.clone())?,
+                ),
+            };
+            payout += calculate_parimutuel_winnings(
+                pot - fee_amount.u128(),
+                winning_results,
+                team_shares,
+                shares.unwrap_or_default(),
+            )?;
+        }
     }
 
-    let mut messages: Vec<CosmosMsg> = vec![];
-
     if payout > 0 {
         messages.push(
             BankMsg::Send {
@@ -218,6 +930,9 @@ pub fn execute_claim_winnings(
 
 /// Updates the market with the new params
 ///
+/// The label, the team names and the metadata can be edited as well,
+/// the given metadata replaces the whole metadata of the market.
+///
 /// It will make the following checks:
 /// - The sender needs to be the admin
 /// - The market needs to be active
@@ -259,9 +974,40 @@ pub fn execute_update(
     let mut start_timestamp_update = String::default();
     if let Some(start_timestamp) = params.start_timestamp {
         market.start_timestamp = start_timestamp;
+        market.resolution_deadline = start_timestamp
+            .checked_add(config.resolution_grace_period)
+            .ok_or(ContractError::InvalidTimestamp(start_timestamp))?;
         start_timestamp_update = start_timestamp.to_string();
     }
 
+    let mut label_update = String::default();
+    if let Some(label) = params.label {
+        validate_text("label", &label, MAX_TEXT_LENGTH)?;
+        market.label = label.clone();
+        label_update = label;
+    }
+
+    let mut home_team_update = String::default();
+    if let Some(home_team) = params.home_team {
+        validate_text("home_team", &home_team, MAX_TEXT_LENGTH)?;
+        market.home_team = home_team.clone();
+        home_team_update = home_team;
+    }
+
+    let mut away_team_update = String::default();
+    if let Some(away_team) = params.away_team {
+        validate_text("away_team", &away_team, MAX_TEXT_LENGTH)?;
+        market.away_team = away_team.clone();
+        away_team_update = away_team;
+    }
+
+    let mut metadata_update = MarketMetadata::default();
+    if let Some(metadata) = params.metadata {
+        validate_metadata(&metadata)?;
+        market.metadata = Some(metadata.clone());
+        metadata_update = metadata;
+    }
+
     CONFIG.save(deps.storage, &config)?;
     MARKET.save(deps.storage, &market)?;
 
@@ -274,6 +1020,10 @@ pub fn execute_update(
         .add_attribute("treasury_addr", treasury_addr_update)
         .add_attribute("fee_bps", fee_bps_update)
         .add_attribute("start_timestamp", start_timestamp_update)
+        .add_attribute("label", label_update)
+        .add_attribute("home_team", home_team_update)
+        .add_attribute("away_team", away_team_update)
+        .add_attributes(metadata_update.attributes())
         .add_attribute("total_home", TOTAL_HOME.load(deps.storage)?.to_string())
         .add_attribute("total_away", TOTAL_AWAY.load(deps.storage)?.to_string())
         .add_attribute("total_draw", TOTAL_DRAW.load(deps.storage)?.to_string()))
@@ -282,17 +1032,25 @@ pub fn execute_update(
 /// Scores the market and collects fees to the treasury,
 /// based on the fee_bps in the config
 ///
+/// Several results can be given on a dead heat, the pool after the fee
+/// is then split equally among the winning results with some bets.
+///
+/// When there are no winners or no losers the result is still recorded
+/// but the market is settled as a refund, all the bets can be claimed back
+/// and no fee is collected, the withdrawal penalties left in the pool are
+/// sent to the treasury.
+///
 /// It will make the following checks:
 /// - The sender needs to be the admin
-/// - If the result is DRAW, the market needs to be drawable
+/// - There needs to be at least one result and no duplicated results
+/// - If one of the results is DRAW, the market needs to be drawable
 /// - The market needs to be active
 /// - The current block timestamp needs to be at least 30 minutes after the start timestamp
-/// - There needs to be some winnings
 pub fn execute_score(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    result: MarketResult,
+    results: Vec<MarketResult>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     let market = MARKET.load(deps.storage)?;
@@ -301,51 +1059,62 @@ pub fn execute_score(
         return Err(ContractError::Unauthorized {});
     }
 
-    if !market.is_drawable && result == MarketResult::DRAW {
-        return Err(ContractError::MarketNotDrawable {});
-    }
+    validate_results(&results, market.is_drawable)?;
 
     if market.status != Status::ACTIVE {
         return Err(ContractError::MarketNotActive {});
     }
 
     // Market can only be scored after 30 minutes of its start timestamp
-    if env.block.time.seconds() < market.start_timestamp + 30 * 60 {
+    let scoring_timestamp = market
+        .start_timestamp
+        .checked_add(30 * 60)
+        .ok_or(ContractError::InvalidTimestamp(market.start_timestamp))?;
+    if env.block.time.seconds() < scoring_timestamp {
         return Err(ContractError::MarketNotScoreable {});
     }
 
     let total_home = TOTAL_HOME.load(deps.storage)?;
     let total_away = TOTAL_AWAY.load(deps.storage)?;
     let total_draw = TOTAL_DRAW.load(deps.storage)?;
+    let total_bets = calculate_total_bets(total_home, total_away, total_draw)?;
 
-    let winning_side = match result {
-        MarketResult::HOME => total_home,
-        MarketResult::AWAY => total_away,
-        MarketResult::DRAW => total_draw,
-    };
+    let total_shares_home = TOTAL_SHARES_HOME.load(deps.storage)?;
+    let total_shares_away = TOTAL_SHARES_AWAY.load(deps.storage)?;
+    let total_shares_draw = TOTAL_SHARES_DRAW.load(deps.storage)?;
 
-    let losing_side = match result {
-        MarketResult::HOME => total_away + total_draw,
-        MarketResult::AWAY => total_home + total_draw,
-        MarketResult::DRAW => total_home + total_away,
-    };
+    // The winners are found with the shares as nobody can claim
+    // the winnings of a result without any
+    let winning_sides: Vec<(u128, u128)> = results
+        .iter()
+        .map(|result| match result {
+            MarketResult::HOME => (total_home, total_shares_home),
+            MarketResult::AWAY => (total_away, total_shares_away),
+            MarketResult::DRAW => (total_draw, total_shares_draw),
+        })
+        .collect();
 
-    if winning_side == 0 || losing_side == 0 {
-        return Err(ContractError::NoWinnings {});
-    }
+    let winning_side: u128 = winning_sides.iter().map(|(total, _)| total).sum();
+    let winning_shares: u128 = winning_sides.iter().map(|(_, shares)| shares).sum();
+    let losing_side = total_bets - winning_side;
+    let is_refund = winning_shares == 0 || losing_side == 0;
 
     let mut market = market;
     market.status = Status::CLOSED;
-    market.result = Some(result.clone());
+    market.results = results.clone();
+    market.is_refund = is_refund;
     MARKET.save(deps.storage, &market)?;
 
     let mut fee_amount = Uint128::zero();
-    if config.fee_bps > 0 {
-        fee_amount = Uint128::from(total_home + total_away + total_draw)
+    if config.fee_bps > 0 && !is_refund {
+        fee_amount = Uint128::from(load_pot(deps.storage)?)
             .multiply_ratio(Uint128::from(config.fee_bps), Uint128::from(10000_u128));
     }
 
     let mut messages: Vec<CosmosMsg> = vec![];
+    if is_refund {
+        messages = sweep_pool_penalties(deps.storage, &config)?;
+    }
 
     if fee_amount > Uint128::zero() {
         messages.push(
@@ -364,7 +1133,15 @@ pub fn execute_score(
         .add_attribute("action", "score_market")
         .add_attribute("sender", info.sender)
         .add_attribute("status", Status::CLOSED.to_string())
-        .add_attribute("result", result.to_string())
+        .add_attribute(
+            "results",
+            results
+                .iter()
+                .map(|result| result.to_string())
+                .collect::<Vec<String>>()
+                .join(","),
+        )
+        .add_attribute("refund", is_refund.to_string())
         .add_attribute("fee_collected", fee_amount)
         .add_attribute("total_home", total_home.to_string())
         .add_attribute("total_away", total_away.to_string())
@@ -375,7 +1152,7 @@ pub fn execute_score(
 ///
 /// It will make the following checks:
 /// - The sender needs to be the admin
-/// - The market needs to be active
+/// - The market needs to be active or postponed
 pub fn execute_cancel(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     let market = MARKET.load(deps.storage)?;
@@ -384,7 +1161,7 @@ pub fn execute_cancel(deps: DepsMut, info: MessageInfo) -> Result<Response, Cont
         return Err(ContractError::Unauthorized {});
     }
 
-    if market.status != Status::ACTIVE {
+    if !market.status.is_open() {
         return Err(ContractError::MarketNotActive {});
     }
 
@@ -393,7 +1170,10 @@ pub fn execute_cancel(deps: DepsMut, info: MessageInfo) -> Result<Response, Cont
         Ok(market)
     })?;
 
+    let messages = sweep_pool_penalties(deps.storage, &config)?;
+
     Ok(Response::new()
+        .add_messages(messages)
         .add_attribute("protocol", "vendetta-markets")
         .add_attribute("market_type", "parimutuel")
         .add_attribute("action", "cancel_market")
@@ -403,3 +1183,255 @@ pub fn execute_cancel(deps: DepsMut, info: MessageInfo) -> Result<Response, Cont
         .add_attribute("total_away", TOTAL_AWAY.load(deps.storage)?.to_string())
         .add_attribute("total_draw", TOTAL_DRAW.load(deps.storage)?.to_string()))
 }
+
+/// Voids the market when the match is abandoned after kickoff
+///
+/// The bets are refunded the same way as a cancelled market but the market
+/// keeps a distinct status and the reason it was voided.
+///
+/// It will make the following checks:
+/// - The sender needs to be the admin
+/// - The market needs to be active or postponed
+pub fn execute_void(
+    deps: DepsMut,
+    info: MessageInfo,
+    reason: VoidReason,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
+
+    if info.sender != config.admin_addr {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if !market.status.is_open() {
+        return Err(ContractError::MarketNotActive {});
+    }
+
+    MARKET.update(deps.storage, |mut market| -> Result<_, ContractError> {
+        market.status = Status::VOIDED;
+        market.void_reason = Some(reason.clone());
+        Ok(market)
+    })?;
+
+    let messages = sweep_pool_penalties(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "parimutuel")
+        .add_attribute("action", "void_market")
+        .add_attribute("sender", info.sender)
+        .add_attribute("status", Status::VOIDED.to_string())
+        .add_attribute("reason", reason.to_string())
+        .add_attribute("total_home", TOTAL_HOME.load(deps.storage)?.to_string())
+        .add_attribute("total_away", TOTAL_AWAY.load(deps.storage)?.to_string())
+        .add_attribute("total_draw", TOTAL_DRAW.load(deps.storage)?.to_string()))
+}
+
+/// Postpones the market when its match is delayed without a new start timestamp yet
+///
+/// The bets are frozen until the market is rescheduled, in the meantime each bettor can
+/// withdraw their stake, see `execute_withdraw_stake`, or stay in. If the market isn't
+/// rescheduled before the postponement deadline its bets are refunded as if cancelled.
+///
+/// It will make the following checks:
+/// - The sender needs to be the admin
+/// - The market needs to be active
+pub fn execute_postpone(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut market = MARKET.load(deps.storage)?;
+
+    if info.sender != config.admin_addr {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if market.status != Status::ACTIVE {
+        return Err(ContractError::MarketNotActive {});
+    }
+
+    let postponement_deadline = env
+        .block
+        .time
+        .seconds()
+        .checked_add(config.postponement_period)
+        .ok_or(ContractError::InvalidTimestamp(env.block.time.seconds()))?;
+
+    market.status = Status::POSTPONED;
+    market.postponement_deadline = Some(postponement_deadline);
+    MARKET.save(deps.storage, &market)?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "parimutuel")
+        .add_attribute("action", "postpone_market")
+        .add_attribute("sender", info.sender)
+        .add_attribute("status", Status::POSTPONED.to_string())
+        .add_attribute("postponement_deadline", postponement_deadline.to_string())
+        .add_attribute("total_home", TOTAL_HOME.load(deps.storage)?.to_string())
+        .add_attribute("total_away", TOTAL_AWAY.load(deps.storage)?.to_string())
+        .add_attribute("total_draw", TOTAL_DRAW.load(deps.storage)?.to_string()))
+}
+
+/// Reschedules a postponed market to its new start timestamp and reopens the bets
+///
+/// It will make the following checks:
+/// - The sender needs to be the admin
+/// - The market needs to be postponed and its postponement deadline can't have passed
+/// - The start timestamp needs to be after the current block timestamp
+pub fn execute_reschedule(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    start_timestamp: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut market = MARKET.load(deps.storage)?;
+
+    if info.sender != config.admin_addr {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if market.status != Status::POSTPONED {
+        return Err(ContractError::MarketNotPostponed {});
+    }
+
+    if is_postponement_expired(&market, &env) {
+        return Err(ContractError::PostponementExpired {});
+    }
+
+    if start_timestamp <= env.block.time.seconds() {
+        return Err(ContractError::InvalidTimestamp(start_timestamp));
+    }
+
+    market.status = Status::ACTIVE;
+    market.start_timestamp = start_timestamp;
+    market.resolution_deadline = start_timestamp
+        .checked_add(config.resolution_grace_period)
+        .ok_or(ContractError::InvalidTimestamp(start_timestamp))?;
+    market.postponement_deadline = None;
+    MARKET.save(deps.storage, &market)?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "parimutuel")
+        .add_attribute("action", "reschedule_market")
+        .add_attribute("sender", info.sender)
+        .add_attribute("status", Status::ACTIVE.to_string())
+        .add_attribute("start_timestamp", start_timestamp.to_string())
+        .add_attribute(
+            "resolution_deadline",
+            market.resolution_deadline.to_string(),
+        )
+        .add_attribute("total_home", TOTAL_HOME.load(deps.storage)?.to_string())
+        .add_attribute("total_away", TOTAL_AWAY.load(deps.storage)?.to_string())
+        .add_attribute("total_draw", TOTAL_DRAW.load(deps.storage)?.to_string()))
+}
+
+/// Expires the market when it wasn't scored before its resolution deadline,
+/// or wasn't rescheduled before its postponement deadline once postponed,
+/// it is cancelled so the bets can be refunded
+///
+/// Anyone can expire the market so funds are never stuck if the admin is gone.
+///
+/// It will make the following checks:
+/// - The market needs to be active or postponed
+/// - The current block timestamp needs to be after the resolution deadline,
+///   or after the postponement deadline if the market is postponed
+pub fn execute_expire_market(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
+
+    let is_expired = match market.status {
+        Status::ACTIVE => env.block.time.seconds() >= market.resolution_deadline,
+        Status::POSTPONED => is_postponement_expired(&market, &env),
+        _ => return Err(ContractError::MarketNotActive {}),
+    };
+    if !is_expired {
+        return Err(ContractError::MarketNotExpired {});
+    }
+
+    MARKET.update(deps.storage, |mut market| -> Result<_, ContractError> {
+        market.status = Status::CANCELLED;
+        Ok(market)
+    })?;
+
+    let messages = sweep_pool_penalties(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "parimutuel")
+        .add_attribute("action", "expire_market")
+        .add_attribute("sender", info.sender)
+        .add_attribute("status", Status::CANCELLED.to_string())
+        .add_attribute(
+            "resolution_deadline",
+            market.resolution_deadline.to_string(),
+        )
+        .add_attribute(
+            "postponement_deadline",
+            market
+                .postponement_deadline
+                .map(|deadline| deadline.to_string())
+                .unwrap_or_default(),
+        )
+        .add_attribute("total_home", TOTAL_HOME.load(deps.storage)?.to_string())
+        .add_attribute("total_away", TOTAL_AWAY.load(deps.storage)?.to_string())
+        .add_attribute("total_draw", TOTAL_DRAW.load(deps.storage)?.to_string()))
+}
+
+/// Whether the market is postponed and its postponement deadline passed
+fn is_postponement_expired(market: &Market, env: &Env) -> bool {
+    market.status == Status::POSTPONED
+        && market
+            .postponement_deadline
+            .is_some_and(|deadline| env.block.time.seconds() >= deadline)
+}
+
+/// Loads the market, cancelling it first if its postponement expired,
+/// so its bets are refunded as if cancelled without waiting for `execute_expire_market`
+///
+/// Along with the market it returns the messages sending the withdrawal penalties
+/// left in the pool to the treasury when the market was cancelled.
+fn expire_postponement(
+    storage: &mut dyn Storage,
+    config: &Config,
+    env: &Env,
+) -> StdResult<(Market, Vec<CosmosMsg>)> {
+    let mut market = MARKET.load(storage)?;
+
+    let mut messages = vec![];
+    if is_postponement_expired(&market, env) {
+        market.status = Status::CANCELLED;
+        MARKET.save(storage, &market)?;
+        messages = sweep_pool_penalties(storage, config)?;
+    }
+
+    Ok((market, messages))
+}
+
+/// Sends the withdrawal penalties left in the pool to the treasury once the bets
+/// are refunded, as there are no winners to add them to
+fn sweep_pool_penalties(storage: &mut dyn Storage, config: &Config) -> StdResult<Vec<CosmosMsg>> {
+    let pool_penalties = POOL_PENALTIES.load(storage)?;
+    if pool_penalties == 0 {
+        return Ok(vec![]);
+    }
+
+    POOL_PENALTIES.save(storage, &0)?;
+
+    Ok(vec![BankMsg::Send {
+        to_address: config.treasury_addr.to_string(),
+        amount: vec![coin(pool_penalties, &config.denom)],
+    }
+    .into()])
+}
@@ -1,15 +1,138 @@
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Decimal, OverflowError, Uint128};
 
+use crate::error::ContractError;
+use crate::state::{EarlyBirdConfig, OddsFormat};
+
+/// Calculates the timestamp after which the bets are no longer accepted,
+/// 5 minutes before the start of the match
+///
+/// Fails when the start timestamp is too small to leave room for the cutoff
+pub fn calculate_bets_cutoff(start_timestamp: u64) -> Result<u64, ContractError> {
+    start_timestamp
+        .checked_sub(5 * 60)
+        .ok_or(ContractError::InvalidTimestamp(start_timestamp))
+}
+
+/// Calculates the total bets of the market, the sum of the bets on each result
+pub fn calculate_total_bets(
+    total_home: u128,
+    total_away: u128,
+    total_draw: u128,
+) -> Result<u128, OverflowError> {
+    Ok(Uint128::from(total_home)
+        .checked_add(total_away.into())?
+        .checked_add(total_draw.into())?
+        .u128())
+}
+
+/// Calculates the winnings of the shares on a winning result
+///
+/// On a dead heat the total bets are first split equally among the winning results,
+/// then each part is distributed pro rata among the shares of its result.
+/// The shares are the bet amounts weighted by the early bird multiplier.
+///
+/// `winnings = total_bets / winning_results * shares / total_team_shares`
+///
+/// Fails when the total team shares times the winning results overflows
 pub fn calculate_parimutuel_winnings(
+    total_bets: u128,
+    winning_results: u128,
+    total_team_shares: u128,
+    shares: u128,
+) -> Result<u128, OverflowError> {
+    if shares == 0 || total_team_shares == 0 || total_bets == 0 || winning_results == 0 {
+        return Ok(0);
+    }
+
+    Ok(Uint128::from(total_bets)
+        .multiply_ratio(
+            shares,
+            Uint128::from(total_team_shares).checked_mul(winning_results.into())?,
+        )
+        .u128())
+}
+
+/// Calculates the early bird multiplier of a bet placed at the given timestamp
+///
+/// The multiplier decays linearly from the max multiplier, for the bets placed
+/// at least `decay_period` seconds before the start, down to 1.0 at the start timestamp.
+///
+/// `multiplier = 1 + (max_multiplier - 1) * min(start_timestamp - timestamp, decay_period) / decay_period`
+///
+/// Returns 1.0 when there is no early bird config
+pub fn calculate_early_bird_multiplier(
+    early_bird: &Option<EarlyBirdConfig>,
+    start_timestamp: u64,
+    timestamp: u64,
+) -> Decimal {
+    match early_bird {
+        Some(early_bird) => {
+            let time_before_start = start_timestamp
+                .saturating_sub(timestamp)
+                .min(early_bird.decay_period);
+            Decimal::one()
+                + (early_bird.max_multiplier - Decimal::one())
+                    * Decimal::from_ratio(time_before_start, early_bird.decay_period)
+        }
+        None => Decimal::one(),
+    }
+}
+
+/// Calculates the decimal odds of a pool, derived from the pool sizes after the fee
+///
+/// `odds = (total_bets - fee) / total_team_bets`
+///
+/// Returns `None` when there are no bets on the pool
+pub fn calculate_pool_odds(
     total_bets: u128,
     total_team_bets: u128,
-    total_bet: u128,
-) -> u128 {
-    if total_bet == 0 || total_team_bets == 0 || total_bets == 0 {
-        return 0;
+    fee_bps: u64,
+) -> Option<Decimal> {
+    if total_team_bets == 0 {
+        return None;
+    }
+
+    let fee_amount = Uint128::from(total_bets).multiply_ratio(fee_bps, 10000_u128);
+    let net_total_bets = Uint128::from(total_bets) - fee_amount;
+
+    Some(Decimal::from_ratio(net_total_bets, total_team_bets))
+}
+
+/// Formats decimal odds in the given format, the odds are truncated to 2 decimals first
+///
+/// ```ignore
+/// DECIMAL    => 1.91 => "1.91"
+/// AMERICAN   => 1.91 => "-110", 2.50 => "+150"
+/// FRACTIONAL => 1.91 => "91/100", 2.50 => "3/2"
+/// ```
+///
+/// Returns `None` when the odds can't be represented in the format,
+/// which is the case of American odds of 1.00 or less
+pub fn format_odds(odds: Decimal, format: &OddsFormat) -> Option<String> {
+    let cents = Uint128::new(100).mul_floor(odds).u128();
+    let profit_cents = cents.saturating_sub(100);
+
+    match format {
+        OddsFormat::DECIMAL => Some(format!("{}.{:02}", cents / 100, cents % 100)),
+        OddsFormat::AMERICAN => match profit_cents {
+            0 => None,
+            profit_cents if profit_cents >= 100 => Some(format!("+{profit_cents}")),
+            profit_cents => Some(format!(
+                "-{}",
+                (10_000 + profit_cents / 2) / profit_cents // Rounded to the nearest integer
+            )),
+        },
+        OddsFormat::FRACTIONAL => {
+            let divisor = greatest_common_divisor(profit_cents, 100);
+            Some(format!("{}/{}", profit_cents / divisor, 100 / divisor))
+        }
     }
+}
 
-    Uint128::from(total_bets)
-        .multiply_ratio(total_bet, total_team_bets)
-        .u128()
+fn greatest_common_divisor(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        a
+    } else {
+        greatest_common_divisor(b, a % b)
+    }
 }
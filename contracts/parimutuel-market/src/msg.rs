@@ -1,7 +1,11 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Addr;
+use cosmwasm_std::{to_json_binary, Addr, Binary, CosmosMsg, Decimal, StdResult, WasmMsg};
+use cw_utils::Expiration;
 
-use crate::state::{Config, Market, MarketResult};
+use crate::state::{
+    Approval, Config, EarlyBirdConfig, Market, MarketMetadata, MarketResult, OddsFormat,
+    VoidReason, WithdrawalConfig,
+};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -16,9 +20,15 @@ pub struct InstantiateMsg {
     pub away_team: String,
     pub start_timestamp: u64,
     pub is_drawable: bool,
+    pub resolution_grace_period: Option<u64>, // Defaults to 7 days
+    pub postponement_period: Option<u64>,     // Defaults to 3 days
+    pub early_bird: Option<EarlyBirdConfig>,  // Rewards early bets with more shares when defined
+    pub withdrawal: Option<WithdrawalConfig>, // Allows withdrawing bets before the cutoff when defined
+    pub metadata: Option<MarketMetadata>,
 }
 
 #[cw_serde]
+#[allow(clippy::large_enum_variant)]
 pub enum ExecuteMsg {
     PlaceBet {
         result: MarketResult,
@@ -27,17 +37,67 @@ pub enum ExecuteMsg {
     ClaimWinnings {
         receiver: Option<Addr>,
     },
+    WithdrawBet {
+        result: MarketResult,
+        amount: u128,
+    },
+    ExpireMarket {},
+    WithdrawStake {
+        token_id: Option<String>, // Withdraws the stake of a bet slip instead of the position of the sender
+    },
+    TransferPosition {
+        result: MarketResult,
+        amount: u128,
+        recipient: Addr,
+    },
+    // Bet slips, following the cw721 interface
+    MintBetSlip {
+        result: MarketResult,
+        amount: u128,
+    },
+    ClaimByToken {
+        token_id: String,
+    },
+    TransferNft {
+        recipient: String,
+        token_id: String,
+    },
+    SendNft {
+        contract: String,
+        token_id: String,
+        msg: Binary,
+    },
+    Approve {
+        spender: String,
+        token_id: String,
+        expires: Option<Expiration>,
+    },
+    Revoke {
+        spender: String,
+        token_id: String,
+    },
     // Admin
     Update {
         admin_addr: Option<Addr>,
         treasury_addr: Option<Addr>,
         fee_bps: Option<u64>,
         start_timestamp: Option<u64>,
+        label: Option<String>,
+        home_team: Option<String>,
+        away_team: Option<String>,
+        metadata: Option<MarketMetadata>, // Replaces the whole metadata
     },
     Score {
-        result: MarketResult,
+        results: Vec<MarketResult>, // Several results on a dead heat
     },
     Cancel {},
+    Void {
+        reason: VoidReason,
+    },
+    Postpone {},
+    Reschedule {
+        start_timestamp: u64,
+    },
 }
 
 #[cw_serde]
@@ -46,6 +106,10 @@ pub struct UpdateParams {
     pub treasury_addr: Option<Addr>,
     pub fee_bps: Option<u64>, // Fee in basis points
     pub start_timestamp: Option<u64>,
+    pub label: Option<String>,
+    pub home_team: Option<String>,
+    pub away_team: Option<String>,
+    pub metadata: Option<MarketMetadata>, // Replaces the whole metadata
 }
 
 #[cw_serde]
@@ -60,7 +124,32 @@ pub enum QueryMsg {
     #[returns(BetsByAddressResponse)]
     BetsByAddress { address: Addr },
     #[returns(EstimateWinningsResponse)]
-    EstimateWinnings { address: Addr, result: MarketResult },
+    EstimateWinnings {
+        address: Addr,
+        results: Vec<MarketResult>,
+    },
+    #[returns(OddsResponse)]
+    Odds { format: OddsFormat },
+    #[returns(SolvencyResponse)]
+    Solvency {},
+    // Bet slips, following the cw721 interface
+    #[returns(OwnerOfResponse)]
+    OwnerOf {
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+    #[returns(NftInfoResponse)]
+    NftInfo { token_id: String },
+    #[returns(NumTokensResponse)]
+    NumTokens {},
+    #[returns(TokensResponse)]
+    Tokens {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(ContractInfoResponse)]
+    ContractInfo {},
 }
 
 #[cw_serde]
@@ -83,15 +172,109 @@ pub struct TotalBets {
 #[cw_serde]
 pub struct BetsResponse {
     pub totals: TotalBets,
+    pub shares: TotalBets, // Weighted by the early bird multiplier
 }
 
 #[cw_serde]
 pub struct BetsByAddressResponse {
     pub address: Addr,
     pub totals: TotalBets,
+    pub shares: TotalBets, // Weighted by the early bird multiplier
 }
 
 #[cw_serde]
 pub struct EstimateWinningsResponse {
     pub estimate: u128,
 }
+
+#[cw_serde]
+pub struct OutcomeOdds {
+    pub result: MarketResult,
+    pub odds: Option<String>, // Odds in the requested format, if the pool has bets
+    pub implied_probability: Decimal,
+}
+
+#[cw_serde]
+pub struct OddsResponse {
+    pub format: OddsFormat,
+    pub outcomes: Vec<OutcomeOdds>,
+    pub overround: Decimal, // Fee margin over the pools, ex: 0.0526 for a 5% fee
+}
+
+#[cw_serde]
+pub struct OutcomeLiability {
+    pub result: MarketResult,
+    pub payout: u128, // Paid out to the bettors if the result wins
+    pub fee: u128,    // Collected by the treasury if the result wins
+}
+
+#[cw_serde]
+pub struct SolvencyResponse {
+    pub balance: u128, // Balance of the contract in the market denom
+    pub liabilities: Vec<OutcomeLiability>, // Only while the market is active or postponed
+    pub max_liability: u128,
+    pub unclaimed: u128, // Owed to the positions not claimed yet once the market is no longer active
+    pub surplus: u128,   // Balance left once everything owed is covered
+    pub solvent: bool,
+}
+
+#[cw_serde]
+pub struct OwnerOfResponse {
+    pub owner: String,
+    pub approvals: Vec<Approval>,
+}
+
+#[cw_serde]
+pub struct BetSlipMetadata {
+    pub market_id: String,
+    pub result: MarketResult,
+    pub stake: u128,
+    pub shares: u128,           // Weighted by the early bird multiplier
+    pub potential_payout: u128, // If the result wins, based on the current pools and after the fee
+}
+
+#[cw_serde]
+pub struct NftInfoResponse {
+    pub token_uri: Option<String>,
+    pub extension: BetSlipMetadata,
+}
+
+#[cw_serde]
+pub struct NumTokensResponse {
+    pub count: u64,
+}
+
+#[cw_serde]
+pub struct TokensResponse {
+    pub tokens: Vec<String>,
+}
+
+#[cw_serde]
+pub struct ContractInfoResponse {
+    pub name: String,
+    pub symbol: String,
+}
+
+/// Sent to the contract receiving a bet slip with `SendNft`, as defined by the cw721 spec
+#[cw_serde]
+pub struct Cw721ReceiveMsg {
+    pub sender: String,
+    pub token_id: String,
+    pub msg: Binary,
+}
+
+impl Cw721ReceiveMsg {
+    pub fn into_cosmos_msg(self, contract_addr: String) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&ReceiverExecuteMsg::ReceiveNft(self))?,
+            funds: vec![],
+        }
+        .into())
+    }
+}
+
+#[cw_serde]
+enum ReceiverExecuteMsg {
+    ReceiveNft(Cw721ReceiveMsg),
+}
@@ -1,17 +1,28 @@
-use cosmwasm_std::{Addr, Deps, StdResult};
+use cosmwasm_std::{Addr, Decimal, Deps, Env, Order, StdResult, Uint128};
+use cw_storage_plus::Bound;
 
 use crate::{
-    logic::calculate_parimutuel_winnings,
+    logic::{
+        calculate_parimutuel_winnings, calculate_pool_odds, calculate_total_bets, format_odds,
+    },
     msg::{
-        BetsByAddressResponse, BetsResponse, ConfigResponse, EstimateWinningsResponse,
-        MarketResponse, TotalBets,
+        BetSlipMetadata, BetsByAddressResponse, BetsResponse, ConfigResponse, ContractInfoResponse,
+        EstimateWinningsResponse, MarketResponse, NftInfoResponse, NumTokensResponse, OddsResponse,
+        OutcomeLiability, OutcomeOdds, OwnerOfResponse, SolvencyResponse, TokensResponse,
+        TotalBets,
     },
     state::{
-        MarketResult, CONFIG, MARKET, POOL_AWAY, POOL_DRAW, POOL_HOME, TOTAL_AWAY, TOTAL_DRAW,
-        TOTAL_HOME,
+        load_pot, load_winning_results, MarketResult, OddsFormat, Status, BET_SLIPS, CLAIMS,
+        CONFIG, MARKET, POOL_AWAY, POOL_DRAW, POOL_HOME, POOL_PENALTIES, SHARES_AWAY, SHARES_DRAW,
+        SHARES_HOME, TOTAL_AWAY, TOTAL_DRAW, TOTAL_HOME, TOTAL_SHARES_AWAY, TOTAL_SHARES_DRAW,
+        TOTAL_SHARES_HOME,
     },
 };
 
+pub const BET_SLIP_SYMBOL: &str = "SLIP";
+const DEFAULT_TOKENS_LIMIT: u32 = 10;
+const MAX_TOKENS_LIMIT: u32 = 100;
+
 /// Returns the current config of the market
 pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
     let config = CONFIG.load(deps.storage)?;
@@ -28,19 +39,26 @@ pub fn query_market(deps: Deps) -> StdResult<MarketResponse> {
 ///
 /// This includes the total bet amounts in each pool:
 /// TOTAL_HOME, TOTAL_AWAY, TOTAL_DRAW
+/// and the total shares weighted by the early bird multiplier
 pub fn query_bets(deps: Deps) -> StdResult<BetsResponse> {
     let totals = TotalBets {
         home: TOTAL_HOME.load(deps.storage)?,
         away: TOTAL_AWAY.load(deps.storage)?,
         draw: TOTAL_DRAW.load(deps.storage)?,
     };
-    Ok(BetsResponse { totals })
+    let shares = TotalBets {
+        home: TOTAL_SHARES_HOME.load(deps.storage)?,
+        away: TOTAL_SHARES_AWAY.load(deps.storage)?,
+        draw: TOTAL_SHARES_DRAW.load(deps.storage)?,
+    };
+    Ok(BetsResponse { totals, shares })
 }
 
 /// Retruns the total bets for a specific address
 ///
 /// This includes the total bet amounts in each pool:
 /// TOTAL_HOME, TOTAL_AWAY, TOTAL_DRAW
+/// and the shares weighted by the early bird multiplier
 pub fn query_bets_by_address(deps: Deps, address: Addr) -> StdResult<BetsByAddressResponse> {
     let total_home = if POOL_HOME.has(deps.storage, address.clone()) {
         POOL_HOME.load(deps.storage, address.clone())?
@@ -66,58 +84,408 @@ pub fn query_bets_by_address(deps: Deps, address: Addr) -> StdResult<BetsByAddre
         draw: total_draw,
     };
 
-    Ok(BetsByAddressResponse { address, totals })
+    let shares = TotalBets {
+        home: SHARES_HOME
+            .may_load(deps.storage, address.clone())?
+            .unwrap_or_default(),
+        away: SHARES_AWAY
+            .may_load(deps.storage, address.clone())?
+            .unwrap_or_default(),
+        draw: SHARES_DRAW
+            .may_load(deps.storage, address.clone())?
+            .unwrap_or_default(),
+    };
+
+    Ok(BetsByAddressResponse {
+        address,
+        totals,
+        shares,
+    })
 }
 
-/// Returns the estimated winnings for a specific address and result
+/// Returns the estimated winnings for a specific address if the given results win
 ///
-/// Based on the total bets of the opposing pools and the bet amount of the address on the result pool.
+/// Based on the pot of the market and the shares of the address on each result pool,
+/// the pot being split equally among the results with bets on a dead heat.
+/// The pot is the total bets plus the withdrawal penalties left in the pool.
 /// The result is calculated as follows:
 ///
-/// `winnings = opposing_total_bets * result_address_bet_amount / result_total_bets`
+/// `winnings = sum(pot / results * result_address_shares / result_total_shares)`
 ///
 /// This does not take into account the fees.
 pub fn query_estimate_winnings(
     deps: Deps,
     address: Addr,
-    result: MarketResult,
+    results: Vec<MarketResult>,
 ) -> StdResult<EstimateWinningsResponse> {
+    let pot = load_pot(deps.storage)?;
+
+    let winning_results = load_winning_results(deps.storage, &results)?;
+    let mut estimate = 0;
+    for result in results.iter() {
+        let (team_shares, addr_shares) = match result {
+            MarketResult::HOME => (
+                TOTAL_SHARES_HOME.load(deps.storage)?,
+                SHARES_HOME.may_load(deps.storage, address.clone())?,
+            ),
+            MarketResult::AWAY => (
+                TOTAL_SHARES_AWAY.load(deps.storage)?,
+                SHARES_AWAY.may_load(deps.storage, address.clone())?,
+            ),
+            MarketResult::DRAW => (
+                TOTAL_SHARES_DRAW.load(deps.storage)?,
+                SHARES_DRAW.may_load(deps.storage, address.clone())?,
+            ),
+        };
+        estimate += calculate_parimutuel_winnings(
+            pot,
+            winning_results,
+            team_shares,
+            addr_shares.unwrap_or_default(),
+        )?;
+    }
+
+    Ok(EstimateWinningsResponse { estimate })
+}
+
+/// Returns the current odds of each outcome of the market in the given format,
+/// derived from the pool sizes after the fee, with their implied probability
+/// and the overround of the market
+///
+/// ```ignore
+/// odds = (pot - fee) / total_team_bets
+/// implied_probability = 1 / odds
+/// overround = sum(implied_probabilities) - 1
+/// ```
+///
+/// The pools without bets have no odds and an implied probability of zero.
+pub fn query_odds(deps: Deps, format: OddsFormat) -> StdResult<OddsResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
+
     let total_home = TOTAL_HOME.load(deps.storage)?;
     let total_away = TOTAL_AWAY.load(deps.storage)?;
     let total_draw = TOTAL_DRAW.load(deps.storage)?;
+    let pot = load_pot(deps.storage)?;
 
-    let addr_pool_home = if POOL_HOME.has(deps.storage, address.clone()) {
-        POOL_HOME.load(deps.storage, address.clone())?
-    } else {
-        0
-    };
+    let mut pools = vec![
+        (MarketResult::HOME, total_home),
+        (MarketResult::AWAY, total_away),
+    ];
+    if market.is_drawable {
+        pools.push((MarketResult::DRAW, total_draw));
+    }
 
-    let addr_pool_away = if POOL_AWAY.has(deps.storage, address.clone()) {
-        POOL_AWAY.load(deps.storage, address.clone())?
-    } else {
-        0
-    };
+    let outcomes: Vec<OutcomeOdds> = pools
+        .into_iter()
+        .map(|(result, total_team_bets)| {
+            match calculate_pool_odds(pot, total_team_bets, config.fee_bps) {
+                Some(odds) => OutcomeOdds {
+                    result,
+                    odds: format_odds(odds, &format),
+                    implied_probability: Decimal::one() / odds,
+                },
+                None => OutcomeOdds {
+                    result,
+                    odds: None,
+                    implied_probability: Decimal::zero(),
+                },
+            }
+        })
+        .collect();
 
-    let addr_pool_draw = if POOL_DRAW.has(deps.storage, address.clone()) {
-        POOL_DRAW.load(deps.storage, address)?
-    } else {
-        0
-    };
+    let overround = outcomes
+        .iter()
+        .fold(Decimal::zero(), |total, outcome| {
+            total + outcome.implied_probability
+        })
+        .saturating_sub(Decimal::one());
 
-    let addr_bets = match result {
-        MarketResult::HOME => addr_pool_home,
-        MarketResult::AWAY => addr_pool_away,
-        MarketResult::DRAW => addr_pool_draw,
-    };
+    Ok(OddsResponse {
+        format,
+        outcomes,
+        overround,
+    })
+}
+
+/// Returns whether the contract can pay everything it may owe with its balance
+///
+/// While the market is active it has to cover the payout and the fee of each result
+/// winning on its own, the same way the market is scored, afterwards the winnings
+/// and refunds of the positions not claimed yet.
+///
+/// When a result winning on its own refunds the bets, its payout is what the refunds
+/// pay back, the pools and the bet slips, while the withdrawal penalties left in the pool
+/// are sent to the treasury along with the refund.
+///
+/// ```ignore
+/// surplus = balance - max_liability - unclaimed
+/// ```
+pub fn query_solvency(deps: Deps, env: Env) -> StdResult<SolvencyResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
+
+    let balance = deps
+        .querier
+        .query_balance(&env.contract.address, &config.denom)?
+        .amount;
+
+    let mut liabilities: Vec<OutcomeLiability> = vec![];
+    if market.status.is_open() {
+        let total_home = TOTAL_HOME.load(deps.storage)?;
+        let total_away = TOTAL_AWAY.load(deps.storage)?;
+        let total_draw = TOTAL_DRAW.load(deps.storage)?;
+        let total_bets = calculate_total_bets(total_home, total_away, total_draw)?;
+        let pool_penalties = POOL_PENALTIES.load(deps.storage)?;
+        let pot = load_pot(deps.storage)?;
+        // Nobody can claim while the market is open, so every bet would be refunded
+        let refund_liability = load_unclaimed_refunds(deps)?.u128();
+
+        let mut results = vec![
+            (
+                MarketResult::HOME,
+                total_home,
+                TOTAL_SHARES_HOME.load(deps.storage)?,
+            ),
+            (
+                MarketResult::AWAY,
+                total_away,
+                TOTAL_SHARES_AWAY.load(deps.storage)?,
+            ),
+        ];
+        if market.is_drawable {
+            results.push((
+                MarketResult::DRAW,
+                total_draw,
+                TOTAL_SHARES_DRAW.load(deps.storage)?,
+            ));
+        }
+
+        liabilities = results
+            .into_iter()
+            .map(|(result, total_team_bets, total_team_shares)| {
+                // The bets are refunded without a fee when there are no winners or losers
+                let is_refund = total_team_shares == 0 || total_bets == total_team_bets;
+                if is_refund {
+                    return OutcomeLiability {
+                        result,
+                        payout: refund_liability,
+                        fee: pool_penalties,
+                    };
+                }
+                let fee = Uint128::from(pot)
+                    .multiply_ratio(config.fee_bps, 10000_u128)
+                    .u128();
+                OutcomeLiability {
+                    result,
+                    payout: pot - fee,
+                    fee,
+                }
+            })
+            .collect();
+    }
+
+    let max_liability = liabilities
+        .iter()
+        .map(|outcome| outcome.payout + outcome.fee)
+        .max()
+        .unwrap_or_default();
+    let unclaimed = query_unclaimed(deps)?;
 
-    let team_bets = match result {
-        MarketResult::HOME => total_home,
-        MarketResult::AWAY => total_away,
-        MarketResult::DRAW => total_draw,
+    let owed = Uint128::from(max_liability).checked_add(unclaimed)?;
+
+    Ok(SolvencyResponse {
+        balance: balance.u128(),
+        liabilities,
+        max_liability,
+        unclaimed: unclaimed.u128(),
+        surplus: balance.saturating_sub(owed).u128(),
+        solvent: balance >= owed,
+    })
+}
+
+/// Returns the winnings and refunds owed to the positions that weren't claimed yet,
+/// the addresses that didn't claim and the bet slips that weren't burned
+///
+/// Nothing can be claimed while the market is active or postponed.
+pub fn query_unclaimed(deps: Deps) -> StdResult<Uint128> {
+    let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
+
+    if market.status.is_open() {
+        return Ok(Uint128::zero());
+    }
+
+    let mut unclaimed = Uint128::zero();
+
+    if market.status == Status::CANCELLED || market.status == Status::VOIDED || market.is_refund {
+        return load_unclaimed_refunds(deps);
+    }
+
+    let pot = load_pot(deps.storage)?;
+    let fee_amount = Uint128::from(pot)
+        .multiply_ratio(config.fee_bps, 10000_u128)
+        .u128();
+    let winning_results = load_winning_results(deps.storage, &market.results)?;
+
+    for result in market.results.iter() {
+        let (shares, total_team_shares) = match result {
+            MarketResult::HOME => (SHARES_HOME, TOTAL_SHARES_HOME.load(deps.storage)?),
+            MarketResult::AWAY => (SHARES_AWAY, TOTAL_SHARES_AWAY.load(deps.storage)?),
+            MarketResult::DRAW => (SHARES_DRAW, TOTAL_SHARES_DRAW.load(deps.storage)?),
+        };
+        for item in shares.range(deps.storage, None, None, Order::Ascending) {
+            let (addr, addr_shares) = item?;
+            if !CLAIMS.has(deps.storage, addr) {
+                unclaimed = unclaimed.checked_add(
+                    calculate_parimutuel_winnings(
+                        pot - fee_amount,
+                        winning_results,
+                        total_team_shares,
+                        addr_shares,
+                    )?
+                    .into(),
+                )?;
+            }
+        }
+        for item in BET_SLIPS.range(deps.storage, None, None, Order::Ascending) {
+            let (_, bet_slip) = item?;
+            if bet_slip.result == *result {
+                unclaimed = unclaimed.checked_add(
+                    calculate_parimutuel_winnings(
+                        pot - fee_amount,
+                        winning_results,
+                        total_team_shares,
+                        bet_slip.shares,
+                    )?
+                    .into(),
+                )?;
+            }
+        }
+    }
+
+    Ok(unclaimed)
+}
+
+/// Returns the owner of a bet slip and its approvals,
+/// the expired approvals are only included if requested
+pub fn query_owner_of(
+    deps: Deps,
+    env: Env,
+    token_id: String,
+    include_expired: Option<bool>,
+) -> StdResult<OwnerOfResponse> {
+    let bet_slip = BET_SLIPS.load(deps.storage, token_id)?;
+
+    let include_expired = include_expired.unwrap_or(false);
+    let approvals = bet_slip
+        .approvals
+        .into_iter()
+        .filter(|approval| include_expired || !approval.expires.is_expired(&env.block))
+        .collect();
+
+    Ok(OwnerOfResponse {
+        owner: bet_slip.owner.to_string(),
+        approvals,
+    })
+}
+
+/// Sums the refunds of the addresses that didn't claim and the bet slips that weren't burned,
+/// the bet amounts of their pools and of the bet slips
+fn load_unclaimed_refunds(deps: Deps) -> StdResult<Uint128> {
+    let mut unclaimed = Uint128::zero();
+
+    for pool in [POOL_HOME, POOL_AWAY, POOL_DRAW] {
+        for item in pool.range(deps.storage, None, None, Order::Ascending) {
+            let (addr, bet_amount) = item?;
+            if !CLAIMS.has(deps.storage, addr) {
+                unclaimed = unclaimed.checked_add(bet_amount.into())?;
+            }
+        }
+    }
+    for item in BET_SLIPS.range(deps.storage, None, None, Order::Ascending) {
+        let (_, bet_slip) = item?;
+        unclaimed = unclaimed.checked_add(bet_slip.bet_amount.into())?;
+    }
+
+    Ok(unclaimed)
+}
+
+/// Returns the metadata of a bet slip, with its potential payout if its result wins
+/// based on the current pools and after the fee
+///
+/// `potential_payout = (pot - fee) * shares / result_total_shares`
+pub fn query_nft_info(deps: Deps, token_id: String) -> StdResult<NftInfoResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let market = MARKET.load(deps.storage)?;
+    let bet_slip = BET_SLIPS.load(deps.storage, token_id)?;
+
+    let pot = load_pot(deps.storage)?;
+    let fee_amount = Uint128::from(pot)
+        .multiply_ratio(config.fee_bps, 10000_u128)
+        .u128();
+    let team_shares = match bet_slip.result {
+        MarketResult::HOME => TOTAL_SHARES_HOME.load(deps.storage)?,
+        MarketResult::AWAY => TOTAL_SHARES_AWAY.load(deps.storage)?,
+        MarketResult::DRAW => TOTAL_SHARES_DRAW.load(deps.storage)?,
     };
 
-    let estimate =
-        calculate_parimutuel_winnings(total_home + total_away + total_draw, team_bets, addr_bets);
+    Ok(NftInfoResponse {
+        token_uri: None,
+        extension: BetSlipMetadata {
+            market_id: market.id,
+            result: bet_slip.result,
+            stake: bet_slip.bet_amount,
+            shares: bet_slip.shares,
+            potential_payout: calculate_parimutuel_winnings(
+                pot - fee_amount,
+                1,
+                team_shares,
+                bet_slip.shares,
+            )?,
+        },
+    })
+}
 
-    Ok(EstimateWinningsResponse { estimate })
+/// Returns the number of bet slips that haven't been claimed yet
+pub fn query_num_tokens(deps: Deps) -> StdResult<NumTokensResponse> {
+    let count = BET_SLIPS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .count() as u64;
+
+    Ok(NumTokensResponse { count })
+}
+
+/// Returns the ids of the bet slips owned by an address, paginated by token id
+pub fn query_tokens(
+    deps: Deps,
+    owner: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<TokensResponse> {
+    let owner = deps.api.addr_validate(&owner)?;
+    let limit = limit.unwrap_or(DEFAULT_TOKENS_LIMIT).min(MAX_TOKENS_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let tokens = BET_SLIPS
+        .range(deps.storage, start, None, Order::Ascending)
+        .filter(|item| match item {
+            Ok((_, bet_slip)) => bet_slip.owner == owner,
+            Err(_) => true,
+        })
+        .take(limit)
+        .map(|item| item.map(|(token_id, _)| token_id))
+        .collect::<StdResult<Vec<String>>>()?;
+
+    Ok(TokensResponse { tokens })
+}
+
+/// Returns the name and symbol of the bet slips collection, named after the market
+pub fn query_contract_info(deps: Deps) -> StdResult<ContractInfoResponse> {
+    let market = MARKET.load(deps.storage)?;
+
+    Ok(ContractInfoResponse {
+        name: market.label,
+        symbol: BET_SLIP_SYMBOL.to_string(),
+    })
 }
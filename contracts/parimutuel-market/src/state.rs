@@ -1,10 +1,13 @@
 use std::fmt;
 
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Attribute, Decimal, StdResult, Storage, Uint128};
 use cw_storage_plus::{Item, Map};
+use cw_utils::Expiration;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::logic::calculate_total_bets;
+
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const MARKET: Item<Market> = Item::new("market");
 pub const POOL_HOME: Map<Addr, u128> = Map::new("pool_home");
@@ -15,6 +18,22 @@ pub const TOTAL_AWAY: Item<u128> = Item::new("total_away");
 pub const TOTAL_DRAW: Item<u128> = Item::new("total_draw");
 pub const CLAIMS: Map<Addr, bool> = Map::new("claims");
 
+// Withdrawal penalties left in the pool, added to the pot of the winners or sent
+// to the treasury when the bets are refunded
+pub const POOL_PENALTIES: Item<u128> = Item::new("pool_penalties");
+
+// Shares weighted by the early bird multiplier, used to split the winnings
+pub const SHARES_HOME: Map<Addr, u128> = Map::new("shares_home");
+pub const SHARES_AWAY: Map<Addr, u128> = Map::new("shares_away");
+pub const SHARES_DRAW: Map<Addr, u128> = Map::new("shares_draw");
+pub const TOTAL_SHARES_HOME: Item<u128> = Item::new("total_shares_home");
+pub const TOTAL_SHARES_AWAY: Item<u128> = Item::new("total_shares_away");
+pub const TOTAL_SHARES_DRAW: Item<u128> = Item::new("total_shares_draw");
+
+// Bet slips minted as cw721 tokens, the market contract is the collection and its minter
+pub const BET_SLIP_COUNT: Item<u64> = Item::new("bet_slip_count");
+pub const BET_SLIPS: Map<String, BetSlip> = Map::new("bet_slips");
+
 #[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
 pub struct Config {
     pub admin_addr: Addr,
@@ -22,6 +41,55 @@ pub struct Config {
     pub fee_bps: u64,
     pub denom: String,
     pub denom_precision: u32,
+    pub resolution_grace_period: u64, // Seconds after the start timestamp to score the market
+    pub postponement_period: u64, // Seconds a postponed market has to be rescheduled before it is refunded
+    pub early_bird: Option<EarlyBirdConfig>, // Rewards early bets with more shares when defined
+    pub withdrawal: Option<WithdrawalConfig>, // Allows withdrawing bets before the cutoff when defined
+}
+
+/// Bet amount moved out of the pool of an address into a transferable token,
+/// claimed by whoever owns the token once the market is settled
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct BetSlip {
+    pub token_id: String,
+    pub owner: Addr,
+    pub approvals: Vec<Approval>,
+    pub result: MarketResult,
+    pub bet_amount: u128,
+    pub shares: u128, // Weighted by the early bird multiplier
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct Approval {
+    pub spender: Addr,
+    pub expires: Expiration,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct WithdrawalConfig {
+    pub penalty_bps: u64, // Penalty in basis points of the withdrawn amount
+    pub penalty_destination: PenaltyDestination,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub enum PenaltyDestination {
+    TREASURY, // The penalty is sent to the treasury
+    POOL,     // The penalty stays in the pool of the withdrawn result
+}
+
+impl fmt::Display for PenaltyDestination {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PenaltyDestination::TREASURY => write!(f, "TREASURY"),
+            PenaltyDestination::POOL => write!(f, "POOL"),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct EarlyBirdConfig {
+    pub max_multiplier: Decimal, // Multiplier of the bets placed at least decay_period before the start
+    pub decay_period: u64,       // Seconds before the start timestamp over which it decays to 1.0
 }
 
 #[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
@@ -29,6 +97,8 @@ pub enum Status {
     ACTIVE,
     CLOSED,
     CANCELLED,
+    VOIDED,    // The match was abandoned, all the bets are refunded
+    POSTPONED, // The match was postponed, the bets are frozen until it is rescheduled
 }
 
 impl fmt::Display for Status {
@@ -37,6 +107,32 @@ impl fmt::Display for Status {
             Status::ACTIVE => write!(f, "ACTIVE"),
             Status::CLOSED => write!(f, "CLOSED"),
             Status::CANCELLED => write!(f, "CANCELLED"),
+            Status::VOIDED => write!(f, "VOIDED"),
+            Status::POSTPONED => write!(f, "POSTPONED"),
+        }
+    }
+}
+
+impl Status {
+    /// The bets of an open market are neither settled nor refundable yet
+    pub fn is_open(&self) -> bool {
+        matches!(self, Status::ACTIVE | Status::POSTPONED)
+    }
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub enum VoidReason {
+    ABANDONED, // The match started but was not completed
+    INTEGRITY, // The match integrity is compromised
+    OTHER,
+}
+
+impl fmt::Display for VoidReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VoidReason::ABANDONED => write!(f, "ABANDONED"),
+            VoidReason::INTEGRITY => write!(f, "INTEGRITY"),
+            VoidReason::OTHER => write!(f, "OTHER"),
         }
     }
 }
@@ -58,14 +154,120 @@ impl fmt::Display for MarketResult {
     }
 }
 
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub enum OddsFormat {
+    DECIMAL,    // ex: 1.91
+    AMERICAN,   // ex: -110, +150
+    FRACTIONAL, // ex: 91/100
+}
+
+/// Participant of the market as referenced by the data providers
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct Participant {
+    pub id: Option<String>,
+    pub logo: Option<String>, // Url of the logo, ex: https://example.com/team-a.png
+}
+
+/// Id of the event on an external data provider
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct ExternalId {
+    pub provider: String, // ex: pandascore
+    pub id: String,
+}
+
+/// Structured details about the event of the market
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema, Default)]
+pub struct MarketMetadata {
+    pub sport: Option<String>,  // ex: CS2
+    pub league: Option<String>, // ex: Test League
+    pub season: Option<String>, // ex: 2025
+    pub round: Option<String>,  // ex: Quarterfinals
+    pub venue: Option<String>,
+    pub home_participant: Option<Participant>,
+    pub away_participant: Option<Participant>,
+    pub external_ids: Vec<ExternalId>,
+}
+
+impl MarketMetadata {
+    /// Flattens the metadata into attributes, the empty fields are emitted as empty strings
+    pub fn attributes(&self) -> Vec<Attribute> {
+        let participant_attributes = |side: &str, participant: &Option<Participant>| {
+            let (id, logo) = match participant {
+                Some(participant) => (participant.id.clone(), participant.logo.clone()),
+                None => (None, None),
+            };
+            vec![
+                Attribute::new(format!("{side}_participant_id"), id.unwrap_or_default()),
+                Attribute::new(format!("{side}_participant_logo"), logo.unwrap_or_default()),
+            ]
+        };
+
+        let mut attributes = vec![
+            Attribute::new("sport", self.sport.clone().unwrap_or_default()),
+            Attribute::new("league", self.league.clone().unwrap_or_default()),
+            Attribute::new("season", self.season.clone().unwrap_or_default()),
+            Attribute::new("round", self.round.clone().unwrap_or_default()),
+            Attribute::new("venue", self.venue.clone().unwrap_or_default()),
+        ];
+        attributes.extend(participant_attributes("home", &self.home_participant));
+        attributes.extend(participant_attributes("away", &self.away_participant));
+        attributes.push(Attribute::new(
+            "external_ids",
+            self.external_ids
+                .iter()
+                .map(|external_id| format!("{}:{}", external_id.provider, external_id.id))
+                .collect::<Vec<String>>()
+                .join(","),
+        ));
+        attributes
+    }
+}
+
 #[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
 pub struct Market {
     pub id: String,
     pub label: String,
     pub home_team: String,
     pub away_team: String,
+    pub metadata: Option<MarketMetadata>,
     pub start_timestamp: u64,
+    pub resolution_deadline: u64, // Anyone can expire the market once passed without being scored
+    pub postponement_deadline: Option<u64>, // Refunded as if cancelled once passed without being rescheduled
     pub status: Status,
-    pub result: Option<MarketResult>,
+    pub results: Vec<MarketResult>, // Several results on a dead heat
     pub is_drawable: bool,
+    pub void_reason: Option<VoidReason>,
+    pub is_refund: bool, // Scored without winners or losers, all the bets are refunded
+}
+
+/// Loads the pot split among the winners, the bets on every result
+/// plus the withdrawal penalties left in the pool
+pub fn load_pot(storage: &dyn Storage) -> StdResult<u128> {
+    let total_bets = calculate_total_bets(
+        TOTAL_HOME.load(storage)?,
+        TOTAL_AWAY.load(storage)?,
+        TOTAL_DRAW.load(storage)?,
+    )?;
+
+    Ok(Uint128::from(total_bets)
+        .checked_add(POOL_PENALTIES.load(storage)?.into())?
+        .u128())
+}
+
+/// Counts the winning results with some shares, on a dead heat the pool is only
+/// split among them as nobody can claim the part of a winning result without bets
+pub fn load_winning_results(storage: &dyn Storage, results: &[MarketResult]) -> StdResult<u128> {
+    let mut winning_results = 0;
+    for result in results.iter() {
+        let total_shares = match result {
+            MarketResult::HOME => TOTAL_SHARES_HOME.load(storage)?,
+            MarketResult::AWAY => TOTAL_SHARES_AWAY.load(storage)?,
+            MarketResult::DRAW => TOTAL_SHARES_DRAW.load(storage)?,
+        };
+        if total_shares > 0 {
+            winning_results += 1;
+        }
+    }
+
+    Ok(winning_results)
 }
@@ -1,4 +1,17 @@
-use crate::error::ContractError;
+use cosmwasm_std::Decimal;
+
+use crate::{
+    error::ContractError,
+    state::{EarlyBirdConfig, MarketMetadata, MarketResult, WithdrawalConfig},
+};
+
+pub const MIN_RESOLUTION_GRACE_PERIOD: u64 = 24 * 60 * 60; // 1 day
+pub const DEFAULT_RESOLUTION_GRACE_PERIOD: u64 = 7 * 24 * 60 * 60; // 7 days
+pub const MIN_POSTPONEMENT_PERIOD: u64 = 60 * 60; // 1 hour
+pub const DEFAULT_POSTPONEMENT_PERIOD: u64 = 3 * 24 * 60 * 60; // 3 days
+pub const MAX_TEXT_LENGTH: usize = 128;
+pub const MAX_URL_LENGTH: usize = 512;
+pub const MAX_EXTERNAL_IDS: usize = 10;
 
 pub fn validate_fee_bps(fee_bps: u64) -> Result<(), ContractError> {
     if fee_bps > 1_000 {
@@ -7,3 +20,127 @@ pub fn validate_fee_bps(fee_bps: u64) -> Result<(), ContractError> {
 
     Ok(())
 }
+
+pub fn validate_results(results: &[MarketResult], is_drawable: bool) -> Result<(), ContractError> {
+    if results.is_empty() {
+        return Err(ContractError::InvalidResults {});
+    }
+
+    for (index, result) in results.iter().enumerate() {
+        if results[..index].contains(result) {
+            return Err(ContractError::InvalidResults {});
+        }
+
+        if !is_drawable && *result == MarketResult::DRAW {
+            return Err(ContractError::MarketNotDrawable {});
+        }
+    }
+
+    Ok(())
+}
+
+pub fn validate_resolution_grace_period(resolution_grace_period: u64) -> Result<(), ContractError> {
+    if resolution_grace_period < MIN_RESOLUTION_GRACE_PERIOD {
+        return Err(ContractError::InvalidResolutionGracePeriod(
+            resolution_grace_period,
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_postponement_period(postponement_period: u64) -> Result<(), ContractError> {
+    if postponement_period < MIN_POSTPONEMENT_PERIOD {
+        return Err(ContractError::InvalidPostponementPeriod(
+            postponement_period,
+        ));
+    }
+
+    Ok(())
+}
+
+/// Descriptive texts can't be blank nor longer than the given length
+pub fn validate_text(field: &str, text: &str, max_length: usize) -> Result<(), ContractError> {
+    if text.trim().is_empty() || text.len() > max_length {
+        return Err(ContractError::InvalidMetadata(field.to_string()));
+    }
+
+    Ok(())
+}
+
+pub fn validate_metadata(metadata: &MarketMetadata) -> Result<(), ContractError> {
+    for (field, text) in [
+        ("sport", &metadata.sport),
+        ("league", &metadata.league),
+        ("season", &metadata.season),
+        ("round", &metadata.round),
+        ("venue", &metadata.venue),
+    ] {
+        if let Some(text) = text {
+            validate_text(field, text, MAX_TEXT_LENGTH)?;
+        }
+    }
+
+    for (side, participant) in [
+        ("home", &metadata.home_participant),
+        ("away", &metadata.away_participant),
+    ] {
+        if let Some(participant) = participant {
+            if let Some(id) = &participant.id {
+                validate_text(&format!("{side}_participant_id"), id, MAX_TEXT_LENGTH)?;
+            }
+            if let Some(logo) = &participant.logo {
+                validate_text(&format!("{side}_participant_logo"), logo, MAX_URL_LENGTH)?;
+            }
+        }
+    }
+
+    if metadata.external_ids.len() > MAX_EXTERNAL_IDS {
+        return Err(ContractError::InvalidMetadata("external_ids".to_string()));
+    }
+    for (index, external_id) in metadata.external_ids.iter().enumerate() {
+        validate_text("external_ids", &external_id.provider, MAX_TEXT_LENGTH)?;
+        validate_text("external_ids", &external_id.id, MAX_TEXT_LENGTH)?;
+
+        // The separators of the external ids attribute can't be part of the ids,
+        // and each provider can only be given once
+        if external_id.provider.contains([':', ','])
+            || external_id.id.contains(',')
+            || metadata.external_ids[..index]
+                .iter()
+                .any(|other| other.provider == external_id.provider)
+        {
+            return Err(ContractError::InvalidMetadata("external_ids".to_string()));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn validate_early_bird(early_bird: &EarlyBirdConfig) -> Result<(), ContractError> {
+    if early_bird.max_multiplier < Decimal::one()
+        || early_bird.max_multiplier > Decimal::from_atomics(3_u128, 0).unwrap()
+    {
+        return Err(ContractError::InvalidEarlyBirdMultiplier(
+            early_bird.max_multiplier,
+        ));
+    }
+
+    if early_bird.decay_period == 0 {
+        return Err(ContractError::InvalidEarlyBirdDecayPeriod(
+            early_bird.decay_period,
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_withdrawal(withdrawal: &WithdrawalConfig) -> Result<(), ContractError> {
+    if withdrawal.penalty_bps > 5_000 {
+        return Err(ContractError::InvalidWithdrawalPenaltyBps(
+            withdrawal.penalty_bps,
+        ));
+    }
+
+    Ok(())
+}
@@ -8,9 +8,10 @@ use parimutuel_market::{
     contract::{execute, instantiate, query},
     msg::{
         BetsByAddressResponse, BetsResponse, ConfigResponse, EstimateWinningsResponse, ExecuteMsg,
-        InstantiateMsg, MarketResponse, QueryMsg, UpdateParams,
+        InstantiateMsg, MarketResponse, NftInfoResponse, NumTokensResponse, OddsResponse,
+        OwnerOfResponse, QueryMsg, SolvencyResponse, TokensResponse, UpdateParams,
     },
-    state::MarketResult,
+    state::{MarketResult, OddsFormat, VoidReason},
 };
 
 /// BlockchainContract is a wrapper around blockchain App and contract Addr
@@ -64,8 +65,158 @@ impl BlockchainContract {
             self.addr(),
             &QueryMsg::EstimateWinnings {
                 address: address.clone(),
+                results: vec![result],
+            },
+        )
+    }
+
+    pub fn query_odds(&self, format: OddsFormat) -> StdResult<OddsResponse> {
+        self.blockchain
+            .wrap()
+            .query_wasm_smart(self.addr(), &QueryMsg::Odds { format })
+    }
+
+    pub fn query_solvency(&self) -> StdResult<SolvencyResponse> {
+        self.blockchain
+            .wrap()
+            .query_wasm_smart(self.addr(), &QueryMsg::Solvency {})
+    }
+
+    pub fn query_owner_of(&self, token_id: &str) -> StdResult<OwnerOfResponse> {
+        self.blockchain.wrap().query_wasm_smart(
+            self.addr(),
+            &QueryMsg::OwnerOf {
+                token_id: token_id.to_string(),
+                include_expired: None,
+            },
+        )
+    }
+
+    pub fn query_nft_info(&self, token_id: &str) -> StdResult<NftInfoResponse> {
+        self.blockchain.wrap().query_wasm_smart(
+            self.addr(),
+            &QueryMsg::NftInfo {
+                token_id: token_id.to_string(),
+            },
+        )
+    }
+
+    pub fn query_num_tokens(&self) -> StdResult<NumTokensResponse> {
+        self.blockchain
+            .wrap()
+            .query_wasm_smart(self.addr(), &QueryMsg::NumTokens {})
+    }
+
+    pub fn query_tokens(&self, owner: &Addr) -> StdResult<TokensResponse> {
+        self.blockchain.wrap().query_wasm_smart(
+            self.addr(),
+            &QueryMsg::Tokens {
+                owner: owner.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+    }
+
+    pub fn withdraw_bet(
+        &mut self,
+        sender: &Addr,
+        result: MarketResult,
+        amount: u128,
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::WithdrawBet { result, amount },
+            &[],
+        )
+    }
+
+    pub fn transfer_position(
+        &mut self,
+        sender: &Addr,
+        result: MarketResult,
+        amount: u128,
+        recipient: &Addr,
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::TransferPosition {
                 result,
+                amount,
+                recipient: recipient.clone(),
+            },
+            &[],
+        )
+    }
+
+    pub fn mint_bet_slip(
+        &mut self,
+        sender: &Addr,
+        result: MarketResult,
+        amount: u128,
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::MintBetSlip { result, amount },
+            &[],
+        )
+    }
+
+    pub fn claim_by_token(&mut self, sender: &Addr, token_id: &str) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::ClaimByToken {
+                token_id: token_id.to_string(),
             },
+            &[],
+        )
+    }
+
+    pub fn transfer_nft(
+        &mut self,
+        sender: &Addr,
+        recipient: &Addr,
+        token_id: &str,
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::TransferNft {
+                recipient: recipient.to_string(),
+                token_id: token_id.to_string(),
+            },
+            &[],
+        )
+    }
+
+    pub fn approve_nft(
+        &mut self,
+        sender: &Addr,
+        spender: &Addr,
+        token_id: &str,
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::Approve {
+                spender: spender.to_string(),
+                token_id: token_id.to_string(),
+                expires: None,
+            },
+            &[],
+        )
+    }
+
+    pub fn expire_market(&mut self, sender: &Addr) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::ExpireMarket {},
+            &[],
         )
     }
 
@@ -74,6 +225,48 @@ impl BlockchainContract {
             .execute_contract(sender.clone(), self.addr(), &ExecuteMsg::Cancel {}, &[])
     }
 
+    pub fn postpone_market(&mut self, sender: &Addr) -> AnyResult<AppResponse> {
+        self.blockchain
+            .execute_contract(sender.clone(), self.addr(), &ExecuteMsg::Postpone {}, &[])
+    }
+
+    pub fn reschedule_market(
+        &mut self,
+        sender: &Addr,
+        start_timestamp: u64,
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::Reschedule { start_timestamp },
+            &[],
+        )
+    }
+
+    pub fn withdraw_stake(
+        &mut self,
+        sender: &Addr,
+        token_id: Option<&str>,
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::WithdrawStake {
+                token_id: token_id.map(|token_id| token_id.to_string()),
+            },
+            &[],
+        )
+    }
+
+    pub fn void_market(&mut self, sender: &Addr, reason: VoidReason) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::Void { reason },
+            &[],
+        )
+    }
+
     pub fn update_market(&mut self, sender: &Addr, params: UpdateParams) -> AnyResult<AppResponse> {
         self.blockchain.execute_contract(
             sender.clone(),
@@ -83,6 +276,10 @@ impl BlockchainContract {
                 treasury_addr: params.treasury_addr,
                 fee_bps: params.fee_bps,
                 start_timestamp: params.start_timestamp,
+                label: params.label,
+                home_team: params.home_team,
+                away_team: params.away_team,
+                metadata: params.metadata,
             },
             &[],
         )
@@ -92,7 +289,22 @@ impl BlockchainContract {
         self.blockchain.execute_contract(
             sender.clone(),
             self.addr(),
-            &ExecuteMsg::Score { result },
+            &ExecuteMsg::Score {
+                results: vec![result],
+            },
+            &[],
+        )
+    }
+
+    pub fn score_market_dead_heat(
+        &mut self,
+        sender: &Addr,
+        results: Vec<MarketResult>,
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::Score { results },
             &[],
         )
     }
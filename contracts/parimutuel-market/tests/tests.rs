@@ -1,10 +1,10 @@
-use cosmwasm_std::{coin, coins, Timestamp, Uint128};
+use cosmwasm_std::{coin, coins, Decimal, Timestamp, Uint128};
 use cw_multi_test::MockApiBech32;
 use helpers::setup_blockchain_and_contract;
 use parimutuel_market::{
     error::ContractError,
     msg::InstantiateMsg,
-    state::{MarketResult, Status},
+    state::{MarketResult, OddsFormat, Status},
 };
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -52,6 +52,11 @@ mod create_market {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -80,7 +85,7 @@ mod create_market {
         assert_eq!(start_timestamp, query_market.market.start_timestamp);
         assert!(query_market.market.is_drawable);
         assert_eq!(Status::ACTIVE, query_market.market.status);
-        assert_eq!(None, query_market.market.result);
+        assert!(query_market.market.results.is_empty());
     }
 
     #[test]
@@ -109,6 +114,11 @@ mod create_market {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -166,6 +176,11 @@ mod place_bet {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -293,7 +308,7 @@ mod place_bet {
 
         let query_market = blockchain_contract.query_market().unwrap();
         assert_eq!(query_market.market.status, Status::CLOSED);
-        assert_eq!(query_market.market.result.unwrap(), MarketResult::DRAW);
+        assert_eq!(query_market.market.results, vec![MarketResult::DRAW]);
 
         let treasury_balance = blockchain_contract
             .blockchain
@@ -417,6 +432,11 @@ mod place_bet {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -477,6 +497,11 @@ mod place_bet {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: false,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -540,6 +565,11 @@ mod place_bet {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -610,6 +640,11 @@ mod place_bet {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -680,6 +715,11 @@ mod place_bet {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -759,6 +799,11 @@ mod claim_winnings {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -814,7 +859,7 @@ mod claim_winnings {
 
         let query_market = blockchain_contract.query_market().unwrap();
         assert_eq!(Status::CLOSED, query_market.market.status);
-        assert_eq!(MarketResult::AWAY, query_market.market.result.unwrap());
+        assert_eq!(vec![MarketResult::AWAY], query_market.market.results);
 
         let user_a_balance = blockchain_contract
             .blockchain
@@ -869,6 +914,11 @@ mod claim_winnings {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -924,7 +974,7 @@ mod claim_winnings {
 
         let query_market = blockchain_contract.query_market().unwrap();
         assert_eq!(Status::CLOSED, query_market.market.status);
-        assert_eq!(MarketResult::HOME, query_market.market.result.unwrap());
+        assert_eq!(vec![MarketResult::HOME], query_market.market.results);
 
         let user_a_balance = blockchain_contract
             .blockchain
@@ -982,6 +1032,11 @@ mod claim_winnings {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -1098,6 +1153,11 @@ mod claim_winnings {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -1130,7 +1190,7 @@ mod claim_winnings {
 
         let query_market = blockchain_contract.query_market().unwrap();
         assert_eq!(Status::ACTIVE, query_market.market.status);
-        assert_eq!(None, query_market.market.result);
+        assert!(query_market.market.results.is_empty());
 
         let user_a_balance = blockchain_contract
             .blockchain
@@ -1191,6 +1251,11 @@ mod claim_winnings {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -1246,7 +1311,7 @@ mod claim_winnings {
 
         let query_market = blockchain_contract.query_market().unwrap();
         assert_eq!(Status::CLOSED, query_market.market.status);
-        assert_eq!(MarketResult::DRAW, query_market.market.result.unwrap());
+        assert_eq!(vec![MarketResult::DRAW], query_market.market.results);
 
         let user_a_balance = blockchain_contract
             .blockchain
@@ -1309,6 +1374,11 @@ mod claim_winnings {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -1354,7 +1424,7 @@ mod claim_winnings {
 
         let query_market = blockchain_contract.query_market().unwrap();
         assert_eq!(Status::CLOSED, query_market.market.status);
-        assert_eq!(MarketResult::DRAW, query_market.market.result.unwrap());
+        assert_eq!(vec![MarketResult::DRAW], query_market.market.results);
 
         let other_balance = blockchain_contract
             .blockchain
@@ -1409,6 +1479,11 @@ mod update_market {
                     .as_secs()
                     - 60 * 5, // 5 minutes ago
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -1428,6 +1503,10 @@ mod update_market {
                     treasury_addr: None,
                     fee_bps: None,
                     start_timestamp: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap();
@@ -1463,6 +1542,11 @@ mod update_market {
                     .as_secs()
                     - 60 * 5, // 5 minutes ago
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -1482,6 +1566,10 @@ mod update_market {
                     treasury_addr: Some(MockApiBech32::new("neutron").addr_make(OTHER)),
                     fee_bps: None,
                     start_timestamp: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap();
@@ -1517,6 +1605,11 @@ mod update_market {
                     .as_secs()
                     + 60 * 15, // 15 minutes in future
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -1534,6 +1627,10 @@ mod update_market {
                     treasury_addr: None,
                     fee_bps: Some(new_fee_bps),
                     start_timestamp: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap();
@@ -1568,6 +1665,11 @@ mod update_market {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -1585,6 +1687,10 @@ mod update_market {
                     treasury_addr: None,
                     fee_bps: None,
                     start_timestamp: Some(new_start_timestamp),
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap();
@@ -1619,6 +1725,11 @@ mod update_market {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -1636,6 +1747,10 @@ mod update_market {
                     treasury_addr: None,
                     fee_bps: None,
                     start_timestamp: Some(start_timestamp - 60 * 30),
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             ) // 30 minutes ago
             .unwrap_err();
@@ -1674,6 +1789,11 @@ mod update_market {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -1726,6 +1846,10 @@ mod update_market {
                     treasury_addr: None,
                     fee_bps: None,
                     start_timestamp: Some(start_timestamp - 60 * 30),
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap_err();
@@ -1762,6 +1886,11 @@ mod update_market {
                     .as_secs()
                     + 60 * 15, // 15 minutes in future
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -1778,6 +1907,10 @@ mod update_market {
                     treasury_addr: None,
                     fee_bps: Some(1_001),
                     start_timestamp: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
                 },
             )
             .unwrap_err();
@@ -1820,6 +1953,11 @@ mod score_market {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -1873,7 +2011,7 @@ mod score_market {
 
         let query_market = blockchain_contract.query_market().unwrap();
         assert_eq!(Status::CLOSED, query_market.market.status);
-        assert_eq!(MarketResult::DRAW, query_market.market.result.unwrap());
+        assert_eq!(vec![MarketResult::DRAW], query_market.market.results);
     }
 
     #[test]
@@ -1902,6 +2040,11 @@ mod score_market {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -1955,7 +2098,7 @@ mod score_market {
 
         let query_market = blockchain_contract.query_market().unwrap();
         assert_eq!(Status::CLOSED, query_market.market.status);
-        assert_eq!(MarketResult::DRAW, query_market.market.result.unwrap());
+        assert_eq!(vec![MarketResult::DRAW], query_market.market.results);
     }
 
     #[test]
@@ -1984,6 +2127,11 @@ mod score_market {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -2031,7 +2179,7 @@ mod score_market {
 
         let query_market = blockchain_contract.query_market().unwrap();
         assert_eq!(Status::ACTIVE, query_market.market.status);
-        assert_eq!(None, query_market.market.result);
+        assert!(query_market.market.results.is_empty());
     }
 
     #[test]
@@ -2060,6 +2208,11 @@ mod score_market {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: false,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -2107,7 +2260,7 @@ mod score_market {
 
         let query_market = blockchain_contract.query_market().unwrap();
         assert_eq!(Status::ACTIVE, query_market.market.status);
-        assert_eq!(None, query_market.market.result);
+        assert!(query_market.market.results.is_empty());
     }
 
     #[test]
@@ -2136,6 +2289,11 @@ mod score_market {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -2147,7 +2305,7 @@ mod score_market {
 
         let query_market = blockchain_contract.query_market().unwrap();
         assert_ne!(Status::ACTIVE, query_market.market.status);
-        assert_eq!(None, query_market.market.result);
+        assert!(query_market.market.results.is_empty());
 
         let err = blockchain_contract
             .score_market(
@@ -2161,7 +2319,7 @@ mod score_market {
         );
 
         let query_market = blockchain_contract.query_market().unwrap();
-        assert_eq!(None, query_market.market.result);
+        assert!(query_market.market.results.is_empty());
     }
 
     #[test]
@@ -2190,6 +2348,11 @@ mod score_market {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -2237,7 +2400,7 @@ mod score_market {
 
         let query_market = blockchain_contract.query_market().unwrap();
         assert_eq!(Status::ACTIVE, query_market.market.status);
-        assert_eq!(None, query_market.market.result);
+        assert!(query_market.market.results.is_empty());
 
         blockchain_contract.blockchain.update_block(|block| {
             block.time = Timestamp::from_seconds(
@@ -2254,11 +2417,11 @@ mod score_market {
 
         let query_market = blockchain_contract.query_market().unwrap();
         assert_eq!(Status::CLOSED, query_market.market.status);
-        assert_eq!(MarketResult::HOME, query_market.market.result.unwrap());
+        assert_eq!(vec![MarketResult::HOME], query_market.market.results);
     }
 
     #[test]
-    fn market_has_no_winnings() {
+    fn market_has_no_losers_is_refunded() {
         let start_timestamp = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .expect("Time went backwards")
@@ -2283,6 +2446,11 @@ mod score_market {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -2308,24 +2476,46 @@ mod score_market {
             );
         });
 
-        let err = blockchain_contract
+        let response = blockchain_contract
             .score_market(
                 &MockApiBech32::new("neutron").addr_make(ADMIN),
                 MarketResult::DRAW,
             )
-            .unwrap_err();
-        assert_eq!(
-            ContractError::NoWinnings {},
-            err.downcast::<ContractError>().unwrap()
-        );
+            .unwrap();
+        assert!(response.events.iter().any(|event| event
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "refund" && attribute.value == "true")));
 
         let query_market = blockchain_contract.query_market().unwrap();
-        assert_eq!(Status::ACTIVE, query_market.market.status);
-        assert_eq!(None, query_market.market.result);
+        assert_eq!(Status::CLOSED, query_market.market.status);
+        assert_eq!(vec![MarketResult::DRAW], query_market.market.results);
+        assert!(query_market.market.is_refund);
+
+        let treasury_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(TREASURY),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(0, treasury_balance.amount.u128());
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(ADMIN), None)
+            .unwrap();
+
+        let admin_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(MockApiBech32::new("neutron").addr_make(ADMIN), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, admin_balance.amount.u128());
     }
 
     #[test]
-    fn market_has_no_winners() {
+    fn market_has_no_winners_is_refunded() {
         let start_timestamp = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .expect("Time went backwards")
@@ -2350,6 +2540,11 @@ mod score_market {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -2384,20 +2579,36 @@ mod score_market {
             );
         });
 
-        let err = blockchain_contract
+        blockchain_contract
             .score_market(
                 &MockApiBech32::new("neutron").addr_make(ADMIN),
                 MarketResult::HOME,
             )
+            .unwrap();
+
+        let query = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::CLOSED, query.market.status);
+        assert_eq!(vec![MarketResult::HOME], query.market.results);
+        assert!(query.market.is_refund);
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(ADMIN), None)
+            .unwrap();
+
+        let admin_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(MockApiBech32::new("neutron").addr_make(ADMIN), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, admin_balance.amount.u128());
+
+        let err = blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(ADMIN), None)
             .unwrap_err();
         assert_eq!(
-            ContractError::NoWinnings {},
+            ContractError::ClaimAlreadyMade {},
             err.downcast::<ContractError>().unwrap()
         );
-
-        let query = blockchain_contract.query_market().unwrap();
-        assert_eq!(Status::ACTIVE, query.market.status);
-        assert_eq!(None, query.market.result);
     }
 }
 
@@ -2428,6 +2639,11 @@ mod cancel_market {
                     .as_secs()
                     + 60 * 5, // 5 minutes from now
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -2468,6 +2684,11 @@ mod cancel_market {
                     .as_secs()
                     + 60 * 5, // 5 minutes from now
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -2512,6 +2733,11 @@ mod cancel_market {
                 away_team: "Team B".to_string(),
                 start_timestamp,
                 is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
             },
             vec![],
         )
@@ -2568,3 +2794,5131 @@ mod cancel_market {
         assert_ne!(Status::CANCELLED, query_market.market.status);
     }
 }
+
+mod odds {
+    use super::*;
+
+    #[test]
+    fn it_properly_derives_the_odds_from_the_pools_after_the_fee() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let query_odds = blockchain_contract.query_odds(OddsFormat::DECIMAL).unwrap();
+        assert_eq!(3, query_odds.outcomes.len());
+        for outcome in query_odds.outcomes {
+            assert_eq!(None, outcome.odds);
+            assert_eq!(Decimal::zero(), outcome.implied_probability);
+        }
+        assert_eq!(Decimal::zero(), query_odds.overround);
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(30_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        // 40 in the pools minus the 2.5% fee leaves 39 to the winners
+        let query_odds = blockchain_contract.query_odds(OddsFormat::DECIMAL).unwrap();
+        assert_eq!(MarketResult::HOME, query_odds.outcomes[0].result);
+        assert_eq!(Some("3.90".to_string()), query_odds.outcomes[0].odds);
+        assert_eq!(
+            Decimal::from_ratio(10_u128, 39_u128),
+            query_odds.outcomes[0].implied_probability
+        );
+        assert_eq!(MarketResult::AWAY, query_odds.outcomes[1].result);
+        assert_eq!(Some("1.30".to_string()), query_odds.outcomes[1].odds);
+        assert_eq!(
+            Decimal::from_ratio(30_u128, 39_u128),
+            query_odds.outcomes[1].implied_probability
+        );
+        assert_eq!(MarketResult::DRAW, query_odds.outcomes[2].result);
+        assert_eq!(None, query_odds.outcomes[2].odds);
+        assert_eq!(Decimal::zero(), query_odds.outcomes[2].implied_probability);
+        assert_eq!(
+            Decimal::from_ratio(10_u128, 39_u128) + Decimal::from_ratio(30_u128, 39_u128)
+                - Decimal::one(),
+            query_odds.overround
+        );
+
+        let query_odds = blockchain_contract
+            .query_odds(OddsFormat::AMERICAN)
+            .unwrap();
+        assert_eq!(Some("+290".to_string()), query_odds.outcomes[0].odds);
+        assert_eq!(Some("-333".to_string()), query_odds.outcomes[1].odds);
+
+        let query_odds = blockchain_contract
+            .query_odds(OddsFormat::FRACTIONAL)
+            .unwrap();
+        assert_eq!(Some("29/10".to_string()), query_odds.outcomes[0].odds);
+        assert_eq!(Some("3/10".to_string()), query_odds.outcomes[1].odds);
+    }
+
+    #[test]
+    fn it_doesnt_return_the_draw_odds_when_market_isnt_drawable() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: false,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let query_odds = blockchain_contract.query_odds(OddsFormat::DECIMAL).unwrap();
+        assert_eq!(2, query_odds.outcomes.len());
+        assert_eq!(MarketResult::HOME, query_odds.outcomes[0].result);
+        assert_eq!(MarketResult::AWAY, query_odds.outcomes[1].result);
+    }
+}
+
+mod void_market {
+    use super::*;
+    use parimutuel_market::state::VoidReason;
+
+    #[test]
+    fn it_properly_voids_the_market_and_refunds_the_bets() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        let response = blockchain_contract
+            .void_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                VoidReason::ABANDONED,
+            )
+            .unwrap();
+        assert!(response.events.iter().any(|event| event
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "reason" && attribute.value == "ABANDONED")));
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::VOIDED, query_market.market.status);
+        assert_eq!(Some(VoidReason::ABANDONED), query_market.market.void_reason);
+        assert!(query_market.market.results.is_empty());
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_B), None)
+            .unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_a_balance.amount.u128());
+        let user_b_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_B),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_b_balance.amount.u128());
+        let treasury_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(TREASURY),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(0, treasury_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_properly_voids_a_postponed_market() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .postpone_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap();
+
+        let response = blockchain_contract
+            .void_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                VoidReason::OTHER,
+            )
+            .unwrap();
+        assert!(response.events.iter().any(|event| event
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "reason" && attribute.value == "OTHER")));
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::VOIDED, query_market.market.status);
+        assert_eq!(Some(VoidReason::OTHER), query_market.market.void_reason);
+        assert!(query_market.market.results.is_empty());
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_B), None)
+            .unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_a_balance.amount.u128());
+        let user_b_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_B),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_b_balance.amount.u128());
+        let treasury_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(TREASURY),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(0, treasury_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_cant_void_the_market_if_sender_isnt_the_admin() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let err = blockchain_contract
+            .void_market(
+                &MockApiBech32::new("neutron").addr_make(ANYONE),
+                VoidReason::ABANDONED,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_void_a_market_that_isnt_active() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .cancel_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap();
+
+        let err = blockchain_contract
+            .void_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                VoidReason::OTHER,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotActive {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::CANCELLED, query_market.market.status);
+        assert_eq!(None, query_market.market.void_reason);
+    }
+}
+
+mod dead_heat {
+    use super::*;
+
+    #[test]
+    fn it_properly_splits_the_pool_among_the_winning_results() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_C),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(OTHER),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(OTHER),
+                MarketResult::HOME,
+                None,
+                &coins(30_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(20_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_C),
+                MarketResult::DRAW,
+                None,
+                &coins(40_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_estimate_winnings = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_wasm_smart::<parimutuel_market::msg::EstimateWinningsResponse>(
+                blockchain_contract.addr(),
+                &parimutuel_market::msg::QueryMsg::EstimateWinnings {
+                    address: MockApiBech32::new("neutron").addr_make(USER_B),
+                    results: vec![MarketResult::HOME, MarketResult::AWAY],
+                },
+            )
+            .unwrap();
+        assert_eq!(50_000_000, query_estimate_winnings.estimate);
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market_dead_heat(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                vec![MarketResult::HOME, MarketResult::AWAY],
+            )
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::CLOSED, query_market.market.status);
+        assert_eq!(
+            vec![MarketResult::HOME, MarketResult::AWAY],
+            query_market.market.results
+        );
+
+        // Total bets of 100_000_000 minus the 2.5% fee, split in two shares of 48_750_000
+        let treasury_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(TREASURY),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(2_500_000, treasury_balance.amount.u128());
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(OTHER), None)
+            .unwrap();
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_B), None)
+            .unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(
+            INITIAL_BALANCE - 10_000_000 + 12_187_500,
+            user_a_balance.amount.u128()
+        );
+        let other_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(MockApiBech32::new("neutron").addr_make(OTHER), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(
+            INITIAL_BALANCE - 30_000_000 + 36_562_500,
+            other_balance.amount.u128()
+        );
+        let user_b_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_B),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(
+            INITIAL_BALANCE - 20_000_000 + 48_750_000,
+            user_b_balance.amount.u128()
+        );
+
+        let err = blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_C), None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NoWinnings {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let contract_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(blockchain_contract.addr(), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(0, contract_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_cant_score_with_empty_or_duplicated_results() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_C),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(OTHER),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(20_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        let err = blockchain_contract
+            .score_market_dead_heat(&MockApiBech32::new("neutron").addr_make(ADMIN), vec![])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidResults {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .score_market_dead_heat(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                vec![MarketResult::HOME, MarketResult::HOME],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidResults {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::ACTIVE, query_market.market.status);
+    }
+
+    #[test]
+    fn it_properly_settles_a_dead_heat_among_the_winning_results_with_bets() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_C),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(OTHER),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_C),
+                MarketResult::DRAW,
+                None,
+                &coins(20_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market_dead_heat(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                vec![MarketResult::HOME, MarketResult::AWAY],
+            )
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::CLOSED, query_market.market.status);
+        assert!(!query_market.market.is_refund);
+
+        // The whole pool after the fee goes to the only winning result with bets
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE + 19_250_000, user_a_balance.amount.u128());
+
+        let err = blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_C), None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NoWinnings {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let query_solvency = blockchain_contract.query_solvency().unwrap();
+        assert_eq!(0, query_solvency.unclaimed);
+        assert_eq!(0, query_solvency.balance);
+    }
+}
+
+mod expire_market {
+    use super::*;
+    use parimutuel_market::msg::UpdateParams;
+
+    #[test]
+    fn it_lets_anyone_expire_the_market_after_the_resolution_deadline() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(
+            start_timestamp + 7 * 24 * 60 * 60,
+            query_market.market.resolution_deadline
+        );
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                query_market.market.resolution_deadline, // At the resolution deadline
+            );
+        });
+
+        blockchain_contract
+            .expire_market(&MockApiBech32::new("neutron").addr_make(ANYONE))
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::CANCELLED, query_market.market.status);
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_B), None)
+            .unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_a_balance.amount.u128());
+        let user_b_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_B),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_b_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_cant_expire_the_market_before_the_resolution_deadline() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: Some(2 * 24 * 60 * 60), // 2 days,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .update_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                UpdateParams {
+                    admin_addr: None,
+                    treasury_addr: None,
+                    fee_bps: None,
+                    start_timestamp: Some(start_timestamp + 60 * 60),
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
+                },
+            )
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(
+            start_timestamp + 60 * 60 + 2 * 24 * 60 * 60,
+            query_market.market.resolution_deadline
+        );
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                query_market.market.resolution_deadline - 1, // 1 second before the resolution deadline
+            );
+        });
+
+        let err = blockchain_contract
+            .expire_market(&MockApiBech32::new("neutron").addr_make(ANYONE))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotExpired {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::ACTIVE, query_market.market.status);
+    }
+
+    #[test]
+    fn it_cant_expire_a_market_that_isnt_active() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::HOME,
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 8 * 24 * 60 * 60, // 8 days after the start timestamp
+            );
+        });
+
+        let err = blockchain_contract
+            .expire_market(&MockApiBech32::new("neutron").addr_make(ANYONE))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotActive {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::CLOSED, query_market.market.status);
+    }
+
+    #[test]
+    fn it_cant_create_a_market_with_an_invalid_resolution_grace_period() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let err = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: Some(60 * 60), // 1 hour,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidResolutionGracePeriod(60 * 60),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod postpone {
+    use super::*;
+
+    #[test]
+    fn it_properly_postpones_and_reschedules_the_market() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let block_time = blockchain_contract.blockchain.block_info().time.seconds();
+        blockchain_contract
+            .postpone_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::POSTPONED, query_market.market.status);
+        assert_eq!(
+            Some(block_time + 3 * 24 * 60 * 60),
+            query_market.market.postponement_deadline
+        );
+
+        let err = blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(1_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotActive {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotClosed {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract
+            .reschedule_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                start_timestamp + 24 * 60 * 60,
+            )
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::ACTIVE, query_market.market.status);
+        assert_eq!(
+            start_timestamp + 24 * 60 * 60,
+            query_market.market.start_timestamp
+        );
+        assert_eq!(
+            start_timestamp + 24 * 60 * 60 + 7 * 24 * 60 * 60,
+            query_market.market.resolution_deadline
+        );
+        assert_eq!(None, query_market.market.postponement_deadline);
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(1_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(11_000_000, query_bets.totals.home);
+        assert_eq!(5_000_000, query_bets.totals.away);
+    }
+
+    #[test]
+    fn it_properly_withdraws_the_stake_while_postponed() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .mint_bet_slip(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                2_000_000,
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .withdraw_stake(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotPostponed {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract
+            .postpone_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap();
+
+        blockchain_contract
+            .withdraw_stake(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_a_balance.amount.u128());
+
+        let err = blockchain_contract
+            .withdraw_stake(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NoStakeToWithdraw {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .withdraw_stake(&MockApiBech32::new("neutron").addr_make(USER_A), Some("1"))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract
+            .withdraw_stake(&MockApiBech32::new("neutron").addr_make(USER_B), Some("1"))
+            .unwrap();
+
+        let user_b_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_B),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE - 3_000_000, user_b_balance.amount.u128());
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(0, query_bets.totals.home);
+        assert_eq!(3_000_000, query_bets.totals.away);
+        assert_eq!(0, query_bets.totals.draw);
+
+        blockchain_contract
+            .reschedule_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                start_timestamp + 24 * 60 * 60,
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(start_timestamp + 24 * 60 * 60 + 60 * 30);
+        });
+        blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::AWAY,
+            )
+            .unwrap();
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_B), None)
+            .unwrap();
+
+        let user_b_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_B),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_b_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_properly_refunds_the_bets_once_the_postponement_deadline_passed() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: Some(24 * 60 * 60), // 1 day,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .mint_bet_slip(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                2_000_000,
+            )
+            .unwrap();
+        blockchain_contract
+            .postpone_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        let postponement_deadline = query_market.market.postponement_deadline.unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                postponement_deadline - 1, // 1 second before the postponement deadline
+            );
+        });
+
+        let err = blockchain_contract
+            .expire_market(&MockApiBech32::new("neutron").addr_make(ANYONE))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotExpired {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotClosed {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                postponement_deadline, // At the postponement deadline
+            );
+        });
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::CANCELLED, query_market.market.status);
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_B), None)
+            .unwrap();
+        blockchain_contract
+            .claim_by_token(&MockApiBech32::new("neutron").addr_make(USER_B), "1")
+            .unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_a_balance.amount.u128());
+
+        let user_b_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_B),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_b_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_lets_anyone_expire_the_market_after_the_postponement_deadline() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .postpone_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                query_market.market.postponement_deadline.unwrap(), // At the postponement deadline
+            );
+        });
+
+        let err = blockchain_contract
+            .reschedule_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                start_timestamp + 24 * 60 * 60,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::PostponementExpired {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .withdraw_stake(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::PostponementExpired {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract
+            .expire_market(&MockApiBech32::new("neutron").addr_make(ANYONE))
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Status::CANCELLED, query_market.market.status);
+    }
+
+    #[test]
+    fn it_cant_postpone_if_sender_isnt_the_admin() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let err = blockchain_contract
+            .postpone_market(&MockApiBech32::new("neutron").addr_make(USER_A))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .reschedule_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                start_timestamp + 24 * 60 * 60,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotPostponed {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract
+            .postpone_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap();
+
+        let err = blockchain_contract
+            .postpone_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotActive {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .reschedule_market(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                start_timestamp + 24 * 60 * 60,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .reschedule_market(&MockApiBech32::new("neutron").addr_make(ADMIN), 0)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidTimestamp(0),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_create_a_market_with_an_invalid_postponement_period() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 5; // 5 minutes from now
+
+        let err = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: Some(30 * 60), // 30 minutes
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidPostponementPeriod(30 * 60),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod early_bird {
+    use super::*;
+    use parimutuel_market::state::EarlyBirdConfig;
+
+    #[test]
+    fn it_properly_weights_the_shares_of_early_bets() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 20 * 24 * 60 * 60; // 20 days from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(OTHER),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: Some(EarlyBirdConfig {
+                    max_multiplier: Decimal::from_atomics(2_u128, 0).unwrap(), // 2
+                    decay_period: 10 * 24 * 60 * 60,                           // 10 days
+                }),
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp - 12 * 24 * 60 * 60, // 12 days before the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp - 5 * 24 * 60 * 60, // 5 days before the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(OTHER),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp - 6 * 60, // 6 minutes before the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(20_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(20_000_000, query_bets.totals.home);
+        assert_eq!(20_000_000, query_bets.totals.away);
+        assert_eq!(20_000_000 + 15_000_000, query_bets.shares.home); // 2x and 1.5x
+        assert_eq!(20_008_333, query_bets.shares.away); // Almost 1x
+
+        let query_bets_by_address = blockchain_contract
+            .query_bets_by_address(&MockApiBech32::new("neutron").addr_make(USER_A))
+            .unwrap();
+        assert_eq!(10_000_000, query_bets_by_address.totals.home);
+        assert_eq!(20_000_000, query_bets_by_address.shares.home);
+
+        let query_estimate_winnings = blockchain_contract
+            .query_estimate_winnings(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+            )
+            .unwrap();
+        assert_eq!(22_857_142, query_estimate_winnings.estimate);
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::HOME,
+            )
+            .unwrap();
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(OTHER), None)
+            .unwrap();
+
+        // Total bets of 40_000_000 minus the 2.5% fee, split 20/35 and 15/35
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(
+            INITIAL_BALANCE - 10_000_000 + 22_285_714,
+            user_a_balance.amount.u128()
+        );
+        let other_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(MockApiBech32::new("neutron").addr_make(OTHER), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(
+            INITIAL_BALANCE - 10_000_000 + 16_714_285,
+            other_balance.amount.u128()
+        );
+    }
+
+    #[test]
+    fn it_cant_create_a_market_with_an_invalid_early_bird_multiplier() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 20 * 24 * 60 * 60; // 20 days from now
+
+        let err = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: Some(EarlyBirdConfig {
+                    max_multiplier: Decimal::from_atomics(5_u128, 1).unwrap(), // 0.5
+                    decay_period: 10 * 24 * 60 * 60,                           // 10 days
+                }),
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidEarlyBirdMultiplier(Decimal::from_atomics(5_u128, 1).unwrap()),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_create_a_market_with_an_invalid_early_bird_decay_period() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 20 * 24 * 60 * 60; // 20 days from now
+
+        let err = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: Some(EarlyBirdConfig {
+                    max_multiplier: Decimal::from_atomics(2_u128, 0).unwrap(), // 2
+                    decay_period: 0,
+                }),
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidEarlyBirdDecayPeriod(0),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod withdraw_bet {
+    use super::*;
+    use parimutuel_market::state::{PenaltyDestination, WithdrawalConfig};
+
+    #[test]
+    fn it_properly_withdraws_a_bet_and_sends_the_penalty_to_the_treasury() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(OTHER),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: Some(WithdrawalConfig {
+                    penalty_bps: 500,
+                    penalty_destination: PenaltyDestination::TREASURY,
+                }),
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .withdraw_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                4_000_000,
+            )
+            .unwrap();
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(6_000_000, query_bets.totals.home);
+        assert_eq!(6_000_000, query_bets.shares.home);
+
+        let query_bets_by_address = blockchain_contract
+            .query_bets_by_address(&MockApiBech32::new("neutron").addr_make(USER_A))
+            .unwrap();
+        assert_eq!(6_000_000, query_bets_by_address.totals.home);
+        assert_eq!(6_000_000, query_bets_by_address.shares.home);
+
+        // 5% penalty of the 4_000_000 withdrawn
+        let treasury_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(TREASURY),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(200_000, treasury_balance.amount.u128());
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(
+            INITIAL_BALANCE - 10_000_000 + 3_800_000,
+            user_a_balance.amount.u128()
+        );
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::HOME,
+            )
+            .unwrap();
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+
+        // Total bets of 16_000_000 minus the 2.5% fee
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(
+            INITIAL_BALANCE - 10_000_000 + 3_800_000 + 15_600_000,
+            user_a_balance.amount.u128()
+        );
+    }
+
+    #[test]
+    fn it_properly_withdraws_a_bet_and_leaves_the_penalty_in_the_pool() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(OTHER),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: Some(WithdrawalConfig {
+                    penalty_bps: 500,
+                    penalty_destination: PenaltyDestination::POOL,
+                }),
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(OTHER),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .withdraw_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                10_000_000,
+            )
+            .unwrap();
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(10_000_000, query_bets.totals.home);
+        assert_eq!(10_000_000, query_bets.shares.home);
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE - 500_000, user_a_balance.amount.u128());
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::HOME,
+            )
+            .unwrap();
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(OTHER), None)
+            .unwrap();
+
+        // Total bets of 20_500_000 minus the 2.5% fee
+        let other_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(MockApiBech32::new("neutron").addr_make(OTHER), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(
+            INITIAL_BALANCE - 10_000_000 + 19_987_500,
+            other_balance.amount.u128()
+        );
+
+        let err = blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NoWinnings {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_properly_sends_the_penalties_left_in_the_pool_to_the_treasury_when_cancelled() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: Some(WithdrawalConfig {
+                    penalty_bps: 500,
+                    penalty_destination: PenaltyDestination::POOL,
+                }),
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .withdraw_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                4_000_000,
+            )
+            .unwrap();
+
+        blockchain_contract
+            .cancel_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap();
+
+        // The penalty of 200_000 left in the pool has no winners to go to
+        let treasury_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(TREASURY),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(200_000, treasury_balance.amount.u128());
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_B), None)
+            .unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE - 200_000, user_a_balance.amount.u128());
+
+        let user_b_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_B),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_b_balance.amount.u128());
+
+        let market_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(blockchain_contract.addr().clone(), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(0, market_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_cant_withdraw_more_than_the_bet_amount() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(OTHER),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: Some(WithdrawalConfig {
+                    penalty_bps: 500,
+                    penalty_destination: PenaltyDestination::TREASURY,
+                }),
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .withdraw_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                10_000_001,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidWithdrawalAmount {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .withdraw_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                0,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidWithdrawalAmount {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .withdraw_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::AWAY,
+                1_000,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidWithdrawalAmount {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_withdraw_a_bet_if_withdrawals_are_not_enabled() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(OTHER),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .withdraw_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                1_000_000,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::WithdrawalsNotEnabled {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_withdraw_a_bet_after_the_cutoff() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(OTHER),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: Some(WithdrawalConfig {
+                    penalty_bps: 500,
+                    penalty_destination: PenaltyDestination::TREASURY,
+                }),
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp - 60 * 4, // 4 minutes before the start timestamp
+            );
+        });
+
+        let err = blockchain_contract
+            .withdraw_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                1_000_000,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::BetsNotAccepted {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_create_a_market_with_an_invalid_withdrawal_penalty_bps() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now
+
+        let err = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: Some(WithdrawalConfig {
+                    penalty_bps: 5_001,
+                    penalty_destination: PenaltyDestination::TREASURY,
+                }),
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidWithdrawalPenaltyBps(5_001),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod transfer_position {
+    use super::*;
+    use parimutuel_market::state::{EarlyBirdConfig, VoidReason};
+
+    #[test]
+    fn it_properly_transfers_part_of_a_position_with_its_share_of_the_shares() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 20 * 24 * 60 * 60; // 20 days from now;
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: Some(EarlyBirdConfig {
+                    max_multiplier: Decimal::from_atomics(2_u128, 0).unwrap(), // 2
+                    decay_period: 10 * 24 * 60 * 60,                           // 10 days
+                }),
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp - 12 * 24 * 60 * 60, // 12 days before the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp - 6 * 60, // 6 minutes before the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .transfer_position(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                4_000_000,
+                &MockApiBech32::new("neutron").addr_make(USER_C),
+            )
+            .unwrap();
+
+        let query_bets_by_address = blockchain_contract
+            .query_bets_by_address(&MockApiBech32::new("neutron").addr_make(USER_A))
+            .unwrap();
+        assert_eq!(6_000_000, query_bets_by_address.totals.home);
+        assert_eq!(12_000_000, query_bets_by_address.shares.home);
+        let query_bets_by_address = blockchain_contract
+            .query_bets_by_address(&MockApiBech32::new("neutron").addr_make(USER_C))
+            .unwrap();
+        assert_eq!(4_000_000, query_bets_by_address.totals.home);
+        assert_eq!(8_000_000, query_bets_by_address.shares.home);
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(10_000_000, query_bets.totals.home);
+        assert_eq!(20_000_000, query_bets.shares.home);
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::HOME,
+            )
+            .unwrap();
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_C), None)
+            .unwrap();
+
+        // Total bets of 20_000_000 minus the 2.5% fee, split 60% / 40%
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(
+            INITIAL_BALANCE - 10_000_000 + 11_700_000,
+            user_a_balance.amount.u128()
+        );
+        let user_c_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_C),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(7_800_000, user_c_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_properly_transfers_a_whole_position_and_the_claim_follows_the_new_owner() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now;
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .transfer_position(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                10_000_000,
+                &MockApiBech32::new("neutron").addr_make(USER_C),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::HOME,
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NoWinnings {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_C), None)
+            .unwrap();
+
+        let user_c_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_C),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(19_500_000, user_c_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_properly_transfers_a_position_while_the_market_is_postponed() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now;
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .postpone_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap();
+
+        blockchain_contract
+            .transfer_position(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                10_000_000,
+                &MockApiBech32::new("neutron").addr_make(USER_C),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .void_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                VoidReason::OTHER,
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NoWinnings {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_C), None)
+            .unwrap();
+
+        let user_c_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_C),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(10_000_000, user_c_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_cant_transfer_more_than_the_position() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now;
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .transfer_position(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                10_000_001,
+                &MockApiBech32::new("neutron").addr_make(USER_C),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidTransferAmount {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .transfer_position(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                0,
+                &MockApiBech32::new("neutron").addr_make(USER_C),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidTransferAmount {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .transfer_position(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::DRAW,
+                1_000,
+                &MockApiBech32::new("neutron").addr_make(USER_C),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidTransferAmount {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_transfer_a_position_to_the_sender() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now;
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .transfer_position(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                1_000_000,
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidTransferRecipient {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_transfer_a_position_once_the_market_is_settled() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now;
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::HOME,
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .transfer_position(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                1_000_000,
+                &MockApiBech32::new("neutron").addr_make(USER_C),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotActive {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod bet_slip {
+    use super::*;
+
+    #[test]
+    fn it_properly_mints_a_bet_slip_with_its_metadata() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now;
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .mint_bet_slip(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                4_000_000,
+            )
+            .unwrap();
+
+        let query_owner_of = blockchain_contract.query_owner_of("1").unwrap();
+        assert_eq!(
+            MockApiBech32::new("neutron").addr_make(USER_A).to_string(),
+            query_owner_of.owner
+        );
+        assert!(query_owner_of.approvals.is_empty());
+
+        // Total bets of 20_000_000 minus the 2.5% fee, 40% of the home shares
+        let query_nft_info = blockchain_contract.query_nft_info("1").unwrap();
+        assert_eq!(None, query_nft_info.token_uri);
+        assert_eq!("game-cs2-test-league", query_nft_info.extension.market_id);
+        assert_eq!(MarketResult::HOME, query_nft_info.extension.result);
+        assert_eq!(4_000_000, query_nft_info.extension.stake);
+        assert_eq!(4_000_000, query_nft_info.extension.shares);
+        assert_eq!(7_800_000, query_nft_info.extension.potential_payout);
+
+        let query_bets_by_address = blockchain_contract
+            .query_bets_by_address(&MockApiBech32::new("neutron").addr_make(USER_A))
+            .unwrap();
+        assert_eq!(6_000_000, query_bets_by_address.totals.home);
+        assert_eq!(6_000_000, query_bets_by_address.shares.home);
+
+        let query_bets = blockchain_contract.query_bets().unwrap();
+        assert_eq!(10_000_000, query_bets.totals.home);
+        assert_eq!(10_000_000, query_bets.shares.home);
+
+        let query_num_tokens = blockchain_contract.query_num_tokens().unwrap();
+        assert_eq!(1, query_num_tokens.count);
+        let query_tokens = blockchain_contract
+            .query_tokens(&MockApiBech32::new("neutron").addr_make(USER_A))
+            .unwrap();
+        assert_eq!(vec!["1".to_string()], query_tokens.tokens);
+    }
+
+    #[test]
+    fn it_lets_the_holder_of_the_bet_slip_claim_it() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now;
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .mint_bet_slip(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                4_000_000,
+            )
+            .unwrap();
+
+        blockchain_contract
+            .transfer_nft(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                &MockApiBech32::new("neutron").addr_make(USER_C),
+                "1",
+            )
+            .unwrap();
+
+        let query_owner_of = blockchain_contract.query_owner_of("1").unwrap();
+        assert_eq!(
+            MockApiBech32::new("neutron").addr_make(USER_C).to_string(),
+            query_owner_of.owner
+        );
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::HOME,
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .claim_by_token(&MockApiBech32::new("neutron").addr_make(USER_A), "1")
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract
+            .claim_by_token(&MockApiBech32::new("neutron").addr_make(USER_C), "1")
+            .unwrap();
+
+        let user_c_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_C),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(7_800_000, user_c_balance.amount.u128());
+
+        let query_num_tokens = blockchain_contract.query_num_tokens().unwrap();
+        assert_eq!(0, query_num_tokens.count);
+
+        let err = blockchain_contract
+            .claim_by_token(&MockApiBech32::new("neutron").addr_make(USER_C), "1")
+            .unwrap_err();
+        assert_eq!(
+            ContractError::BetSlipNotFound {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(
+            INITIAL_BALANCE - 10_000_000 + 11_700_000,
+            user_a_balance.amount.u128()
+        );
+    }
+
+    #[test]
+    fn it_lets_an_approved_spender_transfer_the_bet_slip() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now;
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .mint_bet_slip(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                4_000_000,
+            )
+            .unwrap();
+
+        blockchain_contract
+            .approve_nft(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                "1",
+            )
+            .unwrap();
+
+        let query_owner_of = blockchain_contract.query_owner_of("1").unwrap();
+        assert_eq!(1, query_owner_of.approvals.len());
+        assert_eq!(
+            MockApiBech32::new("neutron").addr_make(USER_B),
+            query_owner_of.approvals[0].spender
+        );
+
+        blockchain_contract
+            .transfer_nft(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                &MockApiBech32::new("neutron").addr_make(USER_C),
+                "1",
+            )
+            .unwrap();
+
+        let query_owner_of = blockchain_contract.query_owner_of("1").unwrap();
+        assert_eq!(
+            MockApiBech32::new("neutron").addr_make(USER_C).to_string(),
+            query_owner_of.owner
+        );
+        assert!(query_owner_of.approvals.is_empty());
+
+        let err = blockchain_contract
+            .transfer_nft(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                "1",
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_refunds_the_bet_slip_when_the_market_is_cancelled() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now;
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .mint_bet_slip(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                4_000_000,
+            )
+            .unwrap();
+
+        blockchain_contract
+            .cancel_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap();
+
+        blockchain_contract
+            .claim_by_token(&MockApiBech32::new("neutron").addr_make(USER_A), "1")
+            .unwrap();
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+
+        let user_a_balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM,
+            )
+            .unwrap();
+        assert_eq!(INITIAL_BALANCE, user_a_balance.amount.u128());
+    }
+
+    #[test]
+    fn it_cant_claim_a_losing_bet_slip() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now;
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .mint_bet_slip(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                10_000_000,
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::HOME,
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .claim_by_token(&MockApiBech32::new("neutron").addr_make(USER_B), "1")
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NoWinnings {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_mint_a_bet_slip_for_more_than_the_position() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now;
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .mint_bet_slip(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                10_000_001,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidBetSlipAmount {},
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        let err = blockchain_contract
+            .mint_bet_slip(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                0,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidBetSlipAmount {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_claim_a_bet_slip_before_the_market_is_closed() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now;
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract
+            .mint_bet_slip(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                4_000_000,
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .claim_by_token(&MockApiBech32::new("neutron").addr_make(USER_A), "1")
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotClosed {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod checked_math {
+    use super::*;
+    use cosmwasm_std::{OverflowError, OverflowOperation};
+    use parimutuel_market::state::EarlyBirdConfig;
+
+    #[test]
+    fn it_cant_place_a_bet_when_the_start_timestamp_leaves_no_room_for_the_cutoff() {
+        let start_timestamp = 60; // 1 minute after the epoch
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                coins(INITIAL_BALANCE, NATIVE_DENOM),
+            )],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let err = blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(1_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidTimestamp(start_timestamp),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_create_a_market_with_a_resolution_deadline_out_of_range() {
+        let err = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp: u64::MAX,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidTimestamp(u64::MAX),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_place_a_bet_with_shares_out_of_range() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 20 * 24 * 60 * 60; // 20 days from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![(
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                coins(u128::MAX, NATIVE_DENOM),
+            )],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: Some(EarlyBirdConfig {
+                    max_multiplier: Decimal::from_atomics(2_u128, 0).unwrap(), // 2
+                    decay_period: 10 * 24 * 60 * 60,                           // 10 days
+                }),
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp - 12 * 24 * 60 * 60, // 12 days before the start timestamp
+            );
+        });
+
+        // Weighted by the early bird multiplier of 2, the shares exceed the range of a u128
+        let err = blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(u128::MAX / 2 + 1, NATIVE_DENOM),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::Overflow(OverflowError::new(OverflowOperation::Mul)),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_place_a_bet_overflowing_the_total_shares() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 20 * 24 * 60 * 60; // 20 days from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(u128::MAX / 2, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: Some(EarlyBirdConfig {
+                    max_multiplier: Decimal::from_atomics(2_u128, 0).unwrap(), // 2
+                    decay_period: 10 * 24 * 60 * 60,                           // 10 days
+                }),
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp - 12 * 24 * 60 * 60, // 12 days before the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(u128::MAX / 2, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let err = blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::HOME,
+                None,
+                &coins(1_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::Overflow(OverflowError::new(OverflowOperation::Add)),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod solvency {
+    use super::*;
+    use helpers::BlockchainContract;
+    use parimutuel_market::{
+        msg::{OutcomeLiability, UpdateParams},
+        state::{EarlyBirdConfig, PenaltyDestination, VoidReason, WithdrawalConfig},
+    };
+    use proptest::prelude::*;
+
+    #[test]
+    fn it_properly_reports_the_liability_of_each_result() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::AWAY,
+                None,
+                &coins(5_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_solvency = blockchain_contract.query_solvency().unwrap();
+        assert_eq!(15_000_000, query_solvency.balance);
+        assert_eq!(
+            vec![
+                OutcomeLiability {
+                    result: MarketResult::HOME,
+                    payout: 14_625_000,
+                    fee: 375_000,
+                },
+                OutcomeLiability {
+                    result: MarketResult::AWAY,
+                    payout: 14_625_000,
+                    fee: 375_000,
+                },
+                // No one bet on a draw, all the bets would be refunded
+                OutcomeLiability {
+                    result: MarketResult::DRAW,
+                    payout: 15_000_000,
+                    fee: 0,
+                },
+            ],
+            query_solvency.liabilities
+        );
+        assert_eq!(15_000_000, query_solvency.max_liability);
+        assert_eq!(0, query_solvency.unclaimed);
+        assert_eq!(0, query_solvency.surplus);
+        assert!(query_solvency.solvent);
+    }
+
+    #[test]
+    fn it_properly_reports_the_unclaimed_winnings_once_scored() {
+        let start_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60 * 10; // 10 minutes from now
+
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_A),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_B),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+                (
+                    MockApiBech32::new("neutron").addr_make(USER_C),
+                    coins(INITIAL_BALANCE, NATIVE_DENOM),
+                ),
+            ],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp,
+                is_drawable: false,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                MarketResult::HOME,
+                None,
+                &coins(1_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_B),
+                MarketResult::HOME,
+                None,
+                &coins(2_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+        blockchain_contract
+            .place_bet(
+                &MockApiBech32::new("neutron").addr_make(USER_C),
+                MarketResult::AWAY,
+                None,
+                &coins(4_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contract.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contract
+            .score_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                MarketResult::HOME,
+            )
+            .unwrap();
+
+        // The fee of 175_000 was collected, the winnings are split 1/3 and 2/3
+        let query_solvency = blockchain_contract.query_solvency().unwrap();
+        assert_eq!(6_825_000, query_solvency.balance);
+        assert!(query_solvency.liabilities.is_empty());
+        assert_eq!(6_825_000, query_solvency.unclaimed);
+        assert_eq!(0, query_solvency.surplus);
+        assert!(query_solvency.solvent);
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_A), None)
+            .unwrap();
+
+        let query_solvency = blockchain_contract.query_solvency().unwrap();
+        assert_eq!(4_550_000, query_solvency.balance);
+        assert_eq!(4_550_000, query_solvency.unclaimed);
+
+        blockchain_contract
+            .claim_winnings(&MockApiBech32::new("neutron").addr_make(USER_B), None)
+            .unwrap();
+
+        let query_solvency = blockchain_contract.query_solvency().unwrap();
+        assert_eq!(0, query_solvency.balance);
+        assert_eq!(0, query_solvency.unclaimed);
+        assert_eq!(0, query_solvency.surplus);
+        assert!(query_solvency.solvent);
+    }
+
+    #[derive(Debug, Clone)]
+    enum Action {
+        PlaceBet {
+            user: usize,
+            result: MarketResult,
+            amount: u128,
+        },
+        WithdrawBet {
+            user: usize,
+            result: MarketResult,
+            amount: u128,
+        },
+        Wait {
+            seconds: u64,
+        },
+        Update {
+            fee_bps: u64,
+        },
+        Score {
+            results: Vec<MarketResult>,
+        },
+        Cancel,
+        Void,
+        Expire,
+        Postpone,
+        Reschedule {
+            delay: u64,
+        },
+        WithdrawStake {
+            user: usize,
+        },
+        Claim {
+            user: usize,
+        },
+    }
+
+    const USERS: [&str; 3] = [USER_A, USER_B, USER_C];
+
+    fn result_strategy() -> impl Strategy<Value = MarketResult> {
+        prop_oneof![
+            Just(MarketResult::HOME),
+            Just(MarketResult::AWAY),
+            Just(MarketResult::DRAW),
+        ]
+    }
+
+    fn score_strategy() -> impl Strategy<Value = Action> {
+        prop_oneof![
+            result_strategy().prop_map(|result| vec![result]),
+            Just(vec![MarketResult::HOME, MarketResult::AWAY]),
+            Just(vec![MarketResult::HOME, MarketResult::DRAW]),
+        ]
+        .prop_map(|results| Action::Score { results })
+    }
+
+    /// Settles the market at the end of the sequence, scored or refunded
+    fn ending_strategy() -> impl Strategy<Value = Action> {
+        prop_oneof![
+            3 => score_strategy(),
+            1 => Just(Action::Cancel),
+            1 => Just(Action::Void),
+            1 => Just(Action::Expire),
+        ]
+    }
+
+    fn penalty_destination_strategy() -> impl Strategy<Value = PenaltyDestination> {
+        prop_oneof![
+            Just(PenaltyDestination::TREASURY),
+            Just(PenaltyDestination::POOL),
+        ]
+    }
+
+    fn action_strategy() -> impl Strategy<Value = Action> {
+        prop_oneof![
+            6 => (0..USERS.len(), result_strategy(), 1..50_000_000_u128)
+                .prop_map(|(user, result, amount)| Action::PlaceBet { user, result, amount }),
+            2 => (0..USERS.len(), result_strategy(), 1..50_000_000_u128)
+                .prop_map(|(user, result, amount)| Action::WithdrawBet { user, result, amount }),
+            2 => (0..6 * 60 * 60_u64).prop_map(|seconds| Action::Wait { seconds }),
+            1 => (0..1_000_u64).prop_map(|fee_bps| Action::Update { fee_bps }),
+            1 => score_strategy(),
+            1 => Just(Action::Cancel),
+            1 => Just(Action::Void),
+            1 => Just(Action::Expire),
+            1 => Just(Action::Postpone),
+            1 => (60..24 * 60 * 60_u64).prop_map(|delay| Action::Reschedule { delay }),
+            1 => (0..USERS.len()).prop_map(|user| Action::WithdrawStake { user }),
+            3 => (0..USERS.len()).prop_map(|user| Action::Claim { user }),
+        ]
+    }
+
+    /// Runs the action on the market, the actions rejected by the contract are ignored
+    fn run_action(blockchain_contract: &mut BlockchainContract, action: &Action) {
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+        let _ = match action {
+            Action::PlaceBet {
+                user,
+                result,
+                amount,
+            } => blockchain_contract.place_bet(
+                &MockApiBech32::new("neutron").addr_make(USERS[*user]),
+                result.clone(),
+                None,
+                &coins(*amount, NATIVE_DENOM),
+            ),
+            Action::WithdrawBet {
+                user,
+                result,
+                amount,
+            } => blockchain_contract.withdraw_bet(
+                &MockApiBech32::new("neutron").addr_make(USERS[*user]),
+                result.clone(),
+                *amount,
+            ),
+            Action::Wait { seconds } => {
+                blockchain_contract
+                    .blockchain
+                    .update_block(|block| block.time = block.time.plus_seconds(*seconds));
+                return;
+            }
+            Action::Update { fee_bps } => blockchain_contract.update_market(
+                &admin,
+                UpdateParams {
+                    admin_addr: None,
+                    treasury_addr: None,
+                    fee_bps: Some(*fee_bps),
+                    start_timestamp: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: None,
+                },
+            ),
+            Action::Score { results } => {
+                let start_timestamp = blockchain_contract
+                    .query_market()
+                    .unwrap()
+                    .market
+                    .start_timestamp;
+                blockchain_contract.blockchain.update_block(|block| {
+                    let scoring_timestamp = start_timestamp + 60 * 30;
+                    if block.time.seconds() < scoring_timestamp {
+                        block.time = Timestamp::from_seconds(scoring_timestamp);
+                    }
+                });
+                blockchain_contract.score_market_dead_heat(&admin, results.clone())
+            }
+            Action::Cancel => blockchain_contract.cancel_market(&admin),
+            Action::Void => blockchain_contract.void_market(&admin, VoidReason::ABANDONED),
+            Action::Expire => {
+                let market = blockchain_contract.query_market().unwrap().market;
+                let expiry_timestamp = market
+                    .postponement_deadline
+                    .unwrap_or(market.resolution_deadline);
+                blockchain_contract.blockchain.update_block(|block| {
+                    if block.time.seconds() < expiry_timestamp {
+                        block.time = Timestamp::from_seconds(expiry_timestamp);
+                    }
+                });
+                blockchain_contract.expire_market(&admin)
+            }
+            Action::Postpone => blockchain_contract.postpone_market(&admin),
+            Action::Reschedule { delay } => {
+                let block_time = blockchain_contract.blockchain.block_info().time.seconds();
+                blockchain_contract.reschedule_market(&admin, block_time + delay)
+            }
+            Action::WithdrawStake { user } => blockchain_contract
+                .withdraw_stake(&MockApiBech32::new("neutron").addr_make(USERS[*user]), None),
+            Action::Claim { user } => blockchain_contract
+                .claim_winnings(&MockApiBech32::new("neutron").addr_make(USERS[*user]), None),
+        };
+    }
+
+    /// Checks that the market can pay everything it may owe and that each result
+    /// winning on its own pays out the whole pot of the market, which is all its balance
+    /// while it is open, whether its bets are refunded or not
+    fn assert_solvency_invariants(blockchain_contract: &BlockchainContract) {
+        let query_solvency = blockchain_contract.query_solvency().unwrap();
+        let balance = blockchain_contract
+            .blockchain
+            .wrap()
+            .query_balance(blockchain_contract.addr(), NATIVE_DENOM)
+            .unwrap();
+        assert_eq!(balance.amount.u128(), query_solvency.balance);
+        assert!(query_solvency.solvent, "{query_solvency:?}");
+
+        for liability in query_solvency.liabilities.iter() {
+            assert_eq!(
+                query_solvency.balance,
+                liability.payout + liability.fee,
+                "{query_solvency:?}"
+            );
+        }
+        assert_eq!(
+            query_solvency.balance - query_solvency.max_liability - query_solvency.unclaimed,
+            query_solvency.surplus
+        );
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn the_market_stays_solvent_through_any_sequence_of_actions(
+            actions in prop::collection::vec(action_strategy(), 1..40),
+            ending in ending_strategy(),
+            is_drawable in any::<bool>(),
+            penalty_destination in penalty_destination_strategy(),
+        ) {
+            let start_timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("Time went backwards")
+                .as_secs()
+                + 24 * 60 * 60; // 1 day from now
+
+            let mut blockchain_contract = setup_blockchain_and_contract(
+                MockApiBech32::new("neutron").addr_make(ADMIN),
+                USERS
+                    .iter()
+                    .map(|user| {
+                        (
+                            MockApiBech32::new("neutron").addr_make(user),
+                            coins(INITIAL_BALANCE, NATIVE_DENOM),
+                        )
+                    })
+                    .collect(),
+                InstantiateMsg {
+                    admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                    treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                    denom: NATIVE_DENOM.to_string(),
+                    denom_precision: NATIVE_DENOM_PRECISION,
+                    fee_bps: DEFAULT_FEE_BPS,
+                    id: "game-cs2-test-league".to_string(),
+                    label: "CS2 - Test League - Team A vs Team B".to_string(),
+                    home_team: "Team A".to_string(),
+                    away_team: "Team B".to_string(),
+                    start_timestamp,
+                    is_drawable,
+                    resolution_grace_period: None,
+                    postponement_period: None,
+                    early_bird: Some(EarlyBirdConfig {
+                        max_multiplier: Decimal::from_atomics(2_u128, 0).unwrap(), // 2
+                        decay_period: 12 * 60 * 60,                                 // 12 hours
+                    }),
+                    withdrawal: Some(WithdrawalConfig {
+                        penalty_bps: 500,
+                        penalty_destination,
+                    }),
+                    metadata: None,
+                },
+                vec![],
+            )
+            .unwrap();
+            blockchain_contract.blockchain.update_block(|block| {
+                block.time = Timestamp::from_seconds(start_timestamp - 24 * 60 * 60);
+            });
+
+            for action in actions.iter() {
+                run_action(&mut blockchain_contract, action);
+                assert_solvency_invariants(&blockchain_contract);
+            }
+
+            // A market left postponed is rescheduled first so it can be settled
+            run_action(&mut blockchain_contract, &Action::Reschedule { delay: 60 * 60 });
+            run_action(&mut blockchain_contract, &ending);
+            // An ending rejected by the market, like a draw when it isn't drawable, is a cancel
+            run_action(&mut blockchain_contract, &Action::Cancel);
+            assert_solvency_invariants(&blockchain_contract);
+            for user in 0..USERS.len() {
+                run_action(&mut blockchain_contract, &Action::Claim { user });
+                assert_solvency_invariants(&blockchain_contract);
+            }
+
+            // Once everything is claimed nothing is left after a refund, otherwise only
+            // the rounding dust of the winnings, at most one unit per winning result of each bettor
+            let query_solvency = blockchain_contract.query_solvency().unwrap();
+            let market = blockchain_contract.query_market().unwrap().market;
+            prop_assert_eq!(0, query_solvency.unclaimed);
+            prop_assert_eq!(query_solvency.balance, query_solvency.surplus);
+            if market.status == Status::CANCELLED || market.status == Status::VOIDED || market.is_refund {
+                prop_assert_eq!(0, query_solvency.balance);
+            } else {
+                prop_assert!(query_solvency.balance < (USERS.len() * market.results.len().max(1)) as u128);
+            }
+
+            // Nothing is left to claim
+            for user in USERS.iter() {
+                let user = MockApiBech32::new("neutron").addr_make(user);
+                let claim = blockchain_contract.claim_winnings(&user, None);
+                prop_assert!(claim.is_err());
+            }
+        }
+    }
+}
+
+mod metadata {
+    use parimutuel_market::{
+        msg::UpdateParams,
+        state::{ExternalId, MarketMetadata, Participant},
+    };
+
+    use super::*;
+
+    fn metadata() -> MarketMetadata {
+        MarketMetadata {
+            sport: Some("CS2".to_string()),
+            league: Some("Test League".to_string()),
+            season: Some("2025".to_string()),
+            round: Some("Quarterfinals".to_string()),
+            venue: None,
+            home_participant: Some(Participant {
+                id: Some("team-a".to_string()),
+                logo: Some("https://example.com/team-a.png".to_string()),
+            }),
+            away_participant: Some(Participant {
+                id: Some("team-b".to_string()),
+                logo: None,
+            }),
+            external_ids: vec![ExternalId {
+                provider: "pandascore".to_string(),
+                id: "1234".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn it_properly_creates_a_market_with_metadata() {
+        let blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp: SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .expect("Time went backwards")
+                    .as_secs()
+                    + 60 * 5, // 5 minutes from now
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: Some(metadata()),
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(Some(metadata()), query_market.market.metadata);
+    }
+
+    #[test]
+    fn it_properly_updates_the_team_names_and_the_metadata() {
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Taem A vs Team B".to_string(),
+                home_team: "Taem A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp: SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .expect("Time went backwards")
+                    .as_secs()
+                    + 60 * 5, // 5 minutes from now
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(None, query_market.market.metadata);
+
+        let response = blockchain_contract
+            .update_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                UpdateParams {
+                    admin_addr: None,
+                    treasury_addr: None,
+                    fee_bps: None,
+                    start_timestamp: None,
+                    label: Some("CS2 - Test League - Team A vs Team B".to_string()),
+                    home_team: Some("Team A".to_string()),
+                    away_team: None,
+                    metadata: Some(metadata()),
+                },
+            )
+            .unwrap();
+
+        let query_market = blockchain_contract.query_market().unwrap();
+        assert_eq!(
+            "CS2 - Test League - Team A vs Team B",
+            query_market.market.label
+        );
+        assert_eq!("Team A", query_market.market.home_team);
+        assert_eq!("Team B", query_market.market.away_team);
+        assert_eq!(Some(metadata()), query_market.market.metadata);
+
+        let attributes = &response
+            .events
+            .iter()
+            .find(|event| event.ty == "wasm")
+            .unwrap()
+            .attributes;
+        for (key, value) in [
+            ("home_team", "Team A"),
+            ("away_team", ""),
+            ("sport", "CS2"),
+            ("league", "Test League"),
+            ("venue", ""),
+            ("home_participant_id", "team-a"),
+            ("home_participant_logo", "https://example.com/team-a.png"),
+            ("away_participant_logo", ""),
+            ("external_ids", "pandascore:1234"),
+        ] {
+            assert!(attributes
+                .iter()
+                .any(|attribute| attribute.key == key && attribute.value == value));
+        }
+    }
+
+    #[test]
+    fn it_cant_update_the_metadata_if_market_isnt_active() {
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp: SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .expect("Time went backwards")
+                    .as_secs()
+                    + 60 * 5, // 5 minutes from now
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        blockchain_contract
+            .cancel_market(&MockApiBech32::new("neutron").addr_make(ADMIN))
+            .unwrap();
+
+        let err = blockchain_contract
+            .update_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                UpdateParams {
+                    admin_addr: None,
+                    treasury_addr: None,
+                    fee_bps: None,
+                    start_timestamp: None,
+                    label: None,
+                    home_team: None,
+                    away_team: None,
+                    metadata: Some(metadata()),
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::MarketNotActive {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_update_the_team_names_with_blank_names() {
+        let mut blockchain_contract = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp: SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .expect("Time went backwards")
+                    .as_secs()
+                    + 60 * 5, // 5 minutes from now
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: None,
+            },
+            vec![],
+        )
+        .unwrap();
+
+        let err = blockchain_contract
+            .update_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                UpdateParams {
+                    admin_addr: None,
+                    treasury_addr: None,
+                    fee_bps: None,
+                    start_timestamp: None,
+                    label: None,
+                    home_team: None,
+                    away_team: Some("  ".to_string()),
+                    metadata: None,
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidMetadata("away_team".to_string()),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_create_a_market_with_duplicated_external_ids() {
+        let mut metadata = metadata();
+        metadata.external_ids.push(ExternalId {
+            provider: "pandascore".to_string(),
+            id: "5678".to_string(),
+        });
+
+        let err = setup_blockchain_and_contract(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            vec![],
+            InstantiateMsg {
+                admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                denom: NATIVE_DENOM.to_string(),
+                denom_precision: NATIVE_DENOM_PRECISION,
+                fee_bps: DEFAULT_FEE_BPS,
+                id: "game-cs2-test-league".to_string(),
+                label: "CS2 - Test League - Team A vs Team B".to_string(),
+                home_team: "Team A".to_string(),
+                away_team: "Team B".to_string(),
+                start_timestamp: SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .expect("Time went backwards")
+                    .as_secs()
+                    + 60 * 5, // 5 minutes from now
+                is_drawable: true,
+                resolution_grace_period: None,
+                postponement_period: None,
+                early_bird: None,
+                withdrawal: None,
+                metadata: Some(metadata),
+            },
+            vec![],
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidMetadata("external_ids".to_string()),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
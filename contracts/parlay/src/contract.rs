@@ -0,0 +1,98 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cw2::set_contract_version;
+
+use crate::{
+    error::ContractError,
+    execute::{execute_place_parlay, execute_settle_parlay, execute_update},
+    msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
+    queries::{query_config, query_parlay, query_parlays_by_address},
+    state::{Config, CONFIG, PARLAY_COUNT},
+    validation::validate_max_legs,
+};
+
+pub const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    validate_max_legs(msg.max_legs)?;
+
+    set_contract_version(
+        deps.storage,
+        format!("crates.io:{CONTRACT_NAME}"),
+        CONTRACT_VERSION,
+    )?;
+
+    let config = Config {
+        admin_addr: msg.admin_addr.clone(),
+        treasury_addr: msg.treasury_addr.clone(),
+        denom: msg.denom.clone(),
+        max_legs: msg.max_legs,
+        market_code_id: msg.market_code_id,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    PARLAY_COUNT.save(deps.storage, &0)?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "parlay")
+        .add_attribute("action", "create_parlay_contract")
+        .add_attribute("sender", info.sender)
+        .add_attribute("admin_addr", msg.admin_addr.to_string())
+        .add_attribute("treasury_addr", msg.treasury_addr.to_string())
+        .add_attribute("denom", msg.denom)
+        .add_attribute("max_legs", msg.max_legs.to_string())
+        .add_attribute("market_code_id", msg.market_code_id.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::Parlay { id } => to_json_binary(&query_parlay(deps, id)?),
+        QueryMsg::ParlaysByAddress { address } => {
+            to_json_binary(&query_parlays_by_address(deps, address)?)
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::PlaceParlay { legs, receiver } => {
+            execute_place_parlay(deps, env, info, legs, receiver)
+        }
+        ExecuteMsg::SettleParlay { id } => execute_settle_parlay(deps, env, info, id),
+        ExecuteMsg::Update {
+            admin_addr,
+            treasury_addr,
+            max_legs,
+            market_code_id,
+        } => execute_update(
+            deps,
+            info,
+            admin_addr,
+            treasury_addr,
+            max_legs,
+            market_code_id,
+        ),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    Ok(Response::default())
+}
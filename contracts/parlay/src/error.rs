@@ -0,0 +1,41 @@
+use cosmwasm_std::{Addr, OverflowError, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("Invalid max legs: {0}")]
+    InvalidMaxLegs(u32),
+
+    #[error("Invalid legs count: {0}")]
+    InvalidLegsCount(usize),
+
+    #[error("Duplicate leg market: {0}")]
+    DuplicateLegMarket(Addr),
+
+    #[error("Invalid leg market: {0}")]
+    InvalidLegMarket(Addr),
+
+    #[error("Market not active: {0}")]
+    MarketNotActive(Addr),
+
+    #[error("Minimum odds not kept: {0}")]
+    MinimumOddsNotKept(Addr),
+
+    #[error("Payment error")]
+    PaymentError {},
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Parlay not active")]
+    ParlayNotActive {},
+
+    #[error("Parlay not settleable")]
+    ParlayNotSettleable {},
+}
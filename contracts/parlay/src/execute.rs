@@ -0,0 +1,348 @@
+use std::collections::HashSet;
+
+use cosmwasm_std::{
+    coin, to_json_binary, Addr, BankMsg, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Response,
+    Uint128, WasmMsg,
+};
+use fixed_odds_market::{
+    msg::{
+        ConfigResponse as MarketConfigResponse, ExecuteMsg as MarketExecuteMsg, MarketResponse,
+        QueryMsg as MarketQueryMsg,
+    },
+    state::{SettlementOutcome, Status as MarketStatus},
+};
+
+use crate::{
+    error::ContractError,
+    logic::{calculate_leg_liabilities, calculate_parlay_payout},
+    msg::LegParams,
+    state::{Leg, LegStatus, Parlay, Status, ADDR_PARLAYS, CONFIG, PARLAYS, PARLAY_COUNT},
+    validation::{validate_legs_count, validate_max_legs},
+};
+
+/// Places a parlay combining bets on several fixed odds markets
+///
+/// The combined odds are the product of the current odds of each leg, the stake
+/// rides on every leg in order and each leg market reserves the liability for the
+/// growth of the running amount on its leg, see `calculate_leg_liabilities`.
+///
+/// It will make the following checks:
+/// - The number of legs needs to be between 2 and the max legs
+/// - The legs need to be on different markets
+/// - The leg markets need to be instantiated from the market code id
+/// - The leg markets need to reserve their liability for this parlay contract
+/// - The leg markets need to use the same denom as the parlay contract
+/// - The leg markets need to be active
+/// - The minimum odds of each leg need to be less than the current odds
+/// - The stake needs to be greater than zero
+///
+/// Each leg market will also check that the leg can be reserved, see
+/// `execute_reserve_parlay_liability` of the fixed odds market.
+pub fn execute_place_parlay(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    legs: Vec<LegParams>,
+    receiver: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let addr = match receiver {
+        Some(receiver) => deps.api.addr_validate(receiver.as_str())?,
+        None => info.sender.clone(),
+    };
+
+    validate_legs_count(legs.len(), config.max_legs)?;
+
+    let mut markets = HashSet::new();
+    for leg in legs.iter() {
+        if !markets.insert(leg.market_addr.clone()) {
+            return Err(ContractError::DuplicateLegMarket(leg.market_addr.clone()));
+        }
+    }
+
+    let stake = cw_utils::must_pay(&info, &config.denom);
+    if stake.is_err() {
+        return Err(ContractError::PaymentError {});
+    }
+    let stake = stake.unwrap();
+
+    let mut legs_odds: Vec<Decimal> = vec![];
+    for leg in legs.iter() {
+        let market_addr = deps.api.addr_validate(leg.market_addr.as_str())?;
+
+        let contract_info = deps.querier.query_wasm_contract_info(&market_addr)?;
+        if contract_info.code_id != config.market_code_id {
+            return Err(ContractError::InvalidLegMarket(market_addr));
+        }
+
+        let MarketConfigResponse {
+            config: market_config,
+        } = deps
+            .querier
+            .query_wasm_smart(&market_addr, &MarketQueryMsg::Config {})?;
+        if market_config.parlay_addr != Some(env.contract.address.clone())
+            || market_config.denom != config.denom
+        {
+            return Err(ContractError::InvalidLegMarket(market_addr));
+        }
+
+        let MarketResponse { market } = deps
+            .querier
+            .query_wasm_smart(&market_addr, &MarketQueryMsg::Market {})?;
+        if market.status != MarketStatus::ACTIVE {
+            return Err(ContractError::MarketNotActive(market_addr));
+        }
+
+        let odds = if leg.result.is_home_side() {
+            market.home_odds
+        } else {
+            market.away_odds
+        };
+        if odds < leg.min_odds {
+            return Err(ContractError::MinimumOddsNotKept(market_addr));
+        }
+
+        legs_odds.push(odds);
+    }
+
+    let liabilities = calculate_leg_liabilities(stake, &legs_odds);
+    let potential_payout = stake + liabilities.iter().sum::<Uint128>();
+    let odds = legs_odds
+        .iter()
+        .fold(Decimal::one(), |combined_odds, odds| combined_odds * odds);
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let mut parlay_legs: Vec<Leg> = vec![];
+    for ((leg, leg_odds), liability) in legs.into_iter().zip(legs_odds).zip(liabilities) {
+        messages.push(
+            WasmMsg::Execute {
+                contract_addr: leg.market_addr.to_string(),
+                msg: to_json_binary(&MarketExecuteMsg::ReserveParlayLiability {
+                    result: leg.result.clone(),
+                    amount: liability.into(),
+                })?,
+                funds: vec![],
+            }
+            .into(),
+        );
+        parlay_legs.push(Leg {
+            market_addr: leg.market_addr,
+            result: leg.result,
+            odds: leg_odds,
+            liability: liability.into(),
+            status: LegStatus::PENDING,
+        });
+    }
+
+    let id = PARLAY_COUNT.load(deps.storage)? + 1;
+    PARLAY_COUNT.save(deps.storage, &id)?;
+
+    let parlay = Parlay {
+        id,
+        owner: addr.clone(),
+        stake: stake.into(),
+        odds,
+        potential_payout: potential_payout.into(),
+        legs: parlay_legs,
+        status: Status::ACTIVE,
+        payout: 0,
+    };
+    PARLAYS.save(deps.storage, id, &parlay)?;
+    ADDR_PARLAYS.save(deps.storage, (addr.clone(), id), &true)?;
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "parlay")
+        .add_attribute("action", "place_parlay")
+        .add_attribute("sender", info.sender)
+        .add_attribute("receiver", addr)
+        .add_attribute("id", id.to_string())
+        .add_attribute("legs", parlay.legs.len().to_string())
+        .add_attribute("stake", stake.to_string())
+        .add_attribute("odds", odds.to_string())
+        .add_attribute("potential_payout", potential_payout.to_string()))
+}
+
+/// Settles a parlay once all of its leg markets are closed or cancelled
+///
+/// A leg is won when its side of the market won, void when its side was pushed or
+/// the market was cancelled and lost otherwise. Void legs count as odds of 1.
+///
+/// If a leg was lost the stake and the liabilities received from the won legs are sent
+/// to the treasury, otherwise the owner is paid the stake compounded with the odds of
+/// the won legs and any surplus left from the received liabilities is sent to the treasury.
+///
+/// The funds held for the parlay are capped at the balance of the parlay contract,
+/// so a liability that was never received can't be paid out of the funds of other parlays.
+///
+/// Anyone can settle a parlay.
+///
+/// It will make the following checks:
+/// - The parlay needs to be active
+/// - All leg markets need to be closed or cancelled
+pub fn execute_settle_parlay(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut parlay = PARLAYS.load(deps.storage, id)?;
+
+    if parlay.status != Status::ACTIVE {
+        return Err(ContractError::ParlayNotActive {});
+    }
+
+    for leg in parlay.legs.iter_mut() {
+        let MarketResponse { market } = deps
+            .querier
+            .query_wasm_smart(&leg.market_addr, &MarketQueryMsg::Market {})?;
+
+        leg.status = match (market.status, market.settlement) {
+            (MarketStatus::ACTIVE | MarketStatus::POSTPONED, _) => {
+                return Err(ContractError::ParlayNotSettleable {})
+            }
+            (MarketStatus::CLOSED, Some(settlement)) => {
+                let outcome = if leg.result.is_home_side() {
+                    settlement.home
+                } else {
+                    settlement.away
+                };
+                match outcome {
+                    SettlementOutcome::WIN => LegStatus::WON,
+                    SettlementOutcome::LOSE => LegStatus::LOST,
+                    // Half outcomes only happen on handicap markets, which can't be legs
+                    _ => LegStatus::VOID,
+                }
+            }
+            _ => LegStatus::VOID,
+        };
+    }
+
+    let stake = Uint128::from(parlay.stake);
+    let received_liabilities: Uint128 = parlay
+        .legs
+        .iter()
+        .filter(|leg| leg.status == LegStatus::WON)
+        .map(|leg| Uint128::from(leg.liability))
+        .sum();
+    let won_legs_odds: Vec<Decimal> = parlay
+        .legs
+        .iter()
+        .filter(|leg| leg.status == LegStatus::WON)
+        .map(|leg| leg.odds)
+        .collect();
+
+    let held_funds = stake.checked_add(received_liabilities)?.min(
+        deps.querier
+            .query_balance(&env.contract.address, &config.denom)?
+            .amount,
+    );
+
+    let (status, payout) = if parlay.legs.iter().any(|leg| leg.status == LegStatus::LOST) {
+        (Status::LOST, Uint128::zero())
+    } else if won_legs_odds.is_empty() {
+        (Status::CANCELLED, stake.min(held_funds))
+    } else {
+        (
+            Status::WON,
+            calculate_parlay_payout(stake, &won_legs_odds).min(held_funds),
+        )
+    };
+    let surplus = held_funds.checked_sub(payout)?;
+
+    parlay.status = status.clone();
+    parlay.payout = payout.into();
+    PARLAYS.save(deps.storage, id, &parlay)?;
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    if !payout.is_zero() {
+        messages.push(
+            BankMsg::Send {
+                to_address: parlay.owner.to_string(),
+                amount: vec![coin(payout.into(), &config.denom)],
+            }
+            .into(),
+        );
+    }
+    if !surplus.is_zero() {
+        messages.push(
+            BankMsg::Send {
+                to_address: config.treasury_addr.to_string(),
+                amount: vec![coin(surplus.into(), &config.denom)],
+            }
+            .into(),
+        );
+    }
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "parlay")
+        .add_attribute("action", "settle_parlay")
+        .add_attribute("sender", info.sender)
+        .add_attribute("id", id.to_string())
+        .add_attribute("owner", parlay.owner)
+        .add_attribute("status", status.to_string())
+        .add_attribute("payout", payout.to_string())
+        .add_attribute("treasury_amount", surplus.to_string()))
+}
+
+/// Updates the parlay contract with the new params
+///
+/// It will make the following checks:
+/// - The sender needs to be the admin
+pub fn execute_update(
+    deps: DepsMut,
+    info: MessageInfo,
+    admin_addr: Option<Addr>,
+    treasury_addr: Option<Addr>,
+    max_legs: Option<u32>,
+    market_code_id: Option<u64>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin_addr {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut admin_addr_update = String::default();
+    if let Some(admin_addr) = admin_addr {
+        config.admin_addr = admin_addr.clone();
+        admin_addr_update = admin_addr.to_string();
+    }
+
+    let mut treasury_addr_update = String::default();
+    if let Some(treasury_addr) = treasury_addr {
+        config.treasury_addr = treasury_addr.clone();
+        treasury_addr_update = treasury_addr.to_string();
+    }
+
+    let mut max_legs_update = String::default();
+    if let Some(max_legs) = max_legs {
+        validate_max_legs(max_legs)?;
+
+        config.max_legs = max_legs;
+        max_legs_update = max_legs.to_string();
+    }
+
+    let mut market_code_id_update = String::default();
+    if let Some(market_code_id) = market_code_id {
+        config.market_code_id = market_code_id;
+        market_code_id_update = market_code_id.to_string();
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("protocol", "vendetta-markets")
+        .add_attribute("market_type", "parlay")
+        .add_attribute("action", "update_parlay_contract")
+        .add_attribute("sender", info.sender)
+        .add_attribute("admin_addr", admin_addr_update)
+        .add_attribute("treasury_addr", treasury_addr_update)
+        .add_attribute("max_legs", max_legs_update)
+        .add_attribute("market_code_id", market_code_id_update))
+}
@@ -0,0 +1,122 @@
+use cosmwasm_std::{Decimal, Fraction, Uint128};
+
+/// Calculates the liability to reserve on each leg market of a parlay
+///
+/// The stake rides on every leg in order, so each leg market only covers the growth
+/// of the running amount on its leg:
+///
+/// ```ignore
+/// running_amount_0 = stake
+/// running_amount_k = running_amount_k-1 * leg_odds_k
+///
+/// leg_liability_k = running_amount_k - running_amount_k-1
+/// ```
+///
+/// The sum of the liabilities plus the stake is the potential payout of the parlay.
+///
+/// Returns the liabilities in the same order as the legs
+pub fn calculate_leg_liabilities(stake: Uint128, legs_odds: &[Decimal]) -> Vec<Uint128> {
+    let mut running_amount = stake;
+
+    legs_odds
+        .iter()
+        .map(|odds| {
+            let next_amount = running_amount.multiply_ratio(odds.numerator(), odds.denominator());
+            let liability = next_amount - running_amount;
+            running_amount = next_amount;
+            liability
+        })
+        .collect()
+}
+
+/// Calculates the payout of a parlay where none of the legs were lost
+///
+/// The running amount is compounded only with the odds of the won legs, the void
+/// legs count as odds of 1, so it is never higher than the stake plus the liabilities
+/// received from the won legs.
+pub fn calculate_parlay_payout(stake: Uint128, won_legs_odds: &[Decimal]) -> Uint128 {
+    won_legs_odds.iter().fold(stake, |running_amount, odds| {
+        running_amount.multiply_ratio(odds.numerator(), odds.denominator())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::logic::{calculate_leg_liabilities, calculate_parlay_payout};
+    use cosmwasm_std::Decimal;
+    use cosmwasm_std::Uint128;
+
+    mod calculate_leg_liabilities {
+        use super::*;
+
+        #[test]
+        fn it_calculates_the_leg_liabilities() {
+            let liabilities = calculate_leg_liabilities(
+                Uint128::new(10_000_000),
+                &[
+                    Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                    Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                ],
+            );
+            assert_eq!(
+                vec![Uint128::new(9_100_000), Uint128::new(17_381_000)],
+                liabilities
+            );
+
+            let liabilities = calculate_leg_liabilities(
+                Uint128::new(1_000_001),
+                &[
+                    Decimal::from_atomics(1_33_u128, 2).unwrap(),
+                    Decimal::from_atomics(2_57_u128, 2).unwrap(),
+                    Decimal::from_atomics(1_01_u128, 2).unwrap(),
+                ],
+            );
+            assert_eq!(
+                vec![
+                    Uint128::new(330_000),
+                    Uint128::new(2_088_101),
+                    Uint128::new(34_181)
+                ],
+                liabilities
+            );
+        }
+
+        #[test]
+        fn it_adds_up_to_the_parlay_payout() {
+            let stake = Uint128::new(1_000_001);
+            let legs_odds = [
+                Decimal::from_atomics(1_33_u128, 2).unwrap(),
+                Decimal::from_atomics(2_57_u128, 2).unwrap(),
+                Decimal::from_atomics(1_01_u128, 2).unwrap(),
+            ];
+            let liabilities = calculate_leg_liabilities(stake, &legs_odds);
+
+            assert_eq!(
+                calculate_parlay_payout(stake, &legs_odds),
+                stake + liabilities.iter().sum::<Uint128>()
+            );
+        }
+    }
+
+    mod calculate_parlay_payout {
+        use super::*;
+
+        #[test]
+        fn it_calculates_the_parlay_payout() {
+            let payout = calculate_parlay_payout(
+                Uint128::new(10_000_000),
+                &[
+                    Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                    Decimal::from_atomics(1_91_u128, 2).unwrap(),
+                ],
+            );
+            assert_eq!(Uint128::new(36_481_000), payout);
+        }
+
+        #[test]
+        fn it_returns_the_stake_without_won_legs() {
+            let payout = calculate_parlay_payout(Uint128::new(10_000_000), &[]);
+            assert_eq!(Uint128::new(10_000_000), payout);
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal};
+use fixed_odds_market::state::MarketResult;
+
+use crate::state::{Config, Parlay};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub admin_addr: Addr,
+    pub treasury_addr: Addr,
+    pub denom: String,
+    pub max_legs: u32,       // Max number of legs in a parlay, between 2 and 10
+    pub market_code_id: u64, // Code id of the fixed odds markets allowed as legs
+}
+
+#[cw_serde]
+pub struct LegParams {
+    pub market_addr: Addr,
+    pub result: MarketResult,
+    pub min_odds: Decimal,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    PlaceParlay {
+        legs: Vec<LegParams>,
+        receiver: Option<Addr>,
+    },
+    SettleParlay {
+        id: u64,
+    },
+    // Admin
+    Update {
+        admin_addr: Option<Addr>,
+        treasury_addr: Option<Addr>,
+        max_legs: Option<u32>,
+        market_code_id: Option<u64>,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(ConfigResponse)]
+    Config {},
+    #[returns(ParlayResponse)]
+    Parlay { id: u64 },
+    #[returns(ParlaysByAddressResponse)]
+    ParlaysByAddress { address: Addr },
+}
+
+#[cw_serde]
+pub struct ConfigResponse {
+    pub config: Config,
+}
+
+#[cw_serde]
+pub struct ParlayResponse {
+    pub parlay: Parlay,
+}
+
+#[cw_serde]
+pub struct ParlaysByAddressResponse {
+    pub address: Addr,
+    pub parlays: Vec<Parlay>,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
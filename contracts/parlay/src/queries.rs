@@ -0,0 +1,30 @@
+use cosmwasm_std::{Addr, Deps, Order, StdResult};
+
+use crate::{
+    msg::{ConfigResponse, ParlayResponse, ParlaysByAddressResponse},
+    state::{ADDR_PARLAYS, CONFIG, PARLAYS},
+};
+
+/// Returns the current config of the parlay contract
+pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(ConfigResponse { config })
+}
+
+/// Returns a parlay with its legs
+pub fn query_parlay(deps: Deps, id: u64) -> StdResult<ParlayResponse> {
+    let parlay = PARLAYS.load(deps.storage, id)?;
+
+    Ok(ParlayResponse { parlay })
+}
+
+/// Returns all the parlays of a specific address
+pub fn query_parlays_by_address(deps: Deps, address: Addr) -> StdResult<ParlaysByAddressResponse> {
+    let parlays = ADDR_PARLAYS
+        .prefix(address.clone())
+        .keys(deps.storage, None, None, Order::Ascending)
+        .map(|id| PARLAYS.load(deps.storage, id?))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ParlaysByAddressResponse { address, parlays })
+}
@@ -0,0 +1,80 @@
+use std::fmt;
+
+use cosmwasm_std::{Addr, Decimal};
+use cw_storage_plus::{Item, Map};
+use fixed_odds_market::state::MarketResult;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const PARLAY_COUNT: Item<u64> = Item::new("parlay_count");
+pub const PARLAYS: Map<u64, Parlay> = Map::new("parlays");
+pub const ADDR_PARLAYS: Map<(Addr, u64), bool> = Map::new("addr_parlays");
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct Config {
+    pub admin_addr: Addr,
+    pub treasury_addr: Addr,
+    pub denom: String,
+    pub max_legs: u32,       // Max number of legs in a parlay
+    pub market_code_id: u64, // Code id of the fixed odds markets allowed as legs
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub enum Status {
+    ACTIVE,
+    WON,
+    LOST,
+    CANCELLED,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::ACTIVE => write!(f, "ACTIVE"),
+            Status::WON => write!(f, "WON"),
+            Status::LOST => write!(f, "LOST"),
+            Status::CANCELLED => write!(f, "CANCELLED"),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub enum LegStatus {
+    PENDING,
+    WON,
+    LOST,
+    VOID, // Pushed or cancelled, counts as odds of 1
+}
+
+impl fmt::Display for LegStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LegStatus::PENDING => write!(f, "PENDING"),
+            LegStatus::WON => write!(f, "WON"),
+            LegStatus::LOST => write!(f, "LOST"),
+            LegStatus::VOID => write!(f, "VOID"),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct Leg {
+    pub market_addr: Addr,
+    pub result: MarketResult,
+    pub odds: Decimal,
+    pub liability: u128, // Liability reserved on the leg market
+    pub status: LegStatus,
+}
+
+#[derive(Serialize, Debug, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct Parlay {
+    pub id: u64,
+    pub owner: Addr,
+    pub stake: u128,
+    pub odds: Decimal, // Combined odds of all the legs
+    pub potential_payout: u128,
+    pub legs: Vec<Leg>,
+    pub status: Status,
+    pub payout: u128,
+}
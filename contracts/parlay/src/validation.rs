@@ -0,0 +1,20 @@
+use crate::error::ContractError;
+
+pub const MIN_LEGS: u32 = 2;
+pub const MAX_LEGS: u32 = 10;
+
+pub fn validate_max_legs(max_legs: u32) -> Result<(), ContractError> {
+    if !(MIN_LEGS..=MAX_LEGS).contains(&max_legs) {
+        return Err(ContractError::InvalidMaxLegs(max_legs));
+    }
+
+    Ok(())
+}
+
+pub fn validate_legs_count(legs_count: usize, max_legs: u32) -> Result<(), ContractError> {
+    if legs_count < MIN_LEGS as usize || legs_count > max_legs as usize {
+        return Err(ContractError::InvalidLegsCount(legs_count));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,237 @@
+use cosmwasm_std::{Addr, Coin, StdResult};
+use cw_multi_test::{
+    error::{AnyError, AnyResult},
+    App, AppBuilder, AppResponse, BankKeeper, ContractWrapper, Executor, MockApiBech32,
+};
+use derivative::Derivative;
+use fixed_odds_market::{
+    msg::{
+        BetsResponse, ExecuteMsg as MarketExecuteMsg, InstantiateMsg as MarketInstantiateMsg,
+        MarketResponse, QueryMsg as MarketQueryMsg,
+    },
+    state::MarketResult,
+};
+use parlay::{
+    contract::{execute, instantiate, query},
+    msg::{
+        ConfigResponse, ExecuteMsg, InstantiateMsg, LegParams, ParlayResponse,
+        ParlaysByAddressResponse, QueryMsg,
+    },
+};
+
+/// BlockchainContracts is a wrapper around blockchain App, the parlay contract Addr
+/// and the leg markets Addr that provides a lot of helpers for working with these contracts.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct BlockchainContracts {
+    #[derivative(Debug = "ignore")]
+    pub blockchain: App<BankKeeper, MockApiBech32>,
+    pub contract_addr: Addr,
+    pub market_addrs: Vec<Addr>,
+}
+
+impl BlockchainContracts {
+    pub fn addr(&self) -> Addr {
+        self.contract_addr.clone()
+    }
+
+    pub fn market_addr(&self, index: usize) -> Addr {
+        self.market_addrs[index].clone()
+    }
+
+    pub fn query_config(&self) -> StdResult<ConfigResponse> {
+        self.blockchain
+            .wrap()
+            .query_wasm_smart(self.addr(), &QueryMsg::Config {})
+    }
+
+    pub fn query_parlay(&self, id: u64) -> StdResult<ParlayResponse> {
+        self.blockchain
+            .wrap()
+            .query_wasm_smart(self.addr(), &QueryMsg::Parlay { id })
+    }
+
+    pub fn query_parlays_by_address(&self, address: &Addr) -> StdResult<ParlaysByAddressResponse> {
+        self.blockchain.wrap().query_wasm_smart(
+            self.addr(),
+            &QueryMsg::ParlaysByAddress {
+                address: address.clone(),
+            },
+        )
+    }
+
+    pub fn query_market(&self, index: usize) -> StdResult<MarketResponse> {
+        self.blockchain
+            .wrap()
+            .query_wasm_smart(self.market_addr(index), &MarketQueryMsg::Market {})
+    }
+
+    pub fn query_market_bets(&self, index: usize) -> StdResult<BetsResponse> {
+        self.blockchain
+            .wrap()
+            .query_wasm_smart(self.market_addr(index), &MarketQueryMsg::Bets {})
+    }
+
+    pub fn query_balance(&self, address: &Addr, denom: &str) -> u128 {
+        self.blockchain
+            .wrap()
+            .query_balance(address, denom)
+            .unwrap()
+            .amount
+            .u128()
+    }
+
+    pub fn place_parlay(
+        &mut self,
+        sender: &Addr,
+        legs: Vec<LegParams>,
+        receiver: Option<Addr>,
+        funds: &[Coin],
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::PlaceParlay { legs, receiver },
+            funds,
+        )
+    }
+
+    pub fn settle_parlay(&mut self, sender: &Addr, id: u64) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::SettleParlay { id },
+            &[],
+        )
+    }
+
+    pub fn update(
+        &mut self,
+        sender: &Addr,
+        admin_addr: Option<Addr>,
+        treasury_addr: Option<Addr>,
+        max_legs: Option<u32>,
+        market_code_id: Option<u64>,
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.addr(),
+            &ExecuteMsg::Update {
+                admin_addr,
+                treasury_addr,
+                max_legs,
+                market_code_id,
+            },
+            &[],
+        )
+    }
+
+    pub fn score_market(
+        &mut self,
+        sender: &Addr,
+        index: usize,
+        result: MarketResult,
+    ) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.market_addr(index),
+            &MarketExecuteMsg::Score { result },
+            &[],
+        )
+    }
+
+    /// Stores the fixed odds market code again under a new code id
+    pub fn store_market_code(&mut self) -> u64 {
+        self.blockchain.store_code(Box::new(ContractWrapper::new(
+            fixed_odds_market::contract::execute,
+            fixed_odds_market::contract::instantiate,
+            fixed_odds_market::contract::query,
+        )))
+    }
+
+    /// Instantiates a market that isn't one of the leg markets of the setup
+    pub fn instantiate_market(
+        &mut self,
+        sender: &Addr,
+        code_id: u64,
+        market_instantiate_msg: &MarketInstantiateMsg,
+        funds: &[Coin],
+    ) -> AnyResult<Addr> {
+        self.blockchain.instantiate_contract(
+            code_id,
+            sender.clone(),
+            market_instantiate_msg,
+            funds,
+            "Market",
+            None,
+        )
+    }
+
+    pub fn cancel_market(&mut self, sender: &Addr, index: usize) -> AnyResult<AppResponse> {
+        self.blockchain.execute_contract(
+            sender.clone(),
+            self.market_addr(index),
+            &MarketExecuteMsg::Cancel {},
+            &[],
+        )
+    }
+}
+
+/// Instantiates the parlay contract first with the market code id and then each
+/// leg market with the parlay contract as the address allowed to reserve liability
+pub fn setup_blockchain_and_contracts(
+    admin: Addr,
+    initial_balances: Vec<(Addr, Vec<Coin>)>,
+    mut instantiate_msg: InstantiateMsg,
+    market_instantiate_msgs: Vec<MarketInstantiateMsg>,
+    market_instantiate_funds: Vec<Coin>,
+) -> Result<BlockchainContracts, AnyError> {
+    let mut blockchain = AppBuilder::new()
+        .with_api(MockApiBech32::new("neutron"))
+        .build(|router, _, storage| {
+            initial_balances.into_iter().for_each(|(addr, coins)| {
+                router.bank.init_balance(storage, &addr, coins).unwrap();
+            });
+        });
+
+    let code = Box::new(ContractWrapper::new(execute, instantiate, query));
+    let code_id = blockchain.store_code(code);
+
+    let market_code = Box::new(ContractWrapper::new(
+        fixed_odds_market::contract::execute,
+        fixed_odds_market::contract::instantiate,
+        fixed_odds_market::contract::query,
+    ));
+    let market_code_id = blockchain.store_code(market_code);
+    instantiate_msg.market_code_id = market_code_id;
+
+    let contract_addr = blockchain.instantiate_contract(
+        code_id,
+        admin.clone(),
+        &instantiate_msg,
+        &[],
+        "Parlay",
+        None,
+    )?;
+
+    let mut market_addrs = vec![];
+    for mut market_instantiate_msg in market_instantiate_msgs {
+        market_instantiate_msg.parlay_addr = Some(contract_addr.clone());
+
+        let market_addr = blockchain.instantiate_contract(
+            market_code_id,
+            admin.clone(),
+            &market_instantiate_msg,
+            &market_instantiate_funds,
+            "Market",
+            None,
+        )?;
+        market_addrs.push(market_addr);
+    }
+
+    Ok(BlockchainContracts {
+        blockchain,
+        contract_addr,
+        market_addrs,
+    })
+}
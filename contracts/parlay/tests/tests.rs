@@ -0,0 +1,823 @@
+use cosmwasm_std::{coin, coins, Decimal, Timestamp};
+use cw_multi_test::MockApiBech32;
+use fixed_odds_market::{
+    msg::InstantiateMsg as MarketInstantiateMsg,
+    state::{MarketResult, Status as MarketStatus},
+};
+use helpers::{setup_blockchain_and_contracts, BlockchainContracts};
+use parlay::{
+    error::ContractError,
+    msg::{InstantiateMsg, LegParams},
+    state::{LegStatus, Status},
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const NATIVE_DENOM: &str = "denom";
+const NATIVE_DENOM_PRECISION: u32 = 6;
+const FAKE_DENOM: &str = "fakedenom";
+const ADMIN: &str = "ADMIN";
+const TREASURY: &str = "TREASURY";
+const OTHER: &str = "OTHER";
+const ANYONE: &str = "ANYONE";
+const USER_A: &str = "USER_A";
+const USER_B: &str = "USER_B";
+const INITIAL_BALANCE: u128 = 1_000_000_000_000;
+
+mod helpers;
+
+fn start_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs()
+        + 60 * 5 // 5 minutes from now
+}
+
+fn market_instantiate_msg(id: &str, start_timestamp: u64) -> MarketInstantiateMsg {
+    MarketInstantiateMsg {
+        admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+        treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+        denom: NATIVE_DENOM.to_string(),
+        denom_precision: NATIVE_DENOM_PRECISION,
+        id: id.to_string(),
+        label: format!("CS2 - Test League - {id}"),
+        kind: None,
+        home_team: "Team A".to_string(),
+        away_team: "Team B".to_string(),
+        fee_spread_odds: Decimal::from_atomics(15_u128, 2).unwrap(), // 0.15
+        max_bet_risk_factor: Decimal::from_atomics(15_u128, 1).unwrap(), // 1.5
+        seed_liquidity_amplifier: Decimal::from_atomics(3_u128, 0).unwrap(), // 3
+        initial_odds_home: Decimal::from_atomics(22_u128, 1).unwrap(), // 2.2
+        initial_odds_away: Decimal::from_atomics(18_u128, 1).unwrap(), // 1.8
+        start_timestamp,
+        parlay_addr: None, // Set to the parlay contract on setup
+        live: None,
+        resolution_grace_period: None,
+        postponement_period: None,
+        pricing_model: None,
+        trader_pubkey: None,
+        metadata: None,
+    }
+}
+
+/// Sets up the parlay contract with a max of 3 legs and 3 leg markets,
+/// every market has home odds of 1.91 and away odds of 1.56
+fn setup(start_timestamp: u64) -> BlockchainContracts {
+    setup_blockchain_and_contracts(
+        MockApiBech32::new("neutron").addr_make(ADMIN),
+        vec![
+            (
+                MockApiBech32::new("neutron").addr_make(ADMIN),
+                coins(INITIAL_BALANCE, NATIVE_DENOM),
+            ),
+            (
+                MockApiBech32::new("neutron").addr_make(USER_A),
+                vec![
+                    coin(INITIAL_BALANCE, NATIVE_DENOM),
+                    coin(INITIAL_BALANCE, FAKE_DENOM),
+                ],
+            ),
+        ],
+        InstantiateMsg {
+            admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+            treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+            denom: NATIVE_DENOM.to_string(),
+            max_legs: 3,
+            market_code_id: 0, // Set to the market code on setup
+        },
+        vec![
+            market_instantiate_msg("game-1", start_timestamp),
+            market_instantiate_msg("game-2", start_timestamp),
+            market_instantiate_msg("game-3", start_timestamp),
+        ],
+        coins(100_000_000, NATIVE_DENOM),
+    )
+    .unwrap()
+}
+
+fn leg(
+    blockchain_contracts: &BlockchainContracts,
+    index: usize,
+    result: MarketResult,
+) -> LegParams {
+    LegParams {
+        market_addr: blockchain_contracts.market_addr(index),
+        result,
+        min_odds: Decimal::one(),
+    }
+}
+
+mod create_parlay_contract {
+    use super::*;
+
+    #[test]
+    fn it_properly_creates_a_parlay_contract() {
+        let blockchain_contracts = setup(start_timestamp());
+
+        let query_config = blockchain_contracts.query_config().unwrap();
+        assert_eq!(
+            MockApiBech32::new("neutron").addr_make(ADMIN),
+            query_config.config.admin_addr
+        );
+        assert_eq!(
+            MockApiBech32::new("neutron").addr_make(TREASURY),
+            query_config.config.treasury_addr
+        );
+        assert_eq!(NATIVE_DENOM, query_config.config.denom.as_str());
+        assert_eq!(3, query_config.config.max_legs);
+        assert_eq!(2, query_config.config.market_code_id);
+
+        let query_market = blockchain_contracts.query_market(0).unwrap();
+        assert_eq!(
+            Decimal::from_atomics(1_91_u128, 2).unwrap(),
+            query_market.market.home_odds
+        );
+        assert_eq!(
+            Decimal::from_atomics(1_56_u128, 2).unwrap(),
+            query_market.market.away_odds
+        );
+    }
+
+    #[test]
+    fn it_cant_create_a_parlay_contract_with_invalid_max_legs() {
+        for max_legs in [0, 1, 11] {
+            let err = setup_blockchain_and_contracts(
+                MockApiBech32::new("neutron").addr_make(ADMIN),
+                vec![],
+                InstantiateMsg {
+                    admin_addr: MockApiBech32::new("neutron").addr_make(ADMIN),
+                    treasury_addr: MockApiBech32::new("neutron").addr_make(TREASURY),
+                    denom: NATIVE_DENOM.to_string(),
+                    max_legs,
+                    market_code_id: 0,
+                },
+                vec![],
+                vec![],
+            )
+            .unwrap_err();
+
+            assert_eq!(
+                ContractError::InvalidMaxLegs(max_legs),
+                err.downcast::<ContractError>().unwrap()
+            );
+        }
+    }
+}
+
+mod place_parlay {
+    use super::*;
+
+    #[test]
+    fn it_properly_places_a_parlay_and_reserves_the_liability_on_each_leg() {
+        let mut blockchain_contracts = setup(start_timestamp());
+
+        let legs = vec![
+            leg(&blockchain_contracts, 0, MarketResult::HOME),
+            leg(&blockchain_contracts, 1, MarketResult::HOME),
+            leg(&blockchain_contracts, 2, MarketResult::AWAY),
+        ];
+        blockchain_contracts
+            .place_parlay(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                legs,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_parlay = blockchain_contracts.query_parlay(1).unwrap();
+        assert_eq!(1, query_parlay.parlay.id);
+        assert_eq!(
+            MockApiBech32::new("neutron").addr_make(USER_A),
+            query_parlay.parlay.owner
+        );
+        assert_eq!(10_000_000, query_parlay.parlay.stake);
+        assert_eq!(
+            Decimal::from_atomics(5_691036_u128, 6).unwrap(), // 1.91 * 1.91 * 1.56
+            query_parlay.parlay.odds
+        );
+        assert_eq!(56_910_360, query_parlay.parlay.potential_payout);
+        assert_eq!(Status::ACTIVE, query_parlay.parlay.status);
+        assert_eq!(3, query_parlay.parlay.legs.len());
+        assert_eq!(9_100_000, query_parlay.parlay.legs[0].liability);
+        assert_eq!(17_381_000, query_parlay.parlay.legs[1].liability);
+        assert_eq!(20_429_360, query_parlay.parlay.legs[2].liability);
+        assert_eq!(LegStatus::PENDING, query_parlay.parlay.legs[0].status);
+
+        let query_market_bets = blockchain_contracts.query_market_bets(0).unwrap();
+        assert_eq!(9_100_000, query_market_bets.potential_payouts.home);
+        assert_eq!(9_100_000, query_market_bets.parlay_liabilities.home);
+        let query_market_bets = blockchain_contracts.query_market_bets(1).unwrap();
+        assert_eq!(17_381_000, query_market_bets.parlay_liabilities.home);
+        let query_market_bets = blockchain_contracts.query_market_bets(2).unwrap();
+        assert_eq!(0, query_market_bets.parlay_liabilities.home);
+        assert_eq!(20_429_360, query_market_bets.parlay_liabilities.away);
+
+        assert_eq!(
+            10_000_000,
+            blockchain_contracts.query_balance(&blockchain_contracts.addr(), NATIVE_DENOM)
+        );
+
+        let query_parlays_by_address = blockchain_contracts
+            .query_parlays_by_address(&MockApiBech32::new("neutron").addr_make(USER_A))
+            .unwrap();
+        assert_eq!(1, query_parlays_by_address.parlays.len());
+        assert_eq!(query_parlay.parlay, query_parlays_by_address.parlays[0]);
+    }
+
+    #[test]
+    fn the_receiver_will_be_the_owner_when_defined() {
+        let mut blockchain_contracts = setup(start_timestamp());
+
+        let legs = vec![
+            leg(&blockchain_contracts, 0, MarketResult::HOME),
+            leg(&blockchain_contracts, 1, MarketResult::HOME),
+        ];
+        blockchain_contracts
+            .place_parlay(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                legs,
+                Some(MockApiBech32::new("neutron").addr_make(USER_B)),
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let query_parlay = blockchain_contracts.query_parlay(1).unwrap();
+        assert_eq!(
+            MockApiBech32::new("neutron").addr_make(USER_B),
+            query_parlay.parlay.owner
+        );
+
+        let query_parlays_by_address = blockchain_contracts
+            .query_parlays_by_address(&MockApiBech32::new("neutron").addr_make(USER_A))
+            .unwrap();
+        assert_eq!(0, query_parlays_by_address.parlays.len());
+        let query_parlays_by_address = blockchain_contracts
+            .query_parlays_by_address(&MockApiBech32::new("neutron").addr_make(USER_B))
+            .unwrap();
+        assert_eq!(1, query_parlays_by_address.parlays.len());
+    }
+
+    #[test]
+    fn it_cant_place_a_parlay_with_an_invalid_number_of_legs() {
+        let mut blockchain_contracts = setup(start_timestamp());
+
+        let legs = vec![leg(&blockchain_contracts, 0, MarketResult::HOME)];
+        let err = blockchain_contracts
+            .place_parlay(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                legs,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidLegsCount(1),
+            err.downcast::<ContractError>().unwrap()
+        );
+
+        blockchain_contracts
+            .update(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                None,
+                None,
+                Some(2),
+                None,
+            )
+            .unwrap();
+
+        let legs = vec![
+            leg(&blockchain_contracts, 0, MarketResult::HOME),
+            leg(&blockchain_contracts, 1, MarketResult::HOME),
+            leg(&blockchain_contracts, 2, MarketResult::HOME),
+        ];
+        let err = blockchain_contracts
+            .place_parlay(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                legs,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidLegsCount(3),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_place_a_parlay_with_two_legs_on_the_same_market() {
+        let mut blockchain_contracts = setup(start_timestamp());
+
+        let legs = vec![
+            leg(&blockchain_contracts, 0, MarketResult::HOME),
+            leg(&blockchain_contracts, 0, MarketResult::AWAY),
+        ];
+        let err = blockchain_contracts
+            .place_parlay(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                legs,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::DuplicateLegMarket(blockchain_contracts.market_addr(0)),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_place_a_parlay_if_the_min_odds_requirement_of_a_leg_is_not_met() {
+        let mut blockchain_contracts = setup(start_timestamp());
+
+        let legs = vec![
+            leg(&blockchain_contracts, 0, MarketResult::HOME),
+            LegParams {
+                market_addr: blockchain_contracts.market_addr(1),
+                result: MarketResult::AWAY,
+                min_odds: Decimal::from_atomics(1_57_u128, 2).unwrap(),
+            },
+        ];
+        let err = blockchain_contracts
+            .place_parlay(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                legs,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MinimumOddsNotKept(blockchain_contracts.market_addr(1)),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_place_a_parlay_without_sending_funds_in_the_denom() {
+        let mut blockchain_contracts = setup(start_timestamp());
+
+        let legs = vec![
+            leg(&blockchain_contracts, 0, MarketResult::HOME),
+            leg(&blockchain_contracts, 1, MarketResult::HOME),
+        ];
+        let err = blockchain_contracts
+            .place_parlay(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                legs,
+                None,
+                &coins(10_000_000, FAKE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::PaymentError {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_place_a_parlay_with_a_leg_on_a_market_that_isnt_active() {
+        let mut blockchain_contracts = setup(start_timestamp());
+
+        blockchain_contracts
+            .cancel_market(&MockApiBech32::new("neutron").addr_make(ADMIN), 1)
+            .unwrap();
+
+        let legs = vec![
+            leg(&blockchain_contracts, 0, MarketResult::HOME),
+            leg(&blockchain_contracts, 1, MarketResult::HOME),
+        ];
+        let err = blockchain_contracts
+            .place_parlay(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                legs,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::MarketNotActive(blockchain_contracts.market_addr(1)),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_place_a_parlay_with_a_leg_on_a_market_of_another_code() {
+        let start_timestamp = start_timestamp();
+        let mut blockchain_contracts = setup(start_timestamp);
+
+        let code_id = blockchain_contracts.store_market_code();
+        let mut market_instantiate_msg = market_instantiate_msg("game-4", start_timestamp);
+        market_instantiate_msg.parlay_addr = Some(blockchain_contracts.addr());
+        let market_addr = blockchain_contracts
+            .instantiate_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                code_id,
+                &market_instantiate_msg,
+                &coins(100_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let legs = vec![
+            leg(&blockchain_contracts, 0, MarketResult::HOME),
+            LegParams {
+                market_addr: market_addr.clone(),
+                result: MarketResult::HOME,
+                min_odds: Decimal::one(),
+            },
+        ];
+        let err = blockchain_contracts
+            .place_parlay(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                legs,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidLegMarket(market_addr),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_place_a_parlay_with_a_leg_on_a_market_of_another_parlay_contract() {
+        let start_timestamp = start_timestamp();
+        let mut blockchain_contracts = setup(start_timestamp);
+
+        let code_id = blockchain_contracts
+            .query_config()
+            .unwrap()
+            .config
+            .market_code_id;
+        let mut market_instantiate_msg = market_instantiate_msg("game-4", start_timestamp);
+        market_instantiate_msg.parlay_addr = Some(MockApiBech32::new("neutron").addr_make(OTHER));
+        let market_addr = blockchain_contracts
+            .instantiate_market(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                code_id,
+                &market_instantiate_msg,
+                &coins(100_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        let legs = vec![
+            leg(&blockchain_contracts, 0, MarketResult::HOME),
+            LegParams {
+                market_addr: market_addr.clone(),
+                result: MarketResult::HOME,
+                min_odds: Decimal::one(),
+            },
+        ];
+        let err = blockchain_contracts
+            .place_parlay(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                legs,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidLegMarket(market_addr),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_place_a_parlay_when_a_leg_market_no_longer_accepts_bets() {
+        let start_timestamp = start_timestamp();
+        let mut blockchain_contracts = setup(start_timestamp);
+
+        blockchain_contracts.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(start_timestamp);
+        });
+
+        let legs = vec![
+            leg(&blockchain_contracts, 0, MarketResult::HOME),
+            leg(&blockchain_contracts, 1, MarketResult::HOME),
+        ];
+        let err = blockchain_contracts
+            .place_parlay(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                legs,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(
+            fixed_odds_market::error::ContractError::BetsNotAccepted {},
+            err.downcast::<fixed_odds_market::error::ContractError>()
+                .unwrap()
+        );
+    }
+}
+
+mod settle_parlay {
+    use super::*;
+
+    fn place_parlay_and_start_markets(start_timestamp: u64) -> BlockchainContracts {
+        let mut blockchain_contracts = setup(start_timestamp);
+
+        let legs = vec![
+            leg(&blockchain_contracts, 0, MarketResult::HOME),
+            leg(&blockchain_contracts, 1, MarketResult::HOME),
+            leg(&blockchain_contracts, 2, MarketResult::AWAY),
+        ];
+        blockchain_contracts
+            .place_parlay(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                legs,
+                None,
+                &coins(10_000_000, NATIVE_DENOM),
+            )
+            .unwrap();
+
+        blockchain_contracts.blockchain.update_block(|block| {
+            block.time = Timestamp::from_seconds(
+                start_timestamp + 60 * 30, // 30 minutes after the start timestamp
+            );
+        });
+
+        blockchain_contracts
+    }
+
+    #[test]
+    fn it_properly_pays_out_a_winning_parlay() {
+        let mut blockchain_contracts = place_parlay_and_start_markets(start_timestamp());
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+
+        blockchain_contracts
+            .score_market(&admin, 0, MarketResult::HOME)
+            .unwrap();
+        blockchain_contracts
+            .score_market(&admin, 1, MarketResult::HOME)
+            .unwrap();
+        blockchain_contracts
+            .score_market(&admin, 2, MarketResult::AWAY)
+            .unwrap();
+
+        assert_eq!(
+            56_910_360,
+            blockchain_contracts.query_balance(&blockchain_contracts.addr(), NATIVE_DENOM)
+        );
+
+        blockchain_contracts
+            .settle_parlay(&MockApiBech32::new("neutron").addr_make(ANYONE), 1)
+            .unwrap();
+
+        let query_parlay = blockchain_contracts.query_parlay(1).unwrap();
+        assert_eq!(Status::WON, query_parlay.parlay.status);
+        assert_eq!(56_910_360, query_parlay.parlay.payout);
+        for leg in query_parlay.parlay.legs.iter() {
+            assert_eq!(LegStatus::WON, leg.status);
+        }
+
+        assert_eq!(
+            INITIAL_BALANCE - 10_000_000 + 56_910_360,
+            blockchain_contracts.query_balance(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM
+            )
+        );
+        assert_eq!(
+            0,
+            blockchain_contracts.query_balance(
+                &MockApiBech32::new("neutron").addr_make(TREASURY),
+                NATIVE_DENOM
+            )
+        );
+        assert_eq!(
+            0,
+            blockchain_contracts.query_balance(&blockchain_contracts.addr(), NATIVE_DENOM)
+        );
+    }
+
+    #[test]
+    fn it_sends_the_stake_and_received_liabilities_to_the_treasury_when_a_leg_is_lost() {
+        let mut blockchain_contracts = place_parlay_and_start_markets(start_timestamp());
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+
+        blockchain_contracts
+            .score_market(&admin, 0, MarketResult::HOME)
+            .unwrap();
+        blockchain_contracts
+            .score_market(&admin, 1, MarketResult::AWAY)
+            .unwrap();
+        blockchain_contracts
+            .score_market(&admin, 2, MarketResult::AWAY)
+            .unwrap();
+
+        blockchain_contracts
+            .settle_parlay(&MockApiBech32::new("neutron").addr_make(ANYONE), 1)
+            .unwrap();
+
+        let query_parlay = blockchain_contracts.query_parlay(1).unwrap();
+        assert_eq!(Status::LOST, query_parlay.parlay.status);
+        assert_eq!(0, query_parlay.parlay.payout);
+        assert_eq!(LegStatus::WON, query_parlay.parlay.legs[0].status);
+        assert_eq!(LegStatus::LOST, query_parlay.parlay.legs[1].status);
+        assert_eq!(LegStatus::WON, query_parlay.parlay.legs[2].status);
+
+        assert_eq!(
+            INITIAL_BALANCE - 10_000_000,
+            blockchain_contracts.query_balance(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM
+            )
+        );
+        assert_eq!(
+            10_000_000 + 9_100_000 + 20_429_360,
+            blockchain_contracts.query_balance(
+                &MockApiBech32::new("neutron").addr_make(TREASURY),
+                NATIVE_DENOM
+            )
+        );
+        assert_eq!(
+            0,
+            blockchain_contracts.query_balance(&blockchain_contracts.addr(), NATIVE_DENOM)
+        );
+    }
+
+    #[test]
+    fn a_cancelled_leg_counts_as_odds_of_one() {
+        let mut blockchain_contracts = place_parlay_and_start_markets(start_timestamp());
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+
+        blockchain_contracts
+            .score_market(&admin, 0, MarketResult::HOME)
+            .unwrap();
+        blockchain_contracts.cancel_market(&admin, 1).unwrap();
+        blockchain_contracts
+            .score_market(&admin, 2, MarketResult::AWAY)
+            .unwrap();
+
+        let query_market = blockchain_contracts.query_market(1).unwrap();
+        assert_eq!(MarketStatus::CANCELLED, query_market.market.status);
+
+        blockchain_contracts
+            .settle_parlay(&MockApiBech32::new("neutron").addr_make(ANYONE), 1)
+            .unwrap();
+
+        let query_parlay = blockchain_contracts.query_parlay(1).unwrap();
+        assert_eq!(Status::WON, query_parlay.parlay.status);
+        assert_eq!(29_796_000, query_parlay.parlay.payout); // 10 * 1.91 * 1.56
+        assert_eq!(LegStatus::VOID, query_parlay.parlay.legs[1].status);
+
+        assert_eq!(
+            INITIAL_BALANCE - 10_000_000 + 29_796_000,
+            blockchain_contracts.query_balance(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM
+            )
+        );
+        assert_eq!(
+            10_000_000 + 9_100_000 + 20_429_360 - 29_796_000,
+            blockchain_contracts.query_balance(
+                &MockApiBech32::new("neutron").addr_make(TREASURY),
+                NATIVE_DENOM
+            )
+        );
+        assert_eq!(
+            0,
+            blockchain_contracts.query_balance(&blockchain_contracts.addr(), NATIVE_DENOM)
+        );
+    }
+
+    #[test]
+    fn it_refunds_the_stake_when_all_legs_are_cancelled() {
+        let mut blockchain_contracts = place_parlay_and_start_markets(start_timestamp());
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+
+        for index in 0..3 {
+            blockchain_contracts.cancel_market(&admin, index).unwrap();
+        }
+
+        blockchain_contracts
+            .settle_parlay(&MockApiBech32::new("neutron").addr_make(ANYONE), 1)
+            .unwrap();
+
+        let query_parlay = blockchain_contracts.query_parlay(1).unwrap();
+        assert_eq!(Status::CANCELLED, query_parlay.parlay.status);
+        assert_eq!(10_000_000, query_parlay.parlay.payout);
+
+        assert_eq!(
+            INITIAL_BALANCE,
+            blockchain_contracts.query_balance(
+                &MockApiBech32::new("neutron").addr_make(USER_A),
+                NATIVE_DENOM
+            )
+        );
+    }
+
+    #[test]
+    fn it_cant_settle_a_parlay_while_a_leg_market_is_active() {
+        let mut blockchain_contracts = place_parlay_and_start_markets(start_timestamp());
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+
+        blockchain_contracts
+            .score_market(&admin, 0, MarketResult::HOME)
+            .unwrap();
+        blockchain_contracts
+            .score_market(&admin, 1, MarketResult::HOME)
+            .unwrap();
+
+        let err = blockchain_contracts
+            .settle_parlay(&MockApiBech32::new("neutron").addr_make(ANYONE), 1)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ParlayNotSettleable {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_settle_a_parlay_twice() {
+        let mut blockchain_contracts = place_parlay_and_start_markets(start_timestamp());
+        let admin = MockApiBech32::new("neutron").addr_make(ADMIN);
+
+        for index in 0..3 {
+            blockchain_contracts.cancel_market(&admin, index).unwrap();
+        }
+
+        blockchain_contracts
+            .settle_parlay(&MockApiBech32::new("neutron").addr_make(ANYONE), 1)
+            .unwrap();
+
+        let err = blockchain_contracts
+            .settle_parlay(&MockApiBech32::new("neutron").addr_make(ANYONE), 1)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ParlayNotActive {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}
+
+mod update_parlay_contract {
+    use super::*;
+
+    #[test]
+    fn it_properly_updates_the_parlay_contract() {
+        let mut blockchain_contracts = setup(start_timestamp());
+
+        blockchain_contracts
+            .update(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                Some(MockApiBech32::new("neutron").addr_make(OTHER)),
+                Some(MockApiBech32::new("neutron").addr_make(OTHER)),
+                Some(5),
+                Some(7),
+            )
+            .unwrap();
+
+        let query_config = blockchain_contracts.query_config().unwrap();
+        assert_eq!(
+            MockApiBech32::new("neutron").addr_make(OTHER),
+            query_config.config.admin_addr
+        );
+        assert_eq!(
+            MockApiBech32::new("neutron").addr_make(OTHER),
+            query_config.config.treasury_addr
+        );
+        assert_eq!(5, query_config.config.max_legs);
+        assert_eq!(7, query_config.config.market_code_id);
+    }
+
+    #[test]
+    fn unauthorized() {
+        let mut blockchain_contracts = setup(start_timestamp());
+
+        let err = blockchain_contracts
+            .update(
+                &MockApiBech32::new("neutron").addr_make(OTHER),
+                Some(MockApiBech32::new("neutron").addr_make(OTHER)),
+                None,
+                None,
+                None,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {},
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_cant_update_the_parlay_contract_with_invalid_max_legs() {
+        let mut blockchain_contracts = setup(start_timestamp());
+
+        let err = blockchain_contracts
+            .update(
+                &MockApiBech32::new("neutron").addr_make(ADMIN),
+                None,
+                None,
+                Some(1),
+                None,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidMaxLegs(1),
+            err.downcast::<ContractError>().unwrap()
+        );
+    }
+}